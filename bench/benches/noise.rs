@@ -0,0 +1,43 @@
+use std::fs::File;
+
+use criterion::{criterion_group, criterion_main, Criterion};
+use pxtone::{Noise, NoiseOscillator, NoiseUnit, NoiseWave, OutputFormat, RenderOptions};
+
+/// Four units with every oscillator, the most a noise can hold
+fn worst_case() -> Noise {
+    let osc = |wave| Some(NoiseOscillator::new(wave, false, 440.0, 100.0, 0.0));
+    let units = (0..4)
+        .map(|_| {
+            NoiseUnit::new(
+                &[(10, 100), (500, 50)],
+                20,
+                osc(NoiseWave::Saw),
+                osc(NoiseWave::Random),
+                osc(NoiseWave::Tri),
+            )
+            .unwrap()
+        })
+        .collect();
+    Noise::from_units(44100, units).unwrap()
+}
+
+fn build(c: &mut Criterion) {
+    let options = RenderOptions::new(2, 44100, OutputFormat::I16);
+    let file = File::open(concat!(
+        env!("CARGO_MANIFEST_DIR"),
+        "/../resources/drum_bass1.ptnoise"
+    ))
+    .unwrap();
+    let drum = Noise::new(file).unwrap();
+    let worst = worst_case();
+
+    c.bench_function("build drum_bass1", |b| {
+        b.iter(|| drum.build(&options).unwrap())
+    });
+    c.bench_function("build 4 units, 1 second", |b| {
+        b.iter(|| worst.build(&options).unwrap())
+    });
+}
+
+criterion_group!(benches, build);
+criterion_main!(benches);
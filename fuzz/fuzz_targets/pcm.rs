@@ -0,0 +1,9 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let _ = pxtone::Pcm::new(Cursor::new(data));
+});
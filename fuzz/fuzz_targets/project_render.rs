@@ -0,0 +1,22 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use pxtone::{OutputFormat, Project, RenderOptions};
+
+/// Frames rendered of each song, enough to play its first events
+const FRAMES: usize = 4096;
+
+fuzz_target!(|data: &[u8]| {
+    let mut project = match Project::new(Cursor::new(data)) {
+        Ok(project) => project,
+        Err(_) => return,
+    };
+    let _ = project.to_midi();
+
+    let options = RenderOptions::new(2, 22050, OutputFormat::I16);
+    if let Ok(renderer) = project.renderer(&options) {
+        renderer.take(FRAMES).for_each(drop);
+    }
+});
@@ -0,0 +1,25 @@
+#![no_main]
+
+use std::io::Cursor;
+
+use libfuzzer_sys::fuzz_target;
+use pxtone::{EventKind, Noise, OutputFormat, Pcm, RenderOptions, Voice, Woice};
+
+/// Frames played of each woice
+const FRAMES: usize = 4096;
+
+fuzz_target!(|data: &[u8]| {
+    let woices = [
+        Noise::new(Cursor::new(data)).map(Woice::from_noise),
+        Voice::new(Cursor::new(data)).map(Woice::from_voice),
+        Pcm::new(Cursor::new(data)).map(Woice::from_pcm),
+    ];
+
+    let options = RenderOptions::new(2, 22050, OutputFormat::I16);
+    for woice in woices.iter().flatten() {
+        if let Ok(mut tone) = woice.tone(EventKind::DEFAULT_KEY, &options) {
+            tone.key_on(FRAMES as i32 / 2);
+            tone.take(FRAMES).for_each(drop);
+        }
+    }
+});
@@ -0,0 +1,82 @@
+// Reference output of pxtone 170212a for the golden tests in src/golden.rs
+//
+// build from the repository root:
+//   g++ -O1 -w -o /tmp/render resources/reference/render.cpp "pxtone-source-code 170212a"/pxtone/pxtn*.cpp
+// outside Windows, pxtnDescriptor::set_file_r first needs `_size = (int32_t)ftell( fd );` in
+// place of its fpos_t cast; files are only read from memory here
+//
+// usage: render noise in.ptnoise ch sps out.raw   16-bit samples the noise builder makes
+//        render voice in.ptvoice out.raw          readied 44.1kHz stereo samples of each unit
+//        render song in.ptcop ch sps frames out.raw
+//
+// the raw 16-bit output is wrapped in a WAV header to be kept here
+
+#include "../../pxtone-source-code 170212a/pxtone/pxtn.h"
+#include "../../pxtone-source-code 170212a/pxtone/pxtnService.h"
+
+#include <vector>
+
+static std::vector<char> slurp(const char *path)
+{
+	FILE *fp = fopen(path, "rb");
+	if (!fp) exit(1);
+	std::vector<char> buf(1 << 22);
+	buf.resize(fread(buf.data(), 1, buf.size(), fp));
+	fclose(fp);
+	return buf;
+}
+
+static void dump(const char *path, const void *data, size_t size)
+{
+	FILE *fp = fopen(path, "wb");
+	if (!fp) exit(1);
+	fwrite(data, 1, size, fp);
+	fclose(fp);
+}
+
+int main(int argc, char **argv)
+{
+	if (argc < 4) return 1;
+	std::vector<char> buf = slurp(argv[2]);
+	pxtnDescriptor doc;
+	doc.set_memory_r(buf.data(), (int32_t)buf.size());
+
+	pxtnPulse_NoiseBuilder bldr;
+	if (!bldr.Init()) return 2;
+
+	if (!strcmp(argv[1], "noise") && argc == 6) {
+		pxtnPulse_Noise noise;
+		if (noise.read(&doc) != pxtnOK) return 3;
+		pxtnPulse_PCM *pcm = bldr.BuildNoise(&noise, atoi(argv[3]), atoi(argv[4]), 16);
+		if (!pcm) return 4;
+		dump(argv[5], pcm->get_p_buf(), pcm->get_buf_size());
+		delete pcm;
+	} else if (!strcmp(argv[1], "voice") && argc == 4) {
+		pxtnWoice woice;
+		if (woice.read(&doc, pxtnWOICE_PTV) != pxtnOK) return 3;
+		if (woice.Tone_Ready(&bldr, 44100) != pxtnOK) return 4;
+		std::vector<uint8_t> out;
+		for (int32_t v = 0; v < woice.get_voice_num(); v++) {
+			const pxtnVOICEINSTANCE *inst = woice.get_instance(v);
+			out.insert(out.end(), inst->p_smp_w, inst->p_smp_w + inst->smp_body_w * 4);
+		}
+		dump(argv[3], out.data(), out.size());
+	} else if (!strcmp(argv[1], "song") && argc == 7) {
+		int32_t ch = atoi(argv[3]), sps = atoi(argv[4]), frames = atoi(argv[5]);
+		pxtnService pxtn;
+		if (pxtn.init() != pxtnOK) return 3;
+		if (!pxtn.set_destination_quality(ch, sps)) return 3;
+		if (pxtn.read(&doc) != pxtnOK) return 3;
+		if (pxtn.tones_ready() != pxtnOK) return 4;
+		pxtnVOMITPREPARATION prep = {0};
+		prep.flags = pxtnVOMITPREPFLAG_loop;
+		prep.master_volume = 1.0f;
+		if (!pxtn.moo_preparation(&prep)) return 4;
+		std::vector<int16_t> out(frames * ch);
+		if (!pxtn.Moo(out.data(), (int32_t)(out.size() * 2))) return 4;
+		dump(argv[6], out.data(), out.size() * 2);
+	} else {
+		return 1;
+	}
+	return 0;
+}
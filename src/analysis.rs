@@ -0,0 +1,86 @@
+//! Frequency analysis of rendered audio
+
+use std::f64::consts::PI;
+use std::time::Duration;
+
+use rustfft::{num_complex::Complex, FftPlanner};
+
+use crate::pulse::Pcm;
+
+/// Magnitudes over time, from `spectrogram`
+#[derive(Clone, Debug, PartialEq)]
+pub struct Spectrogram {
+    sps: u32,
+    window: usize,
+    hop: usize,
+    frames: Vec<Vec<f32>>,
+}
+
+impl Spectrogram {
+    /// Magnitude of each bin from 0Hz to half the sample rate, every hop;
+    /// a full-scale sine peaks near 1.0
+    pub fn frames(&self) -> &[Vec<f32>] {
+        &self.frames
+    }
+
+    /// Frames of audio in each window
+    pub fn window(&self) -> usize {
+        self.window
+    }
+
+    /// Frames of audio between windows
+    pub fn hop(&self) -> usize {
+        self.hop
+    }
+
+    /// Center frequency of bin `k` in Hz
+    pub fn bin_frequency(&self, k: usize) -> f32 {
+        (k as f64 * f64::from(self.sps) / self.window as f64) as f32
+    }
+
+    /// Start of the window of frame `i`
+    pub fn frame_time(&self, i: usize) -> Duration {
+        Duration::from_secs_f64((i * self.hop) as f64 / f64::from(self.sps))
+    }
+}
+
+/// Short-time Fourier transform of the channels mixed down, over Hann windows of `window`
+/// frames every `hop` frames; the last windows are padded with silence
+pub fn spectrogram(pcm: &Pcm, window: usize, hop: usize) -> Spectrogram {
+    assert!(window > 0 && hop > 0);
+    let channels = pcm.to_channels::<f32>();
+    let len = pcm.smp_num() as usize;
+    let mono = (0..len)
+        .map(|i| channels.iter().map(|c| f64::from(c[i])).sum::<f64>() / channels.len() as f64)
+        .collect::<Vec<_>>();
+
+    let hann = (0..window)
+        .map(|i| 0.5 - 0.5 * (2.0 * PI * i as f64 / window as f64).cos())
+        .collect::<Vec<_>>();
+    // a sine at full scale splits into two bins, each weighted by the window
+    let scale = 2.0 / hann.iter().sum::<f64>();
+
+    let fft = FftPlanner::new().plan_fft_forward(window);
+    let mut bins = vec![Complex::new(0.0, 0.0); window];
+    let frames = (0..len)
+        .step_by(hop)
+        .map(|start| {
+            for (i, bin) in bins.iter_mut().enumerate() {
+                let smp = mono.get(start + i).copied().unwrap_or(0.0);
+                *bin = Complex::new(smp * hann[i], 0.0);
+            }
+            fft.process(&mut bins);
+            bins[..=window / 2]
+                .iter()
+                .map(|bin| (bin.norm() * scale) as f32)
+                .collect()
+        })
+        .collect();
+
+    Spectrogram {
+        sps: pcm.sps(),
+        window,
+        hop,
+        frames,
+    }
+}
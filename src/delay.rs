@@ -0,0 +1,209 @@
+use std::{
+    fmt,
+    io::{Read, Write},
+};
+
+use crate::descriptor::read_struct;
+use crate::error::{ensure, ErrorKind, Result};
+use crate::report::Report;
+
+use num_traits::FromPrimitive;
+
+use byteorder::{LittleEndian, WriteBytesExt as _};
+
+use crate::evelist::EventKind;
+
+pub(crate) const MAX_CHANNEL: usize = 2;
+pub(crate) const MAX_GROUP_NUM: i32 = 7;
+
+/// Unit of measure of `Delay::freq`
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum DelayUnit {
+    Beat,
+    Meas,
+    Second,
+}
+
+/// Feedback delay applied to a unit group
+#[derive(Clone, PartialEq)]
+pub struct Delay {
+    played: bool,
+    unit: DelayUnit,
+    group: i32,
+    rate: f32,
+    freq: f32,
+
+    beat_num: i32,
+    beat_tempo: f32,
+    sps: u32,
+    offset: usize,
+    bufs: [Vec<i32>; MAX_CHANNEL],
+    rate_s32: i32,
+}
+
+impl Default for Delay {
+    fn default() -> Self {
+        Self::new(DelayUnit::Beat, 3.0, 33.0, 0)
+    }
+}
+
+impl Delay {
+    const SIZE: u32 = 12;
+
+    pub fn new(unit: DelayUnit, freq: f32, rate: f32, group: i32) -> Self {
+        Self {
+            played: true,
+            unit,
+            group,
+            rate,
+            freq,
+            beat_num: EventKind::DEFAULT_BEATNUM,
+            beat_tempo: EventKind::DEFAULT_BEATTEMPO as f32,
+            sps: 0,
+            offset: 0,
+            bufs: [Vec::new(), Vec::new()],
+            rate_s32: 100,
+        }
+    }
+
+    pub(crate) fn read<T: Read>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        let (size, unit, group, rate, freq): (u32, u16, u16, f32, f32) = read_struct(bytes)?;
+        ensure(size == Self::SIZE, "delay size")?;
+        // no repeats leave the delay off
+        let rate = report.finite("rate", rate, 0.0)?;
+        let freq = report.finite("frequency", freq, 0.0)?;
+
+        let unit = DelayUnit::from_u16(unit).ok_or(ErrorKind::InvalidFormat("delay unit"))?;
+        let group = i32::from(group);
+        let group = if group >= MAX_GROUP_NUM { 0 } else { group };
+        Ok(Self::new(unit, freq, rate, group))
+    }
+
+    pub(crate) fn write<W: Write>(&self, bytes: &mut W) -> Result<()> {
+        bytes.write_u32::<LittleEndian>(Self::SIZE)?;
+        bytes.write_u16::<LittleEndian>(self.unit as u16)?;
+        bytes.write_u16::<LittleEndian>(self.group as u16)?;
+        bytes.write_f32::<LittleEndian>(self.rate)?;
+        bytes.write_f32::<LittleEndian>(self.freq)?;
+        Ok(())
+    }
+
+    pub fn unit(&self) -> DelayUnit {
+        self.unit
+    }
+
+    /// Repeats per `unit`
+    pub fn freq(&self) -> f32 {
+        self.freq
+    }
+
+    /// Feedback in percent
+    pub fn rate(&self) -> f32 {
+        self.rate
+    }
+
+    pub fn group(&self) -> i32 {
+        self.group
+    }
+
+    pub fn set(&mut self, unit: DelayUnit, freq: f32, rate: f32, group: i32) {
+        self.unit = unit;
+        self.freq = freq;
+        self.rate = rate;
+        self.group = group;
+    }
+
+    pub fn played(&self) -> bool {
+        self.played
+    }
+
+    pub fn set_played(&mut self, played: bool) {
+        self.played = played;
+    }
+
+    /// Allocate the delay line for the song tempo and output rate
+    pub fn tone_ready(&mut self, beat_num: i32, beat_tempo: f32, sps: u32) {
+        self.beat_num = beat_num;
+        self.beat_tempo = beat_tempo;
+        self.sps = sps;
+        self.offset = 0;
+
+        let smp_num = if self.freq != 0.0 && self.rate != 0.0 {
+            self.rate_s32 = self.rate as i32;
+            let sps = sps as f32;
+            (match self.unit {
+                DelayUnit::Beat => sps * 60.0 / beat_tempo / self.freq,
+                DelayUnit::Meas => sps * 60.0 * beat_num as f32 / beat_tempo / self.freq,
+                DelayUnit::Second => sps / self.freq,
+            }) as usize
+        } else {
+            0
+        };
+        for buf in self.bufs.iter_mut() {
+            *buf = vec![0; smp_num];
+        }
+    }
+
+    /// Mix the delayed signal into the group and feed the group back into the line
+    pub fn tone_supple(&mut self, ch: usize, group_smps: &mut [i32]) {
+        if self.bufs[ch].is_empty() {
+            return;
+        }
+        let group = self.group as usize;
+        let a = self.bufs[ch][self.offset] * self.rate_s32 / 100;
+        if self.played {
+            group_smps[group] += a;
+        }
+        self.bufs[ch][self.offset] = group_smps[group];
+    }
+
+    pub fn tone_increment(&mut self) {
+        let smp_num = self.bufs[0].len();
+        if smp_num == 0 {
+            return;
+        }
+        self.offset += 1;
+        if self.offset >= smp_num {
+            self.offset = 0;
+        }
+    }
+
+    /// Take the delay line and its position from `other`, keeping the settings
+    pub(crate) fn copy_line(&mut self, other: &Delay) {
+        self.offset = other.offset;
+        self.bufs.clone_from(&other.bufs);
+    }
+
+    pub fn tone_clear(&mut self) {
+        for buf in self.bufs.iter_mut() {
+            buf.iter_mut().for_each(|smp| *smp = 0);
+        }
+    }
+
+    /// Apply the delay in place to mono samples in [-1.0, 1.0].
+    /// Beat and measure lengths follow the tempo of the last `tone_ready` (4/4 at 120 BPM by default).
+    pub fn process(&mut self, smp: &mut [f32], sps: u32) {
+        if self.sps != sps {
+            self.tone_ready(self.beat_num, self.beat_tempo, sps);
+        }
+        let mut group_smps = [0; MAX_GROUP_NUM as usize];
+        for s in smp.iter_mut() {
+            group_smps[self.group as usize] = (*s * 32767.0) as i32;
+            self.tone_supple(0, &mut group_smps);
+            *s = group_smps[self.group as usize] as f32 / 32767.0;
+            self.tone_increment();
+        }
+    }
+}
+
+impl fmt::Debug for Delay {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Delay")
+            .field("played", &self.played)
+            .field("unit", &self.unit)
+            .field("group", &self.group)
+            .field("rate", &self.rate)
+            .field("freq", &self.freq)
+            .finish()
+    }
+}
@@ -1,15 +1,201 @@
-use byteorder::ReadBytesExt as _;
-use std::{io::Read, mem};
+use byteorder::{LittleEndian, ReadBytesExt as _, WriteBytesExt as _};
+use std::io::{self, Read, Seek, SeekFrom, Write};
+use std::ops::Range;
 
 use crate::error::{Error, ErrorKind};
 
-/// LEB128 limited to 32 bits
+/// Reader keeping count of its position, so spans are recorded without seeking
+pub(crate) struct TrackedReader<R> {
+    inner: R,
+    offset: u64,
+}
+
+impl<R: Seek> TrackedReader<R> {
+    pub(crate) fn new(mut inner: R) -> io::Result<Self> {
+        let offset = inner.stream_position()?;
+        Ok(Self { inner, offset })
+    }
+}
+
+impl<R: Read> Read for TrackedReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> io::Result<usize> {
+        let len = self.inner.read(buf)?;
+        self.offset += len as u64;
+        Ok(len)
+    }
+}
+
+impl<R: Seek> Seek for TrackedReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> io::Result<u64> {
+        self.offset = self.inner.seek(pos)?;
+        Ok(self.offset)
+    }
+
+    fn stream_position(&mut self) -> io::Result<u64> {
+        Ok(self.offset)
+    }
+}
+
+/// Bytes a structure was read from, recorded with the `spans` feature.
+/// Spans tell where data came from, not what it is, so any two are equal.
+#[derive(Clone, Debug, Default)]
+pub(crate) struct Span {
+    #[cfg(feature = "spans")]
+    range: Option<Range<u64>>,
+}
+
+impl Span {
+    #[cfg(feature = "spans")]
+    pub(crate) fn range(&self) -> Option<Range<u64>> {
+        self.range.clone()
+    }
+}
+
+impl PartialEq for Span {
+    fn eq(&self, _: &Self) -> bool {
+        true
+    }
+}
+
+/// Structure remembering where it was read from
+#[cfg(feature = "spans")]
+pub(crate) trait Spanned {
+    fn span_mut(&mut self) -> &mut Span;
+}
+
+/// Run `read`, recording the bytes it reads as the span of the result
+#[cfg(feature = "spans")]
+pub(crate) fn read_spanned<T, S, F>(bytes: &mut T, read: F) -> Result<S, Error>
+where
+    T: Seek,
+    S: Spanned,
+    F: FnOnce(&mut T) -> Result<S, Error>,
+{
+    let start = bytes.stream_position()?;
+    let mut value = read(bytes)?;
+    value.span_mut().range = Some(start..bytes.stream_position()?);
+    Ok(value)
+}
+
+#[cfg(not(feature = "spans"))]
+pub(crate) fn read_spanned<T, S, F>(bytes: &mut T, read: F) -> Result<S, Error>
+where
+    F: FnOnce(&mut T) -> Result<S, Error>,
+{
+    read(bytes)
+}
+
+/// Most items reserved up front for a count read from a file, which may be corrupted
+const MAX_RESERVE: u32 = 0x1_0000;
+
+/// Capacity for `num` items counted in a file
+pub(crate) fn capacity(num: u32) -> usize {
+    num.min(MAX_RESERVE) as usize
+}
+
+/// `num` read from a file as a count of at most `max` items
+pub(crate) fn check_count(num: u32, max: u32) -> Result<usize, Error> {
+    if num > max {
+        return Err(Error::from(ErrorKind::CountOverflow(num, max)));
+    }
+    Ok(num as usize)
+}
+
+/// Value stored in a fixed number of little-endian bytes
+pub(crate) trait FromLeBytes: Sized {
+    const SIZE: usize;
+
+    /// Value in the first `SIZE` bytes of `bytes`
+    fn from_le_bytes(bytes: &[u8]) -> Self;
+}
+
+macro_rules! impl_from_le_bytes {
+    ($($ty:ty),+) => {
+        $(
+            impl FromLeBytes for $ty {
+                const SIZE: usize = std::mem::size_of::<$ty>();
+
+                fn from_le_bytes(bytes: &[u8]) -> Self {
+                    let mut buf = [0; std::mem::size_of::<$ty>()];
+                    buf.copy_from_slice(&bytes[..Self::SIZE]);
+                    <$ty>::from_le_bytes(buf)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_le_bytes!(u8, i8, u16, i16, u32, i32, f32);
+
+/// Records are tuples of their fields in file order
+macro_rules! impl_from_le_bytes_tuple {
+    ($($field:ident),+) => {
+        impl<$($field: FromLeBytes),+> FromLeBytes for ($($field,)+) {
+            const SIZE: usize = 0 $(+ $field::SIZE)+;
+
+            #[allow(unused_assignments)]
+            fn from_le_bytes(bytes: &[u8]) -> Self {
+                let mut offset = 0;
+                ($({
+                    let value = $field::from_le_bytes(&bytes[offset..]);
+                    offset += $field::SIZE;
+                    value
+                },)+)
+            }
+        }
+    };
+}
+
+impl_from_le_bytes_tuple!(A, B);
+impl_from_le_bytes_tuple!(A, B, C);
+impl_from_le_bytes_tuple!(A, B, C, D);
+impl_from_le_bytes_tuple!(A, B, C, D, E);
+impl_from_le_bytes_tuple!(A, B, C, D, E, F);
+impl_from_le_bytes_tuple!(A, B, C, D, E, F, G);
+impl_from_le_bytes_tuple!(A, B, C, D, E, F, G, H);
+impl_from_le_bytes_tuple!(A, B, C, D, E, F, G, H, I);
+
+/// Largest record `read_struct` reads
+const MAX_STRUCT_SIZE: usize = 64;
+
+/// Fixed-layout record read in one go, such as `(u16, u16, f32)` for two shorts and a float
+pub(crate) fn read_struct<S: FromLeBytes, T: Read + ?Sized>(bytes: &mut T) -> Result<S, Error> {
+    assert!(S::SIZE <= MAX_STRUCT_SIZE);
+    let mut buf = [0; MAX_STRUCT_SIZE];
+    bytes.read_exact(&mut buf[..S::SIZE])?;
+    Ok(S::from_le_bytes(&buf))
+}
+
+/// `size` bytes of sample data; when `place` is given they are skipped instead, with the
+/// range they lie at recorded there
+pub(crate) fn read_payload<T: Read + Seek>(
+    bytes: &mut T,
+    size: u32,
+    place: Option<&mut Range<u64>>,
+) -> Result<Vec<u8>, Error> {
+    if let Some(place) = place {
+        let start = bytes.stream_position()?;
+        *place = start..bytes.seek(SeekFrom::Current(i64::from(size)))?;
+        return Ok(Vec::new());
+    }
+    let mut data = Vec::with_capacity(capacity(size));
+    bytes.take(u64::from(size)).read_to_end(&mut data)?;
+    Ok(data)
+}
+
+/// LEB128 limited to 32 bits; a stream ending inside the value is `InvalidVar32`
 #[inline]
 fn read_var_32<T: Read + ?Sized>(bytes: &mut T) -> Result<u32, Error> {
     let mut result = 0;
 
     for i in 0..5 {
-        let byte = u32::from(bytes.read_u8()?);
+        let byte = match bytes.read_u8() {
+            Ok(byte) => u32::from(byte),
+            Err(e) if i > 0 && e.kind() == io::ErrorKind::UnexpectedEof => {
+                return Err(Error::from(ErrorKind::InvalidVar32));
+            }
+            Err(e) => return Err(e.into()),
+        };
         result |= (byte & 0x7F) << (i * 7);
         if byte & 0x80 == 0 {
             break;
@@ -21,19 +207,323 @@ fn read_var_32<T: Read + ?Sized>(bytes: &mut T) -> Result<u32, Error> {
     Ok(result)
 }
 
-pub(crate) trait ReadBytesExt: Read {
+/// Reads of the values pxtone files are made of
+pub trait ReadBytesExt: Read {
+    /// Variable-length integer, 7 bits per byte from the lowest, up to 5 bytes
     fn read_var_u32(&mut self) -> Result<u32, Error> {
         read_var_32(self)
     }
 
+    /// `read_var_u32` reinterpreted as signed
     fn read_var_i32(&mut self) -> Result<i32, Error> {
         Ok(read_var_32(self)? as i32)
     }
 
+    /// `read_var_u32` reinterpreted as the bits of a float
     fn read_var_f32(&mut self) -> Result<f32, Error> {
-        #[allow(clippy::transmute_int_to_float)]
-        Ok(unsafe { mem::transmute::<u32, f32>(read_var_32(self)?) })
+        Ok(f32::from_bits(read_var_32(self)?))
+    }
+
+    /// Variable-length count of at most `max` items
+    fn read_count(&mut self, max: u32) -> Result<usize, Error> {
+        check_count(read_var_32(self)?, max)
+    }
+
+    /// Text prefixed by its 32-bit size
+    fn read_pascal_string(&mut self) -> Result<String, Error> {
+        let size = self.read_i32::<LittleEndian>()?;
+        if size < 0 {
+            return Err(ErrorKind::InvalidFormat("string size").into());
+        }
+        self.read_sjis_string(size as usize)
+    }
+
+    /// Zero-padded text of `size` bytes
+    fn read_sjis_string(&mut self, size: usize) -> Result<String, Error> {
+        let mut buf = Vec::with_capacity(capacity(size as u32));
+        Read::take(&mut *self, size as u64).read_to_end(&mut buf)?;
+        if buf.len() < size {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        let len = buf.iter().position(|&c| c == 0).unwrap_or(buf.len());
+        Ok(decode_string(&buf[..len]))
+    }
+}
+
+/// UTF-8, or Shift-JIS as written by older editors; invalid bytes are replaced
+pub(crate) fn decode_string(bytes: &[u8]) -> String {
+    if let Ok(s) = std::str::from_utf8(bytes) {
+        return s.to_owned();
+    }
+
+    #[cfg(feature = "sjis")]
+    {
+        encoding_rs::SHIFT_JIS.decode_without_bom_handling(bytes).0.into_owned()
+    }
+    #[cfg(not(feature = "sjis"))]
+    {
+        String::from_utf8_lossy(bytes).into_owned()
     }
 }
 
 impl<R: Read + ?Sized> ReadBytesExt for R {}
+
+/// Writes of the values `ReadBytesExt` reads
+pub trait WriteBytesExt: Write {
+    /// Variable-length integer in as few bytes as it takes
+    fn write_var_u32(&mut self, value: u32) -> Result<(), Error> {
+        let mut value = value;
+        while value >= 0x80 {
+            self.write_u8((value & 0x7F) as u8 | 0x80)?;
+            value >>= 7;
+        }
+        self.write_u8(value as u8)?;
+        Ok(())
+    }
+
+    fn write_var_i32(&mut self, value: i32) -> Result<(), Error> {
+        self.write_var_u32(value as u32)
+    }
+
+    fn write_var_f32(&mut self, value: f32) -> Result<(), Error> {
+        self.write_var_u32(value.to_bits())
+    }
+
+    /// Text prefixed by its 32-bit size
+    fn write_pascal_string(&mut self, s: &str) -> Result<(), Error> {
+        let buf = encode_string(s);
+        self.write_i32::<LittleEndian>(buf.len() as i32)?;
+        self.write_all(&buf)?;
+        Ok(())
+    }
+
+    /// Text zero-padded to `size` bytes, cut at a character boundary to fit
+    fn write_sjis_string(&mut self, s: &str, size: usize) -> Result<(), Error> {
+        let mut buf = encode_string(s);
+        let mut chars = s.chars();
+        while buf.len() > size {
+            chars.next_back();
+            buf = encode_string(chars.as_str());
+        }
+        buf.resize(size, 0);
+        self.write_all(&buf)?;
+        Ok(())
+    }
+}
+
+/// Shift-JIS where it covers the text, so older editors read it, otherwise UTF-8
+pub(crate) fn encode_string(s: &str) -> Vec<u8> {
+    #[cfg(feature = "sjis")]
+    {
+        let (bytes, _, unmappable) = encoding_rs::SHIFT_JIS.encode(s);
+        if !unmappable {
+            return bytes.into_owned();
+        }
+    }
+    s.as_bytes().to_vec()
+}
+
+impl<W: Write + ?Sized> WriteBytesExt for W {}
+
+/// 32-bit size of what `write` writes after it, filled in once it is written
+pub(crate) fn write_sized<W, F>(bytes: &mut W, write: F) -> Result<(), Error>
+where
+    W: Write + Seek,
+    F: FnOnce(&mut W) -> Result<(), Error>,
+{
+    let start = bytes.stream_position()?;
+    bytes.write_u32::<LittleEndian>(0)?;
+    write(bytes)?;
+    let end = bytes.stream_position()?;
+    bytes.seek(SeekFrom::Start(start))?;
+    bytes.write_u32::<LittleEndian>((end - start - 4) as u32)?;
+    bytes.seek(SeekFrom::Start(end))?;
+    Ok(())
+}
+
+/// Tagged block kept as is because the reader does not know its tag
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct Chunk {
+    tag: [u8; 8],
+    data: Vec<u8>,
+}
+
+impl Chunk {
+    pub fn new(tag: [u8; 8], data: Vec<u8>) -> Self {
+        Self { tag, data }
+    }
+
+    pub fn tag(&self) -> &[u8; 8] {
+        &self.tag
+    }
+
+    /// Body without the 32-bit size
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub(crate) fn write<W: Write + ?Sized>(&self, bytes: &mut W) -> Result<(), Error> {
+        bytes.write_all(&self.tag)?;
+        bytes.write_u32::<LittleEndian>(self.data.len() as u32)?;
+        bytes.write_all(&self.data)?;
+        Ok(())
+    }
+
+    fn read<T: Read + ?Sized>(tag: [u8; 8], bytes: &mut T) -> Result<Self, Error> {
+        let size = bytes.read_u32::<LittleEndian>()?;
+        let mut data = Vec::new();
+        bytes.take(u64::from(size)).read_to_end(&mut data)?;
+        if data.len() != size as usize {
+            return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+        }
+        Ok(Self { tag, data })
+    }
+}
+
+/// What a chunk handler did with a tag
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ChunkFlow {
+    /// The body was read
+    Read,
+    /// The body is left to be kept as a `Chunk`
+    Unknown,
+    /// No more chunks follow
+    End,
+}
+
+/// Walk tagged blocks until `handler` reaches the end, collecting unknown ones.
+///
+/// Each block is an 8-byte tag followed by a body; `handler` is given the tag and reads the
+/// body, or returns `Unknown` without reading to keep it as a `Chunk`.
+pub fn read_chunks<T, F>(bytes: &mut T, mut handler: F) -> Result<Vec<Chunk>, Error>
+where
+    T: Read,
+    F: FnMut(&[u8; 8], &mut T) -> Result<ChunkFlow, Error>,
+{
+    let mut unknowns = Vec::new();
+    loop {
+        let mut tag = [0; 8];
+        bytes.read_exact(&mut tag)?;
+        let name = String::from_utf8_lossy(&tag);
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("chunk", tag = name.trim_end()).entered();
+        let flow = handler(&tag, bytes).map_err(|err| err.within(name.trim_end()))?;
+        match flow {
+            ChunkFlow::Read => {}
+            ChunkFlow::Unknown => unknowns.push(Chunk::read(tag, bytes)?),
+            ChunkFlow::End => return Ok(unknowns),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(not(target_arch = "wasm32"))]
+    use proptest::prelude::*;
+
+    fn var_u32(value: u32) -> Vec<u8> {
+        let mut bytes = Vec::new();
+        bytes.write_var_u32(value).unwrap();
+        bytes
+    }
+
+    fn is_invalid_var(result: Result<u32, Error>) -> bool {
+        match result {
+            Err(e) => matches!(e.kind(), ErrorKind::InvalidVar32),
+            Ok(_) => false,
+        }
+    }
+
+    #[test]
+    fn var_sizes_at_boundaries() {
+        for bits in 0..=32 {
+            let value = (1u64 << bits).saturating_sub(1).min(u64::from(u32::MAX)) as u32;
+            let size = (bits.max(1) + 6) / 7;
+            assert_eq!(var_u32(value).len(), size as usize, "{:#x}", value);
+            if bits < 32 {
+                assert_eq!(var_u32(value + 1).len(), (bits / 7 + 1) as usize);
+            }
+        }
+    }
+
+    #[test]
+    fn reinterprets_signed_and_float_edges() {
+        for &value in &[0, 1, -1, 63, -64, i32::MAX, i32::MIN] {
+            let bytes = var_u32(value as u32);
+            let mut written = Vec::new();
+            written.write_var_i32(value).unwrap();
+            assert_eq!(written, bytes);
+            assert_eq!((&bytes[..]).read_var_i32().unwrap(), value);
+        }
+        // negative values take all five bytes
+        assert_eq!(var_u32(-1i32 as u32).len(), 5);
+
+        let floats = [
+            0.0,
+            -0.0,
+            1.0,
+            -1.0,
+            f32::MIN_POSITIVE,
+            f32::from_bits(1),
+            f32::MAX,
+            f32::INFINITY,
+            f32::NEG_INFINITY,
+            f32::NAN,
+        ];
+        for &value in &floats {
+            let mut bytes = Vec::new();
+            bytes.write_var_f32(value).unwrap();
+            let read = (&bytes[..]).read_var_f32().unwrap();
+            assert_eq!(read.to_bits(), value.to_bits());
+        }
+    }
+
+    #[test]
+    fn rejects_overlong_var() {
+        let bytes = [0x80, 0x80, 0x80, 0x80, 0x80, 0x00];
+        assert!(is_invalid_var((&bytes[..]).read_var_u32()));
+    }
+
+    #[test]
+    fn empty_var_is_eof() {
+        let err = (&[][..]).read_var_u32().unwrap_err();
+        match err.kind() {
+            ErrorKind::IO(e) => assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof),
+            kind => panic!("{:?}", kind),
+        }
+    }
+
+    #[cfg(not(target_arch = "wasm32"))]
+    proptest! {
+        #[test]
+        fn var_u32_round_trips(value in any::<u32>()) {
+            let bytes = var_u32(value);
+            let mut reader = &bytes[..];
+            prop_assert_eq!(reader.read_var_u32().unwrap(), value);
+            prop_assert!(reader.is_empty());
+        }
+
+        #[test]
+        fn var_i32_round_trips(value in any::<i32>()) {
+            let mut bytes = Vec::new();
+            bytes.write_var_i32(value).unwrap();
+            prop_assert_eq!((&bytes[..]).read_var_i32().unwrap(), value);
+        }
+
+        #[test]
+        fn var_f32_round_trips(bits in any::<u32>()) {
+            let mut bytes = Vec::new();
+            bytes.write_var_f32(f32::from_bits(bits)).unwrap();
+            prop_assert_eq!((&bytes[..]).read_var_f32().unwrap().to_bits(), bits);
+        }
+
+        #[test]
+        fn truncated_var_is_invalid(value in 0x80u32.., cut in any::<prop::sample::Index>()) {
+            let bytes = var_u32(value);
+            let len = 1 + cut.index(bytes.len() - 1);
+            prop_assert!(is_invalid_var((&bytes[..len]).read_var_u32()));
+        }
+    }
+}
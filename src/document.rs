@@ -0,0 +1,321 @@
+//! Command-based editing of projects and noises, with patches to undo each command
+
+use std::mem;
+use std::ops::Range;
+
+use crate::evelist::{EveList, Event};
+use crate::master::Master;
+use crate::project::Project;
+use crate::pulse::{Noise, NoiseUnit};
+use crate::unit::Unit;
+use crate::woice::Woice;
+
+/// Model edited only through commands
+pub trait Edit {
+    type Command;
+
+    /// Apply `command`, returning the command which undoes it
+    fn edit(&mut self, command: Self::Command) -> Self::Command;
+}
+
+/// Change made by a command, kept to revert it
+#[derive(Clone, Debug, PartialEq)]
+pub struct Patch<C> {
+    undo: C,
+}
+
+impl<C> Patch<C> {
+    /// Command restoring the model as it was before the patch
+    pub fn undo_command(&self) -> &C {
+        &self.undo
+    }
+}
+
+/// Project or noise whose changes can be reverted.
+///
+/// Patches must be reverted in the reverse order of their commands; reverting one returns
+/// the patch which redoes it.
+#[derive(Clone, Debug, PartialEq)]
+pub struct Document<T> {
+    model: T,
+}
+
+impl<T: Edit> Document<T> {
+    pub fn new(model: T) -> Self {
+        Self { model }
+    }
+
+    pub fn model(&self) -> &T {
+        &self.model
+    }
+
+    /// Model for rendering; changes made through it are not recorded in patches
+    pub fn model_mut(&mut self) -> &mut T {
+        &mut self.model
+    }
+
+    pub fn into_model(self) -> T {
+        self.model
+    }
+
+    pub fn apply(&mut self, command: T::Command) -> Patch<T::Command> {
+        Patch {
+            undo: self.model.edit(command),
+        }
+    }
+
+    pub fn revert(&mut self, patch: Patch<T::Command>) -> Patch<T::Command> {
+        self.apply(patch.undo)
+    }
+}
+
+/// Edit of a `Project`
+#[derive(Clone, Debug, PartialEq)]
+pub enum ProjectCommand {
+    SetName(String),
+    SetComment(String),
+    SetMaster(Master),
+    /// Add an event as `EveList::add_event` does
+    AddEvent(Event),
+    /// Delete a unit's events as `EveList::delete_range` does
+    DeleteEvents {
+        clocks: Range<i32>,
+        unit_no: u8,
+    },
+    /// Replace `remove` events from `index` with `insert`, which must keep the list sorted
+    SpliceEvents {
+        index: usize,
+        remove: usize,
+        insert: Vec<Event>,
+    },
+    SetUnit(usize, Unit),
+    /// Append a unit; does nothing once the project has `Unit::MAX_NUM` units
+    AddUnit(Unit),
+    /// Remove the last unit with its events
+    RemoveLastUnit,
+    SetWoice(usize, Woice),
+    SetWoiceName(usize, String),
+    /// Commands applied in order
+    Batch(Vec<ProjectCommand>),
+}
+
+impl Edit for Project {
+    type Command = ProjectCommand;
+
+    fn edit(&mut self, command: ProjectCommand) -> ProjectCommand {
+        match command {
+            ProjectCommand::SetName(name) => {
+                let old = self.name().to_owned();
+                self.set_name(&name);
+                ProjectCommand::SetName(old)
+            }
+            ProjectCommand::SetComment(comment) => {
+                let old = self.comment().to_owned();
+                self.set_comment(&comment);
+                ProjectCommand::SetComment(old)
+            }
+            ProjectCommand::SetMaster(master) => {
+                ProjectCommand::SetMaster(mem::replace(self.master_mut(), master))
+            }
+            ProjectCommand::AddEvent(event) => edit_events(self.evelist_mut(), |evelist| {
+                evelist.add_event(event.clock, event.unit_no, event.kind, event.value)
+            }),
+            ProjectCommand::DeleteEvents { clocks, unit_no } => {
+                edit_events(self.evelist_mut(), |evelist| {
+                    evelist.delete_range(clocks, unit_no);
+                })
+            }
+            ProjectCommand::SpliceEvents {
+                index,
+                remove,
+                insert,
+            } => {
+                let insert_num = insert.len();
+                let removed = self.evelist_mut().splice(index, remove, insert);
+                ProjectCommand::SpliceEvents {
+                    index,
+                    remove: insert_num,
+                    insert: removed,
+                }
+            }
+            ProjectCommand::SetUnit(index, unit) => {
+                ProjectCommand::SetUnit(index, mem::replace(&mut self.units_mut()[index], unit))
+            }
+            ProjectCommand::AddUnit(unit) => match self.add_unit(unit) {
+                Ok(_) => ProjectCommand::RemoveLastUnit,
+                Err(_) => ProjectCommand::Batch(Vec::new()),
+            },
+            ProjectCommand::RemoveLastUnit => {
+                let unit_no = self.units().len() - 1;
+                let undo_events = edit_events(self.evelist_mut(), |evelist| {
+                    evelist.delete_range(0..i32::MAX, unit_no as u8);
+                });
+                let unit = self.pop_unit().unwrap();
+                ProjectCommand::Batch(vec![ProjectCommand::AddUnit(unit), undo_events])
+            }
+            ProjectCommand::SetWoice(index, woice) => {
+                ProjectCommand::SetWoice(index, mem::replace(&mut self.woices_mut()[index], woice))
+            }
+            ProjectCommand::SetWoiceName(index, name) => {
+                let old = self.woice_name(index).to_owned();
+                self.set_woice_name(index, &name);
+                ProjectCommand::SetWoiceName(index, old)
+            }
+            ProjectCommand::Batch(commands) => {
+                let mut undo = commands
+                    .into_iter()
+                    .map(|command| self.edit(command))
+                    .collect::<Vec<_>>();
+                undo.reverse();
+                ProjectCommand::Batch(undo)
+            }
+        }
+    }
+}
+
+/// Run `f` on the list, returning the splice which restores its events
+fn edit_events<F: FnOnce(&mut EveList)>(evelist: &mut EveList, f: F) -> ProjectCommand {
+    let before = evelist.events().to_vec();
+    f(evelist);
+    let after = evelist.events();
+
+    let prefix = before.iter().zip(after).take_while(|(b, a)| b == a).count();
+    let suffix = before[prefix..]
+        .iter()
+        .rev()
+        .zip(after[prefix..].iter().rev())
+        .take_while(|(b, a)| b == a)
+        .count();
+    ProjectCommand::SpliceEvents {
+        index: prefix,
+        remove: after.len() - prefix - suffix,
+        insert: before[prefix..before.len() - suffix].to_vec(),
+    }
+}
+
+/// Edit of a `Noise`
+#[derive(Clone, Debug, PartialEq)]
+pub enum NoiseCommand {
+    SetSampleCount44k(u32),
+    SetUnit(usize, NoiseUnit),
+    /// Append a unit; does nothing once the noise has four units
+    AddUnit(NoiseUnit),
+    RemoveLastUnit,
+    /// Commands applied in order
+    Batch(Vec<NoiseCommand>),
+}
+
+impl Edit for Noise {
+    type Command = NoiseCommand;
+
+    fn edit(&mut self, command: NoiseCommand) -> NoiseCommand {
+        match command {
+            NoiseCommand::SetSampleCount44k(smp_num_44k) => {
+                let old = self.sample_count_44k();
+                self.set_sample_count_44k(smp_num_44k);
+                NoiseCommand::SetSampleCount44k(old)
+            }
+            NoiseCommand::SetUnit(index, unit) => {
+                NoiseCommand::SetUnit(index, mem::replace(&mut self.units_mut()[index], unit))
+            }
+            NoiseCommand::AddUnit(unit) => match self.push_unit(unit) {
+                Ok(()) => NoiseCommand::RemoveLastUnit,
+                Err(_) => NoiseCommand::Batch(Vec::new()),
+            },
+            NoiseCommand::RemoveLastUnit => NoiseCommand::AddUnit(self.pop_unit().unwrap()),
+            NoiseCommand::Batch(commands) => {
+                let mut undo = commands
+                    .into_iter()
+                    .map(|command| self.edit(command))
+                    .collect::<Vec<_>>();
+                undo.reverse();
+                NoiseCommand::Batch(undo)
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::evelist::EventKind;
+    use crate::presets;
+    use std::io::Cursor;
+
+    fn bytes(project: &Project) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::new());
+        project.write(&mut bytes).unwrap();
+        bytes.into_inner()
+    }
+
+    fn sample() -> Project {
+        let sample = include_bytes!("../pxtone-source-code 170212a/sample data/sample.ptcop");
+        Project::new(Cursor::new(&sample[..])).unwrap()
+    }
+
+    #[test]
+    fn reverts_project_commands() {
+        let mut document = Document::new(sample());
+        let original = bytes(document.model());
+        let events = document.model().evelist().len();
+
+        let patch = document.apply(ProjectCommand::Batch(vec![
+            ProjectCommand::SetName("edited".to_owned()),
+            ProjectCommand::SetMaster(Master::new(3, 90.0, 480)),
+            ProjectCommand::AddEvent(Event {
+                clock: 240,
+                unit_no: 0,
+                kind: EventKind::On,
+                value: 960,
+            }),
+            ProjectCommand::DeleteEvents {
+                clocks: 1920..7680,
+                unit_no: 1,
+            },
+            ProjectCommand::AddUnit(Unit::new("new")),
+        ]));
+        assert_eq!(document.model().name(), "edited");
+        assert_ne!(document.model().evelist().len(), events);
+        assert_ne!(bytes(document.model()), original);
+
+        let redo = document.revert(patch);
+        assert_eq!(bytes(document.model()), original);
+
+        let edited = {
+            let mut project = sample();
+            project.edit(redo.undo_command().clone());
+            bytes(&project)
+        };
+        document.revert(redo);
+        assert_eq!(bytes(document.model()), edited);
+    }
+
+    #[test]
+    fn reverts_removed_units() {
+        let mut document = Document::new(sample());
+        let original = bytes(document.model());
+        let units = document.model().units().len();
+
+        let patch = document.apply(ProjectCommand::RemoveLastUnit);
+        assert_eq!(document.model().units().len(), units - 1);
+        document.revert(patch);
+        assert_eq!(bytes(document.model()), original);
+    }
+
+    #[test]
+    fn reverts_noise_commands() {
+        let mut document = Document::new(presets::snare_drum());
+        let original = document.model().clone();
+        let unit = document.model().units()[0].clone();
+
+        let patch = document.apply(NoiseCommand::Batch(vec![
+            NoiseCommand::SetSampleCount44k(4410),
+            NoiseCommand::AddUnit(unit),
+            NoiseCommand::RemoveLastUnit,
+            NoiseCommand::RemoveLastUnit,
+        ]));
+        assert_ne!(document.model(), &original);
+        document.revert(patch);
+        assert_eq!(document.model(), &original);
+    }
+}
@@ -0,0 +1,232 @@
+//! Signal processing for audio outside of rendering
+
+use std::f64::consts::PI;
+
+/// How `Resampler` reads between input frames
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum ResampleQuality {
+    /// The frame at or before the position, as pxtone steps through sampled woices
+    Nearest,
+    /// Straight line between the frames around the position
+    #[default]
+    Linear,
+    /// Windowed sinc over 32 frames, filtering out what the output rate cannot hold
+    Sinc,
+}
+
+impl ResampleQuality {
+    /// Frames on each side of a position the sinc reads
+    const SINC_TAPS: usize = 16;
+
+    /// Input frames read before and after a position
+    fn reach(self) -> (usize, usize) {
+        match self {
+            ResampleQuality::Nearest => (0, 0),
+            ResampleQuality::Linear => (0, 1),
+            ResampleQuality::Sinc => (Self::SINC_TAPS - 1, Self::SINC_TAPS),
+        }
+    }
+}
+
+/// Sample rate converter for interleaved f32 frames, fed and drained in pieces
+#[derive(Clone, Debug)]
+pub struct Resampler {
+    ch: usize,
+    from_rate: u32,
+    to_rate: u32,
+    quality: ResampleQuality,
+    /// Interleaved input from the earliest frame still read
+    input: Vec<f32>,
+    /// Input frame of the next output frame
+    pos: usize,
+    /// Part of an input frame past `pos`, in `to_rate`ths
+    frac: u32,
+}
+
+impl Resampler {
+    pub fn new(ch: u16, from_rate: u32, to_rate: u32, quality: ResampleQuality) -> Self {
+        assert!(ch > 0 && from_rate > 0 && to_rate > 0);
+        let ch = usize::from(ch);
+        let (before, _) = quality.reach();
+        Self {
+            ch,
+            from_rate,
+            to_rate,
+            quality,
+            // silence before the first frame
+            input: vec![0.0; before * ch],
+            pos: before,
+            frac: 0,
+        }
+    }
+
+    pub fn ch(&self) -> u16 {
+        self.ch as u16
+    }
+
+    pub fn from_rate(&self) -> u32 {
+        self.from_rate
+    }
+
+    pub fn to_rate(&self) -> u32 {
+        self.to_rate
+    }
+
+    pub fn quality(&self) -> ResampleQuality {
+        self.quality
+    }
+
+    /// Queue interleaved input frames
+    pub fn push(&mut self, samples: &[f32]) {
+        assert_eq!(samples.len() % self.ch, 0);
+        self.input.extend_from_slice(samples);
+    }
+
+    /// Queue the silence after the last input frame, so that `pull` reaches its end
+    pub fn flush(&mut self) {
+        let (_, after) = self.quality.reach();
+        self.input.resize(self.input.len() + after * self.ch, 0.0);
+    }
+
+    /// Output frames `pull` can write from the queued input
+    pub fn available(&self) -> usize {
+        let (_, after) = self.quality.reach();
+        let frames = self.input.len() / self.ch;
+        if frames <= self.pos + after {
+            return 0;
+        }
+        // whole input frames the position may still advance
+        let room = (frames - self.pos - after) as u64;
+        let (from, to) = (u64::from(self.from_rate), u64::from(self.to_rate));
+        ((room * to - u64::from(self.frac)).div_ceil(from)) as usize
+    }
+
+    /// Write interleaved output frames to `out` as far as the input goes, returning how many
+    /// frames were written
+    pub fn pull(&mut self, out: &mut [f32]) -> usize {
+        assert_eq!(out.len() % self.ch, 0);
+        let frames = (out.len() / self.ch).min(self.available());
+        let mut weights = Vec::new();
+        for frame in out.chunks_exact_mut(self.ch).take(frames) {
+            let t = f64::from(self.frac) / f64::from(self.to_rate);
+            match self.quality {
+                ResampleQuality::Nearest => {
+                    frame.copy_from_slice(&self.input[self.pos * self.ch..][..self.ch]);
+                }
+                ResampleQuality::Linear => {
+                    let a = &self.input[self.pos * self.ch..];
+                    let b = &self.input[(self.pos + 1) * self.ch..];
+                    for (c, out) in frame.iter_mut().enumerate() {
+                        *out = (f64::from(a[c]) + f64::from(b[c] - a[c]) * t) as f32;
+                    }
+                }
+                ResampleQuality::Sinc => {
+                    self.sinc_weights(t, &mut weights);
+                    let start = self.pos + 1 - ResampleQuality::SINC_TAPS;
+                    for (c, out) in frame.iter_mut().enumerate() {
+                        *out = weights
+                            .iter()
+                            .enumerate()
+                            .map(|(k, w)| w * f64::from(self.input[(start + k) * self.ch + c]))
+                            .sum::<f64>() as f32;
+                    }
+                }
+            }
+
+            self.frac += self.from_rate;
+            self.pos += (self.frac / self.to_rate) as usize;
+            self.frac %= self.to_rate;
+        }
+
+        // drop frames no later output reads
+        let (before, _) = self.quality.reach();
+        let consumed = (self.pos - before).min(self.input.len() / self.ch);
+        self.input.drain(..consumed * self.ch);
+        self.pos -= consumed;
+        frames
+    }
+
+    /// Weights of the frames around a position `t` past a frame, low-passed below the lower
+    /// of the rates and summing to 1
+    fn sinc_weights(&self, t: f64, weights: &mut Vec<f64>) {
+        let taps = ResampleQuality::SINC_TAPS as f64;
+        let cutoff = (f64::from(self.to_rate) / f64::from(self.from_rate)).min(1.0);
+        weights.clear();
+        weights.extend((0..2 * ResampleQuality::SINC_TAPS).map(|k| {
+            let x = k as f64 + 1.0 - taps - t;
+            let sinc = if x == 0.0 {
+                1.0
+            } else {
+                (PI * cutoff * x).sin() / (PI * cutoff * x)
+            };
+            // Blackman
+            let window = 0.42 + 0.5 * (PI * x / taps).cos() + 0.08 * (2.0 * PI * x / taps).cos();
+            sinc * window
+        }));
+        let sum = weights.iter().sum::<f64>();
+        for w in weights.iter_mut() {
+            *w /= sum;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const QUALITIES: [ResampleQuality; 3] = [
+        ResampleQuality::Nearest,
+        ResampleQuality::Linear,
+        ResampleQuality::Sinc,
+    ];
+
+    /// Resample `frames` stereo frames at half scale, pushing and pulling in uneven pieces
+    fn resample(quality: ResampleQuality, from_rate: u32, to_rate: u32, frames: usize) -> Vec<f32> {
+        let mut resampler = Resampler::new(2, from_rate, to_rate, quality);
+        let input = vec![0.5; frames * 2];
+        let mut output = Vec::new();
+        let mut buf = [0.0; 2 * 37];
+        for piece in input.chunks(2 * 101) {
+            resampler.push(piece);
+            loop {
+                let written = resampler.pull(&mut buf);
+                output.extend_from_slice(&buf[..written * 2]);
+                if written == 0 {
+                    break;
+                }
+            }
+        }
+        resampler.flush();
+        while resampler.available() > 0 {
+            let written = resampler.pull(&mut buf);
+            output.extend_from_slice(&buf[..written * 2]);
+        }
+        output
+    }
+
+    #[test]
+    fn keeps_length() {
+        for &quality in QUALITIES.iter() {
+            for &(from_rate, to_rate) in [(44100, 48000), (48000, 22050), (44100, 44100)].iter() {
+                let frames = 4410;
+                let output = resample(quality, from_rate, to_rate, frames);
+                let expected = (frames as u64 * u64::from(to_rate)).div_ceil(u64::from(from_rate));
+                assert_eq!(output.len(), expected as usize * 2, "{:?}", quality);
+            }
+        }
+    }
+
+    #[test]
+    fn keeps_dc_gain() {
+        for &quality in QUALITIES.iter() {
+            for &(from_rate, to_rate) in [(44100, 48000), (48000, 22050), (22050, 44100)].iter() {
+                let output = resample(quality, from_rate, to_rate, 4410);
+                // away from the silence around the input
+                let edge = 4 * ResampleQuality::SINC_TAPS * 2;
+                for &sample in &output[edge..output.len() - edge] {
+                    assert!((sample - 0.5).abs() < 1e-4, "{:?} {}", quality, sample);
+                }
+            }
+        }
+    }
+}
@@ -0,0 +1,165 @@
+use std::iter;
+
+/// Shape of the ramps between envelope levels
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Straight ramps, as pxtone plays them
+    #[default]
+    Linear,
+    /// Fast at first, then settling into the target level
+    Exponential,
+}
+
+impl Interpolation {
+    const CURVE: f64 = 4.0;
+
+    /// Level `pos` samples into a ramp of `len` samples from `start` to `end`
+    pub fn level(self, start: f64, end: f64, pos: i32, len: i32) -> f64 {
+        self.ramp(start, end, f64::from(pos) / f64::from(len))
+    }
+
+    /// Level at `t` from 0.0 to 1.0 through a ramp from `start` to `end`
+    fn ramp(self, start: f64, end: f64, t: f64) -> f64 {
+        match self {
+            Interpolation::Linear => start + (end - start) * t,
+            Interpolation::Exponential => {
+                start
+                    + (end - start) * (1.0 - (-Self::CURVE * t).exp())
+                        / (1.0 - (-Self::CURVE).exp())
+            }
+        }
+    }
+}
+
+/// Levels from key on: ramps through the points, holds the last one, then releases to silence
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Envelope {
+    fps: u32,
+    points: Vec<(i32, i32)>,
+    sustain: Option<i32>,
+    release: i32,
+    interpolation: Interpolation,
+}
+
+impl Envelope {
+    /// `points` are (frames since the previous point, level) with `fps` frames per second
+    pub fn new(fps: u32, points: Vec<(i32, i32)>, release: i32) -> Self {
+        assert_ne!(fps, 0);
+        Self {
+            fps,
+            points,
+            sustain: None,
+            release,
+            interpolation: Interpolation::Linear,
+        }
+    }
+
+    pub fn fps(&self) -> u32 {
+        self.fps
+    }
+
+    pub fn points(&self) -> &[(i32, i32)] {
+        &self.points
+    }
+
+    /// Frames the last level is held for; `None` holds it until the note is released
+    pub fn sustain(&self) -> Option<i32> {
+        self.sustain
+    }
+
+    pub fn set_sustain(&mut self, sustain: Option<i32>) {
+        self.sustain = sustain;
+    }
+
+    /// Frames taken to fall to silence
+    pub fn release(&self) -> i32 {
+        self.release
+    }
+
+    pub fn set_release(&mut self, release: i32) {
+        self.release = release;
+    }
+
+    pub fn interpolation(&self) -> Interpolation {
+        self.interpolation
+    }
+
+    pub fn set_interpolation(&mut self, interpolation: Interpolation) {
+        self.interpolation = interpolation;
+    }
+
+    /// Length of `frames` in samples at `sps`
+    pub fn samples(&self, frames: i32, sps: u32) -> i32 {
+        (i64::from(frames) * i64::from(sps) / i64::from(self.fps)) as i32
+    }
+
+    pub fn release_samples(&self, sps: u32) -> i32 {
+        self.samples(self.release, sps)
+    }
+
+    /// Levels per sample at `sps` until the sustain, scaled so that `top` is 1.0;
+    /// a timed sustain and its release are included
+    pub fn head_levels(&self, sps: u32, top: f64) -> Vec<f64> {
+        let mut levels = Vec::new();
+        let mut start = 0.0;
+        for &(x, y) in self.points.iter() {
+            let len = self.samples(x, sps);
+            let end = f64::from(y) / top;
+            levels.extend((0..len).map(|pos| self.interpolation.level(start, end, pos, len)));
+            start = end;
+        }
+
+        if let Some(sustain) = self.sustain {
+            levels.extend(iter::repeat_n(start, self.samples(sustain, sps) as usize));
+            let len = self.release_samples(sps);
+            levels.extend((0..len).map(|pos| self.interpolation.level(start, 0.0, pos, len)));
+            levels.push(0.0);
+        }
+        levels
+    }
+
+    /// Level `ms` milliseconds after key on, with the note held
+    pub fn value_at(&self, ms: f64) -> f64 {
+        let frames = ms * f64::from(self.fps) / 1000.0;
+        let mut pos = 0.0;
+        let mut start = 0.0;
+        for &(x, y) in self.points.iter() {
+            let end = f64::from(y);
+            let len = f64::from(x);
+            if frames < pos + len {
+                return self.interpolation.ramp(start, end, (frames - pos) / len);
+            }
+            pos += len;
+            start = end;
+        }
+
+        match self.sustain {
+            None => start,
+            Some(sustain) if frames < pos + f64::from(sustain) => start,
+            Some(sustain) => {
+                let len = f64::from(self.release);
+                let t = (frames - pos - f64::from(sustain)) / len;
+                if t < 1.0 {
+                    self.interpolation.ramp(start, 0.0, t)
+                } else {
+                    0.0
+                }
+            }
+        }
+    }
+
+    /// Levels per sample at `sps` from key on, with the note held forever
+    pub fn sample_iter(&self, sps: u32) -> impl Iterator<Item = f64> {
+        self.head_levels(sps, 1.0)
+            .into_iter()
+            .chain(iter::repeat(self.sustain_level(1.0)))
+    }
+
+    /// Level held after `head_levels` until the note is released, scaled by `top`
+    pub fn sustain_level(&self, top: f64) -> f64 {
+        match (self.sustain, self.points.last()) {
+            (None, Some(&(_, y))) => f64::from(y) / top,
+            _ => 0.0,
+        }
+    }
+}
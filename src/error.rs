@@ -1,11 +1,23 @@
-use failure::{Backtrace, Context, Fail};
-use std::{fmt, io};
+// failure_derive expands its impls inside a const item
+#![allow(non_local_definitions)]
+
+use failure::{Context, Fail};
+use std::error::Error as StdError;
+use std::fmt;
+use std::io::{self, Seek};
+use std::time::Duration;
+
+use crate::pulse::OutputFormat;
+use crate::report::Warning;
 
 pub type Result<T> = std::result::Result<T, Error>;
 
 #[derive(Debug)]
 pub struct Error {
-    inner: Context<ErrorKind>,
+    inner: Box<Context<ErrorKind>>,
+    /// Chunk and unit being read, outermost first
+    location: String,
+    offset: Option<u64>,
 }
 
 #[derive(Debug, Fail)]
@@ -15,48 +27,183 @@ pub enum ErrorKind {
 
     #[fail(display = "Too long variable-length code")]
     InvalidVar32,
+
+    #[fail(display = "Ogg Vorbis is not supported")]
+    OggVorbisNotSupported,
+
+    #[fail(display = "{} items exceed the limit of {}", _0, _1)]
+    CountOverflow(u32, u32),
+
+    #[fail(display = "Invalid format: {}", _0)]
+    InvalidFormat(&'static str),
+
+    /// File revision, such as 20120418, newer than the newest one read
+    #[fail(display = "Version {} is newer than the supported {}", found, max)]
+    UnsupportedVersion { found: u32, max: u32 },
+
+    /// Value rejected by strict parsing; its location is on the `Error`
+    #[fail(display = "Invalid data: {}", _0)]
+    InvalidData(Warning),
+
+    #[fail(display = "Invalid render options: {}", _0)]
+    InvalidRenderOptions(&'static str),
+
+    #[fail(display = "Rendering was cancelled")]
+    Cancelled,
+
+    /// Index past the units of a project, which holds this many
+    #[fail(display = "Unit {} is out of {} units", _0, _1)]
+    UnitOutOfRange(usize, usize),
+
+    /// Renderer state saved from a song with other units or delays
+    #[fail(display = "Renderer state was saved from another song")]
+    StateMismatch,
+
+    /// Whole render running past `RenderOptions::max_duration`
+    #[fail(display = "Output is longer than the limit of {:?}", _0)]
+    TooLong(Duration),
+
+    /// Samples, or bytes of them, beyond what a buffer or WAV header can hold
+    #[fail(display = "{} {} exceed the limit of {}", _0, _1, _2)]
+    SampleOverflow(u64, &'static str, u64),
+
+    #[fail(display = "{:?} samples cannot be written to {}", _0, _1)]
+    UnsupportedOutputFormat(OutputFormat, &'static str),
+
+    /// WAV format tag other than PCM, IEEE float or extensible
+    #[fail(display = "Unsupported WAV format tag {:#x}", _0)]
+    UnsupportedFormatTag(u16),
+
+    /// Bits per sample other than 8, 16 or 24-bit PCM or 32-bit float
+    #[fail(display = "Unsupported bit depth {}", _0)]
+    UnsupportedBitDepth(u16),
+
+    #[fail(display = "Unsupported channel count {}", _0)]
+    UnsupportedChannels(u16),
+
+    #[fail(display = "Unsupported sample rate {}", _0)]
+    UnsupportedSampleRate(u32),
+
+    #[cfg(feature = "ogg")]
+    #[fail(display = "{}", _0)]
+    OggVorbis(#[cause] lewton::VorbisError),
+
+    #[cfg(feature = "symphonia")]
+    #[fail(display = "{}", _0)]
+    Symphonia(#[cause] symphonia::core::errors::Error),
+
+    #[cfg(feature = "hound")]
+    #[fail(display = "{}", _0)]
+    Hound(#[cause] hound::Error),
+
+    #[cfg(feature = "flac")]
+    #[fail(display = "FLAC encoding failed: {}", _0)]
+    Flac(String),
+
+    #[cfg(feature = "ogg-encode")]
+    #[fail(display = "Ogg Vorbis encoding failed: {}", _0)]
+    OggVorbisEncode(String),
+}
+
+/// Fail with `InvalidFormat(what)` unless `valid`
+pub(crate) fn ensure(valid: bool, what: &'static str) -> Result<()> {
+    if valid {
+        Ok(())
+    } else {
+        Err(ErrorKind::InvalidFormat(what).into())
+    }
+}
+
+/// `result` with the position `bytes` stopped at added to its error
+pub(crate) fn at_position<T: Seek, R>(bytes: &mut T, result: Result<R>) -> Result<R> {
+    result.map_err(|err| {
+        let err = match bytes.stream_position() {
+            Ok(offset) => err.at(offset),
+            Err(_) => err,
+        };
+        #[cfg(feature = "tracing")]
+        tracing::warn!(error = %err, "read failed");
+        err
+    })
 }
 
 impl Error {
     pub fn kind(&self) -> &ErrorKind {
         self.inner.get_context()
     }
-}
 
-impl Fail for Error {
-    fn cause(&self) -> Option<&Fail> {
-        self.inner.cause()
+    /// Chunk and unit being read, such as `matePTN / woice 2 / unit 0`, or empty
+    pub fn location(&self) -> &str {
+        &self.location
+    }
+
+    /// Byte in the file where reading stopped
+    pub fn offset(&self) -> Option<u64> {
+        self.offset
+    }
+
+    /// Add `location` as the outermost level
+    pub(crate) fn within(mut self, location: &str) -> Self {
+        self.location = if self.location.is_empty() {
+            location.to_owned()
+        } else {
+            format!("{} / {}", location, self.location)
+        };
+        self
     }
 
-    fn backtrace(&self) -> Option<&Backtrace> {
-        self.inner.backtrace()
+    /// Set the offset unless an inner reader already did
+    pub(crate) fn at(mut self, offset: u64) -> Self {
+        self.offset.get_or_insert(offset);
+        self
+    }
+}
+
+impl StdError for Error {
+    fn source(&self) -> Option<&(dyn StdError + 'static)> {
+        match self.kind() {
+            ErrorKind::IO(err) => Some(err),
+            #[cfg(feature = "ogg")]
+            ErrorKind::OggVorbis(err) => Some(err),
+            #[cfg(feature = "symphonia")]
+            ErrorKind::Symphonia(err) => Some(err),
+            #[cfg(feature = "hound")]
+            ErrorKind::Hound(err) => Some(err),
+            _ => None,
+        }
     }
 }
 
 impl fmt::Display for Error {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        fmt::Display::fmt(&self.inner, f)
+        fmt::Display::fmt(&self.inner, f)?;
+        match (self.location.is_empty(), self.offset) {
+            (true, None) => Ok(()),
+            (true, Some(offset)) => write!(f, " (at byte {})", offset),
+            (false, None) => write!(f, " (in {})", self.location),
+            (false, Some(offset)) => write!(f, " (in {}, at byte {})", self.location, offset),
+        }
     }
 }
 
 impl From<ErrorKind> for Error {
     fn from(kind: ErrorKind) -> Error {
-        Error {
-            inner: Context::new(kind),
-        }
+        Error::from(Context::new(kind))
     }
 }
 
 impl From<Context<ErrorKind>> for Error {
     fn from(inner: Context<ErrorKind>) -> Error {
-        Error { inner }
+        Error {
+            inner: Box::new(inner),
+            location: String::new(),
+            offset: None,
+        }
     }
 }
 
 impl From<io::Error> for Error {
     fn from(err: io::Error) -> Error {
-        Error {
-            inner: Context::new(ErrorKind::IO(err)),
-        }
+        Error::from(ErrorKind::IO(err))
     }
 }
@@ -0,0 +1,541 @@
+use std::{
+    fmt,
+    io::{Read, Seek, Write},
+    ops::Range,
+};
+
+use crate::error::{ensure, ErrorKind, Result};
+use crate::report::Report;
+
+use num_traits::FromPrimitive;
+
+use crate::pitch::KEY_PER_SEMITONE;
+
+use crate::descriptor::{check_count, write_sized, ReadBytesExt as _, WriteBytesExt as _};
+use byteorder::{LittleEndian, ReadBytesExt as _, WriteBytesExt as _};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum EventKind {
+    Null,
+    On,
+    Key,
+    PanVolume,
+    Velocity,
+    Volume,
+    Portament,
+    BeatClock,
+    BeatTempo,
+    BeatNum,
+    Repeat,
+    Last,
+    VoiceNo,
+    GroupNo,
+    Tuning,
+    PanTime,
+}
+
+impl EventKind {
+    pub const DEFAULT_VOLUME: i32 = 104;
+    pub const DEFAULT_VELOCITY: i32 = 104;
+    pub const DEFAULT_PAN_VOLUME: i32 = 64;
+    pub const DEFAULT_PAN_TIME: i32 = 64;
+    pub const DEFAULT_PORTAMENT: i32 = 0;
+    pub const DEFAULT_VOICENO: i32 = 0;
+    pub const DEFAULT_GROUPNO: i32 = 0;
+    pub const DEFAULT_KEY: i32 = 0x6000;
+    pub const DEFAULT_BASICKEY: i32 = 0x4500; // 4A (440Hz?)
+    pub const DEFAULT_TUNING: f32 = 1.0;
+
+    pub const DEFAULT_BEATNUM: i32 = 4;
+    pub const DEFAULT_BEATTEMPO: i32 = 120;
+    pub const DEFAULT_BEATCLOCK: i32 = 480;
+
+    /// Whether the value is a length in clocks (the event has a tail)
+    pub fn is_tail(self) -> bool {
+        self == EventKind::On || self == EventKind::Portament
+    }
+
+    pub fn default_value(self) -> i32 {
+        match self {
+            EventKind::Key => Self::DEFAULT_KEY,
+            EventKind::PanVolume => Self::DEFAULT_PAN_VOLUME,
+            EventKind::Velocity => Self::DEFAULT_VELOCITY,
+            EventKind::Volume => Self::DEFAULT_VOLUME,
+            EventKind::Portament => Self::DEFAULT_PORTAMENT,
+            EventKind::BeatClock => Self::DEFAULT_BEATCLOCK,
+            EventKind::BeatTempo => Self::DEFAULT_BEATTEMPO,
+            EventKind::BeatNum => Self::DEFAULT_BEATNUM,
+            EventKind::VoiceNo => Self::DEFAULT_VOICENO,
+            EventKind::GroupNo => Self::DEFAULT_GROUPNO,
+            EventKind::Tuning => Self::DEFAULT_TUNING.to_bits() as i32,
+            EventKind::PanTime => Self::DEFAULT_PAN_TIME,
+            _ => 0,
+        }
+    }
+
+    /// Order of events at the same clock
+    fn priority(self) -> i32 {
+        match self {
+            EventKind::Null => 0,
+            EventKind::On => 50,
+            EventKind::Key => 40,
+            EventKind::PanVolume => 60,
+            EventKind::Velocity => 70,
+            EventKind::Volume => 80,
+            EventKind::Portament => 30,
+            EventKind::BeatClock => 0,
+            EventKind::BeatTempo => 0,
+            EventKind::BeatNum => 0,
+            EventKind::Repeat => 0,
+            EventKind::Last => 255,
+            EventKind::VoiceNo => 10,
+            EventKind::GroupNo => 20,
+            EventKind::Tuning => 90,
+            EventKind::PanTime => 100,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Event {
+    pub clock: i32,
+    pub unit_no: u8,
+    pub kind: EventKind,
+    pub value: i32,
+}
+
+impl Event {
+    /// Value of a Tuning event
+    pub fn value_f32(&self) -> f32 {
+        f32::from_bits(self.value as u32)
+    }
+
+    fn end_clock(&self) -> i32 {
+        if self.kind.is_tail() {
+            self.clock + self.value
+        } else {
+            self.clock
+        }
+    }
+}
+
+/// Events sorted by clock, then by kind priority
+#[derive(Clone, Default, PartialEq)]
+pub struct EveList {
+    events: Vec<Event>,
+}
+
+impl EveList {
+    /// Events a song holds in pxtone (`pxtnMAX_EVENTNUM`)
+    const MAX_NUM: u32 = 500_000;
+
+    pub fn new() -> Self {
+        Self { events: Vec::new() }
+    }
+
+    pub(crate) fn read<T: Read>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        let _size = bytes.read_u32::<LittleEndian>()?;
+        let eve_num = check_count(bytes.read_u32::<LittleEndian>()?, Self::MAX_NUM)?;
+
+        let mut events = Vec::with_capacity(eve_num);
+        let mut absolute = 0;
+        for _ in 0..eve_num {
+            absolute = Self::advance(absolute, bytes.read_var_i32()?)?;
+            let unit_no = bytes.read_u8()?;
+            let kind = EventKind::from_u8(bytes.read_u8()?);
+            let kind = kind.ok_or(ErrorKind::InvalidFormat("event kind"))?;
+            let value = Self::finite_value(kind, bytes.read_var_i32()?, report)?;
+            events.push(Event {
+                clock: absolute,
+                unit_no,
+                kind,
+                value,
+            });
+        }
+
+        Ok(Self { events })
+    }
+
+    /// Write with clocks and tails divided by `rough`, as tunes are
+    pub(crate) fn write<W: Write + Seek>(&self, bytes: &mut W, rough: i32) -> Result<()> {
+        write_sized(bytes, |bytes| {
+            bytes.write_u32::<LittleEndian>(self.events.len() as u32)?;
+            let mut absolute = 0;
+            for event in &self.events {
+                let clock = event.clock / rough;
+                let value = if event.kind.is_tail() {
+                    event.value / rough
+                } else {
+                    event.value
+                };
+                bytes.write_var_i32(clock - absolute)?;
+                bytes.write_u8(event.unit_no)?;
+                bytes.write_u8(event.kind as u8)?;
+                bytes.write_var_i32(value)?;
+                absolute = clock;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    pub fn events(&self) -> &[Event] {
+        &self.events
+    }
+
+    /// Last clock touched by any event, including tails
+    pub fn max_clock(&self) -> i32 {
+        self.events.iter().map(Event::end_clock).max().unwrap_or(0)
+    }
+
+    /// Value of `kind` for the unit in effect at `clock`
+    pub fn value_at(&self, clock: i32, unit_no: u8, kind: EventKind) -> i32 {
+        self.events
+            .iter()
+            .take_while(|e| e.clock <= clock)
+            .filter(|e| e.unit_no == unit_no && e.kind == kind)
+            .last()
+            .map_or_else(|| kind.default_value(), |e| e.value)
+    }
+
+    /// Insert an event, replacing the unit's event of the same kind at the same clock.
+    /// Tails of the same kind never overlap: the previous one is cut, following ones are removed.
+    pub fn add_event(&mut self, clock: i32, unit_no: u8, kind: EventKind, value: i32) {
+        let index = self.insert(clock, unit_no, kind, value);
+
+        if kind.is_tail() {
+            // cut prev tail
+            if let Some(prev) = self.events[..index]
+                .iter_mut()
+                .rev()
+                .find(|e| e.unit_no == unit_no && e.kind == kind)
+            {
+                if clock < prev.clock + prev.value {
+                    prev.value = clock - prev.clock;
+                }
+            }
+
+            // delete next
+            let end = clock + value;
+            let mut i = index + 1;
+            while i < self.events.len() && self.events[i].clock < end {
+                let e = &self.events[i];
+                if e.unit_no == unit_no && e.kind == kind {
+                    self.events.remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+        }
+    }
+
+    /// Insert an event in order, replacing the unit's event of the same kind at the same clock
+    pub(crate) fn insert(&mut self, clock: i32, unit_no: u8, kind: EventKind, value: i32) -> usize {
+        let start = self.events.partition_point(|e| e.clock < clock);
+        let mut index = start;
+        while index < self.events.len() {
+            let e = &self.events[index];
+            if e.clock != clock {
+                break;
+            }
+            if e.unit_no == unit_no && e.kind == kind {
+                self.events.remove(index);
+                break;
+            }
+            if kind.priority() < e.kind.priority() {
+                break;
+            }
+            index += 1;
+        }
+        self.events.insert(
+            index,
+            Event {
+                clock,
+                unit_no,
+                kind,
+                value,
+            },
+        );
+        index
+    }
+
+    /// Events of one unit and kind in x1x-x4x projects (`EVENT===`, `evenUNIT`).
+    /// x1x tails are not counted in the relative clocks.
+    pub(crate) fn read_x4x_unit<T: Read>(
+        &mut self,
+        bytes: &mut T,
+        x1x: bool,
+        report: &mut Report,
+    ) -> Result<()> {
+        let _size = bytes.read_u32::<LittleEndian>()?;
+        let unit_no = bytes.read_u16::<LittleEndian>()? as u8;
+        let kind = EventKind::from_u16(bytes.read_u16::<LittleEndian>()?);
+        let kind = kind.ok_or(ErrorKind::InvalidFormat("event kind"))?;
+        let data_num = bytes.read_u16::<LittleEndian>()?;
+        let rrr = bytes.read_u16::<LittleEndian>()?;
+        let event_num = check_count(bytes.read_u32::<LittleEndian>()?, Self::MAX_NUM)?;
+        ensure(data_num == 2, "event data count")?;
+        ensure(x1x || rrr == 0, "event reserved data")?;
+
+        let mut absolute = 0;
+        for _ in 0..event_num {
+            absolute = Self::advance(absolute, bytes.read_var_i32()?)?;
+            let value = Self::finite_value(kind, bytes.read_var_i32()?, report)?;
+            self.insert(absolute, unit_no, kind, value);
+            if x1x && kind.is_tail() {
+                absolute = Self::advance(absolute, value)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Clock `delta` after `absolute`, failing past the clocks an `i32` holds
+    pub(crate) fn advance(absolute: i32, delta: i32) -> Result<i32> {
+        let clock = absolute.checked_add(delta);
+        Ok(clock.ok_or(ErrorKind::InvalidFormat("event clock"))?)
+    }
+
+    /// `value`, or the default of `kind` when it is a float that is not finite
+    fn finite_value(kind: EventKind, value: i32, report: &mut Report) -> Result<i32> {
+        if kind != EventKind::Tuning {
+            return Ok(value);
+        }
+        let tuning = f32::from_bits(value as u32);
+        let tuning = report.finite("tuning", tuning, EventKind::DEFAULT_TUNING)?;
+        Ok(tuning.to_bits() as i32)
+    }
+
+    /// Add `delta` to the unit's values of `kind`, kept in the valid range of the kind
+    pub(crate) fn shift_values(&mut self, unit_no: u8, kind: EventKind, delta: i32) {
+        let (min, max) = match kind {
+            EventKind::On => (120, 120),
+            EventKind::Key => (0, 0xbfff),
+            EventKind::PanVolume | EventKind::PanTime => (0, 0x80),
+            EventKind::Velocity | EventKind::Volume => (0, 0x80),
+            _ => (0, 0),
+        };
+        for e in self.events.iter_mut() {
+            if e.unit_no == unit_no && e.kind == kind {
+                e.value = (e.value + delta).clamp(min, max);
+            }
+        }
+    }
+
+    /// Raise the unit's keys by `semitones`, or lower them when negative. A unit playing
+    /// notes at the default key gets a key event at clock 0 first.
+    pub fn transpose(&mut self, unit_no: u8, semitones: i32) {
+        let keyed = self
+            .events_in_range(0..1)
+            .iter()
+            .any(|e| e.unit_no == unit_no && e.kind == EventKind::Key);
+        if !keyed && self.unit_events(unit_no).any(|e| e.kind == EventKind::On) {
+            self.insert(0, unit_no, EventKind::Key, EventKind::DEFAULT_KEY);
+        }
+        self.shift_values(unit_no, EventKind::Key, semitones * KEY_PER_SEMITONE);
+    }
+
+    /// Replace `remove` events from `index` with `insert`, which must keep the list sorted
+    pub(crate) fn splice(&mut self, index: usize, remove: usize, insert: Vec<Event>) -> Vec<Event> {
+        self.events.splice(index..index + remove, insert).collect()
+    }
+
+    pub fn add_event_f32(&mut self, clock: i32, unit_no: u8, kind: EventKind, value: f32) {
+        self.add_event(clock, unit_no, kind, value.to_bits() as i32);
+    }
+
+    /// Delete the unit's events in `clocks`, shortening tails which reach into it.
+    /// Returns the number of events affected.
+    pub fn delete_range(&mut self, clocks: Range<i32>, unit_no: u8) -> usize {
+        let before = self.events.len();
+        self.events.retain(|e| {
+            let inside =
+                e.clock >= clocks.start && (e.clock < clocks.end || e.clock == clocks.start);
+            !(inside && e.unit_no == unit_no)
+        });
+        let mut count = before - self.events.len();
+
+        for e in self.events.iter_mut() {
+            if e.clock >= clocks.start {
+                break;
+            }
+            if e.unit_no == unit_no && e.kind.is_tail() && e.end_clock() > clocks.start {
+                e.value = clocks.start - e.clock;
+                count += 1;
+            }
+        }
+
+        count
+    }
+
+    /// Move events to the nearest multiple of `grid` clocks, keeping tail lengths.
+    /// Events of a unit and kind snapped onto one clock replace each other, as in `add_event`.
+    pub fn quantize(&mut self, grid: i32) {
+        assert!(grid > 0);
+        self.move_events(|e| (e.clock + grid / 2) / grid * grid, |e| e.value);
+    }
+
+    /// Shift each unit's events at a clock together by up to `jitter` clocks, and change
+    /// velocity events by up to `velocity_jitter`. Events at clock 0, which set units up,
+    /// stay there. The same `seed` gives the same result.
+    pub fn humanize(&mut self, jitter: i32, velocity_jitter: i32, seed: u32) {
+        assert!(jitter >= 0 && velocity_jitter >= 0);
+        let offset = |e: &Event, salt: u32, range: i32| {
+            let hash = scatter(seed ^ salt, u32::from(e.unit_no), e.clock as u32);
+            (i64::from(hash) % (2 * i64::from(range) + 1)) as i32 - range
+        };
+        self.move_events(
+            |e| match e.clock {
+                0 => 0,
+                clock => (clock + offset(e, 0, jitter)).max(0),
+            },
+            |e| match e.kind {
+                EventKind::Velocity => (e.value + offset(e, 1, velocity_jitter)).clamp(0, 128),
+                _ => e.value,
+            },
+        );
+    }
+
+    /// Add the events again at new clocks and values, in their old order
+    fn move_events<C, V>(&mut self, clock: C, value: V)
+    where
+        C: Fn(&Event) -> i32,
+        V: Fn(&Event) -> i32,
+    {
+        let events = std::mem::take(&mut self.events);
+        for e in &events {
+            self.add_event(clock(e), e.unit_no, e.kind, value(e));
+        }
+    }
+
+    /// Events whose clock lies in `clocks`
+    pub fn events_in_range(&self, clocks: Range<i32>) -> &[Event] {
+        let start = self.events.partition_point(|e| e.clock < clocks.start);
+        let end = self.events.partition_point(|e| e.clock < clocks.end);
+        &self.events[start..end.max(start)]
+    }
+
+    pub fn unit_events(&self, unit_no: u8) -> impl Iterator<Item = &Event> {
+        self.events.iter().filter(move |e| e.unit_no == unit_no)
+    }
+}
+
+/// Hash of a unit and clock, spread over all 32 bits
+fn scatter(seed: u32, unit_no: u32, clock: u32) -> u32 {
+    let mut x = seed ^ unit_no.wrapping_mul(0x9e37_79b9) ^ clock.wrapping_mul(0x85eb_ca6b);
+    x ^= x >> 16;
+    x = x.wrapping_mul(0x7feb_352d);
+    x ^= x >> 15;
+    x = x.wrapping_mul(0x846c_a68b);
+    x ^ (x >> 16)
+}
+
+impl fmt::Debug for EveList {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("EveList")
+            .field("events", &format_args!("[{} events]", self.events.len()))
+            .finish()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn summary(evelist: &EveList) -> Vec<(i32, u8, EventKind, i32)> {
+        evelist
+            .events()
+            .iter()
+            .map(|e| (e.clock, e.unit_no, e.kind, e.value))
+            .collect()
+    }
+
+    #[test]
+    fn inserts_in_order() {
+        let mut evelist = EveList::new();
+        evelist.add_event(480, 0, EventKind::On, 240);
+        evelist.add_event(0, 1, EventKind::Velocity, 100);
+        evelist.add_event(480, 0, EventKind::Key, 0x5000);
+        evelist.add_event(0, 0, EventKind::VoiceNo, 2);
+        // replaces the velocity of unit 1 at clock 0
+        evelist.add_event(0, 1, EventKind::Velocity, 90);
+        assert_eq!(
+            summary(&evelist),
+            [
+                (0, 0, EventKind::VoiceNo, 2),
+                (0, 1, EventKind::Velocity, 90),
+                (480, 0, EventKind::Key, 0x5000),
+                (480, 0, EventKind::On, 240),
+            ]
+        );
+        assert_eq!(
+            evelist.value_at(479, 0, EventKind::Key),
+            EventKind::DEFAULT_KEY
+        );
+        assert_eq!(evelist.value_at(480, 0, EventKind::Key), 0x5000);
+        assert_eq!(evelist.max_clock(), 720);
+    }
+
+    #[test]
+    fn tails_never_overlap() {
+        let mut evelist = EveList::new();
+        evelist.add_event(0, 0, EventKind::On, 480);
+        evelist.add_event(960, 0, EventKind::On, 480);
+        evelist.add_event(1200, 1, EventKind::On, 480);
+        // cuts the first note and removes the second
+        evelist.add_event(240, 0, EventKind::On, 960);
+        assert_eq!(
+            summary(&evelist),
+            [
+                (0, 0, EventKind::On, 240),
+                (240, 0, EventKind::On, 960),
+                (1200, 1, EventKind::On, 480),
+            ]
+        );
+    }
+
+    #[test]
+    fn queries_ranges() {
+        let mut evelist = EveList::new();
+        for clock in (0..1920).step_by(480) {
+            evelist.add_event(clock, 0, EventKind::On, 240);
+            evelist.add_event(clock, 1, EventKind::On, 240);
+        }
+        let events = evelist.events_in_range(480..1440);
+        assert_eq!(events.len(), 4);
+        assert!(events.iter().all(|e| e.clock == 480 || e.clock == 960));
+        assert!(evelist.events_in_range(1500..1900).is_empty());
+        assert_eq!(evelist.unit_events(1).count(), 4);
+    }
+
+    #[test]
+    fn deletes_ranges() {
+        let mut evelist = EveList::new();
+        for clock in (0..1920).step_by(480) {
+            evelist.add_event(clock, 0, EventKind::On, 480);
+            evelist.add_event(clock, 1, EventKind::On, 480);
+        }
+        // removes one note of unit 0 and shortens the one reaching into the range
+        assert_eq!(evelist.delete_range(720..1440, 0), 2);
+        assert_eq!(
+            summary(&evelist)
+                .into_iter()
+                .filter(|e| e.1 == 0)
+                .collect::<Vec<_>>(),
+            [
+                (0, 0, EventKind::On, 480),
+                (480, 0, EventKind::On, 240),
+                (1440, 0, EventKind::On, 480),
+            ]
+        );
+        assert_eq!(evelist.unit_events(1).count(), 4);
+        assert_eq!(evelist.delete_range(0..i32::MAX, 1), 4);
+        assert_eq!(evelist.len(), 3);
+    }
+}
@@ -0,0 +1,97 @@
+//! Reading any pxtone file by its signature
+
+use std::io::{Cursor, Read, Seek, SeekFrom};
+
+use crate::error::{ErrorKind, Result};
+use crate::options::ParseOptions;
+use crate::project::Project;
+use crate::pulse::{Noise, Voice};
+
+/// File parsed as the type its signature names
+#[derive(Clone, Debug, PartialEq)]
+pub enum PxFile {
+    /// `.ptcop` or `.pttune`
+    Project(Box<Project>),
+    /// `.ptnoise`
+    Noise(Noise),
+    /// `.ptvoice`
+    Voice(Voice),
+}
+
+impl PxFile {
+    pub fn new<T: Read + Seek>(bytes: T) -> Result<Self> {
+        Self::parse(bytes, &ParseOptions::default())
+    }
+
+    /// Parse a file loaded into memory, as asset loaders hand it over
+    pub fn from_bytes(bytes: &[u8], options: &ParseOptions) -> Result<Self> {
+        Self::parse(Cursor::new(bytes), options)
+    }
+
+    pub fn parse<T: Read + Seek>(mut bytes: T, options: &ParseOptions) -> Result<Self> {
+        let start = bytes.stream_position()?;
+        let mut code = Vec::with_capacity(16);
+        (&mut bytes).take(16).read_to_end(&mut code)?;
+        bytes.seek(SeekFrom::Start(start))?;
+
+        if code.starts_with(b"PTCOLLAGE-") || code.starts_with(b"PTTUNE--") {
+            Ok(PxFile::Project(Box::new(Project::parse(bytes, options)?)))
+        } else if code.starts_with(b"PTNOISE-") {
+            Ok(PxFile::Noise(Noise::parse(bytes, options)?))
+        } else if code.starts_with(b"PTVOICE-") {
+            Ok(PxFile::Voice(Voice::parse(bytes, options)?))
+        } else {
+            Err(ErrorKind::InvalidFormat("file code").into())
+        }
+    }
+}
+
+/// Optional parts of the crate this build includes
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Capabilities {
+    /// Ogg Vorbis woices play (`ogg` or `symphonia` feature)
+    pub ogg: bool,
+    /// Names and text in Shift-JIS are decoded (`sjis` feature)
+    pub sjis: bool,
+    /// Rendered audio encodes to FLAC (`flac` feature)
+    pub flac: bool,
+    /// Rendered audio encodes to Ogg Vorbis (`ogg-encode` feature)
+    pub ogg_encode: bool,
+    /// Spectra and overtone waves can be computed (`fft` feature)
+    pub fft: bool,
+    /// Units and oscillators record where they were read from (`spans` feature)
+    pub spans: bool,
+    /// `Pcm` reads and writes through hound (`hound` feature)
+    pub hound: bool,
+    /// `Pcm` and projects play as kira sounds (`kira` feature)
+    pub kira: bool,
+    /// The cdylib is a Python extension module (`python` feature)
+    pub python: bool,
+    /// Noises and songs play from JavaScript (`wasm-bindgen` feature)
+    pub wasm_bindgen: bool,
+    /// Reading and rendering trace spans (`tracing` feature)
+    pub tracing: bool,
+}
+
+/// Features of this build, with `Project`, `Noise` and `Voice` giving the newest versions
+/// they read
+pub fn capabilities() -> Capabilities {
+    Capabilities {
+        ogg: cfg!(any(feature = "ogg", feature = "symphonia")),
+        sjis: cfg!(feature = "sjis"),
+        flac: cfg!(feature = "flac"),
+        ogg_encode: cfg!(feature = "ogg-encode"),
+        fft: cfg!(feature = "fft"),
+        spans: cfg!(feature = "spans"),
+        hound: cfg!(feature = "hound"),
+        kira: cfg!(feature = "kira"),
+        python: cfg!(feature = "python"),
+        wasm_bindgen: cfg!(feature = "wasm-bindgen"),
+        tracing: cfg!(feature = "tracing"),
+    }
+}
+
+/// Parse a project, noise or voice, telling them apart by their signature
+pub fn open<T: Read + Seek>(bytes: T) -> Result<PxFile> {
+    PxFile::new(bytes)
+}
@@ -0,0 +1,130 @@
+//! Output compared with that of the official pxtone 170212a source, kept as 16-bit WAVs in
+//! `resources/reference` and made by `resources/reference/render.cpp`
+
+use std::io::Cursor;
+
+use crate::options::RenderOptions;
+use crate::project::Project;
+use crate::pulse::{Noise, OutputFormat, Pcm, Voice};
+use crate::woice::Woice;
+
+/// How far output may be from the reference
+#[derive(Clone, Copy, Debug)]
+enum Tolerance {
+    /// Equal, but for negative levels one step lower, which pxtone truncates where this crate
+    /// stretches them to -32768
+    Exact,
+    /// Within this many 16-bit steps
+    Steps(i32),
+}
+
+impl Tolerance {
+    fn allows(self, sample: i16, expected: i16) -> bool {
+        let (sample, expected) = (i32::from(sample), i32::from(expected));
+        match self {
+            Tolerance::Exact => sample == expected || expected < 0 && sample == expected - 1,
+            Tolerance::Steps(steps) => (sample - expected).abs() <= steps,
+        }
+    }
+}
+
+/// Compare interleaved `samples` of `ch` channels at `sps` with the reference WAV
+fn assert_reference(samples: &[i16], ch: u16, sps: u32, reference: &[u8], tolerance: Tolerance) {
+    let reference = Pcm::new(Cursor::new(reference)).unwrap();
+    assert_eq!((reference.ch(), reference.sps()), (ch, sps));
+    let expected = interleave(reference.frames(), ch);
+    assert_eq!(samples.len(), expected.len());
+    for (i, (&sample, &expected)) in samples.iter().zip(&expected).enumerate() {
+        assert!(
+            tolerance.allows(sample, expected),
+            "sample {}: {} for {}",
+            i,
+            sample,
+            expected
+        );
+    }
+}
+
+/// Samples of the first `ch` channels of `frames`
+fn interleave<I: Iterator<Item = [i16; 2]>>(frames: I, ch: u16) -> Vec<i16> {
+    frames
+        .flat_map(|frame| (0..usize::from(ch)).map(move |c| frame[c]))
+        .collect()
+}
+
+fn noise_samples(noise: &[u8], ch: u16, sps: u32) -> Vec<i16> {
+    let noise = Noise::new(Cursor::new(noise)).unwrap();
+    let pcm = noise
+        .build(&RenderOptions::new(ch, sps, OutputFormat::I16))
+        .unwrap();
+    interleave(pcm.frames(), ch)
+}
+
+#[test]
+fn tolerance_modes() {
+    assert!(Tolerance::Exact.allows(-100, -100));
+    assert!(Tolerance::Exact.allows(-101, -100));
+    assert!(!Tolerance::Exact.allows(101, 100));
+    assert!(Tolerance::Steps(2).allows(102, 100));
+    assert!(!Tolerance::Steps(2).allows(97, 100));
+}
+
+#[test]
+fn matches_pxtone_drum_bass() {
+    let noise = include_bytes!("../resources/drum_bass1.ptnoise");
+    assert_reference(
+        &noise_samples(noise, 1, 22050),
+        1,
+        22050,
+        include_bytes!("../resources/reference/drum_bass1_22050_mono.wav"),
+        Tolerance::Exact,
+    );
+}
+
+/// Panned units, envelope points at 0 ms, offsets of plain waves, a Random2 value landing on the
+/// end of its cycle and a freq oscillator at 150%
+#[test]
+fn matches_pxtone_oscillators() {
+    let noise = include_bytes!("../resources/reference/oscillators.ptnoise");
+    assert_reference(
+        &noise_samples(noise, 2, 22050),
+        2,
+        22050,
+        include_bytes!("../resources/reference/oscillators_22050_stereo.wav"),
+        Tolerance::Exact,
+    );
+}
+
+/// A panned coordinate wave and a tuned overtone wave, readied as 44.1kHz stereo cycles
+#[test]
+fn matches_pxtone_voice_waves() {
+    let voice = include_bytes!("../resources/reference/waves.ptvoice");
+    let woice = Woice::from_voice(Voice::new(Cursor::new(&voice[..])).unwrap());
+    let instance = woice.ready(&RenderOptions::default()).unwrap();
+    let frames = instance.voice_frames().flatten().copied();
+    let samples = interleave(frames, 2);
+    assert_reference(
+        &samples,
+        2,
+        44100,
+        include_bytes!("../resources/reference/waves_44100_stereo.wav"),
+        Tolerance::Exact,
+    );
+}
+
+/// The first two seconds of the sample song; pxtone rounds its mix a step or two apart
+#[test]
+fn matches_pxtone_sample_song() {
+    let song = include_bytes!("../pxtone-source-code 170212a/sample data/sample.ptcop");
+    let mut project = Project::new(Cursor::new(&song[..])).unwrap();
+    let options = RenderOptions::new(2, 22050, OutputFormat::I16);
+    let frames = project.renderer(&options).unwrap().take(44100);
+    let samples = interleave(frames, 2);
+    assert_reference(
+        &samples,
+        2,
+        22050,
+        include_bytes!("../resources/reference/sample_22050_stereo.wav"),
+        Tolerance::Steps(2),
+    );
+}
@@ -0,0 +1,155 @@
+//! Primitives of pxtone files for related tools, and writing rendered audio out incrementally
+
+use std::io::{Seek, SeekFrom, Write};
+
+pub use crate::descriptor::{read_chunks, Chunk, ChunkFlow, ReadBytesExt, WriteBytesExt};
+
+use crate::error::{ErrorKind, Result};
+use crate::pulse::{OutputFormat, Pcm, PcmWaveFormat};
+
+/// WAV file written as samples arrive, with the sizes filled in when finished
+///
+/// Dropping the writer without `finish` still completes the header, ignoring errors.
+pub struct WavWriter<W: Write + Seek> {
+    writer: Option<W>,
+    fmt: PcmWaveFormat,
+    start: u64,
+    data_size: u64,
+    loop_start: Option<u32>,
+}
+
+impl<W: Write + Seek> WavWriter<W> {
+    /// Writer of `ch` channels at `sps`, failing on what a WAV file cannot hold
+    pub fn new(mut writer: W, ch: u16, sps: u32, format: OutputFormat) -> Result<Self> {
+        let fmt = PcmWaveFormat::new(ch, sps, format)?;
+        let start = writer.stream_position()?;
+        Pcm::write_wav_header(&mut writer, ch, sps, format, 0, 0)?;
+        Ok(Self {
+            writer: Some(writer),
+            fmt,
+            start,
+            data_size: 0,
+            loop_start: None,
+        })
+    }
+
+    pub fn ch(&self) -> u16 {
+        self.fmt.ch()
+    }
+
+    pub fn sps(&self) -> u32 {
+        self.fmt.sps()
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.fmt.format()
+    }
+
+    /// Frame players jump back to from the end, written in a `smpl` chunk when finished
+    pub fn set_loop_start(&mut self, loop_start: Option<u32>) {
+        self.loop_start = loop_start;
+    }
+
+    /// Number of frames written so far
+    pub fn smp_num(&self) -> u32 {
+        (self.data_size / u64::from(self.fmt.block_size())) as u32
+    }
+
+    /// Write a frame of the song renderer; only the first `ch` samples are used
+    pub fn write_frame(&mut self, frame: &[i16]) -> Result<()> {
+        let (ch, format) = (self.ch(), self.format());
+        let writer = self.writer.as_mut().unwrap();
+        for &bits in &frame[..ch as usize] {
+            format.write_i16(writer, bits)?;
+        }
+        self.data_size += u64::from(ch) * format.bytes_per_sample() as u64;
+        Ok(())
+    }
+
+    /// Write one interleaved sample in 16-bit scale, quantized as `Pcm` renders are
+    pub fn write_sample(&mut self, sample: f64) -> Result<()> {
+        let format = self.format();
+        format.write_sample(self.writer.as_mut().unwrap(), sample)?;
+        self.data_size += format.bytes_per_sample() as u64;
+        Ok(())
+    }
+
+    /// Append sample bytes already in the format
+    pub(crate) fn write_raw(&mut self, bytes: &[u8]) -> Result<()> {
+        self.writer.as_mut().unwrap().write_all(bytes)?;
+        self.data_size += bytes.len() as u64;
+        Ok(())
+    }
+
+    /// Fill in the sizes and return the writer, positioned at the end of the file
+    pub fn finish(mut self) -> Result<W> {
+        self.finalize()?;
+        Ok(self.writer.take().unwrap())
+    }
+
+    fn finalize(&mut self) -> Result<()> {
+        let limit = u64::from(u32::MAX);
+        if self.data_size > limit {
+            return Err(ErrorKind::SampleOverflow(self.data_size, "bytes", limit).into());
+        }
+        let smp_num = self.smp_num();
+        let (ch, sps, format) = (self.ch(), self.sps(), self.format());
+        let writer = self.writer.as_mut().unwrap();
+        let mut tail_size = 0;
+        match self.loop_start {
+            Some(loop_start) if loop_start < smp_num => {
+                if self.data_size % 2 == 1 {
+                    writer.write_all(&[0])?;
+                    tail_size += 1;
+                }
+                Pcm::write_smpl_chunk(writer, sps, loop_start, smp_num)?;
+                tail_size += Pcm::SMPL_CHUNK_SIZE;
+            }
+            _ => {}
+        }
+        let end = writer.stream_position()?;
+        writer.seek(SeekFrom::Start(self.start))?;
+        Pcm::write_wav_header(writer, ch, sps, format, self.data_size as u32, tail_size)?;
+        writer.seek(SeekFrom::Start(end))?;
+        writer.flush()?;
+        Ok(())
+    }
+}
+
+impl<W: Write + Seek> Drop for WavWriter<W> {
+    fn drop(&mut self) {
+        if self.writer.is_some() {
+            let _ = self.finalize();
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    #[test]
+    fn rejects_formats_wav_cannot_hold() {
+        let new = |ch, sps| WavWriter::new(Cursor::new(Vec::new()), ch, sps, OutputFormat::I16);
+        assert!(matches!(
+            new(3, 44100).err().unwrap().kind(),
+            ErrorKind::UnsupportedChannels(3)
+        ));
+        assert!(matches!(
+            new(2, 0).err().unwrap().kind(),
+            ErrorKind::UnsupportedSampleRate(0)
+        ));
+
+        let mut wav = new(2, 22050).unwrap();
+        wav.set_loop_start(Some(1));
+        for _ in 0..4 {
+            wav.write_frame(&[100, -100]).unwrap();
+        }
+        let bytes = wav.finish().unwrap().into_inner();
+        let pcm = Pcm::new(Cursor::new(bytes)).unwrap();
+        assert_eq!((pcm.ch(), pcm.sps(), pcm.smp_num()), (2, 22050, 4));
+        assert_eq!(pcm.loop_start(), 1);
+    }
+}
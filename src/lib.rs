@@ -1,19 +1,72 @@
 #![warn(clippy::all)]
 
+#[cfg(feature = "fft")]
+pub mod analysis;
+mod delay;
 mod descriptor;
+pub mod document;
+pub mod dsp;
+mod envelope;
 mod error;
+mod evelist;
+mod file;
+#[cfg(test)]
+mod golden;
+pub mod io;
+mod master;
+mod midi;
+mod options;
+mod overdrive;
+pub mod pitch;
+pub mod presets;
+mod progress;
+mod project;
 mod pulse;
+#[cfg(feature = "python")]
+mod python;
+mod report;
+pub mod timing;
+mod unit;
+#[cfg(feature = "wasm-bindgen")]
+mod wasm;
+mod woice;
 
 #[macro_use]
 extern crate num_derive;
 
 use error::Result;
 
-use pulse::Noise;
+pub use delay::{Delay, DelayUnit};
+pub use descriptor::Chunk;
+pub use envelope::{Envelope, Interpolation};
+pub use evelist::{EveList, Event, EventKind};
+pub use file::{capabilities, open, Capabilities, PxFile};
+pub use master::Master;
+pub use midi::MidiOptions;
+pub use options::{
+    Clipping, Dither, Downmix, PanLaw, ParseOptions, RenderOptions, SampleInterpolation, Upmix,
+    VelocityCurve,
+};
+pub use overdrive::OverDrive;
+pub use progress::Progress;
+#[cfg(feature = "kira")]
+pub use project::SongSoundData;
+pub use project::{
+    FormatVersion, MooState, PlayerHandle, Project, ProjectChanges, ProjectRef, ProjectStats,
+    Renderer, RendererSnapshot, Session, UnitState, UnitStats,
+};
+pub use pulse::{
+    Noise, NoiseOscillator, NoiseUnit, NoiseWave, OggVorbis, OutputFormat, Pcm, PcmWaveFormat,
+    Point, Quality, Voice, VoiceUnit, VoiceWave, Wavetable,
+};
+pub use report::{Report, Warning, WarningKind};
+pub use unit::Unit;
+pub use woice::{ToneSampler, Woice, WoiceCache, WoiceInstance};
+
 use std::fs::File;
 
 pub fn decode_noise() -> Result<()> {
     let noise = Noise::new(File::open("resources/drum_bass1.ptnoise")?)?;
-    noise.build(2, 44100, 16)?;
+    noise.build(&RenderOptions::default())?;
     Ok(())
 }
@@ -0,0 +1,262 @@
+use std::io::{Read, Write};
+
+use crate::error::{ensure, ErrorKind, Result};
+
+use num_traits::FromPrimitive;
+
+use crate::descriptor::{read_struct, ReadBytesExt as _};
+use byteorder::{LittleEndian, ReadBytesExt as _, WriteBytesExt as _};
+
+use crate::evelist::{EveList, EventKind};
+use crate::report::Report;
+
+/// Song-wide beat, tempo and loop settings
+#[derive(Clone, Debug, PartialEq)]
+pub struct Master {
+    beat_num: i32,
+    beat_tempo: f32,
+    beat_clock: i32,
+    meas_num: i32,
+    repeat_meas: i32,
+    last_meas: i32,
+}
+
+impl Default for Master {
+    fn default() -> Self {
+        Self {
+            beat_num: EventKind::DEFAULT_BEATNUM,
+            beat_tempo: Self::DEFAULT_TEMPO,
+            beat_clock: EventKind::DEFAULT_BEATCLOCK,
+            meas_num: 1,
+            repeat_meas: 0,
+            last_meas: 0,
+        }
+    }
+}
+
+impl Master {
+    const V5_SIZE: u32 = 15;
+    const DEFAULT_TEMPO: f32 = EventKind::DEFAULT_BEATTEMPO as f32;
+
+    pub fn new(beat_num: i32, beat_tempo: f32, beat_clock: i32) -> Self {
+        Self {
+            beat_num,
+            beat_tempo,
+            beat_clock,
+            ..Self::default()
+        }
+    }
+
+    pub(crate) fn read<T: Read>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        let (size, beat_clock, beat_num, beat_tempo, clock_repeat, clock_last): (
+            u32,
+            i16,
+            i8,
+            f32,
+            i32,
+            i32,
+        ) = read_struct(bytes)?;
+        ensure(size == Self::V5_SIZE, "master size")?;
+
+        let beat_clock = i32::from(beat_clock);
+        let beat_num = i32::from(beat_num);
+        let beat_tempo = report.finite("tempo", beat_tempo, Self::DEFAULT_TEMPO)?;
+
+        Self::with_loop(beat_num, beat_tempo, beat_clock, clock_repeat, clock_last)
+    }
+
+    /// Master whose repeat and last measures are at `clock_repeat` and `clock_last`
+    fn with_loop(
+        beat_num: i32,
+        beat_tempo: f32,
+        beat_clock: i32,
+        clock_repeat: i32,
+        clock_last: i32,
+    ) -> Result<Self> {
+        ensure(beat_num > 0 && beat_clock > 0, "master beat")?;
+        let meas_clock = beat_num.checked_mul(beat_clock);
+        let meas = |clock: i32| {
+            let meas = meas_clock.and_then(|meas_clock| clock.checked_div(meas_clock));
+            meas.ok_or(ErrorKind::InvalidFormat("master beat"))
+        };
+        let mut master = Self::new(beat_num, beat_tempo, beat_clock);
+        master.set_repeat_meas(meas(clock_repeat)?);
+        master.set_last_meas(meas(clock_last)?);
+        Ok(master)
+    }
+
+    /// Write with beat clocks divided by `rough`, as tunes are
+    pub(crate) fn write<W: Write>(&self, bytes: &mut W, rough: i32) -> Result<()> {
+        let beat_clock = self.beat_clock / rough;
+        let meas_clock = beat_clock * self.beat_num;
+        bytes.write_u32::<LittleEndian>(Self::V5_SIZE)?;
+        bytes.write_i16::<LittleEndian>(beat_clock as i16)?;
+        bytes.write_i8(self.beat_num as i8)?;
+        bytes.write_f32::<LittleEndian>(self.beat_tempo)?;
+        bytes.write_i32::<LittleEndian>(meas_clock * self.repeat_meas)?;
+        bytes.write_i32::<LittleEndian>(meas_clock * self.last_meas)?;
+        Ok(())
+    }
+
+    /// Master events of x4x projects (`evenMAST`)
+    pub(crate) fn read_x4x<T: Read>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        let _size = bytes.read_u32::<LittleEndian>()?;
+        let data_num = bytes.read_u16::<LittleEndian>()?;
+        let rrr = bytes.read_u16::<LittleEndian>()?;
+        let event_num = bytes.read_u32::<LittleEndian>()?;
+        ensure(data_num == 3, "master data count")?;
+        ensure(rrr == 0, "master reserved data")?;
+
+        let mut beat_clock = EventKind::DEFAULT_BEATCLOCK;
+        let mut beat_num = EventKind::DEFAULT_BEATNUM;
+        let mut beat_tempo = Self::DEFAULT_TEMPO;
+        let mut clock_repeat = 0;
+        let mut clock_last = 0;
+
+        let mut absolute = 0;
+        for _ in 0..event_num {
+            let status = bytes.read_var_i32()?;
+            absolute = EveList::advance(absolute, bytes.read_var_i32()?)?;
+            let volume = bytes.read_var_i32()?;
+            let clock = absolute;
+
+            match EventKind::from_i32(status) {
+                Some(EventKind::BeatClock) => {
+                    ensure(clock == 0, "master beat clock")?;
+                    beat_clock = volume;
+                }
+                Some(EventKind::BeatTempo) => {
+                    ensure(clock == 0, "master tempo")?;
+                    let tempo = f32::from_bits(volume as u32);
+                    beat_tempo = report.finite("tempo", tempo, Self::DEFAULT_TEMPO)?;
+                }
+                Some(EventKind::BeatNum) => {
+                    ensure(clock == 0, "master beat count")?;
+                    beat_num = volume;
+                }
+                Some(EventKind::Repeat) => {
+                    ensure(volume == 0, "master repeat")?;
+                    clock_repeat = clock;
+                }
+                Some(EventKind::Last) => {
+                    ensure(volume == 0, "master last")?;
+                    clock_last = clock;
+                }
+                _ => return Err(ErrorKind::InvalidFormat("master event kind").into()),
+            }
+        }
+
+        Self::with_loop(beat_num, beat_tempo, beat_clock, clock_repeat, clock_last)
+    }
+
+    pub fn beat_num(&self) -> i32 {
+        self.beat_num
+    }
+
+    pub fn beat_tempo(&self) -> f32 {
+        self.beat_tempo
+    }
+
+    /// Clocks per beat
+    pub fn beat_clock(&self) -> i32 {
+        self.beat_clock
+    }
+
+    pub fn meas_num(&self) -> i32 {
+        self.meas_num
+    }
+
+    /// Measure the song jumps back to when looping
+    pub fn repeat_meas(&self) -> i32 {
+        self.repeat_meas
+    }
+
+    /// Measure the song ends at, or 0 to play every measure
+    pub fn last_meas(&self) -> i32 {
+        self.last_meas
+    }
+
+    pub fn set_beat(&mut self, beat_num: i32, beat_tempo: f32, beat_clock: i32) {
+        self.beat_num = beat_num;
+        self.beat_tempo = beat_tempo;
+        self.set_beat_clock(beat_clock);
+    }
+
+    pub fn set_beat_clock(&mut self, beat_clock: i32) {
+        self.beat_clock = beat_clock.max(0);
+    }
+
+    pub fn set_meas_num(&mut self, meas_num: i32) {
+        self.meas_num = meas_num
+            .max(1)
+            .max(self.repeat_meas + 1)
+            .max(self.last_meas);
+    }
+
+    pub fn set_repeat_meas(&mut self, meas: i32) {
+        self.repeat_meas = meas.max(0);
+    }
+
+    pub fn set_last_meas(&mut self, meas: i32) {
+        self.last_meas = meas.max(0);
+    }
+
+    /// Grow the song so that `clock` fits in it
+    pub fn adjust_meas_num(&mut self, clock: i32) {
+        let b_num = (clock + self.beat_clock - 1) / self.beat_clock;
+        let m_num = (b_num + self.beat_num - 1) / self.beat_num;
+        if self.meas_num <= m_num {
+            self.meas_num = m_num;
+        }
+        if self.repeat_meas >= self.meas_num {
+            self.repeat_meas = 0;
+        }
+        if self.last_meas > self.meas_num {
+            self.last_meas = self.meas_num;
+        }
+    }
+
+    /// Number of measures actually played
+    pub fn play_meas(&self) -> i32 {
+        if self.last_meas != 0 {
+            self.last_meas
+        } else {
+            self.meas_num
+        }
+    }
+
+    pub fn last_clock(&self) -> i32 {
+        self.last_meas * self.beat_clock * self.beat_num
+    }
+
+    pub fn meas_clock(&self, meas: i32) -> i32 {
+        self.this_clock(meas, 0, 0)
+    }
+
+    pub fn this_clock(&self, meas: i32, beat: i32, clock: i32) -> i32 {
+        self.beat_num * self.beat_clock * meas + self.beat_clock * beat + clock
+    }
+
+    /// Samples per clock at `sps`
+    pub fn clock_rate(&self, sps: u32) -> f32 {
+        (60.0 * f64::from(sps) / (f64::from(self.beat_tempo) * f64::from(self.beat_clock))) as f32
+    }
+
+    pub fn clock_to_sample(&self, clock: i32, sps: u32) -> i32 {
+        (f64::from(clock) * f64::from(self.clock_rate(sps))) as i32
+    }
+
+    pub fn sample_to_clock(&self, sample: i32, sps: u32) -> i32 {
+        (sample as f32 / self.clock_rate(sps)) as i32
+    }
+
+    /// Sample position of the loop point
+    pub fn repeat_sample(&self, sps: u32) -> i32 {
+        self.clock_to_sample(self.meas_clock(self.repeat_meas), sps)
+    }
+
+    /// Sample position where playing stops or loops
+    pub fn end_sample(&self, sps: u32) -> i32 {
+        self.clock_to_sample(self.meas_clock(self.play_meas()), sps)
+    }
+}
@@ -0,0 +1,628 @@
+//! Standard MIDI files (SMF)
+
+use std::collections::BTreeMap;
+use std::io::{self, Read};
+
+use byteorder::{BigEndian, ReadBytesExt as _, WriteBytesExt as _};
+
+use crate::descriptor::capacity;
+use crate::error::{ensure, ErrorKind, Result};
+use crate::evelist::EventKind;
+use crate::master::Master;
+use crate::pitch::KEY_PER_SEMITONE;
+use crate::presets;
+use crate::project::Project;
+use crate::pulse::{Noise, Voice};
+use crate::unit::Unit;
+use crate::woice::Woice;
+
+const HEADER_CODE: &[u8] = b"MThd";
+const TRACK_CODE: &[u8] = b"MTrk";
+
+const NOTE_OFF: u8 = 0x80;
+const NOTE_ON: u8 = 0x90;
+const CONTROL_CHANGE: u8 = 0xb0;
+const CONTROL_VOLUME: u8 = 7;
+const CONTROL_PAN: u8 = 10;
+
+const META: u8 = 0xff;
+const META_TRACK_NAME: u8 = 0x03;
+const META_END_OF_TRACK: u8 = 0x2f;
+const META_TEMPO: u8 = 0x51;
+const META_TIME_SIGNATURE: u8 = 0x58;
+
+/// General MIDI percussion channel
+const DRUM_CHANNEL: u8 = 9;
+
+/// Channels given to units in turn, leaving out the General MIDI drums
+const CHANNELS: [u8; 15] = [0, 1, 2, 3, 4, 5, 6, 7, 8, 10, 11, 12, 13, 14, 15];
+
+/// MIDI note sounding at `EventKind::DEFAULT_KEY`
+const NOTE_DEFAULT_KEY: i32 = 69;
+
+/// MIDI note closest to a pxtone key event value
+fn key_to_note(key: i32) -> u8 {
+    let semitones = f64::from(key - EventKind::DEFAULT_KEY) / f64::from(KEY_PER_SEMITONE);
+    (NOTE_DEFAULT_KEY + semitones.round() as i32).clamp(0, 127) as u8
+}
+
+/// pxtone levels run up to 128
+fn to_data_byte(value: i32) -> u8 {
+    value.clamp(0, 127) as u8
+}
+
+fn write_var(bytes: &mut Vec<u8>, value: u32) {
+    let mut groups = vec![(value & 0x7f) as u8];
+    let mut rest = value >> 7;
+    while rest > 0 {
+        groups.push((rest & 0x7f) as u8 | 0x80);
+        rest >>= 7;
+    }
+    bytes.extend(groups.iter().rev());
+}
+
+/// Track events before sorting; note offs go first and note ons last at the same tick
+#[derive(Default)]
+struct Track {
+    events: Vec<(u32, u8, Vec<u8>)>,
+}
+
+impl Track {
+    fn push(&mut self, tick: i32, message: Vec<u8>) {
+        let order = match message[0] & 0xf0 {
+            NOTE_OFF => 0,
+            NOTE_ON => 2,
+            _ => 1,
+        };
+        self.events.push((tick.max(0) as u32, order, message));
+    }
+
+    fn push_meta(&mut self, tick: i32, kind: u8, data: &[u8]) {
+        let mut message = vec![META, kind];
+        write_var(&mut message, data.len() as u32);
+        message.extend_from_slice(data);
+        self.push(tick, message);
+    }
+
+    fn write(mut self, bytes: &mut Vec<u8>, end_tick: i32) {
+        self.events.sort_by_key(|&(tick, order, _)| (tick, order));
+        let end_tick = self
+            .events
+            .last()
+            .map_or(0, |&(tick, _, _)| tick)
+            .max(end_tick.max(0) as u32);
+
+        let mut body = Vec::new();
+        let mut last = 0;
+        for (tick, _, message) in self.events {
+            write_var(&mut body, tick - last);
+            body.extend(message);
+            last = tick;
+        }
+        write_var(&mut body, end_tick - last);
+        body.extend_from_slice(&[META, META_END_OF_TRACK, 0]);
+
+        bytes.extend_from_slice(TRACK_CODE);
+        bytes.write_u32::<BigEndian>(body.len() as u32).unwrap();
+        bytes.extend(body);
+    }
+}
+
+/// Format 1 file: a tempo track, then a track per unit
+pub(crate) fn write(project: &Project) -> Vec<u8> {
+    let master = project.master();
+    let end_tick = master.meas_clock(master.play_meas());
+
+    let mut tempo = Track::default();
+    tempo.push_meta(0, META_TRACK_NAME, project.name().as_bytes());
+    for &(clock, beat_tempo) in project.clock_map().tempos() {
+        let micros = (60_000_000.0 / f64::from(beat_tempo)).round() as u32;
+        tempo.push_meta(clock, META_TEMPO, &micros.to_be_bytes()[1..]);
+    }
+    // beats are quarter notes
+    let beat_num = master.beat_num().clamp(1, 255) as u8;
+    tempo.push_meta(0, META_TIME_SIGNATURE, &[beat_num, 2, 24, 8]);
+
+    let mut tracks = vec![tempo];
+    for (unit_no, unit) in project.units().iter().enumerate() {
+        let channel = CHANNELS[unit_no % CHANNELS.len()];
+        let mut track = Track::default();
+        track.push_meta(0, META_TRACK_NAME, unit.name().as_bytes());
+
+        let mut key = EventKind::DEFAULT_KEY;
+        let mut velocity = EventKind::DEFAULT_VELOCITY;
+        for event in project.evelist().unit_events(unit_no as u8) {
+            match event.kind {
+                EventKind::On => {
+                    let note = key_to_note(key);
+                    let velocity = to_data_byte(velocity).max(1);
+                    track.push(event.clock, vec![NOTE_ON | channel, note, velocity]);
+                    track.push(event.clock + event.value, vec![NOTE_OFF | channel, note, 0]);
+                }
+                EventKind::Key => key = event.value,
+                EventKind::Velocity => velocity = event.value,
+                EventKind::Volume => track.push(
+                    event.clock,
+                    vec![
+                        CONTROL_CHANGE | channel,
+                        CONTROL_VOLUME,
+                        to_data_byte(event.value),
+                    ],
+                ),
+                EventKind::PanVolume => track.push(
+                    event.clock,
+                    vec![
+                        CONTROL_CHANGE | channel,
+                        CONTROL_PAN,
+                        to_data_byte(event.value),
+                    ],
+                ),
+                _ => {}
+            }
+        }
+        tracks.push(track);
+    }
+
+    let mut bytes = Vec::new();
+    bytes.extend_from_slice(HEADER_CODE);
+    bytes.write_u32::<BigEndian>(6).unwrap();
+    bytes.write_u16::<BigEndian>(1).unwrap();
+    bytes.write_u16::<BigEndian>(tracks.len() as u16).unwrap();
+    bytes
+        .write_u16::<BigEndian>(master.beat_clock() as u16)
+        .unwrap();
+    for track in tracks {
+        track.write(&mut bytes, end_tick);
+    }
+    bytes
+}
+
+/// How `Project::from_midi` voices a file
+#[derive(Clone, Copy, Debug)]
+pub struct MidiOptions {
+    /// Instrument of every melodic channel
+    pub voice: fn() -> Voice,
+    /// Play channel 10 with the percussion presets instead of `voice`
+    pub drums: bool,
+}
+
+impl MidiOptions {
+    pub fn with_voice(mut self, voice: fn() -> Voice) -> Self {
+        self.voice = voice;
+        self
+    }
+
+    pub fn with_drums(mut self, drums: bool) -> Self {
+        self.drums = drums;
+        self
+    }
+}
+
+impl Default for MidiOptions {
+    fn default() -> Self {
+        Self {
+            voice: presets::square_wave,
+            drums: true,
+        }
+    }
+}
+
+/// Percussion preset closest to a General MIDI drum note
+fn drum(note: u8) -> (&'static str, fn() -> Noise) {
+    match note {
+        35 | 36 => ("bass drum", presets::bass_drum),
+        42 | 44 => ("hihat closed", presets::hihat_closed),
+        46 | 49..=59 => ("hihat open", presets::hihat_open),
+        _ => ("snare drum", presets::snare_drum),
+    }
+}
+
+/// Note with its length, in file ticks
+struct Note {
+    tick: u32,
+    len: u32,
+    channel: u8,
+    key: u8,
+    velocity: u8,
+}
+
+/// Control change, in file ticks
+struct Control {
+    tick: u32,
+    channel: u8,
+    number: u8,
+    value: u8,
+}
+
+#[derive(Default)]
+struct TrackData {
+    name: Option<String>,
+    notes: Vec<Note>,
+    controls: Vec<Control>,
+}
+
+/// The parts of a file pxtone can play
+struct Smf {
+    division: u16,
+    tempo: Option<u32>,
+    time_signature: Option<(u8, u8)>,
+    tracks: Vec<TrackData>,
+}
+
+fn read_var<T: Read>(bytes: &mut T) -> Result<u32> {
+    let mut value = 0;
+    for _ in 0..4 {
+        let byte = bytes.read_u8()?;
+        value = value << 7 | u32::from(byte & 0x7f);
+        if byte & 0x80 == 0 {
+            return Ok(value);
+        }
+    }
+    Err(ErrorKind::InvalidVar32.into())
+}
+
+/// Split `len` bytes off the front
+fn take<'a>(bytes: &mut &'a [u8], len: u32) -> Result<&'a [u8]> {
+    let len = len as usize;
+    if bytes.len() < len {
+        return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+    }
+    let (data, rest) = bytes.split_at(len);
+    *bytes = rest;
+    Ok(data)
+}
+
+impl Smf {
+    fn read<T: Read>(bytes: &mut T) -> Result<Self> {
+        let mut code = [0; 4];
+        bytes.read_exact(&mut code)?;
+        ensure(code == HEADER_CODE, "MIDI header code")?;
+        let size = bytes.read_u32::<BigEndian>()?;
+        ensure(size >= 6, "MIDI header size")?;
+        let _format = bytes.read_u16::<BigEndian>()?;
+        let track_num = bytes.read_u16::<BigEndian>()?;
+        let division = bytes.read_u16::<BigEndian>()?;
+        // SMPTE time codes are not supported
+        ensure(division > 0 && division & 0x8000 == 0, "MIDI time division")?;
+        io::copy(&mut bytes.take(u64::from(size - 6)), &mut io::sink())?;
+
+        let mut smf = Self {
+            division,
+            tempo: None,
+            time_signature: None,
+            tracks: Vec::with_capacity(track_num as usize),
+        };
+        while smf.tracks.len() < track_num as usize {
+            bytes.read_exact(&mut code)?;
+            let size = bytes.read_u32::<BigEndian>()?;
+            let mut body = Vec::with_capacity(capacity(size));
+            bytes.take(u64::from(size)).read_to_end(&mut body)?;
+            if body.len() < size as usize {
+                return Err(io::Error::from(io::ErrorKind::UnexpectedEof).into());
+            }
+            // skip unknown chunks
+            if code == TRACK_CODE {
+                let track = smf.read_track(&body)?;
+                smf.tracks.push(track);
+            }
+        }
+        Ok(smf)
+    }
+
+    fn read_track(&mut self, mut bytes: &[u8]) -> Result<TrackData> {
+        let mut track = TrackData::default();
+        let mut held: BTreeMap<(u8, u8), Vec<(u32, u8)>> = BTreeMap::new();
+        let mut tick: u32 = 0;
+        let mut status = 0;
+        while !bytes.is_empty() {
+            // clocks of the project hold up to `i32::MAX`
+            let delta = read_var(&mut bytes)?;
+            tick = tick
+                .checked_add(delta)
+                .filter(|&tick| tick <= i32::MAX as u32)
+                .ok_or(ErrorKind::InvalidFormat("MIDI delta time"))?;
+            let byte = bytes.read_u8()?;
+            match byte {
+                META => {
+                    let kind = bytes.read_u8()?;
+                    let len = read_var(&mut bytes)?;
+                    let data = take(&mut bytes, len)?;
+                    match kind {
+                        META_TRACK_NAME if track.name.is_none() => {
+                            track.name = Some(String::from_utf8_lossy(data).into_owned());
+                        }
+                        META_TEMPO if len == 3 && self.tempo.is_none() => {
+                            let micros = data.iter().fold(0, |acc, &b| acc << 8 | u32::from(b));
+                            self.tempo = Some(micros.max(1));
+                        }
+                        META_TIME_SIGNATURE if len >= 2 && self.time_signature.is_none() => {
+                            self.time_signature = Some((data[0], data[1]));
+                        }
+                        META_END_OF_TRACK => break,
+                        _ => {}
+                    }
+                }
+                // system exclusive
+                0xf0 | 0xf7 => {
+                    let len = read_var(&mut bytes)?;
+                    take(&mut bytes, len)?;
+                    status = 0;
+                }
+                _ => {
+                    // running status
+                    let first = if byte & 0x80 != 0 {
+                        status = byte;
+                        bytes.read_u8()?
+                    } else {
+                        ensure(status != 0, "MIDI running status")?;
+                        byte
+                    };
+                    let channel = status & 0x0f;
+                    let kind = status & 0xf0;
+                    // program change and channel pressure have one data byte
+                    if kind == 0xc0 || kind == 0xd0 {
+                        continue;
+                    }
+                    let second = bytes.read_u8()?;
+                    match kind {
+                        NOTE_ON if second > 0 => held
+                            .entry((channel, first))
+                            .or_default()
+                            .push((tick, second)),
+                        NOTE_ON | NOTE_OFF => {
+                            if let Some(notes) = held.get_mut(&(channel, first)) {
+                                if !notes.is_empty() {
+                                    let (start, velocity) = notes.remove(0);
+                                    track.notes.push(Note {
+                                        tick: start,
+                                        len: tick - start,
+                                        channel,
+                                        key: first,
+                                        velocity,
+                                    });
+                                }
+                            }
+                        }
+                        CONTROL_CHANGE => track.controls.push(Control {
+                            tick,
+                            channel,
+                            number: first,
+                            value: second,
+                        }),
+                        _ => {}
+                    }
+                }
+            }
+        }
+
+        // notes never released end with the track
+        for ((channel, key), notes) in held {
+            for (start, velocity) in notes {
+                track.notes.push(Note {
+                    tick: start,
+                    len: tick - start,
+                    channel,
+                    key,
+                    velocity,
+                });
+            }
+        }
+        Ok(track)
+    }
+}
+
+/// Unit taking the notes of a channel that do not overlap
+struct Lane {
+    unit_no: u8,
+    end: u32,
+    key: i32,
+    velocity: i32,
+}
+
+/// Project with a woice per instrument and a unit per overlapping note of each channel.
+/// Only the first tempo and time signature are used, and notes beyond `Unit::MAX_NUM` units are dropped.
+pub(crate) fn read<T: Read>(bytes: &mut T, options: &MidiOptions) -> Result<Project> {
+    let smf = Smf::read(bytes)?;
+    let mut project = Project::empty();
+    if let Some(name) = smf.tracks.first().and_then(|track| track.name.as_ref()) {
+        project.set_name(name);
+    }
+
+    let beat_tempo = smf
+        .tempo
+        .map_or(EventKind::DEFAULT_BEATTEMPO as f32, |micros| {
+            (60_000_000.0 / f64::from(micros)) as f32
+        });
+    let beat_num = smf
+        .time_signature
+        .map_or(EventKind::DEFAULT_BEATNUM, |(num, den)| {
+            (f64::from(num) * 4.0 / 2f64.powi(i32::from(den)))
+                .round()
+                .max(1.0) as i32
+        });
+    *project.master_mut() = Master::new(beat_num, beat_tempo, i32::from(smf.division));
+
+    project.push_woice(Woice::from_voice((options.voice)()));
+    project.set_woice_name(0, "voice");
+    let mut drum_woices: Vec<&str> = Vec::new();
+
+    // notes by track, channel and woice
+    let mut groups: BTreeMap<(usize, u8, usize), (String, Vec<&Note>)> = BTreeMap::new();
+    for (t, track) in smf.tracks.iter().enumerate() {
+        for note in &track.notes {
+            let (woice, name) = if options.drums && note.channel == DRUM_CHANNEL {
+                let (name, noise) = drum(note.key);
+                let index = drum_woices.iter().position(|&n| n == name);
+                let index = index.unwrap_or_else(|| {
+                    drum_woices.push(name);
+                    let index = project.woices().len();
+                    project.push_woice(Woice::from_noise(noise()));
+                    project.set_woice_name(index, name);
+                    index
+                });
+                (index, name.to_owned())
+            } else {
+                let name = match &track.name {
+                    Some(name) if !name.is_empty() => name.clone(),
+                    _ => format!("ch{}", note.channel + 1),
+                };
+                (0, name)
+            };
+            groups
+                .entry((t, note.channel, woice))
+                .or_insert_with(|| (name, Vec::new()))
+                .1
+                .push(note);
+        }
+    }
+
+    for ((t, channel, woice), (name, mut notes)) in groups {
+        notes.sort_by_key(|note| note.tick);
+        let controls = smf.tracks[t]
+            .controls
+            .iter()
+            .filter(|control| control.channel == channel);
+
+        let mut lanes: Vec<Lane> = Vec::new();
+        for note in notes {
+            let lane = match lanes.iter().position(|lane| lane.end <= note.tick) {
+                Some(lane) => lane,
+                None if project.units().len() < Unit::MAX_NUM => {
+                    let unit_no = project.add_unit(Unit::new(&name))? as u8;
+                    let evelist = project.evelist_mut();
+                    if woice != 0 {
+                        evelist.add_event(0, unit_no, EventKind::VoiceNo, woice as i32);
+                    }
+                    for control in controls.clone() {
+                        let kind = match control.number {
+                            CONTROL_VOLUME => EventKind::Volume,
+                            CONTROL_PAN => EventKind::PanVolume,
+                            _ => continue,
+                        };
+                        let value = i32::from(control.value);
+                        evelist.add_event(control.tick as i32, unit_no, kind, value);
+                    }
+                    lanes.push(Lane {
+                        unit_no,
+                        end: 0,
+                        key: EventKind::DEFAULT_KEY,
+                        velocity: EventKind::DEFAULT_VELOCITY,
+                    });
+                    lanes.len() - 1
+                }
+                None => continue,
+            };
+
+            let lane = &mut lanes[lane];
+            let clock = note.tick as i32;
+            let evelist = project.evelist_mut();
+            let key = if woice == 0 {
+                EventKind::DEFAULT_KEY + (i32::from(note.key) - NOTE_DEFAULT_KEY) * KEY_PER_SEMITONE
+            } else {
+                EventKind::DEFAULT_KEY
+            };
+            if key != lane.key {
+                evelist.add_event(clock, lane.unit_no, EventKind::Key, key);
+                lane.key = key;
+            }
+            let velocity = i32::from(note.velocity);
+            if velocity != lane.velocity {
+                evelist.add_event(clock, lane.unit_no, EventKind::Velocity, velocity);
+                lane.velocity = velocity;
+            }
+            let len = note.len.max(1);
+            evelist.add_event(clock, lane.unit_no, EventKind::On, len as i32);
+            lane.end = note.tick + len;
+        }
+    }
+
+    Ok(project)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    /// Format 0 file with one track of `events`
+    fn smf(division: u16, events: &[u8]) -> Vec<u8> {
+        let mut bytes = HEADER_CODE.to_vec();
+        bytes.write_u32::<BigEndian>(6).unwrap();
+        bytes.write_u16::<BigEndian>(0).unwrap();
+        bytes.write_u16::<BigEndian>(1).unwrap();
+        bytes.write_u16::<BigEndian>(division).unwrap();
+        bytes.extend_from_slice(TRACK_CODE);
+        bytes.write_u32::<BigEndian>(events.len() as u32).unwrap();
+        bytes.extend_from_slice(events);
+        bytes
+    }
+
+    fn import(bytes: &[u8]) -> Result<Project> {
+        Project::from_midi(Cursor::new(bytes), &MidiOptions::default())
+    }
+
+    fn invalid(bytes: &[u8]) -> &'static str {
+        let err = import(bytes).err().unwrap();
+        match err.kind() {
+            ErrorKind::InvalidFormat(what) => what,
+            kind => panic!("{:?}", kind),
+        }
+    }
+
+    #[test]
+    fn imports_notes() {
+        let note = [0x00, 0x90, 60, 100, 0x83, 0x60, 0x80, 60, 0];
+        let project = import(&smf(480, &note)).unwrap();
+        assert_eq!(project.units().len(), 1);
+    }
+
+    #[test]
+    fn rejects_bad_headers() {
+        let mut riff = smf(480, &[]);
+        riff[..4].copy_from_slice(b"RIFF");
+        assert_eq!(invalid(&riff), "MIDI header code");
+        let mut short = smf(480, &[]);
+        short[7] = 5;
+        assert_eq!(invalid(&short), "MIDI header size");
+        assert_eq!(invalid(&smf(0xe728, &[])), "MIDI time division");
+        assert_eq!(invalid(&smf(0, &[])), "MIDI time division");
+    }
+
+    #[test]
+    fn rejects_running_status_without_status() {
+        assert_eq!(invalid(&smf(480, &[0x00, 60, 100])), "MIDI running status");
+    }
+
+    #[test]
+    fn rejects_clocks_past_i32() {
+        // empty text events at the longest delta time
+        let events = [0xff, 0xff, 0xff, 0x7f, META, 0x01, 0x00].repeat(9);
+        assert_eq!(invalid(&smf(480, &events)), "MIDI delta time");
+    }
+
+    #[test]
+    fn survives_truncation() {
+        let sample = include_bytes!("../pxtone-source-code 170212a/sample data/sample.ptcop");
+        let midi = Project::new(Cursor::new(&sample[..])).unwrap().to_midi();
+        for len in (0..midi.len()).step_by(midi.len() / 100 + 1) {
+            assert!(import(&midi[..len]).is_err());
+        }
+        assert!(import(&midi).is_ok());
+    }
+
+    #[test]
+    fn skips_invalid_tempos() {
+        let sample = include_bytes!("../pxtone-source-code 170212a/sample data/sample.ptcop");
+        let mut project = Project::new(Cursor::new(&sample[..])).unwrap();
+        let evelist = project.evelist_mut();
+        evelist.add_event_f32(480, 0, EventKind::BeatTempo, 0.0);
+        evelist.add_event_f32(960, 0, EventKind::BeatTempo, f32::NAN);
+        evelist.add_event_f32(1440, 0, EventKind::BeatTempo, -120.0);
+        evelist.add_event_f32(1920, 0, EventKind::BeatTempo, 90.0);
+        let tempos = project.clock_map().tempos().to_vec();
+        assert_eq!(tempos.len(), 2);
+        assert_eq!(tempos[1], (1920, 90.0));
+        assert!(import(&project.to_midi()).is_ok());
+    }
+}
@@ -0,0 +1,547 @@
+use std::iter;
+use std::time::Duration;
+
+use crate::delay::MAX_CHANNEL;
+use crate::error::{ErrorKind, Result};
+use crate::pulse::{integrated_loudness, OutputFormat, Pcm, Quality, NOISE_TABLE_SIZE};
+
+const SAMPLING_TOP: f64 = i16::MAX as f64;
+
+/// Handling of samples beyond full scale
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Clipping {
+    /// Cut at full scale when quantized, as pxtone does
+    #[default]
+    Hard,
+    /// Bend samples above the knee smoothly into full scale
+    Soft,
+}
+
+impl Clipping {
+    const KNEE: f64 = 0.75;
+
+    /// `sample` in a scale where `top` is full
+    pub fn apply(self, sample: f64, top: f64) -> f64 {
+        match self {
+            Clipping::Hard => sample,
+            Clipping::Soft => {
+                let knee = top * Self::KNEE;
+                let level = sample.abs();
+                if level <= knee {
+                    return sample;
+                }
+                let room = top - knee;
+                (knee + room * ((level - knee) / room).tanh()).copysign(sample)
+            }
+        }
+    }
+}
+
+/// Gain of unit velocity and volume values, which are 128 at full level
+#[derive(Clone, Copy, Debug, Default, PartialEq)]
+pub enum VelocityCurve {
+    /// Integer scaling by each value over 128, as pxtone does
+    #[default]
+    Original,
+    /// Both values over 128, without rounding in between
+    Linear,
+    /// 0dB at 128, falling by the given dB toward 0, and silent at 0
+    Decibel(f32),
+}
+
+impl VelocityCurve {
+    /// Gain of one value
+    pub fn gain(self, value: i32) -> f64 {
+        match self {
+            VelocityCurve::Original | VelocityCurve::Linear => f64::from(value) / 128.0,
+            VelocityCurve::Decibel(_) if value <= 0 => 0.0,
+            VelocityCurve::Decibel(range) => {
+                let db = -f64::from(range) * f64::from(128 - value) / 128.0;
+                10f64.powf(db / 20.0)
+            }
+        }
+    }
+
+    /// `smp` scaled by the velocity and the volume
+    pub(crate) fn apply(self, smp: i32, velocity: i32, volume: i32) -> i32 {
+        match self {
+            VelocityCurve::Original => smp * velocity / 128 * volume / 128,
+            _ => (f64::from(smp) * self.gain(velocity) * self.gain(volume)) as i32,
+        }
+    }
+}
+
+/// How sampled woices are read between their samples when pitched
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum SampleInterpolation {
+    /// The sample at or before the position, as pxtone does
+    #[default]
+    Nearest,
+    /// Straight line between the samples around the position
+    Linear,
+    /// Catmull-Rom spline through the four samples around the position
+    Cubic,
+}
+
+/// Levels of the two channels for a sound panned off center
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum PanLaw {
+    /// The far channel falls linearly to silence while the near one stays at full level, as
+    /// pxtone does
+    #[default]
+    Original,
+    /// Levels along a quarter circle, so that their power stays the same at every position;
+    /// full level on the near channel when panned hard, -3dB on both when centered
+    ConstantPower,
+}
+
+impl PanLaw {
+    /// Left and right levels at `position`, from -1.0 (left) to 1.0 (right)
+    pub fn levels(self, position: f64) -> [f64; 2] {
+        let position = position.clamp(-1.0, 1.0);
+        match self {
+            PanLaw::Original if position < 0.0 => [1.0, 1.0 + position],
+            PanLaw::Original => [1.0 - position, 1.0],
+            PanLaw::ConstantPower => {
+                let angle = (position + 1.0) * std::f64::consts::FRAC_PI_4;
+                [angle.cos(), angle.sin()]
+            }
+        }
+    }
+}
+
+/// How stereo sources are mixed into one channel
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Downmix {
+    /// As pxtone: woices average their channels and noise units keep their left level
+    #[default]
+    Original,
+    /// Half of each channel, keeping a centered sound at its level
+    Average,
+    /// Each channel at -3dB, keeping the power of unrelated channels
+    ConstantPower,
+}
+
+impl Downmix {
+    /// One sample from a left and a right sample
+    pub fn apply(self, left: f64, right: f64) -> f64 {
+        match self {
+            Downmix::Original | Downmix::Average => (left + right) / 2.0,
+            Downmix::ConstantPower => (left + right) * std::f64::consts::FRAC_1_SQRT_2,
+        }
+    }
+}
+
+/// How mono noise units are spread over two channels
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Upmix {
+    /// Both channels at full level when centered, as pxtone does
+    #[default]
+    Duplicate,
+    /// Both channels at -3dB when centered, keeping the power of the mono sound
+    ConstantPower,
+}
+
+impl Upmix {
+    /// Level of each channel of a centered sound
+    pub fn level(self) -> f64 {
+        match self {
+            Upmix::Duplicate => 1.0,
+            Upmix::ConstantPower => std::f64::consts::FRAC_1_SQRT_2,
+        }
+    }
+}
+
+/// Noise added before reducing samples to 8 or 16 bits
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Dither {
+    #[default]
+    None,
+    /// Triangular noise of one output step
+    Tpdf,
+    /// `Tpdf` with the quantization error pushed toward high frequencies
+    TpdfShaped,
+}
+
+/// Dither state over interleaved samples
+struct Ditherer {
+    dither: Dither,
+    format: OutputFormat,
+    step: f64,
+    ch: usize,
+    index: usize,
+    random: u32,
+    errors: [f64; MAX_CHANNEL],
+}
+
+impl Ditherer {
+    const DEFAULT_SEED: u32 = 0x2545_f491;
+
+    fn new(options: &RenderOptions) -> Self {
+        let step = match options.format {
+            OutputFormat::U8 => 256.0,
+            OutputFormat::I16 => 1.0,
+            _ => 0.0,
+        };
+        Self {
+            dither: if step > 0.0 {
+                options.dither
+            } else {
+                Dither::None
+            },
+            format: options.format,
+            step,
+            ch: usize::from(options.channels),
+            index: 0,
+            random: options.random_seed.unwrap_or(Self::DEFAULT_SEED).max(1),
+            errors: [0.0; MAX_CHANNEL],
+        }
+    }
+
+    /// xorshift32 in [0, 1)
+    fn next_random(&mut self) -> f64 {
+        self.random ^= self.random << 13;
+        self.random ^= self.random >> 17;
+        self.random ^= self.random << 5;
+        f64::from(self.random) / (f64::from(u32::MAX) + 1.0)
+    }
+
+    fn apply(&mut self, sample: f64) -> f64 {
+        if self.dither == Dither::None {
+            return sample;
+        }
+        let c = self.index % self.ch;
+        self.index += 1;
+
+        let noise = (self.next_random() - self.next_random()) * self.step;
+        if self.dither == Dither::Tpdf {
+            return sample + noise;
+        }
+
+        let wanted = sample - self.errors[c];
+        let dithered = wanted + noise;
+        self.errors[c] = self.format.quantized(dithered) - wanted;
+        dithered
+    }
+}
+
+/// Output settings shared by noise, woice and song rendering
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct RenderOptions {
+    /// 1 or 2
+    pub channels: u16,
+    pub sample_rate: u32,
+    pub format: OutputFormat,
+    pub quality: Quality,
+    /// Factor applied to the output before quantizing
+    pub gain: f64,
+    /// Scale the whole output so that its peak reaches full scale, in place of `gain`
+    pub normalize: bool,
+    /// Scale the whole output so that its integrated loudness reaches this many LUFS, in place
+    /// of `gain` and `normalize`
+    pub loudness_target: Option<f64>,
+    pub clipping: Clipping,
+    /// Applied when the format is `U8` or `I16`
+    pub dither: Dither,
+    /// Draw noise random waves from a generator seeded by this instead of the pxtone table
+    pub random_seed: Option<u32>,
+    /// Samples in one cycle of the noise wave tables; pxtone's 441 keeps noises bit-exact
+    pub noise_table_size: u32,
+    /// Response of unit velocity and volume
+    pub velocity_curve: VelocityCurve,
+    pub sample_interpolation: SampleInterpolation,
+    pub pan_law: PanLaw,
+    /// Mix of stereo sources when `channels` is 1
+    pub downmix: Downmix,
+    /// Spread of mono noise units when `channels` is 2
+    pub upmix: Upmix,
+    /// Longest output of a whole render, failing with `TooLong` beyond it; streaming playback
+    /// is not limited
+    pub max_duration: Option<Duration>,
+}
+
+/// Fail unless `gain` is a level from silence up; NaN is rejected as well
+pub(crate) fn check_gain(gain: f64) -> Result<()> {
+    if !gain.is_finite() || gain < 0.0 {
+        return Err(ErrorKind::InvalidRenderOptions("gain must be finite and non-negative").into());
+    }
+    Ok(())
+}
+
+impl RenderOptions {
+    const NOISE_SAMPLE_RATES: [u32; 4] = [11025, 22050, 44100, 48000];
+    const LIMIT_SAMPLE_RATE: u32 = 192_000;
+    const MIN_NOISE_TABLE_SIZE: u32 = 16;
+    const LIMIT_NOISE_TABLE_SIZE: u32 = 1 << 16;
+
+    pub fn new(channels: u16, sample_rate: u32, format: OutputFormat) -> Self {
+        Self {
+            channels,
+            sample_rate,
+            format,
+            ..Self::default()
+        }
+    }
+
+    pub fn with_quality(mut self, quality: Quality) -> Self {
+        self.quality = quality;
+        self
+    }
+
+    pub fn with_gain(mut self, gain: f64) -> Self {
+        self.gain = gain;
+        self
+    }
+
+    pub fn with_normalize(mut self, normalize: bool) -> Self {
+        self.normalize = normalize;
+        self
+    }
+
+    pub fn with_loudness_target(mut self, loudness_target: Option<f64>) -> Self {
+        self.loudness_target = loudness_target;
+        self
+    }
+
+    pub fn with_clipping(mut self, clipping: Clipping) -> Self {
+        self.clipping = clipping;
+        self
+    }
+
+    pub fn with_dither(mut self, dither: Dither) -> Self {
+        self.dither = dither;
+        self
+    }
+
+    pub fn with_random_seed(mut self, random_seed: Option<u32>) -> Self {
+        self.random_seed = random_seed;
+        self
+    }
+
+    pub fn with_noise_table_size(mut self, noise_table_size: u32) -> Self {
+        self.noise_table_size = noise_table_size;
+        self
+    }
+
+    pub fn with_velocity_curve(mut self, velocity_curve: VelocityCurve) -> Self {
+        self.velocity_curve = velocity_curve;
+        self
+    }
+
+    pub fn with_sample_interpolation(mut self, sample_interpolation: SampleInterpolation) -> Self {
+        self.sample_interpolation = sample_interpolation;
+        self
+    }
+
+    pub fn with_pan_law(mut self, pan_law: PanLaw) -> Self {
+        self.pan_law = pan_law;
+        self
+    }
+
+    pub fn with_downmix(mut self, downmix: Downmix) -> Self {
+        self.downmix = downmix;
+        self
+    }
+
+    pub fn with_upmix(mut self, upmix: Upmix) -> Self {
+        self.upmix = upmix;
+        self
+    }
+
+    pub fn with_max_duration(mut self, max_duration: Option<Duration>) -> Self {
+        self.max_duration = max_duration;
+        self
+    }
+
+    pub(crate) fn validate(&self) -> Result<()> {
+        if self.channels != 1 && self.channels != 2 {
+            return Err(ErrorKind::InvalidRenderOptions("channels must be 1 or 2").into());
+        }
+        if self.sample_rate == 0 || self.sample_rate > Self::LIMIT_SAMPLE_RATE {
+            return Err(ErrorKind::InvalidRenderOptions("sample rate is out of range").into());
+        }
+        check_gain(self.gain)?;
+        if matches!(self.loudness_target, Some(target) if !target.is_finite()) {
+            return Err(ErrorKind::InvalidRenderOptions("loudness target must be finite").into());
+        }
+        if let VelocityCurve::Decibel(range) = self.velocity_curve {
+            if !range.is_finite() || range < 0.0 {
+                return Err(ErrorKind::InvalidRenderOptions(
+                    "decibel range must be finite and non-negative",
+                )
+                .into());
+            }
+        }
+        Ok(())
+    }
+
+    /// One sample in 16-bit scale after gain and clipping
+    pub(crate) fn master(&self, sample: f64) -> f64 {
+        self.clipping.apply(sample * self.gain, SAMPLING_TOP)
+    }
+
+    /// Fail with `TooLong` when `frames` run past `max_duration`
+    pub(crate) fn check_length(&self, frames: u64) -> Result<()> {
+        match self.max_duration {
+            Some(max) if frames as f64 > max.as_secs_f64() * f64::from(self.sample_rate) => {
+                Err(ErrorKind::TooLong(max).into())
+            }
+            _ => Ok(()),
+        }
+    }
+
+    /// Interleaved `samples` cut one frame past `max_duration`, so that `check_length` catches
+    /// an endless song without rendering all of it
+    pub(crate) fn limit<I: Iterator<Item = f64>>(&self, samples: I) -> iter::Take<I> {
+        let frames = match self.max_duration {
+            Some(max) => (max.as_secs_f64() * f64::from(self.sample_rate)) as usize + 1,
+            None => usize::MAX,
+        };
+        samples.take(frames.saturating_mul(usize::from(self.channels)))
+    }
+
+    /// Quantize interleaved samples in 16-bit scale, applying gain, normalization, clipping
+    /// and dither
+    pub(crate) fn quantize<I: IntoIterator<Item = f64>>(&self, samples: I) -> Result<Pcm> {
+        let (ch, sps, format) = (self.channels, self.sample_rate, self.format);
+        let mut ditherer = Ditherer::new(self);
+        let samples = self.limit(samples.into_iter());
+        if !self.normalize && self.loudness_target.is_none() {
+            let samples = samples.map(|s| ditherer.apply(self.master(s)));
+            let pcm = Pcm::from_samples(ch, sps, format, samples)?;
+            self.check_length(u64::from(pcm.smp_num()))?;
+            return Ok(pcm);
+        }
+
+        let samples = samples.collect::<Vec<_>>();
+        self.check_length((samples.len() / usize::from(ch)) as u64)?;
+        let scale = if let Some(target) = self.loudness_target {
+            let full_scale = samples.iter().map(|s| s / (SAMPLING_TOP + 1.0));
+            let loudness = integrated_loudness(full_scale, usize::from(ch), sps);
+            if loudness.is_finite() {
+                10f64.powf((target - loudness) / 20.0)
+            } else {
+                1.0
+            }
+        } else {
+            let peak = samples.iter().fold(0.0, |peak: f64, s| peak.max(s.abs()));
+            if peak > 0.0 {
+                SAMPLING_TOP / peak
+            } else {
+                1.0
+            }
+        };
+        let samples = samples
+            .into_iter()
+            .map(|s| ditherer.apply(self.clipping.apply(s * scale, SAMPLING_TOP)));
+        Pcm::from_samples(ch, sps, format, samples)
+    }
+
+    /// Fade interleaved samples linearly to silence over the last `duration`
+    pub(crate) fn fade_out(&self, samples: &mut [f64], duration: Duration) {
+        let ch = usize::from(self.channels);
+        let frames = samples.len() / ch;
+        let fade = ((duration.as_secs_f64() * f64::from(self.sample_rate)) as usize).min(frames);
+        let start = frames - fade;
+        for (i, frame) in samples.chunks_mut(ch).skip(start).enumerate() {
+            let level = (fade - i) as f64 / fade as f64;
+            for smp in frame.iter_mut() {
+                *smp *= level;
+            }
+        }
+    }
+
+    /// `validate` for the rates noise building supports
+    pub(crate) fn validate_noise(&self) -> Result<()> {
+        self.validate()?;
+        if !Self::NOISE_SAMPLE_RATES.contains(&self.sample_rate) {
+            return Err(ErrorKind::InvalidRenderOptions(
+                "noise sample rate must be 11025, 22050, 44100 or 48000",
+            )
+            .into());
+        }
+        if self.noise_table_size < Self::MIN_NOISE_TABLE_SIZE
+            || self.noise_table_size > Self::LIMIT_NOISE_TABLE_SIZE
+        {
+            return Err(ErrorKind::InvalidRenderOptions("noise table size is out of range").into());
+        }
+        Ok(())
+    }
+}
+
+impl Default for RenderOptions {
+    /// 16-bit stereo at 44.1kHz, as pxtone plays
+    fn default() -> Self {
+        Self {
+            channels: 2,
+            sample_rate: 44100,
+            format: OutputFormat::I16,
+            quality: Quality::Original,
+            gain: 1.0,
+            normalize: false,
+            loudness_target: None,
+            clipping: Clipping::Hard,
+            dither: Dither::None,
+            random_seed: None,
+            noise_table_size: NOISE_TABLE_SIZE as u32,
+            velocity_curve: VelocityCurve::Original,
+            sample_interpolation: SampleInterpolation::Nearest,
+            pan_law: PanLaw::Original,
+            downmix: Downmix::Original,
+            upmix: Upmix::Duplicate,
+            max_duration: None,
+        }
+    }
+}
+
+/// Handling of out-of-range data while reading files
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Fail on values pxtone would clamp instead of clamping them
+    pub strict: bool,
+    /// Read files newer than this crate knows as the newest version it does, instead of
+    /// failing with `UnsupportedVersion`
+    pub allow_newer: bool,
+}
+
+impl ParseOptions {
+    pub fn with_strict(mut self, strict: bool) -> Self {
+        self.strict = strict;
+        self
+    }
+
+    pub fn with_allow_newer(mut self, allow_newer: bool) -> Self {
+        self.allow_newer = allow_newer;
+        self
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn validates_gain() {
+        let options = |gain| RenderOptions::default().with_gain(gain);
+        assert!(options(0.0).validate().is_ok());
+        assert!(options(2.0).validate().is_ok());
+        for &gain in &[-1.0, f64::NAN, f64::INFINITY] {
+            let err = options(gain).validate().unwrap_err();
+            assert!(matches!(err.kind(), ErrorKind::InvalidRenderOptions(_)));
+        }
+    }
+
+    #[test]
+    fn constant_power_pan_levels() {
+        let law = PanLaw::ConstantPower;
+        let close = |a: [f64; 2], b: [f64; 2]| a.iter().zip(&b).all(|(a, b)| (a - b).abs() < 1e-12);
+        assert!(close(law.levels(-1.0), [1.0, 0.0]));
+        assert!(close(law.levels(1.0), [0.0, 1.0]));
+        let center = std::f64::consts::FRAC_1_SQRT_2;
+        assert!(close(law.levels(0.0), [center, center]));
+        for i in -10..=10 {
+            let [left, right] = law.levels(f64::from(i) / 10.0);
+            assert!((left * left + right * right - 1.0).abs() < 1e-12);
+        }
+    }
+}
@@ -0,0 +1,128 @@
+use std::io::{Read, Write};
+
+use crate::descriptor::read_struct;
+use crate::error::{ensure, Result};
+use crate::report::Report;
+
+use byteorder::{LittleEndian, WriteBytesExt as _};
+
+use crate::delay::MAX_GROUP_NUM;
+
+/// Clipping distortion applied to a unit group
+#[derive(Clone, Debug, PartialEq)]
+pub struct OverDrive {
+    played: bool,
+    group: i32,
+    cut: f32,
+    amp: f32,
+
+    cut_16bit_top: i32,
+}
+
+impl Default for OverDrive {
+    fn default() -> Self {
+        Self::new(Self::DEFAULT_CUT, Self::DEFAULT_AMP, 0)
+    }
+}
+
+impl OverDrive {
+    const SIZE: u32 = 16;
+
+    pub const CUT_MAX: f32 = 99.9;
+    pub const CUT_MIN: f32 = 50.0;
+    pub const AMP_MAX: f32 = 8.0;
+    pub const AMP_MIN: f32 = 0.1;
+    pub const DEFAULT_CUT: f32 = 90.0;
+    pub const DEFAULT_AMP: f32 = 2.0;
+
+    pub fn new(cut: f32, amp: f32, group: i32) -> Self {
+        let mut overdrive = Self {
+            played: true,
+            group,
+            cut,
+            amp,
+            cut_16bit_top: 0,
+        };
+        overdrive.tone_ready();
+        overdrive
+    }
+
+    pub(crate) fn read<T: Read>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        let (size, xxx, group, cut, amp, yyy): (u32, u16, u16, f32, f32, f32) = read_struct(bytes)?;
+        ensure(size == Self::SIZE, "overdrive size")?;
+        let cut = report.finite("cut", cut, Self::DEFAULT_CUT)?;
+        let amp = report.finite("amp", amp, Self::DEFAULT_AMP)?;
+
+        ensure(xxx == 0 && yyy == 0.0, "overdrive reserved data")?;
+        let (cuts, amps) = (Self::CUT_MIN..=Self::CUT_MAX, Self::AMP_MIN..=Self::AMP_MAX);
+        ensure(cuts.contains(&cut), "overdrive cut")?;
+        ensure(amps.contains(&amp), "overdrive amp")?;
+        // as with delays, pxtone plays groups it has no bus for in the first
+        let group = i32::from(group);
+        let group = if group >= MAX_GROUP_NUM { 0 } else { group };
+
+        Ok(Self::new(cut, amp, group))
+    }
+
+    pub(crate) fn write<W: Write>(&self, bytes: &mut W) -> Result<()> {
+        bytes.write_u32::<LittleEndian>(Self::SIZE)?;
+        bytes.write_u16::<LittleEndian>(0)?;
+        bytes.write_u16::<LittleEndian>(self.group as u16)?;
+        bytes.write_f32::<LittleEndian>(self.cut)?;
+        bytes.write_f32::<LittleEndian>(self.amp)?;
+        bytes.write_f32::<LittleEndian>(0.0)?;
+        Ok(())
+    }
+
+    /// Clipping level in percent of full scale cut away
+    pub fn cut(&self) -> f32 {
+        self.cut
+    }
+
+    /// Gain applied after clipping
+    pub fn amp(&self) -> f32 {
+        self.amp
+    }
+
+    pub fn group(&self) -> i32 {
+        self.group
+    }
+
+    pub fn set(&mut self, cut: f32, amp: f32, group: i32) {
+        self.cut = cut;
+        self.amp = amp;
+        self.group = group;
+        self.tone_ready();
+    }
+
+    pub fn played(&self) -> bool {
+        self.played
+    }
+
+    pub fn set_played(&mut self, played: bool) {
+        self.played = played;
+    }
+
+    pub fn tone_ready(&mut self) {
+        self.cut_16bit_top = (32767.0 * (100.0 - self.cut) / 100.0) as i32;
+    }
+
+    pub fn tone_supple(&self, group_smps: &mut [i32]) {
+        if !self.played {
+            return;
+        }
+        let group = self.group as usize;
+        let work = group_smps[group].clamp(-self.cut_16bit_top, self.cut_16bit_top);
+        group_smps[group] = (work as f32 * self.amp) as i32;
+    }
+
+    /// Apply the overdrive in place to samples in [-1.0, 1.0]
+    pub fn process(&self, smp: &mut [f32]) {
+        let mut group_smps = [0; MAX_GROUP_NUM as usize];
+        for s in smp.iter_mut() {
+            group_smps[self.group as usize] = (*s * 32767.0) as i32;
+            self.tone_supple(&mut group_smps);
+            *s = group_smps[self.group as usize] as f32 / 32767.0;
+        }
+    }
+}
@@ -0,0 +1,83 @@
+//! Keys as pxtone counts them: 0x100 per semitone, with `0x4500` the A at 440Hz
+
+#[cfg(not(feature = "computed-frequency"))]
+mod frequency_table;
+
+#[cfg(not(feature = "computed-frequency"))]
+use frequency_table::*;
+
+#[cfg(feature = "computed-frequency")]
+use std::cell::Cell;
+
+/// Key steps in a semitone
+pub const KEY_PER_SEMITONE: i32 = 0x100;
+/// Key steps in an octave
+pub const KEY_PER_OCTAVE: i32 = KEY_PER_SEMITONE * 12;
+
+/// Frequency ratio of a key offset; `KEY_PER_OCTAVE` doubles it
+///
+/// Looked up in 1/16 semitone steps within 8 octaves either way, as pxtone does, or computed
+/// for every key with the `computed-frequency` feature.
+pub fn key_to_frequency(key: i32) -> f32 {
+    Frequency::get(key)
+}
+
+/// Key offset giving the frequency ratio `frequency`, the inverse of `key_to_frequency`
+pub fn frequency_to_key(frequency: f32) -> i32 {
+    assert!(frequency > 0.0);
+    (f64::from(frequency).log2() * f64::from(KEY_PER_OCTAVE)).round() as i32
+}
+
+/// Key of a MIDI note number, so that note 69 is `0x4500`
+pub fn midi_note_to_key(note: u8) -> i32 {
+    i32::from(note) * KEY_PER_SEMITONE
+}
+
+pub(crate) struct Frequency {}
+
+#[cfg(not(feature = "computed-frequency"))]
+impl Frequency {
+    pub(crate) fn get(key: i32) -> f32 {
+        let i = ((i64::from(key) + 0x6000) * (FREQUENCY_PER_KEY as i64) / 0x100)
+            .clamp(0, (FREQUENCY_TABLE_SIZE as i64) - 1) as usize;
+        FREQUENCY_TABLE[i]
+    }
+
+    pub(crate) fn get2(key: i32) -> f32 {
+        let i = (key >> 4).clamp(0, (FREQUENCY_TABLE_SIZE as i32) - 1) as usize;
+        FREQUENCY_TABLE[i]
+    }
+}
+
+#[cfg(feature = "computed-frequency")]
+thread_local! {
+    /// Recent keys and their ratios, as the same keys are asked for sample after sample
+    static FREQUENCY_CACHE: [Cell<(i32, f32)>; Frequency::CACHE_SIZE] =
+        Default::default();
+}
+
+#[cfg(feature = "computed-frequency")]
+impl Frequency {
+    const CACHE_SIZE: usize = 16;
+    const LIMIT_KEY: i32 = KEY_PER_OCTAVE * 8;
+
+    pub(crate) fn get(key: i32) -> f32 {
+        let key = key.clamp(-Self::LIMIT_KEY, Self::LIMIT_KEY);
+        FREQUENCY_CACHE.with(|cache| {
+            let entry = &cache[key.rem_euclid(Self::CACHE_SIZE as i32) as usize];
+            match entry.get() {
+                (k, frequency) if k == key && frequency != 0.0 => frequency,
+                _ => {
+                    let frequency = (f64::from(key) / f64::from(KEY_PER_OCTAVE)).exp2() as f32;
+                    entry.set((key, frequency));
+                    frequency
+                }
+            }
+        })
+    }
+
+    /// `get` for keys where 0x6000 plays at the original frequency
+    pub(crate) fn get2(key: i32) -> f32 {
+        Self::get(key - 0x6000)
+    }
+}
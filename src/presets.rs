@@ -0,0 +1,127 @@
+//! Stock instruments, built without reading `.ptnoise` or `.ptvoice` files
+
+use crate::envelope::Envelope;
+use crate::pulse::{Noise, NoiseOscillator, NoiseUnit, NoiseWave, Voice, VoiceUnit};
+
+fn osc(wave: NoiseWave, freq: f32, volu: f32, offset: f32) -> Option<NoiseOscillator> {
+    Some(NoiseOscillator::new(wave, false, freq, volu, offset))
+}
+
+/// Noise of preset `units`, which stay within the limits of the format
+fn noise(smp_num_44k: u32, units: Vec<NoiseUnit>) -> Noise {
+    Noise::from_units(smp_num_44k, units).unwrap()
+}
+
+/// Centered unit of preset envelope points
+fn unit(
+    enves: &[(i32, i32)],
+    main: Option<NoiseOscillator>,
+    freq: Option<NoiseOscillator>,
+) -> NoiseUnit {
+    NoiseUnit::new(enves, 0, main, freq, None).unwrap()
+}
+
+/// Sine kick with a noisy attack (`drum_bass1`)
+pub fn bass_drum() -> Noise {
+    noise(
+        8714,
+        vec![
+            unit(
+                &[(1, 100), (100, 20), (200, 0)],
+                osc(NoiseWave::Sine, 50.0, 180.0, 2.0),
+                osc(NoiseWave::Saw, 5.0, 20.0, 0.0),
+            ),
+            unit(
+                &[(1, 100), (3, 20), (100, 0)],
+                osc(NoiseWave::Random, 22000.0, 10.0, 0.0),
+                None,
+            ),
+        ],
+    )
+}
+
+/// Deeper kick with a falling pitch
+pub fn bass_drum_2() -> Noise {
+    noise(
+        12140,
+        vec![
+            unit(
+                &[(2, 100), (80, 50), (10, 0)],
+                osc(NoiseWave::Sine, 6.0, 140.0, 0.0),
+                osc(NoiseWave::Saw, 10.0, 120.0, 95.0),
+            ),
+            unit(
+                &[(2, 100), (60, 50), (10, 0)],
+                osc(NoiseWave::Sine, 10.0, 50.0, 0.0),
+                osc(NoiseWave::Saw, 16.0, 110.0, 4.0),
+            ),
+            unit(
+                &[(1, 100), (20, 0), (0, 0)],
+                osc(NoiseWave::Random, 44000.0, 30.0, 0.0),
+                osc(NoiseWave::Random, 100.0, 100.0, 0.0),
+            ),
+        ],
+    )
+}
+
+/// Tone and noise snare (`drum_snare2`)
+pub fn snare_drum() -> Noise {
+    noise(
+        8600,
+        vec![
+            unit(
+                &[(0, 100), (100, 20), (100, 0)],
+                osc(NoiseWave::Sine, 200.0, 110.0, 6.0),
+                osc(NoiseWave::Random, 2200.0, 12.0, 0.0),
+            ),
+            unit(
+                &[(0, 100), (10, 30), (200, 0)],
+                osc(NoiseWave::Random, 20000.0, 60.0, 0.0),
+                None,
+            ),
+        ],
+    )
+}
+
+/// Short noise burst
+pub fn hihat_closed() -> Noise {
+    noise(
+        4444,
+        vec![unit(
+            &[(0, 100), (10, 40), (100, 0)],
+            osc(NoiseWave::Random, 30000.0, 60.0, 0.0),
+            None,
+        )],
+    )
+}
+
+/// `hihat_closed` left ringing
+pub fn hihat_open() -> Noise {
+    noise(
+        22050,
+        vec![unit(
+            &[(0, 100), (30, 60), (400, 0)],
+            osc(NoiseWave::Random, 30000.0, 60.0, 0.0),
+            None,
+        )],
+    )
+}
+
+/// Looping wave with a short attack and release
+fn tone(set_wave: impl FnOnce(&mut VoiceUnit)) -> Voice {
+    let mut unit = VoiceUnit::default();
+    set_wave(&mut unit);
+    unit.set_flags(VoiceUnit::FLAG_WAVELOOP | VoiceUnit::FLAG_SMOOTH);
+    unit.set_envelope(Some(Envelope::new(1000, vec![(5, 128)], 40)));
+    Voice::from_units(vec![unit])
+}
+
+/// Half-duty pulse
+pub fn square_wave() -> Voice {
+    tone(|unit| unit.set_coordinate_wave(&[(0, 32), (1, -32)], 2))
+}
+
+/// Softer than `square_wave`, for bass lines
+pub fn triangle_wave() -> Voice {
+    tone(|unit| unit.set_coordinate_wave(&[(0, 0), (1, 48), (3, -48)], 4))
+}
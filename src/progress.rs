@@ -0,0 +1,81 @@
+//! Progress of long renders, for progress bars and cancelling
+
+use std::ops::ControlFlow;
+
+use crate::error::{ErrorKind, Result};
+
+/// Frames rendered out of the frames to render
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Progress {
+    pub done: u64,
+    pub total: u64,
+}
+
+impl Progress {
+    /// `done` over `total`, from 0.0 to 1.0
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 1.0;
+        }
+        (self.done as f64 / self.total as f64).min(1.0) as f32
+    }
+}
+
+/// Calls a progress callback every few thousand frames, until it breaks
+pub(crate) struct Reporter<'a> {
+    callback: &'a mut dyn FnMut(Progress) -> ControlFlow<()>,
+    total: u64,
+    next: u64,
+    cancelled: bool,
+    /// Span of the frames since the last report
+    #[cfg(feature = "tracing")]
+    block: Option<tracing::span::EnteredSpan>,
+}
+
+impl<'a> Reporter<'a> {
+    const INTERVAL: u64 = 4096;
+
+    pub(crate) fn new(
+        callback: &'a mut dyn FnMut(Progress) -> ControlFlow<()>,
+        total: u64,
+    ) -> Self {
+        Self {
+            callback,
+            total,
+            next: 0,
+            cancelled: false,
+            #[cfg(feature = "tracing")]
+            block: None,
+        }
+    }
+
+    /// Report `done` frames if an interval has passed since the last report; false once
+    /// cancelled
+    pub(crate) fn report(&mut self, done: u64) -> bool {
+        if !self.cancelled && done >= self.next {
+            self.next = done + Self::INTERVAL;
+            let progress = Progress {
+                done,
+                total: self.total,
+            };
+            self.cancelled = (self.callback)(progress).is_break();
+            #[cfg(feature = "tracing")]
+            {
+                // the last block is left before the next is entered
+                self.block = None;
+                self.block = Some(tracing::debug_span!("block", start = done).entered());
+            }
+        }
+        !self.cancelled
+    }
+
+    /// Report the end, failing with `Cancelled` if the callback broke
+    pub(crate) fn finish(self) -> Result<()> {
+        if self.cancelled {
+            return Err(ErrorKind::Cancelled.into());
+        }
+        let total = self.total;
+        let _ = (self.callback)(Progress { done: total, total });
+        Ok(())
+    }
+}
@@ -0,0 +1,1139 @@
+use std::io::{Cursor, Read, Seek, Write};
+use std::iter;
+use std::ops::{ControlFlow, Range};
+use std::sync::Arc;
+use std::time::Duration;
+
+mod borrowed;
+#[cfg(feature = "kira")]
+mod kira_io;
+mod player;
+mod reload;
+mod renderer;
+mod session;
+mod stats;
+
+use crate::error::{at_position, ensure, ErrorKind, Result};
+
+use crate::descriptor::{
+    check_count, read_chunks, Chunk, ChunkFlow, ReadBytesExt as _, TrackedReader,
+    WriteBytesExt as _,
+};
+use byteorder::{LittleEndian, ReadBytesExt as _, WriteBytesExt as _};
+
+use crate::delay::{Delay, MAX_GROUP_NUM};
+use crate::evelist::{EveList, EventKind};
+use crate::master::Master;
+use crate::midi::{self, MidiOptions};
+use crate::options::{ParseOptions, RenderOptions};
+use crate::overdrive::OverDrive;
+use crate::progress::{Progress, Reporter};
+use crate::pulse::Pcm;
+use crate::report::Report;
+use crate::timing::ClockMap;
+use crate::unit::Unit;
+use crate::woice::{Woice, WoiceCache, WoiceInstance};
+
+pub use borrowed::ProjectRef;
+#[cfg(feature = "kira")]
+pub use kira_io::SongSoundData;
+pub use player::PlayerHandle;
+pub use reload::ProjectChanges;
+pub use renderer::{MooState, Renderer, RendererSnapshot, UnitState};
+pub use session::Session;
+pub use stats::{ProjectStats, UnitStats};
+
+use borrowed::Places;
+use renderer::Song;
+
+/// Revision of the project format
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord)]
+pub enum FormatVersion {
+    /// v0.1.2.8: project holds quality, tempo and clock
+    X1x,
+    /// v0.6: no exe version
+    X2x,
+    /// v0.7: units include voices, basic key is only for view
+    X3x,
+    /// v0.8.3.4: units have event lists
+    X4x,
+    /// Newest revision read, and the one written
+    V5,
+}
+
+/// Song (`.ptcop`) or tune (`.pttune`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Project {
+    name: String,
+    comment: String,
+    master: Master,
+    evelist: EveList,
+    units: Vec<Unit>,
+    woices: Vec<Woice>,
+    woice_names: Vec<String>,
+    delays: Vec<Delay>,
+    overdrives: Vec<OverDrive>,
+    unknown_chunks: Vec<Chunk>,
+    version: FormatVersion,
+    blocks: Vec<[u8; 8]>,
+    /// Unit played alone in renders, whatever the mutes
+    solo: Option<usize>,
+}
+
+impl Project {
+    const CODE_PROJ_X1X: &'static [u8] = b"PTCOLLAGE-050227";
+    const CODE_PROJ_X2X: &'static [u8] = b"PTCOLLAGE-050608";
+    const CODE_PROJ_X3X: &'static [u8] = b"PTCOLLAGE-060115";
+    const CODE_PROJ_X4X: &'static [u8] = b"PTCOLLAGE-060930";
+    const CODE_PROJ_V5: &'static [u8] = b"PTCOLLAGE-071119";
+    const CODE_TUNE_X2X: &'static [u8] = b"PTTUNE--20050608";
+    const CODE_TUNE_X3X: &'static [u8] = b"PTTUNE--20060115";
+    const CODE_TUNE_X4X: &'static [u8] = b"PTTUNE--20060930";
+    const CODE_TUNE_V5: &'static [u8] = b"PTTUNE--20071119";
+    /// Date of the newest codes
+    const V5_DATE: u32 = 2007_1119;
+
+    const TAG_NUM_UNIT: &'static [u8] = b"num UNIT";
+    const TAG_MASTER_V5: &'static [u8] = b"MasterV5";
+    const TAG_EVENT_V5: &'static [u8] = b"Event V5";
+    const TAG_MATE_PCM: &'static [u8] = b"matePCM ";
+    const TAG_MATE_PTV: &'static [u8] = b"matePTV ";
+    const TAG_MATE_PTN: &'static [u8] = b"matePTN ";
+    const TAG_MATE_OGGV: &'static [u8] = b"mateOGGV";
+    const TAG_EFFE_DELA: &'static [u8] = b"effeDELA";
+    const TAG_EFFE_OVER: &'static [u8] = b"effeOVER";
+    const TAG_TEXT_NAME: &'static [u8] = b"textNAME";
+    const TAG_TEXT_COMM: &'static [u8] = b"textCOMM";
+    const TAG_ASSI_UNIT: &'static [u8] = b"assiUNIT";
+    const TAG_ASSI_WOIC: &'static [u8] = b"assiWOIC";
+    const TAG_END: &'static [u8] = b"pxtoneND";
+
+    const TAG_X1X_PROJ: &'static [u8] = b"PROJECT=";
+    const TAG_X1X_UNIT: &'static [u8] = b"UNIT====";
+    const TAG_X1X_PCM: &'static [u8] = b"matePCM=";
+    const TAG_X1X_EVEN: &'static [u8] = b"EVENT===";
+    const TAG_X1X_END: &'static [u8] = b"END=====";
+    const TAG_X3X_UNIT: &'static [u8] = b"pxtnUNIT";
+    const TAG_X4X_MAST: &'static [u8] = b"evenMAST";
+    const TAG_X4X_EVEN: &'static [u8] = b"evenUNIT";
+
+    const ASSIST_SIZE: u32 = 4 + Unit::MAX_NAME_SIZE as u32;
+    /// Clocks per clock of tunes
+    const TUNE_ROUGH: i32 = 10;
+
+    const X3X_UNIT_PCM: u16 = 1;
+    const X3X_UNIT_PTN: u16 = 3;
+
+    pub const MAX_WOICE_NUM: usize = 100;
+    pub const MAX_DELAY_NUM: usize = 4;
+    pub const MAX_OVERDRIVE_NUM: usize = 2;
+
+    pub fn new<T: Read + Seek>(bytes: T) -> Result<Self> {
+        Self::parse(bytes, &ParseOptions::default())
+    }
+
+    pub fn parse<T: Read + Seek>(bytes: T, options: &ParseOptions) -> Result<Self> {
+        let mut bytes = TrackedReader::new(bytes)?;
+        let result = Self::read(&mut bytes, &mut Report::with_options(options), None);
+        at_position(&mut bytes, result)
+    }
+
+    /// Parse a project from memory, such as a mapped file, leaving the data of PCM and Ogg
+    /// Vorbis woices in `bytes` instead of copying it
+    pub fn parse_ref(bytes: &[u8]) -> Result<ProjectRef<'_>> {
+        let mut places = Vec::new();
+        let options = ParseOptions::default();
+        let mut cursor = Cursor::new(bytes);
+        let report = &mut Report::with_options(&options);
+        let result = Self::read(&mut cursor, report, Some(&mut places));
+        let project = at_position(&mut cursor, result)?;
+        Ok(ProjectRef::new(project, bytes, places))
+    }
+
+    /// Read a project, reporting values it clamps and flags it drops
+    pub fn validate<T: Read + Seek>(bytes: T) -> Result<Report> {
+        let mut bytes = TrackedReader::new(bytes)?;
+        let mut report = Report::default();
+        let result = Self::read(&mut bytes, &mut report, None);
+        at_position(&mut bytes, result)?;
+        Ok(report)
+    }
+
+    /// With `places`, sampled woices are read empty and where their data lies is recorded
+    fn read<T: Read + Seek>(
+        bytes: &mut T,
+        report: &mut Report,
+        mut places: Option<&mut Places>,
+    ) -> Result<Self> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("read_project").entered();
+        let version = Self::read_version(bytes, report)?;
+
+        let mut project = Self::empty();
+        project.version = version;
+
+        project.unknown_chunks = read_chunks(bytes, |tag, bytes: &mut T| {
+            project.blocks.push(*tag);
+            match &tag[..] {
+                Self::TAG_NUM_UNIT => {
+                    let num = Unit::read_num(bytes)?;
+                    project.units = (0..num).map(|_| Unit::default()).collect();
+                }
+                Self::TAG_MASTER_V5 => project.master = Master::read(bytes, report)?,
+                Self::TAG_EVENT_V5 => project.evelist = EveList::read(bytes, report)?,
+                Self::TAG_MATE_PCM => {
+                    let places = places.as_deref_mut();
+                    project.read_sampled_woice(bytes, report, places, Woice::read_mate_pcm)?
+                }
+                Self::TAG_MATE_PTV => project.read_woice(bytes, report, Woice::read_mate_ptv)?,
+                Self::TAG_MATE_PTN => project.read_woice(bytes, report, Woice::read_mate_ptn)?,
+                Self::TAG_MATE_OGGV => {
+                    let places = places.as_deref_mut();
+                    project.read_sampled_woice(bytes, report, places, Woice::read_mate_oggv)?
+                }
+                Self::TAG_EFFE_DELA => {
+                    check_count(project.delays.len() as u32 + 1, Self::MAX_DELAY_NUM as u32)?;
+                    project.delays.push(Delay::read(bytes, report)?);
+                }
+                Self::TAG_EFFE_OVER => {
+                    let num = project.overdrives.len() as u32 + 1;
+                    check_count(num, Self::MAX_OVERDRIVE_NUM as u32)?;
+                    project.overdrives.push(OverDrive::read(bytes, report)?);
+                }
+                Self::TAG_ASSI_UNIT => {
+                    let (index, name) = Self::read_assist(bytes)?;
+                    let unit = project.units.get_mut(index);
+                    let unit = unit.ok_or(ErrorKind::InvalidFormat("unit index"))?;
+                    unit.set_name(&name);
+                }
+                Self::TAG_ASSI_WOIC => {
+                    let (index, name) = Self::read_assist(bytes)?;
+                    let woice_name = project.woice_names.get_mut(index);
+                    *woice_name.ok_or(ErrorKind::InvalidFormat("woice index"))? = name;
+                }
+                Self::TAG_TEXT_NAME => project.name = bytes.read_pascal_string()?,
+                Self::TAG_TEXT_COMM => project.comment = bytes.read_pascal_string()?,
+                Self::TAG_END => return Ok(ChunkFlow::End),
+
+                // old
+                Self::TAG_X4X_MAST => project.master = Master::read_x4x(bytes, report)?,
+                Self::TAG_X4X_EVEN => project.evelist.read_x4x_unit(bytes, false, report)?,
+                Self::TAG_X3X_UNIT => project.read_x3x_unit(bytes, report)?,
+                Self::TAG_X1X_PROJ => project.read_x1x_project(bytes, report)?,
+                Self::TAG_X1X_UNIT => project.read_x1x_unit(bytes, report)?,
+                Self::TAG_X1X_PCM => {
+                    let places = places.as_deref_mut();
+                    project.read_sampled_woice(bytes, report, places, Woice::read_mate_pcm)?
+                }
+                Self::TAG_X1X_EVEN => project.evelist.read_x4x_unit(bytes, true, report)?,
+                Self::TAG_X1X_END => return Ok(ChunkFlow::End),
+                _ => return Ok(ChunkFlow::Unknown),
+            }
+            Ok(ChunkFlow::Read)
+        })?;
+
+        if version <= FormatVersion::X3x {
+            project.convert_x3x()?;
+        }
+
+        project.init_units();
+        Ok(project)
+    }
+
+    /// Write as a v5 project file, with the blocks in the order pxtone writes them.
+    /// Unknown chunks are kept, before the end of the file.
+    pub fn write<W: Write + Seek>(&self, mut writer: W) -> Result<()> {
+        self.write_as(&mut writer, false)
+    }
+
+    /// Write as a v5 tune file (`.pttune`) for playing only: without unit and woice names
+    /// or unknown chunks, and with clocks a tenth as fine, rounded down
+    pub fn write_tune<W: Write + Seek>(&self, mut writer: W) -> Result<()> {
+        self.write_as(&mut writer, true)
+    }
+
+    fn write_as<W: Write + Seek>(&self, bytes: &mut W, tune: bool) -> Result<()> {
+        let rough = if tune { Self::TUNE_ROUGH } else { 1 };
+        let code = if tune {
+            Self::CODE_TUNE_V5
+        } else {
+            Self::CODE_PROJ_V5
+        };
+        bytes.write_all(code)?;
+        bytes.write_u16::<LittleEndian>(0)?; // exe version
+        bytes.write_u16::<LittleEndian>(0)?;
+
+        bytes.write_all(Self::TAG_MASTER_V5)?;
+        self.master.write(bytes, rough)?;
+        bytes.write_all(Self::TAG_EVENT_V5)?;
+        self.evelist.write(bytes, rough)?;
+
+        if !self.name.is_empty() {
+            bytes.write_all(Self::TAG_TEXT_NAME)?;
+            bytes.write_pascal_string(&self.name)?;
+        }
+        if !self.comment.is_empty() {
+            bytes.write_all(Self::TAG_TEXT_COMM)?;
+            bytes.write_pascal_string(&self.comment)?;
+        }
+
+        for delay in &self.delays {
+            bytes.write_all(Self::TAG_EFFE_DELA)?;
+            delay.write(bytes)?;
+        }
+        for overdrive in &self.overdrives {
+            bytes.write_all(Self::TAG_EFFE_OVER)?;
+            overdrive.write(bytes)?;
+        }
+
+        for (w, woice) in self.woices.iter().enumerate() {
+            bytes.write_all(match woice {
+                Woice::Voice(_) => Self::TAG_MATE_PTV,
+                Woice::Noise(..) => Self::TAG_MATE_PTN,
+                Woice::Pcm(..) => Self::TAG_MATE_PCM,
+                Woice::OggVorbis(..) => Self::TAG_MATE_OGGV,
+            })?;
+            woice.write_mate(bytes)?;
+            if !tune && !self.woice_names[w].is_empty() {
+                bytes.write_all(Self::TAG_ASSI_WOIC)?;
+                Self::write_assist(bytes, w, &self.woice_names[w])?;
+            }
+        }
+
+        bytes.write_all(Self::TAG_NUM_UNIT)?;
+        Unit::write_num(bytes, self.units.len())?;
+        for (u, unit) in self.units.iter().enumerate() {
+            if !tune && !unit.name().is_empty() {
+                bytes.write_all(Self::TAG_ASSI_UNIT)?;
+                Self::write_assist(bytes, u, unit.name())?;
+            }
+        }
+
+        if !tune {
+            for chunk in &self.unknown_chunks {
+                chunk.write(bytes)?;
+            }
+        }
+        bytes.write_all(Self::TAG_END)?;
+        bytes.write_u32::<LittleEndian>(0)?;
+        bytes.flush()?;
+        Ok(())
+    }
+
+    /// Song from a standard MIDI file, with units voiced by `options`
+    pub fn from_midi<T: Read>(mut bytes: T, options: &MidiOptions) -> Result<Self> {
+        let mut project = midi::read(&mut bytes, options)?;
+        project.init_units();
+        Ok(project)
+    }
+
+    pub(crate) fn empty() -> Self {
+        Self {
+            name: String::new(),
+            comment: String::new(),
+            master: Master::default(),
+            evelist: EveList::new(),
+            units: Vec::new(),
+            woices: Vec::new(),
+            woice_names: Vec::new(),
+            delays: Vec::new(),
+            overdrives: Vec::new(),
+            unknown_chunks: Vec::new(),
+            version: FormatVersion::V5,
+            blocks: Vec::new(),
+            solo: None,
+        }
+    }
+
+    /// Unit state at clock 0, and enough measures for every event
+    fn init_units(&mut self) {
+        let evelist = &self.evelist;
+        for (u, unit) in self.units.iter_mut().enumerate() {
+            let value = |kind| evelist.value_at(0, u as u8, kind);
+            unit.set_woice(value(EventKind::VoiceNo) as usize);
+            unit.set_group(value(EventKind::GroupNo));
+            unit.set_key(value(EventKind::Key));
+            unit.set_velocity(value(EventKind::Velocity));
+            unit.set_volume(value(EventKind::Volume));
+            unit.set_pan_volume(value(EventKind::PanVolume));
+            unit.set_pan_time(value(EventKind::PanTime));
+            unit.set_tuning(f32::from_bits(value(EventKind::Tuning) as u32));
+        }
+
+        let clock = self.evelist.max_clock().max(self.master.last_clock());
+        self.master.adjust_meas_num(clock);
+    }
+
+    fn read_version<T: Read>(bytes: &mut T, report: &mut Report) -> Result<FormatVersion> {
+        let mut code = [0; 16];
+        bytes.read_exact(&mut code)?;
+        let version = match &code[..] {
+            Self::CODE_PROJ_X1X => FormatVersion::X1x,
+            Self::CODE_PROJ_X2X | Self::CODE_TUNE_X2X => FormatVersion::X2x,
+            Self::CODE_PROJ_X3X | Self::CODE_TUNE_X3X => FormatVersion::X3x,
+            Self::CODE_PROJ_X4X | Self::CODE_TUNE_X4X => FormatVersion::X4x,
+            Self::CODE_PROJ_V5 | Self::CODE_TUNE_V5 => FormatVersion::V5,
+            // a later revision, read as the newest one when allowed
+            _ => match Self::code_date(&code) {
+                Some(date) if date > Self::V5_DATE => {
+                    report.version("project version", date, Self::V5_DATE)?;
+                    FormatVersion::V5
+                }
+                _ => return Err(ErrorKind::InvalidFormat("project code").into()),
+            },
+        };
+
+        if version >= FormatVersion::X3x {
+            let _exe_ver = bytes.read_u16::<LittleEndian>()?;
+            let _dummy = bytes.read_u16::<LittleEndian>()?;
+        }
+        Ok(version)
+    }
+
+    /// Date a project or tune code names, such as 20071119
+    fn code_date(code: &[u8]) -> Option<u32> {
+        let digits = if let Some(digits) = code.strip_prefix(b"PTCOLLAGE-") {
+            digits
+        } else {
+            code.strip_prefix(b"PTTUNE--")?
+        };
+        if digits.is_empty() || !digits.iter().all(u8::is_ascii_digit) {
+            return None;
+        }
+        let date = std::str::from_utf8(digits).ok()?.parse::<u32>().ok()?;
+        // projects leave out the century
+        let century = if digits.len() == 6 { 2000_0000 } else { 0 };
+        Some(century + date)
+    }
+
+    /// Song name and beat of x1x projects (`PROJECT=`)
+    fn read_x1x_project<T: Read>(&mut self, bytes: &mut T, report: &mut Report) -> Result<()> {
+        let _size = bytes.read_u32::<LittleEndian>()?;
+        self.name = bytes.read_sjis_string(Unit::MAX_NAME_SIZE)?;
+        let beat_tempo = bytes.read_f32::<LittleEndian>()?;
+        let beat_tempo = report.finite("tempo", beat_tempo, EventKind::DEFAULT_BEATTEMPO as f32)?;
+        let beat_clock = i32::from(bytes.read_u16::<LittleEndian>()?);
+        let beat_num = i32::from(bytes.read_u16::<LittleEndian>()?);
+        let _beat_note = bytes.read_u16::<LittleEndian>()?;
+        let _meas_num = bytes.read_u16::<LittleEndian>()?;
+        let _ch = bytes.read_u16::<LittleEndian>()?;
+        let _bps = bytes.read_u16::<LittleEndian>()?;
+        let _sps = bytes.read_u32::<LittleEndian>()?;
+        ensure(beat_num > 0 && beat_clock > 0, "project beat")?;
+
+        self.master.set_beat(beat_num, beat_tempo, beat_clock);
+        Ok(())
+    }
+
+    /// Sampled unit of x1x projects (`UNIT====`)
+    fn read_x1x_unit<T: Read>(&mut self, bytes: &mut T, report: &mut Report) -> Result<()> {
+        let _size = bytes.read_u32::<LittleEndian>()?;
+        let name = bytes.read_sjis_string(Unit::MAX_NAME_SIZE)?;
+        let kind = bytes.read_u16::<LittleEndian>()?;
+        let group = bytes.read_u16::<LittleEndian>()?;
+        ensure(kind == Self::X3X_UNIT_PCM, "x1x unit kind")?;
+
+        self.push_old_unit(Unit::new(&name), i32::from(group), report)
+    }
+
+    /// Unit of x3x projects, playing the woice of the same index (`pxtnUNIT`)
+    fn read_x3x_unit<T: Read>(&mut self, bytes: &mut T, report: &mut Report) -> Result<()> {
+        let _size = bytes.read_u32::<LittleEndian>()?;
+        let kind = bytes.read_u16::<LittleEndian>()?;
+        let group = bytes.read_u16::<LittleEndian>()?;
+        let kinds = Self::X3X_UNIT_PCM..=Self::X3X_UNIT_PTN;
+        ensure(kinds.contains(&kind), "x3x unit kind")?;
+
+        self.push_old_unit(Unit::default(), i32::from(group), report)
+    }
+
+    fn push_old_unit(&mut self, unit: Unit, group: i32, report: &mut Report) -> Result<()> {
+        let unit_no = self.add_unit(unit)? as u8;
+        let group = report.within(format!("unit {}", unit_no), |report| {
+            report.clamp("group", group, 0, MAX_GROUP_NUM - 1)
+        })?;
+        self.evelist.insert(0, unit_no, EventKind::GroupNo, group);
+        self.evelist
+            .insert(0, unit_no, EventKind::VoiceNo, i32::from(unit_no));
+        Ok(())
+    }
+
+    /// Make x3x key events absolute and move unit tunings to events
+    fn convert_x3x(&mut self) -> Result<()> {
+        // each unit plays the woice of its index
+        ensure(self.units.len() <= self.woices.len(), "x3x unit woice")?;
+
+        for (u, woice) in self.woices.iter().enumerate().take(self.units.len()) {
+            let unit_no = u as u8;
+            if self
+                .evelist
+                .unit_events(unit_no)
+                .all(|e| e.kind != EventKind::Key)
+            {
+                self.evelist
+                    .add_event(0, unit_no, EventKind::Key, EventKind::DEFAULT_KEY);
+            }
+            let change = woice.x3x_basic_key() - EventKind::DEFAULT_BASICKEY;
+            self.evelist.shift_values(unit_no, EventKind::Key, change);
+
+            let tuning = woice.x3x_tuning();
+            if tuning != 0.0 {
+                self.evelist
+                    .add_event_f32(0, unit_no, EventKind::Tuning, tuning);
+            }
+        }
+
+        for (i, name) in self.woice_names.iter_mut().enumerate() {
+            *name = format!("voice_{:02}", i);
+        }
+        Ok(())
+    }
+
+    /// Index and name of a unit or woice (`assiUNIT`, `assiWOIC`)
+    fn read_assist<T: Read>(bytes: &mut T) -> Result<(usize, String)> {
+        let size = bytes.read_u32::<LittleEndian>()?;
+        ensure(size == Self::ASSIST_SIZE, "assist size")?;
+
+        let index = bytes.read_u16::<LittleEndian>()?;
+        let rrr = bytes.read_u16::<LittleEndian>()?;
+        ensure(rrr == 0, "assist reserved data")?;
+
+        let name = bytes.read_sjis_string(Unit::MAX_NAME_SIZE)?;
+        Ok((index as usize, name))
+    }
+
+    fn write_assist<W: Write>(bytes: &mut W, index: usize, name: &str) -> Result<()> {
+        bytes.write_u32::<LittleEndian>(Self::ASSIST_SIZE)?;
+        bytes.write_u16::<LittleEndian>(index as u16)?;
+        bytes.write_u16::<LittleEndian>(0)?;
+        bytes.write_sjis_string(name, Unit::MAX_NAME_SIZE)?;
+        Ok(())
+    }
+
+    fn read_woice<T, F>(&mut self, bytes: &mut T, report: &mut Report, read: F) -> Result<()>
+    where
+        F: FnOnce(&mut T, &mut Report) -> Result<Woice>,
+    {
+        check_count(self.woices.len() as u32 + 1, Self::MAX_WOICE_NUM as u32)?;
+        let woice = report.within(format!("woice {}", self.woices.len()), |report| {
+            read(bytes, report)
+        })?;
+        self.push_woice(woice);
+        Ok(())
+    }
+
+    /// `read_woice` for woices whose data may be left in place
+    fn read_sampled_woice<T, F>(
+        &mut self,
+        bytes: &mut T,
+        report: &mut Report,
+        places: Option<&mut Places>,
+        read: F,
+    ) -> Result<()>
+    where
+        F: FnOnce(&mut T, &mut Report, Option<&mut Range<u64>>) -> Result<Woice>,
+    {
+        let index = self.woices.len();
+        let mut place = places.as_ref().map(|_| 0..0);
+        self.read_woice(bytes, report, |bytes, report| {
+            read(bytes, report, place.as_mut())
+        })?;
+        if let (Some(places), Some(place)) = (places, place) {
+            places.push((index, place));
+        }
+        Ok(())
+    }
+
+    pub(crate) fn push_woice(&mut self, woice: Woice) {
+        assert!(self.woices.len() < Self::MAX_WOICE_NUM);
+        self.woices.push(woice);
+        self.woice_names.push(String::new());
+    }
+
+    /// Song title
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.to_owned();
+    }
+
+    pub fn comment(&self) -> &str {
+        &self.comment
+    }
+
+    pub fn set_comment(&mut self, comment: &str) {
+        self.comment = comment.to_owned();
+    }
+
+    pub fn master(&self) -> &Master {
+        &self.master
+    }
+
+    pub fn master_mut(&mut self) -> &mut Master {
+        &mut self.master
+    }
+
+    pub fn evelist(&self) -> &EveList {
+        &self.evelist
+    }
+
+    pub fn evelist_mut(&mut self) -> &mut EveList {
+        &mut self.evelist
+    }
+
+    pub fn units(&self) -> &[Unit] {
+        &self.units
+    }
+
+    pub fn units_mut(&mut self) -> &mut [Unit] {
+        &mut self.units
+    }
+
+    /// Append a unit, returning its index; fails with `CountOverflow` beyond `Unit::MAX_NUM`
+    pub fn add_unit(&mut self, unit: Unit) -> Result<usize> {
+        check_count(self.units.len() as u32 + 1, Unit::MAX_NUM as u32)?;
+        self.units.push(unit);
+        Ok(self.units.len() - 1)
+    }
+
+    pub(crate) fn pop_unit(&mut self) -> Option<Unit> {
+        self.units.pop()
+    }
+
+    /// Raise every unit by `semitones`, or lower it when negative; keys stay within the
+    /// range of the editor. Drum woices keep their pitch with `Woice::transpose(-semitones)`.
+    pub fn transpose(&mut self, semitones: i32) {
+        for u in 0..self.units.len() {
+            self.transpose_unit(u, semitones);
+        }
+    }
+
+    /// `transpose` for the unit at `index` alone
+    pub fn transpose_unit(&mut self, index: usize, semitones: i32) {
+        let unit_no = index as u8;
+        self.evelist.transpose(unit_no, semitones);
+        let key = self.evelist.value_at(0, unit_no, EventKind::Key);
+        self.units[index].set_key(key);
+    }
+
+    /// Silence a unit in renders
+    pub fn set_unit_mute(&mut self, index: usize, mute: bool) {
+        self.units[index].set_played(!mute);
+    }
+
+    pub fn unit_solo(&self) -> Option<usize> {
+        self.solo
+    }
+
+    /// Play only the unit at `index` in renders, even if it is muted, or clear the solo
+    /// with `None`; mutes are kept and apply again once it is cleared
+    pub fn set_unit_solo(&mut self, index: Option<usize>) -> Result<()> {
+        if let Some(index) = index {
+            if index >= self.units.len() {
+                return Err(ErrorKind::UnitOutOfRange(index, self.units.len()).into());
+            }
+        }
+        self.solo = index;
+        Ok(())
+    }
+
+    /// Whether the unit at `index` is heard in renders, after mutes and the solo
+    pub fn unit_heard(&self, index: usize) -> bool {
+        match self.solo {
+            Some(solo) => solo == index,
+            None => self.units[index].played(),
+        }
+    }
+
+    pub fn woices(&self) -> &[Woice] {
+        &self.woices
+    }
+
+    pub fn woices_mut(&mut self) -> &mut [Woice] {
+        &mut self.woices
+    }
+
+    pub fn woice_name(&self, index: usize) -> &str {
+        &self.woice_names[index]
+    }
+
+    /// Set a woice name, truncated to `Unit::MAX_NAME_SIZE` characters
+    pub fn set_woice_name(&mut self, index: usize, name: &str) {
+        self.woice_names[index] = name.chars().take(Unit::MAX_NAME_SIZE).collect();
+    }
+
+    pub fn delays(&self) -> &[Delay] {
+        &self.delays
+    }
+
+    pub fn overdrives(&self) -> &[OverDrive] {
+        &self.overdrives
+    }
+
+    /// Clock conversions at the master tempo and any tempo events
+    pub fn clock_map(&self) -> ClockMap {
+        ClockMap::with_events(&self.master, &self.evelist)
+    }
+
+    /// Playing time up to the end or loop point, without rendering
+    pub fn duration(&self) -> Duration {
+        let master = &self.master;
+        let clock = master.meas_clock(master.play_meas());
+        Duration::from_secs_f64(self.clock_map().clock_to_secs(f64::from(clock)))
+    }
+
+    /// Blocks with tags this reader does not know, in file order
+    pub fn unknown_chunks(&self) -> &[Chunk] {
+        &self.unknown_chunks
+    }
+
+    /// Revision the project was read as; `V5` when made in code
+    pub fn format_version(&self) -> FormatVersion {
+        self.version
+    }
+
+    /// Tags of the blocks read, in file order and including unknown ones; empty when made in
+    /// code
+    pub fn blocks(&self) -> &[[u8; 8]] {
+        &self.blocks
+    }
+
+    /// Mixer playing the song once from the beginning
+    pub fn renderer(&mut self, options: &RenderOptions) -> Result<Renderer<'_>> {
+        Renderer::new(Song::Borrowed(self), options)
+    }
+
+    /// `renderer` owning the project, to keep or send to another thread
+    pub fn into_renderer(self, options: &RenderOptions) -> Result<Renderer<'static>> {
+        Renderer::new(Song::Owned(Box::new(self)), options)
+    }
+
+    /// `renderer` with the woices taken from `cache`, readying only those not held
+    pub fn renderer_with_cache(
+        &mut self,
+        options: &RenderOptions,
+        cache: &mut WoiceCache,
+    ) -> Result<Renderer<'_>> {
+        let instances = self.ready_woices(options, cache)?;
+        Ok(Renderer::with_instances(
+            Song::Borrowed(self),
+            options,
+            instances,
+        ))
+    }
+
+    /// `into_renderer` with the woices taken from `cache`
+    pub fn into_renderer_with_cache(
+        self,
+        options: &RenderOptions,
+        cache: &mut WoiceCache,
+    ) -> Result<Renderer<'static>> {
+        let instances = self.ready_woices(options, cache)?;
+        Ok(Renderer::with_instances(
+            Song::Owned(Box::new(self)),
+            options,
+            instances,
+        ))
+    }
+
+    fn ready_woices(
+        &self,
+        options: &RenderOptions,
+        cache: &mut WoiceCache,
+    ) -> Result<Vec<Arc<WoiceInstance>>> {
+        options.validate()?;
+        self.woices
+            .iter()
+            .map(|woice| cache.ready(woice, options))
+            .collect()
+    }
+
+    /// Frames played again when looping at `sample_rate`, from the repeat measure to the end
+    pub fn loop_points(&self, sample_rate: u32) -> Range<u32> {
+        let master = &self.master;
+        let clock_map = self.clock_map();
+        let sample = |meas| clock_map.clock_to_sample(master.meas_clock(meas), sample_rate) as u32;
+        sample(master.repeat_meas())..sample(master.play_meas())
+    }
+
+    /// Render the whole song, with the repeat measure as the loop start
+    pub fn render(&mut self, options: &RenderOptions) -> Result<Pcm> {
+        self.render_with_progress(options, &mut |_| ControlFlow::Continue(()))
+    }
+
+    /// `render`, calling `progress` as it goes and failing with `Cancelled` when it breaks
+    pub fn render_with_progress(
+        &mut self,
+        options: &RenderOptions,
+        progress: &mut dyn FnMut(Progress) -> ControlFlow<()>,
+    ) -> Result<Pcm> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("render").entered();
+        let ch = options.channels as usize;
+        let loop_start = self.loop_points(options.sample_rate).start;
+        let mut renderer = self.renderer(options)?;
+        options.check_length(renderer.smp_end().max(0) as u64)?;
+        let mut reporter = Reporter::new(progress, renderer.smp_end().max(0) as u64);
+        let mut done = 0;
+        let samples = iter::from_fn(|| {
+            if !reporter.report(done) {
+                return None;
+            }
+            done += 1;
+            renderer.mix()
+        })
+        .flat_map(move |frame| (0..ch).map(move |c| f64::from(frame[c])));
+        let mut pcm = options.quantize(samples)?;
+        reporter.finish()?;
+        pcm.set_loop_start(loop_start);
+        Ok(pcm)
+    }
+
+    /// Render the song played `loops` times in a row, fading out over the last `fade_out`
+    pub fn render_looped(
+        &mut self,
+        options: &RenderOptions,
+        loops: u32,
+        fade_out: Duration,
+    ) -> Result<Pcm> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("render_looped", loops).entered();
+        let ch = options.channels as usize;
+        let mut renderer = self.renderer(options)?;
+        renderer.set_loops(loops);
+        let samples = iter::from_fn(|| renderer.mix())
+            .flat_map(move |frame| (0..ch).map(move |c| f64::from(frame[c])));
+        let mut samples = options.limit(samples).collect::<Vec<_>>();
+        options.check_length((samples.len() / ch) as u64)?;
+        options.fade_out(&mut samples, fade_out);
+        options.quantize(samples)
+    }
+
+    /// Standard MIDI file with a track per unit, channel 10 left out
+    pub fn to_midi(&self) -> Vec<u8> {
+        midi::write(self)
+    }
+
+    /// Render each unit on its own, with the effects of its group
+    pub fn render_stems(&mut self, options: &RenderOptions) -> Result<Vec<Pcm>> {
+        let solo = self.solo;
+        let stems = (0..self.units.len())
+            .map(|u| {
+                self.set_unit_solo(Some(u))?;
+                self.render(options)
+            })
+            .collect();
+        self.solo = solo;
+        stems
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Empty v5 project with `chunk` before its end
+    fn with_chunk(chunk: &[u8]) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::new());
+        Project::empty().write(&mut bytes).unwrap();
+        let mut bytes = bytes.into_inner();
+        let end = bytes.len() - 12;
+        bytes.splice(end..end, chunk.iter().copied());
+        bytes
+    }
+
+    fn assist(tag: &[u8], size: u32, index: u16, rrr: u16) -> Vec<u8> {
+        let mut chunk = tag.to_vec();
+        chunk.write_u32::<LittleEndian>(size).unwrap();
+        chunk.write_u16::<LittleEndian>(index).unwrap();
+        chunk.write_u16::<LittleEndian>(rrr).unwrap();
+        chunk.extend_from_slice(&[0; Unit::MAX_NAME_SIZE]);
+        chunk
+    }
+
+    fn is_invalid(bytes: &[u8]) -> bool {
+        let err = Project::new(Cursor::new(bytes)).unwrap_err();
+        matches!(err.kind(), ErrorKind::InvalidFormat(_))
+    }
+
+    #[test]
+    fn reads_empty_project() {
+        assert!(Project::new(Cursor::new(with_chunk(&[]))).is_ok());
+    }
+
+    #[test]
+    fn rejects_assist_out_of_range() {
+        let size = Project::ASSIST_SIZE;
+        let unit = assist(Project::TAG_ASSI_UNIT, size, 5, 0);
+        assert!(is_invalid(&with_chunk(&unit)));
+        let woice = assist(Project::TAG_ASSI_WOIC, size, 0, 0);
+        assert!(is_invalid(&with_chunk(&woice)));
+    }
+
+    fn unit_count(size: u32, num: i16, rrr: i16) -> Vec<u8> {
+        let mut chunk = Project::TAG_NUM_UNIT.to_vec();
+        chunk.write_u32::<LittleEndian>(size).unwrap();
+        chunk.write_i16::<LittleEndian>(num).unwrap();
+        chunk.write_i16::<LittleEndian>(rrr).unwrap();
+        chunk
+    }
+
+    #[test]
+    fn rejects_bad_assist_header() {
+        let mut chunk = unit_count(4, 1, 0);
+        chunk.extend(assist(Project::TAG_ASSI_UNIT, 4, 0, 0));
+        assert!(is_invalid(&with_chunk(&chunk)));
+
+        let mut chunk = unit_count(4, 1, 0);
+        chunk.extend(assist(Project::TAG_ASSI_UNIT, Project::ASSIST_SIZE, 0, 1));
+        assert!(is_invalid(&with_chunk(&chunk)));
+    }
+
+    #[test]
+    fn rejects_bad_unit_count() {
+        assert!(is_invalid(&with_chunk(&unit_count(8, 1, 0))));
+        assert!(is_invalid(&with_chunk(&unit_count(4, 1, 1))));
+    }
+
+    fn x3x_unit(kind: u16) -> Vec<u8> {
+        let mut bytes = Project::CODE_PROJ_X3X.to_vec();
+        bytes.extend_from_slice(&[0; 4]);
+        bytes.extend_from_slice(Project::TAG_X3X_UNIT);
+        bytes.write_u32::<LittleEndian>(4).unwrap();
+        bytes.write_u16::<LittleEndian>(kind).unwrap();
+        bytes.write_u16::<LittleEndian>(0).unwrap();
+        bytes.extend_from_slice(Project::TAG_END);
+        bytes.write_u32::<LittleEndian>(0).unwrap();
+        bytes
+    }
+
+    #[test]
+    fn rejects_bad_old_units() {
+        assert!(is_invalid(&x3x_unit(Project::X3X_UNIT_PTN + 1)));
+        // a unit without the woice it plays
+        assert!(is_invalid(&x3x_unit(Project::X3X_UNIT_PCM)));
+
+        let mut bytes = Project::CODE_PROJ_X1X.to_vec();
+        bytes.extend_from_slice(Project::TAG_X1X_UNIT);
+        bytes.write_u32::<LittleEndian>(20).unwrap();
+        bytes.extend_from_slice(&[0; Unit::MAX_NAME_SIZE]);
+        let kind = Project::X3X_UNIT_PTN;
+        bytes.write_u16::<LittleEndian>(kind).unwrap();
+        bytes.write_u16::<LittleEndian>(0).unwrap();
+        bytes.extend_from_slice(Project::TAG_X1X_END);
+        bytes.write_u32::<LittleEndian>(0).unwrap();
+        assert!(is_invalid(&bytes));
+    }
+
+    #[test]
+    fn rejects_bad_mate_reserved_data() {
+        let mut chunk = Project::TAG_MATE_PTV.to_vec();
+        chunk.write_u32::<LittleEndian>(12).unwrap();
+        chunk.write_u16::<LittleEndian>(0).unwrap();
+        chunk.write_u16::<LittleEndian>(1).unwrap();
+        chunk.write_f32::<LittleEndian>(1.0).unwrap();
+        chunk.write_i32::<LittleEndian>(0).unwrap();
+        assert!(is_invalid(&with_chunk(&chunk)));
+
+        let mut chunk = Project::TAG_MATE_PTN.to_vec();
+        chunk.write_u32::<LittleEndian>(20).unwrap();
+        chunk.write_u16::<LittleEndian>(0).unwrap();
+        chunk.write_u16::<LittleEndian>(60).unwrap();
+        chunk.write_u32::<LittleEndian>(0).unwrap();
+        chunk.write_f32::<LittleEndian>(1.0).unwrap();
+        chunk.write_i32::<LittleEndian>(2).unwrap();
+        assert!(is_invalid(&with_chunk(&chunk)));
+    }
+
+    fn chunk(tag: &[u8], fields: &[u8]) -> Vec<u8> {
+        let mut chunk = tag.to_vec();
+        let size = fields.len() as u32;
+        chunk.write_u32::<LittleEndian>(size).unwrap();
+        chunk.extend_from_slice(fields);
+        chunk
+    }
+
+    #[test]
+    fn rejects_bad_blocks() {
+        // unknown delay unit
+        let mut delay = vec![9, 0, 0, 0];
+        delay.extend_from_slice(&[0; 8]);
+        let delay = chunk(Project::TAG_EFFE_DELA, &delay);
+        assert!(is_invalid(&with_chunk(&delay)));
+
+        // cut past its maximum
+        let mut overdrive = vec![0; 4];
+        overdrive.write_f32::<LittleEndian>(100.0).unwrap();
+        overdrive.write_f32::<LittleEndian>(2.0).unwrap();
+        overdrive.write_f32::<LittleEndian>(0.0).unwrap();
+        let overdrive = chunk(Project::TAG_EFFE_OVER, &overdrive);
+        assert!(is_invalid(&with_chunk(&overdrive)));
+
+        // no clocks in a beat
+        let mut master = vec![0, 0, 4];
+        master.write_f32::<LittleEndian>(120.0).unwrap();
+        master.extend_from_slice(&[0; 8]);
+        let master = chunk(Project::TAG_MASTER_V5, &master);
+        assert!(is_invalid(&with_chunk(&master)));
+
+        // negative clocks and beats, which multiply to a positive measure
+        let mut master = vec![0xff, 0xff, 0xfc];
+        master.write_f32::<LittleEndian>(120.0).unwrap();
+        master.extend_from_slice(&[0; 8]);
+        let master = chunk(Project::TAG_MASTER_V5, &master);
+        assert!(is_invalid(&with_chunk(&master)));
+
+        // unknown event kind
+        let events = chunk(Project::TAG_EVENT_V5, &[1, 0, 0, 0, 0, 0, 255, 0]);
+        assert!(is_invalid(&with_chunk(&events)));
+    }
+
+    fn sample() -> Project {
+        let sample = include_bytes!("../pxtone-source-code 170212a/sample data/sample.ptcop");
+        Project::new(Cursor::new(&sample[..])).unwrap()
+    }
+
+    #[test]
+    fn solo_keeps_mutes() {
+        let mut project = sample();
+        project.set_unit_mute(1, true);
+        project.set_unit_solo(Some(1)).unwrap();
+        assert!(project.unit_heard(1));
+        assert!(!project.unit_heard(0));
+
+        project.set_unit_solo(None).unwrap();
+        assert!(project.unit_heard(0));
+        assert!(!project.unit_heard(1));
+        assert!(!project.units()[1].played());
+    }
+
+    #[test]
+    fn stems_keep_mutes_and_solo() {
+        let mut project = sample();
+        project.master_mut().set_last_meas(2);
+        project.set_unit_mute(1, true);
+        project.set_unit_solo(Some(0)).unwrap();
+        let options = RenderOptions::new(1, 11025, crate::pulse::OutputFormat::I16);
+        let stems = project.render_stems(&options).unwrap();
+        assert_eq!(stems.len(), project.units().len());
+        // a muted unit still has its stem
+        assert!(stems[1].frames::<i16>().any(|[l, _]| l != 0));
+        assert_eq!(project.unit_solo(), Some(0));
+        assert!(!project.units()[1].played());
+    }
+
+    #[test]
+    fn rejects_out_of_range_units() {
+        let mut project = sample();
+        let num = project.units().len();
+        let err = project.set_unit_solo(Some(num)).unwrap_err();
+        assert!(
+            matches!(err.kind(), ErrorKind::UnitOutOfRange(i, len) if *i == num && *len == num)
+        );
+        assert_eq!(project.unit_solo(), None);
+
+        for _ in num..Unit::MAX_NUM {
+            project.add_unit(Unit::default()).unwrap();
+        }
+        let err = project.add_unit(Unit::default()).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::CountOverflow(..)));
+        assert_eq!(project.units().len(), Unit::MAX_NUM);
+    }
+
+    #[test]
+    fn rejects_state_of_another_song() {
+        let options = RenderOptions::new(2, 22050, crate::pulse::OutputFormat::I16);
+        let mut project = sample();
+        let snapshot = project.renderer(&options).unwrap().save_state();
+
+        let mut other = sample();
+        other.add_unit(Unit::default()).unwrap();
+        let mut renderer = other.renderer(&options).unwrap();
+        let err = renderer.restore_state(&snapshot).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::StateMismatch));
+    }
+
+    #[test]
+    fn replays_restored_state() {
+        let options = RenderOptions::new(2, 22050, crate::pulse::OutputFormat::I16);
+        let mut project = sample();
+        // a delay line to carry over as well
+        project.delays.push(Delay::default());
+        let mut renderer = project.renderer(&options).unwrap();
+        renderer.by_ref().take(22050).for_each(drop);
+
+        let snapshot = renderer.save_state();
+        let first = renderer.by_ref().take(44100).collect::<Vec<_>>();
+        renderer.restore_state(&snapshot).unwrap();
+        let second = renderer.by_ref().take(44100).collect::<Vec<_>>();
+        assert_eq!(first.len(), 44100);
+        assert!(first.iter().any(|frame| frame[0] != 0));
+        assert_eq!(first, second);
+    }
+
+    #[test]
+    fn rejects_bad_player_volume() {
+        let options = RenderOptions::new(2, 22050, crate::pulse::OutputFormat::I16);
+        let player = PlayerHandle::new(sample(), &options).unwrap();
+        player.set_volume(0.5).unwrap();
+        for &volume in &[-1.0, f32::NAN, f32::INFINITY] {
+            let err = player.set_volume(volume).unwrap_err();
+            assert!(matches!(err.kind(), ErrorKind::InvalidRenderOptions(_)));
+        }
+        assert_eq!(player.volume(), 0.5);
+    }
+
+    #[test]
+    fn plays_tempo_changes() {
+        let options = RenderOptions::new(1, 11025, crate::pulse::OutputFormat::I16);
+        let mut project = sample();
+        project.master_mut().set_last_meas(4);
+        let steady = project.render(&options).unwrap();
+
+        let meas_clock = project.master().meas_clock(1);
+        let tempo = project.master().beat_tempo() * 2.0;
+        let evelist = project.evelist_mut();
+        evelist.add_event_f32(meas_clock * 2, 0, EventKind::BeatTempo, tempo);
+        let clock_map = project.clock_map();
+        let end = clock_map.clock_to_sample(meas_clock * 4, 11025);
+        assert_eq!(project.loop_points(11025).end, end as u32);
+        assert_eq!(project.renderer(&options).unwrap().smp_end(), end);
+
+        let faster = project.render(&options).unwrap();
+        assert_eq!(faster.smp_num(), end as u32);
+        assert!(faster.smp_num() < steady.smp_num());
+        // the first measures are played as before
+        let half = clock_map.clock_to_sample(meas_clock * 2, 11025) as usize;
+        let frames = |pcm: &Pcm| pcm.frames::<i16>().take(half).collect::<Vec<_>>();
+        assert_eq!(frames(&faster), frames(&steady));
+    }
+
+    #[test]
+    fn rejects_bad_group_gain() {
+        let mut project = sample();
+        let options = RenderOptions::new(2, 44100, crate::pulse::OutputFormat::I16);
+        let mut renderer = project.renderer(&options).unwrap();
+        renderer.set_group_gain(0, 0.0).unwrap();
+        for &gain in &[-1.0, f32::NAN] {
+            let err = renderer.set_group_gain(0, gain).unwrap_err();
+            assert!(matches!(err.kind(), ErrorKind::InvalidRenderOptions(_)));
+        }
+        assert_eq!(renderer.group_gain(0), 0.0);
+    }
+
+    #[test]
+    fn survives_corrupt_sample() {
+        let sample = include_bytes!("../pxtone-source-code 170212a/sample data/sample.ptcop");
+        for len in (0..sample.len()).step_by(97) {
+            let _ = Project::new(Cursor::new(&sample[..len]));
+        }
+        let mut bytes = sample.to_vec();
+        for i in (0..bytes.len()).step_by(37) {
+            bytes[i] ^= 0xff;
+            let _ = Project::new(Cursor::new(&bytes));
+            bytes[i] ^= 0xff;
+        }
+    }
+}
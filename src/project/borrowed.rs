@@ -0,0 +1,65 @@
+use std::ops::Range;
+
+use super::Project;
+
+use crate::pulse::OggVorbis;
+use crate::woice::Woice;
+
+/// (woice index, range in the file) of each sampled woice read empty
+pub(super) type Places = Vec<(usize, Range<u64>)>;
+
+/// Project parsed by `Project::parse_ref`, whose PCM and Ogg Vorbis data borrows the file
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectRef<'a> {
+    project: Project,
+    /// (woice index, data) in woice order
+    payloads: Vec<(usize, &'a [u8])>,
+}
+
+impl<'a> ProjectRef<'a> {
+    pub(super) fn new(project: Project, bytes: &'a [u8], places: Places) -> Self {
+        let payloads = places
+            .into_iter()
+            .map(|(index, place)| {
+                // data cut off by the end of the file is kept as far as it goes
+                let end = (place.end as usize).min(bytes.len());
+                let start = (place.start as usize).min(end);
+                (index, &bytes[start..end])
+            })
+            .collect();
+        Self { project, payloads }
+    }
+
+    /// Project without the data of PCM and Ogg Vorbis woices, which `woice_data` holds
+    pub fn project(&self) -> &Project {
+        &self.project
+    }
+
+    /// Raw samples of a PCM woice or the stream of an Ogg Vorbis woice
+    pub fn woice_data(&self, index: usize) -> Option<&'a [u8]> {
+        self.payloads
+            .binary_search_by_key(&index, |&(i, _)| i)
+            .ok()
+            .map(|i| self.payloads[i].1)
+    }
+
+    /// Project owning a copy of the woice data, ready to render
+    pub fn into_owned(self) -> Project {
+        let mut project = self.project;
+        for (index, data) in self.payloads {
+            match &mut project.woices[index] {
+                Woice::Pcm(pcm, _) => pcm.set_raw(data.to_vec()),
+                Woice::OggVorbis(ogg_vorbis, _) => {
+                    *ogg_vorbis = OggVorbis::new(
+                        ogg_vorbis.ch(),
+                        ogg_vorbis.sps(),
+                        ogg_vorbis.smp_num(),
+                        data.to_vec(),
+                    );
+                }
+                _ => unreachable!(),
+            }
+        }
+        project
+    }
+}
@@ -0,0 +1,106 @@
+//! Songs streamed to kira as they render
+
+use std::convert::Infallible;
+
+use kira::clock::clock_info::ClockInfoProvider;
+use kira::modulator::value_provider::ModulatorValueProvider;
+use kira::sound::{Sound, SoundData};
+use kira::{Frame, OutputDestination};
+
+use super::{Project, Renderer};
+
+use crate::error::Result;
+use crate::options::RenderOptions;
+use crate::pulse::Sample as _;
+
+/// Song kira plays by rendering it frame by frame, resampled to the output rate
+pub struct SongSoundData {
+    renderer: Renderer<'static>,
+    output_destination: OutputDestination,
+}
+
+impl SongSoundData {
+    pub fn new(renderer: Renderer<'static>) -> Self {
+        Self {
+            renderer,
+            output_destination: OutputDestination::default(),
+        }
+    }
+
+    pub fn output_destination(&self) -> OutputDestination {
+        self.output_destination
+    }
+
+    /// Track or emitter the song plays through, the main track by default
+    pub fn set_output_destination(&mut self, output_destination: OutputDestination) {
+        self.output_destination = output_destination;
+    }
+}
+
+impl SoundData for SongSoundData {
+    type Error = Infallible;
+    type Handle = ();
+
+    fn into_sound(self) -> std::result::Result<(Box<dyn Sound>, Self::Handle), Self::Error> {
+        let sps = f64::from(self.renderer.sps());
+        let sound = SongSound {
+            renderer: self.renderer,
+            output_destination: self.output_destination,
+            sps,
+            frames: [Frame::ZERO; 2],
+            position: 1.0,
+            finished: false,
+        };
+        Ok((Box::new(sound), ()))
+    }
+}
+
+struct SongSound {
+    renderer: Renderer<'static>,
+    output_destination: OutputDestination,
+    sps: f64,
+    /// Rendered frames on either side of the playing position
+    frames: [Frame; 2],
+    /// Playing position between `frames`, in rendered frames
+    position: f64,
+    finished: bool,
+}
+
+impl Sound for SongSound {
+    fn output_destination(&mut self) -> OutputDestination {
+        self.output_destination
+    }
+
+    fn process(
+        &mut self,
+        dt: f64,
+        _clock_info_provider: &ClockInfoProvider,
+        _modulator_value_provider: &ModulatorValueProvider,
+    ) -> Frame {
+        self.position += dt * self.sps;
+        while self.position >= 1.0 && !self.finished {
+            self.position -= 1.0;
+            self.frames[0] = self.frames[1];
+            self.frames[1] = match self.renderer.next() {
+                Some([left, right]) => Frame::new(f32::from_i16(left), f32::from_i16(right)),
+                None => {
+                    self.finished = true;
+                    Frame::ZERO
+                }
+            };
+        }
+        let [prev, next] = self.frames;
+        prev + (next - prev) * self.position.min(1.0) as f32
+    }
+
+    fn finished(&self) -> bool {
+        self.finished
+    }
+}
+
+impl Project {
+    /// Sound kira streams from the song as it renders with `options`
+    pub fn into_sound_data(self, options: &RenderOptions) -> Result<SongSoundData> {
+        Ok(SongSoundData::new(self.into_renderer(options)?))
+    }
+}
@@ -0,0 +1,98 @@
+use std::sync::atomic::{AtomicU32, Ordering};
+use std::sync::{Arc, Mutex, MutexGuard, PoisonError};
+
+use super::{MooState, Project, Renderer, RendererSnapshot};
+
+use crate::error::Result;
+use crate::options::{check_gain, RenderOptions};
+
+/// Renderer shared between an audio callback and a user interface.
+///
+/// Clones control the same playback; volume changes never wait for a render to finish.
+#[derive(Clone)]
+pub struct PlayerHandle {
+    renderer: Arc<Mutex<Renderer<'static>>>,
+    /// f32 bits
+    volume: Arc<AtomicU32>,
+    ch: usize,
+}
+
+impl PlayerHandle {
+    pub fn new(project: Project, options: &RenderOptions) -> Result<Self> {
+        Ok(Self {
+            renderer: Arc::new(Mutex::new(project.into_renderer(options)?)),
+            volume: Arc::new(AtomicU32::new(1.0f32.to_bits())),
+            ch: usize::from(options.channels),
+        })
+    }
+
+    fn lock(&self) -> MutexGuard<'_, Renderer<'static>> {
+        self.renderer.lock().unwrap_or_else(PoisonError::into_inner)
+    }
+
+    /// Fill `buf` with interleaved frames, returning how many were rendered.
+    /// The rest of `buf` is silenced once the song ends.
+    pub fn render(&self, buf: &mut [i16]) -> usize {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("block", frames = buf.len() / self.ch).entered();
+        let volume = self.volume();
+        let mut renderer = self.lock();
+        let mut frames = 0;
+        for out in buf.chunks_exact_mut(self.ch) {
+            match renderer.next() {
+                Some(frame) => {
+                    for (out, &smp) in out.iter_mut().zip(&frame) {
+                        *out = (f32::from(smp) * volume) as i16;
+                    }
+                    frames += 1;
+                }
+                None => out.fill(0),
+            }
+        }
+        frames
+    }
+
+    /// Level applied to rendered frames, 1.0 as mixed; louder frames are clipped
+    pub fn volume(&self) -> f32 {
+        f32::from_bits(self.volume.load(Ordering::Relaxed))
+    }
+
+    /// Fails with `InvalidRenderOptions` unless `volume` is finite and non-negative
+    pub fn set_volume(&self, volume: f32) -> Result<()> {
+        check_gain(f64::from(volume))?;
+        self.volume.store(volume.to_bits(), Ordering::Relaxed);
+        Ok(())
+    }
+
+    /// Silence a unit from the next render on
+    pub fn set_unit_mute(&self, index: usize, mute: bool) {
+        self.lock().set_unit_mute(index, mute);
+    }
+
+    /// Play only the unit at `index` from the next render on, or clear the solo with `None`
+    pub fn set_unit_solo(&self, index: Option<usize>) -> Result<()> {
+        self.lock().set_unit_solo(index)
+    }
+
+    /// Continue playing from `smp_count`
+    pub fn seek(&self, smp_count: i32) {
+        self.lock().seek(smp_count);
+    }
+
+    pub fn set_loops(&self, loops: u32) {
+        self.lock().set_loops(loops);
+    }
+
+    /// Playing state between renders
+    pub fn state(&self) -> MooState {
+        self.lock().state()
+    }
+
+    pub fn save_state(&self) -> RendererSnapshot {
+        self.lock().save_state()
+    }
+
+    pub fn restore_state(&self, snapshot: &RendererSnapshot) -> Result<()> {
+        self.lock().restore_state(snapshot)
+    }
+}
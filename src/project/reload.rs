@@ -0,0 +1,181 @@
+use std::io::{Read, Seek};
+
+use super::Project;
+
+use crate::error::Result;
+
+/// Parts of a project replaced by `reload`
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ProjectChanges {
+    /// Tempo, beats or measures
+    pub master: bool,
+    pub events: bool,
+    /// Units added, removed or edited
+    pub units: bool,
+    /// Indices of woices added or edited
+    pub woices: Vec<usize>,
+    /// Woices dropped from the end
+    pub woices_removed: bool,
+    /// Delays or overdrives
+    pub effects: bool,
+    /// Name, comment, woice names or unknown blocks
+    pub text: bool,
+}
+
+impl ProjectChanges {
+    pub fn is_empty(&self) -> bool {
+        *self == Self::default()
+    }
+}
+
+impl Project {
+    /// Replace the song with the project in `bytes`, keeping the effects that did not change
+    /// along with their running state
+    pub fn reload<T: Read + Seek>(&mut self, bytes: T) -> Result<ProjectChanges> {
+        let new = Project::new(bytes)?;
+        let changes = self.changes(&new);
+        self.replace(new, &changes);
+        Ok(changes)
+    }
+
+    pub(super) fn changes(&self, new: &Project) -> ProjectChanges {
+        let delay_settings = |project: &Project| {
+            project
+                .delays
+                .iter()
+                .map(|d| (d.unit(), d.freq(), d.rate(), d.group(), d.played()))
+                .collect::<Vec<_>>()
+        };
+        let overdrive_settings = |project: &Project| {
+            project
+                .overdrives
+                .iter()
+                .map(|o| (o.cut(), o.amp(), o.group(), o.played()))
+                .collect::<Vec<_>>()
+        };
+
+        ProjectChanges {
+            master: self.master != new.master,
+            events: self.evelist != new.evelist,
+            units: self.units != new.units,
+            woices: (0..new.woices.len())
+                .filter(|&w| self.woices.get(w) != Some(&new.woices[w]))
+                .collect(),
+            woices_removed: new.woices.len() < self.woices.len(),
+            effects: delay_settings(self) != delay_settings(new)
+                || overdrive_settings(self) != overdrive_settings(new),
+            text: self.name != new.name
+                || self.comment != new.comment
+                || self.woice_names != new.woice_names
+                || self.unknown_chunks != new.unknown_chunks,
+        }
+    }
+
+    pub(super) fn replace(&mut self, new: Project, changes: &ProjectChanges) {
+        let Project {
+            name,
+            comment,
+            master,
+            evelist,
+            units,
+            woices,
+            woice_names,
+            delays,
+            overdrives,
+            unknown_chunks,
+            version,
+            blocks,
+            solo: _,
+        } = new;
+        self.name = name;
+        self.comment = comment;
+        self.master = master;
+        self.evelist = evelist;
+        self.units = units;
+        self.woices = woices;
+        self.woice_names = woice_names;
+        if changes.effects {
+            self.delays = delays;
+            self.overdrives = overdrives;
+        }
+        self.unknown_chunks = unknown_chunks;
+        self.version = version;
+        self.blocks = blocks;
+        // the solo stays on while its unit is there
+        self.solo = self.solo.filter(|&u| u < self.units.len());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::delay::Delay;
+    use crate::evelist::EventKind;
+    use crate::unit::Unit;
+    use std::io::Cursor;
+
+    fn sample() -> Project {
+        let sample = include_bytes!("../../pxtone-source-code 170212a/sample data/sample.ptcop");
+        Project::new(Cursor::new(&sample[..])).unwrap()
+    }
+
+    fn reload(project: &mut Project, edited: &Project) -> ProjectChanges {
+        let mut bytes = Cursor::new(Vec::new());
+        edited.write(&mut bytes).unwrap();
+        bytes.set_position(0);
+        project.reload(bytes).unwrap()
+    }
+
+    #[test]
+    fn finds_nothing_in_the_same_song() {
+        let mut project = sample();
+        assert!(reload(&mut project, &sample()).is_empty());
+    }
+
+    #[test]
+    fn finds_changes() {
+        let mut project = sample();
+
+        let mut edited = sample();
+        edited.master_mut().set_beat(3, 90.0, 480);
+        edited.evelist_mut().add_event(1, 0, EventKind::Velocity, 1);
+        edited.set_comment("edited");
+        let changes = reload(&mut project, &edited);
+        assert_eq!(
+            changes,
+            ProjectChanges {
+                master: true,
+                events: true,
+                text: true,
+                ..Default::default()
+            }
+        );
+        assert!(reload(&mut project, &edited).is_empty());
+
+        let mut edited = sample();
+        let woice = edited.woices()[1].clone();
+        edited.woices_mut()[0] = woice;
+        edited.delays.push(Delay::default());
+        let changes = reload(&mut project, &edited);
+        assert!(changes.master && changes.events && !changes.units);
+        assert_eq!(changes.woices, [0]);
+        assert!(changes.effects);
+    }
+
+    #[test]
+    fn finds_units_and_woices_removed() {
+        let mut project = sample();
+        project.add_unit(Unit::new("extra")).unwrap();
+        let solo = project.units().len() - 1;
+        project.set_unit_solo(Some(solo)).unwrap();
+
+        let mut edited = sample();
+        edited.woices.pop();
+        edited.woice_names.pop();
+        let changes = reload(&mut project, &edited);
+        assert!(changes.units && changes.woices_removed && !changes.events);
+        assert!(changes.woices.is_empty());
+        // the soloed unit is gone
+        assert_eq!(project.unit_solo(), None);
+    }
+}
@@ -0,0 +1,688 @@
+use std::io::{Read, Seek};
+use std::ops::{Deref, DerefMut};
+use std::sync::Arc;
+
+use super::{Project, ProjectChanges};
+
+use crate::error::{ErrorKind, Result};
+use crate::options::{check_gain, RenderOptions};
+use crate::pulse::Sample;
+use crate::timing::ClockMap;
+
+use crate::delay::{Delay, MAX_CHANNEL, MAX_GROUP_NUM};
+use crate::evelist::{Event, EventKind};
+use crate::woice::{ToneSampler, WoiceInstance};
+
+const TIME_PAN_BUF_SIZE: usize = 64;
+const SAMPLING_TOP: i32 = i16::MAX as i32;
+
+/// Playing state of a unit
+#[derive(Clone)]
+struct UnitTone {
+    sampler: Option<ToneSampler>,
+    group: usize,
+    pan_times: [usize; MAX_CHANNEL],
+    pan_time_bufs: [[i32; TIME_PAN_BUF_SIZE]; MAX_CHANNEL],
+}
+
+impl UnitTone {
+    fn new(sampler: Option<ToneSampler>) -> Self {
+        Self {
+            sampler,
+            group: EventKind::DEFAULT_GROUPNO as usize,
+            pan_times: [0; MAX_CHANNEL],
+            pan_time_bufs: [[0; TIME_PAN_BUF_SIZE]; MAX_CHANNEL],
+        }
+    }
+
+    /// Back to the default settings, keeping what is left in the pan time buffers
+    fn init(&mut self, sampler: Option<ToneSampler>) {
+        self.sampler = sampler;
+        self.group = EventKind::DEFAULT_GROUPNO as usize;
+        self.pan_times = [0; MAX_CHANNEL];
+    }
+
+    fn set_pan_time(&mut self, ch: u16, pan: i32, sps: u32) {
+        self.pan_times = [0; MAX_CHANNEL];
+        if ch == 2 {
+            let (c, time) = if pan >= 64 {
+                (0, pan - 64)
+            } else {
+                (1, 64 - pan)
+            };
+            self.pan_times[c] = time.min(63) as usize * 44100 / sps as usize;
+        }
+    }
+
+    fn sample(&mut self, played: bool, ch: u16, time_pan_index: usize) {
+        let smps = match &self.sampler {
+            Some(sampler) if played => sampler.sample(ch),
+            _ => [0; MAX_CHANNEL],
+        };
+        for (buf, smp) in self.pan_time_bufs.iter_mut().zip(&smps) {
+            buf[time_pan_index] = *smp;
+        }
+    }
+
+    fn supple(&self, group_smps: &mut [i32], ch: usize, time_pan_index: usize) {
+        let index = time_pan_index.wrapping_sub(self.pan_times[ch]) & (TIME_PAN_BUF_SIZE - 1);
+        group_smps[self.group] += self.pan_time_bufs[ch][index];
+    }
+}
+
+/// Units of one group mixed through the effects set on it
+struct GroupBus {
+    gain: f32,
+    overdrives: Vec<usize>,
+    delays: Vec<usize>,
+}
+
+impl GroupBus {
+    fn new(project: &Project, group: i32) -> Self {
+        let on_group = |effect_group| effect_group == group;
+        Self {
+            gain: 1.0,
+            overdrives: (0..project.overdrives.len())
+                .filter(|&o| on_group(project.overdrives[o].group()))
+                .collect(),
+            delays: (0..project.delays.len())
+                .filter(|&d| on_group(project.delays[d].group()))
+                .collect(),
+        }
+    }
+}
+
+/// What a unit plays at a moment of the song
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct UnitState {
+    /// Key sounding now, moving toward `target_key` during portamento
+    pub key: i32,
+    pub target_key: i32,
+    /// Portamento progress from 0.0 to 1.0
+    pub glide: f32,
+    pub tuning: f32,
+    pub velocity: i32,
+    /// A note is held
+    pub on: bool,
+    /// A note or its release is still heard
+    pub sounding: bool,
+}
+
+impl UnitState {
+    fn new(sampler: Option<&ToneSampler>) -> Self {
+        match sampler {
+            Some(sampler) => Self {
+                key: sampler.key(),
+                target_key: sampler.target_key(),
+                glide: sampler.glide(),
+                tuning: sampler.tuning(),
+                velocity: sampler.velocity(),
+                on: sampler.is_on(),
+                sounding: sampler.is_alive(),
+            },
+            None => Self {
+                key: EventKind::DEFAULT_KEY,
+                target_key: EventKind::DEFAULT_KEY,
+                glide: 1.0,
+                tuning: EventKind::DEFAULT_TUNING,
+                velocity: EventKind::DEFAULT_VELOCITY,
+                on: false,
+                sounding: false,
+            },
+        }
+    }
+}
+
+/// Position of the next frame and the state of every unit, for playheads and piano rolls
+#[derive(Clone, Debug, PartialEq)]
+pub struct MooState {
+    /// Samples since the start; goes back to the repeat point when looping
+    pub smp_count: i32,
+    pub clock: i32,
+    /// Measure from 0
+    pub meas: i32,
+    /// Beat in the measure from 0
+    pub beat: i32,
+    /// Clock in the beat
+    pub clock_in_beat: i32,
+    pub units: Vec<UnitState>,
+}
+
+/// Everything a renderer changes while playing: samplers, envelopes, delay lines and the
+/// event cursor, to go back to with `Renderer::restore_state`
+#[derive(Clone)]
+pub struct RendererSnapshot {
+    units: Vec<UnitTone>,
+    delays: Vec<Delay>,
+    smp_count: i32,
+    loops: u32,
+    eve_index: usize,
+    live_events: Vec<Event>,
+    time_pan_index: usize,
+}
+
+impl RendererSnapshot {
+    /// Position of the next frame in samples
+    pub fn smp_count(&self) -> i32 {
+        self.smp_count
+    }
+}
+
+/// Project a renderer plays, lent to it or owned by it
+pub(super) enum Song<'a> {
+    Borrowed(&'a mut Project),
+    Owned(Box<Project>),
+}
+
+impl Deref for Song<'_> {
+    type Target = Project;
+
+    fn deref(&self) -> &Project {
+        match self {
+            Song::Borrowed(project) => project,
+            Song::Owned(project) => project,
+        }
+    }
+}
+
+impl DerefMut for Song<'_> {
+    fn deref_mut(&mut self) -> &mut Project {
+        match self {
+            Song::Borrowed(project) => project,
+            Song::Owned(project) => project,
+        }
+    }
+}
+
+/// Song mixer yielding 16-bit frames; mono output repeats the channel
+pub struct Renderer<'a> {
+    project: Song<'a>,
+    instances: Vec<Arc<WoiceInstance>>,
+    units: Vec<UnitTone>,
+    groups: Vec<GroupBus>,
+
+    ch: u16,
+    sps: u32,
+    options: RenderOptions,
+    beat_tempo: f32,
+    clock_map: ClockMap,
+
+    smp_count: i32,
+    smp_repeat: i32,
+    smp_end: i32,
+    loops: u32,
+    eve_index: usize,
+    live_events: Vec<Event>,
+    time_pan_index: usize,
+}
+
+impl<'a> Renderer<'a> {
+    pub(super) fn new(song: Song<'a>, options: &RenderOptions) -> Result<Self> {
+        options.validate()?;
+        let instances = song
+            .woices
+            .iter()
+            .map(|woice| woice.ready(options).map(Arc::new))
+            .collect::<Result<Vec<_>>>()?;
+        Ok(Self::with_instances(song, options, instances))
+    }
+
+    /// Renderer playing the woices of the song as `instances` readied for valid `options`
+    pub(super) fn with_instances(
+        mut song: Song<'a>,
+        options: &RenderOptions,
+        instances: Vec<Arc<WoiceInstance>>,
+    ) -> Self {
+        let project = &mut *song;
+        let ch = options.channels;
+        let sps = options.sample_rate;
+
+        let master = &project.master;
+        // delays and woices keep the master tempo
+        let beat_tempo = master.beat_tempo();
+        let clock_map = project.clock_map();
+        let (smp_repeat, smp_end) = Self::loop_samples(project, &clock_map, sps);
+
+        for delay in project.delays.iter_mut() {
+            delay.tone_ready(master.beat_num(), beat_tempo, sps);
+            delay.tone_clear();
+        }
+        for overdrive in project.overdrives.iter_mut() {
+            overdrive.tone_ready();
+        }
+
+        let units = project
+            .units
+            .iter()
+            .map(|_| UnitTone::new(Self::default_sampler(&instances, sps, beat_tempo)))
+            .collect();
+        let groups = (0..MAX_GROUP_NUM)
+            .map(|group| GroupBus::new(project, group))
+            .collect();
+
+        Self {
+            project: song,
+            instances,
+            units,
+            groups,
+            ch,
+            sps,
+            options: *options,
+            beat_tempo,
+            clock_map,
+            smp_count: 0,
+            smp_repeat,
+            smp_end,
+            loops: 1,
+            eve_index: 0,
+            live_events: Vec::new(),
+            time_pan_index: 0,
+        }
+    }
+
+    /// Sample positions of the repeat measure and of the end of the song
+    fn loop_samples(project: &Project, clock_map: &ClockMap, sps: u32) -> (i32, i32) {
+        let master = &project.master;
+        let sample = |meas| clock_map.clock_to_sample(master.meas_clock(meas), sps);
+        (sample(master.repeat_meas()), sample(master.play_meas()))
+    }
+
+    fn default_sampler(
+        instances: &[Arc<WoiceInstance>],
+        sps: u32,
+        beat_tempo: f32,
+    ) -> Option<ToneSampler> {
+        instances
+            .get(EventKind::DEFAULT_VOICENO as usize)
+            .map(|instance| ToneSampler::new(instance.clone(), sps, beat_tempo))
+    }
+
+    /// Play the song `loops` times in total, going back to the repeat measure at each end
+    pub fn set_loops(&mut self, loops: u32) {
+        self.loops = loops;
+    }
+
+    /// Silence a unit from now on
+    pub fn set_unit_mute(&mut self, index: usize, mute: bool) {
+        self.project.set_unit_mute(index, mute);
+    }
+
+    /// Play only the unit at `index` from now on, or every unmuted unit again with `None`
+    pub fn set_unit_solo(&mut self, index: Option<usize>) -> Result<()> {
+        self.project.set_unit_solo(index)
+    }
+
+    /// Level of a group after its effects, 1.0 as pxtone mixes
+    pub fn group_gain(&self, group: usize) -> f32 {
+        self.groups[group].gain
+    }
+
+    /// Change the level of a group from now on; delays keep feeding back at the old level.
+    /// Fails with `InvalidRenderOptions` unless `gain` is finite and non-negative.
+    pub fn set_group_gain(&mut self, group: usize, gain: f32) -> Result<()> {
+        check_gain(f64::from(gain))?;
+        self.groups[group].gain = gain;
+        Ok(())
+    }
+
+    /// Continue from `smp_count`, as if the song had been played up to there.
+    /// Units restart from their first events; delays keep their tails.
+    pub fn seek(&mut self, smp_count: i32) {
+        self.restart(smp_count.clamp(0, self.smp_end));
+    }
+
+    /// Go to `smp_count` with every unit reset, replaying events from the start
+    fn restart(&mut self, smp_count: i32) {
+        self.smp_count = smp_count;
+        self.eve_index = 0;
+        for unit in self.units.iter_mut() {
+            unit.init(Self::default_sampler(
+                &self.instances,
+                self.sps,
+                self.beat_tempo,
+            ));
+        }
+    }
+
+    /// Sample rate frames render at
+    pub fn sps(&self) -> u32 {
+        self.sps
+    }
+
+    /// Current position in samples
+    pub fn smp_count(&self) -> i32 {
+        self.smp_count
+    }
+
+    /// Position where the song stops, in samples
+    pub fn smp_end(&self) -> i32 {
+        self.smp_end
+    }
+
+    /// Play an event at its clock, or on the next frame if that has passed.
+    /// It is not added to the project, so loops do not repeat it.
+    pub fn push_event(&mut self, event: Event) {
+        let index = self.live_events.partition_point(|e| e.clock <= event.clock);
+        self.live_events.insert(index, event);
+    }
+
+    /// Switch to the project in `bytes` without stopping: notes keep sounding, edited woices
+    /// are readied again and events after the current position come from the new song
+    pub fn reload<T: Read + Seek>(&mut self, bytes: T) -> Result<ProjectChanges> {
+        let new = Project::new(bytes)?;
+        let changes = self.project.changes(&new);
+        let mut instances = self.instances.clone();
+        instances.truncate(new.woices.len());
+        for &w in &changes.woices {
+            let instance = Arc::new(new.woices[w].ready(&self.options)?);
+            match instances.get_mut(w) {
+                Some(old) => *old = instance,
+                None => instances.push(instance),
+            }
+        }
+        // the clock of the last frame, to go on from in the new song
+        let clock = self
+            .clock_map
+            .clocks_at(f64::from(self.smp_count - 1), self.sps);
+        self.project.replace(new, &changes);
+
+        for unit in self.units.iter_mut() {
+            if let Some(sampler) = &mut unit.sampler {
+                let woice = self
+                    .instances
+                    .iter()
+                    .position(|instance| Arc::ptr_eq(instance, sampler.instance()));
+                if let Some(instance) = woice.and_then(|w| instances.get(w)) {
+                    if !Arc::ptr_eq(instance, sampler.instance()) {
+                        sampler.set_instance(instance.clone(), self.beat_tempo);
+                    }
+                }
+            }
+        }
+        self.instances = instances;
+
+        let project = &mut *self.project;
+        let clock_map = project.clock_map();
+        if changes.master || clock_map != self.clock_map {
+            self.beat_tempo = project.master.beat_tempo();
+            self.clock_map = clock_map;
+            let (smp_repeat, smp_end) = Self::loop_samples(project, &self.clock_map, self.sps);
+            self.smp_repeat = smp_repeat;
+            self.smp_end = smp_end;
+            if self.smp_count > 0 {
+                self.smp_count = self.clock_map.samples_at(clock + 1.0, self.sps) as i32;
+            }
+        }
+        if changes.master || changes.effects {
+            let master = &project.master;
+            for delay in project.delays.iter_mut() {
+                delay.tone_ready(master.beat_num(), self.beat_tempo, self.sps);
+                delay.tone_clear();
+            }
+            for overdrive in project.overdrives.iter_mut() {
+                overdrive.tone_ready();
+            }
+            self.groups = (0..MAX_GROUP_NUM)
+                .map(|group| GroupBus::new(project, group))
+                .collect();
+        }
+        let (instances, sps, beat_tempo) = (&self.instances, self.sps, self.beat_tempo);
+        self.units.resize_with(project.units.len(), || {
+            UnitTone::new(Self::default_sampler(instances, sps, beat_tempo))
+        });
+        self.eve_index = 0;
+        if self.smp_count > 0 {
+            // bring units up to the new settings without playing past notes again
+            let clock = clock as i32;
+            let past = project
+                .evelist
+                .events()
+                .partition_point(|e| e.clock <= clock);
+            for index in 0..past {
+                let event = self.project.evelist.events()[index];
+                let playing = self.units.get(usize::from(event.unit_no)).and_then(|unit| {
+                    let sampler = unit.sampler.as_ref()?;
+                    let instance = self.instances.get(event.value as usize)?;
+                    Some(Arc::ptr_eq(sampler.instance(), instance))
+                });
+                let skip = match event.kind {
+                    EventKind::On => true,
+                    EventKind::VoiceNo => playing == Some(true),
+                    _ => false,
+                };
+                if !skip {
+                    self.on_event(event, clock, index + 1);
+                }
+            }
+            self.eve_index = past;
+        }
+        Ok(changes)
+    }
+
+    /// Render up to `bucket_size` frames, returning their lowest and highest samples as
+    /// `Pcm::peaks` does; `None` once the song has ended
+    pub fn next_peak(&mut self, bucket_size: usize) -> Option<(f32, f32)> {
+        assert!(bucket_size > 0);
+        let ch = usize::from(self.ch);
+        let mut peak: Option<(f32, f32)> = None;
+        for frame in self.by_ref().take(bucket_size) {
+            for &bits in &frame[..ch] {
+                let smp = <f32 as Sample>::from_i16(bits);
+                let (low, high) = peak.unwrap_or((smp, smp));
+                peak = Some((low.min(smp), high.max(smp)));
+            }
+        }
+        peak
+    }
+
+    /// Playing state to poll between frames
+    pub fn state(&self) -> MooState {
+        let clock = self.clock_map.sample_to_clock(self.smp_count, self.sps);
+        let (meas, beat, clock_in_beat) = self.clock_map.position(clock);
+        MooState {
+            smp_count: self.smp_count,
+            clock,
+            meas,
+            beat,
+            clock_in_beat,
+            units: self
+                .units
+                .iter()
+                .map(|unit| UnitState::new(unit.sampler.as_ref()))
+                .collect(),
+        }
+    }
+
+    /// Playing state to come back to, rendering the same frames again from there
+    pub fn save_state(&self) -> RendererSnapshot {
+        RendererSnapshot {
+            units: self.units.clone(),
+            delays: self.project.delays.clone(),
+            smp_count: self.smp_count,
+            loops: self.loops,
+            eve_index: self.eve_index,
+            live_events: self.live_events.clone(),
+            time_pan_index: self.time_pan_index,
+        }
+    }
+
+    /// Go back to a state saved from this renderer, keeping mutes and group gains.
+    /// Fails with `StateMismatch` if units or delays were added or removed since.
+    pub fn restore_state(&mut self, snapshot: &RendererSnapshot) -> Result<()> {
+        if snapshot.units.len() != self.units.len()
+            || snapshot.delays.len() != self.project.delays.len()
+        {
+            return Err(ErrorKind::StateMismatch.into());
+        }
+        self.units.clone_from(&snapshot.units);
+        for (delay, saved) in self.project.delays.iter_mut().zip(&snapshot.delays) {
+            delay.copy_line(saved);
+        }
+        self.smp_count = snapshot.smp_count;
+        self.loops = snapshot.loops;
+        self.eve_index = snapshot.eve_index;
+        self.live_events.clone_from(&snapshot.live_events);
+        self.time_pan_index = snapshot.time_pan_index;
+        Ok(())
+    }
+
+    /// Apply an event; `following` is the index of the first list event after it
+    fn on_event(&mut self, event: Event, clock: i32, following: usize) {
+        let ch = self.ch;
+        let sps = self.sps;
+        let clock_rate = self.clock_map.clock_rate_at(clock, sps);
+        let smp_now = self.clock_map.clock_to_sample(clock, sps);
+        let smp_end = self.smp_end;
+        let following = &self.project.evelist.events()[following..];
+        let unit = match self.units.get_mut(event.unit_no as usize) {
+            Some(unit) => unit,
+            None => return,
+        };
+
+        match event.kind {
+            EventKind::VoiceNo => {
+                if let Some(instance) = self.instances.get(event.value as usize) {
+                    match &mut unit.sampler {
+                        Some(sampler) => sampler.set_instance(instance.clone(), self.beat_tempo),
+                        None => {
+                            unit.sampler =
+                                Some(ToneSampler::new(instance.clone(), sps, self.beat_tempo))
+                        }
+                    }
+                }
+                return;
+            }
+            EventKind::GroupNo => {
+                unit.group = event.value.clamp(0, MAX_GROUP_NUM - 1) as usize;
+                return;
+            }
+            EventKind::PanTime => {
+                unit.set_pan_time(ch, event.value, sps);
+                return;
+            }
+            _ => {}
+        }
+
+        let sampler = match &mut unit.sampler {
+            Some(sampler) => sampler,
+            None => return,
+        };
+        match event.kind {
+            EventKind::On => {
+                let on_count = ((event.clock + event.value - clock) as f32 * clock_rate) as i32;
+                let rest = ((event.value - (clock - event.clock)) as f32 * clock_rate) as i32;
+                sampler.key_on_with(on_count, |env_release| {
+                    // cut the release at the next note of the unit
+                    let release_clock = (env_release as f32 / clock_rate) as i32;
+                    let c = event.clock + event.value + release_clock;
+                    let next = following
+                        .iter()
+                        .take_while(|e| e.clock <= c)
+                        .find(|e| e.unit_no == event.unit_no && e.kind == EventKind::On);
+                    let max_life_count = match next {
+                        Some(next) => ((next.clock - clock) as f32 * clock_rate) as i32,
+                        None => smp_end - smp_now,
+                    };
+                    (rest + env_release).min(max_life_count)
+                });
+            }
+            EventKind::Key => sampler.set_key(event.value),
+            EventKind::PanVolume => sampler.set_pan_volume(ch, event.value),
+            EventKind::Velocity => sampler.set_velocity(event.value),
+            EventKind::Volume => sampler.set_volume(event.value),
+            EventKind::Portament => sampler.set_portament((event.value as f32 * clock_rate) as i32),
+            EventKind::Tuning => sampler.set_tuning(event.value_f32()),
+            _ => {}
+        }
+    }
+
+    /// Next frame as the sum of the groups, before gain and clipping
+    pub(super) fn mix(&mut self) -> Option<[i32; MAX_CHANNEL]> {
+        if self.loops == 0 || self.smp_count >= self.smp_end {
+            return None;
+        }
+
+        // envelope
+        for unit in self.units.iter_mut() {
+            if let Some(sampler) = &mut unit.sampler {
+                sampler.envelope();
+            }
+        }
+
+        // events
+        let clock = self.clock_map.sample_to_clock(self.smp_count, self.sps);
+        while let Some(&event) = self.project.evelist.events().get(self.eve_index) {
+            if event.clock > clock {
+                break;
+            }
+            self.on_event(event, clock, self.eve_index + 1);
+            self.eve_index += 1;
+        }
+        while self.live_events.first().is_some_and(|e| e.clock <= clock) {
+            let event = self.live_events.remove(0);
+            let event = Event { clock, ..event };
+            self.on_event(event, clock, self.eve_index);
+        }
+
+        // sampling
+        for (u, unit) in self.units.iter_mut().enumerate() {
+            unit.sample(self.project.unit_heard(u), self.ch, self.time_pan_index);
+        }
+
+        let mut frame = [0; MAX_CHANNEL];
+        for (ch, smp) in frame.iter_mut().enumerate().take(self.ch as usize) {
+            let mut group_smps = [0; MAX_GROUP_NUM as usize];
+            for unit in self.units.iter() {
+                unit.supple(&mut group_smps, ch, self.time_pan_index);
+            }
+            for (group, bus) in self.groups.iter().enumerate() {
+                for &o in &bus.overdrives {
+                    self.project.overdrives[o].tone_supple(&mut group_smps);
+                }
+                for &d in &bus.delays {
+                    self.project.delays[d].tone_supple(ch, &mut group_smps);
+                }
+                *smp += if bus.gain == 1.0 {
+                    group_smps[group]
+                } else {
+                    (f64::from(group_smps[group]) * f64::from(bus.gain)) as i32
+                };
+            }
+        }
+        if self.ch == 1 {
+            frame[1] = frame[0];
+        }
+
+        // increment
+        self.smp_count += 1;
+        self.time_pan_index = (self.time_pan_index + 1) & (TIME_PAN_BUF_SIZE - 1);
+        for unit in self.units.iter_mut() {
+            if let Some(sampler) = &mut unit.sampler {
+                sampler.increment();
+            }
+        }
+        for delay in self.project.delays.iter_mut() {
+            delay.tone_increment();
+        }
+
+        if self.smp_count >= self.smp_end && self.loops > 1 {
+            self.loops -= 1;
+            self.restart(self.smp_repeat);
+        }
+
+        Some(frame)
+    }
+}
+
+impl Iterator for Renderer<'_> {
+    type Item = [i16; MAX_CHANNEL];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.mix()?;
+        let options = &self.options;
+        Some(frame.map(|smp| {
+            let work = options.master(f64::from(smp)) as i32;
+            work.clamp(-SAMPLING_TOP, SAMPLING_TOP) as i16
+        }))
+    }
+}
@@ -0,0 +1,179 @@
+use std::iter;
+use std::sync::Arc;
+
+use super::{Project, Renderer, Song};
+
+use crate::delay::MAX_CHANNEL;
+use crate::error::Result;
+use crate::options::{Clipping, Dither, RenderOptions};
+use crate::pulse::{Noise, OutputFormat, Pcm};
+use crate::woice::{Woice, WoiceInstance};
+
+const SAMPLING_TOP: i32 = i16::MAX as i32;
+
+/// Queued song or noise
+enum Track {
+    Song {
+        project: Box<Project>,
+        instances: Vec<Arc<WoiceInstance>>,
+    },
+    /// Built frames in 16-bit scale
+    Noise(Arc<[[i32; MAX_CHANNEL]]>),
+}
+
+/// Track being played
+enum Playing {
+    Song(Box<Renderer<'static>>),
+    Noise(Arc<[[i32; MAX_CHANNEL]]>, usize),
+}
+
+/// Songs and noises played one after another without gaps, such as the tracks of an album.
+///
+/// Woices are readied once when queued and shared by every song using an equal one.
+pub struct Session {
+    options: RenderOptions,
+    tracks: Vec<Track>,
+    cache: Vec<(Woice, Arc<WoiceInstance>)>,
+    track: usize,
+    playing: Option<Playing>,
+}
+
+impl Session {
+    pub fn new(options: &RenderOptions) -> Result<Self> {
+        options.validate()?;
+        Ok(Self {
+            options: *options,
+            tracks: Vec::new(),
+            cache: Vec::new(),
+            track: 0,
+            playing: None,
+        })
+    }
+
+    pub fn options(&self) -> &RenderOptions {
+        &self.options
+    }
+
+    /// Queue a song, readying the woices no queued song shares
+    pub fn push_project(&mut self, project: Project) -> Result<()> {
+        let mut instances = Vec::with_capacity(project.woices.len());
+        for woice in &project.woices {
+            let instance = match self.cache.iter().find(|(cached, _)| cached == woice) {
+                Some((_, instance)) => instance.clone(),
+                None => {
+                    let instance = Arc::new(woice.ready(&self.options)?);
+                    self.cache.push((woice.clone(), instance.clone()));
+                    instance
+                }
+            };
+            instances.push(instance);
+        }
+        let project = Box::new(project);
+        self.tracks.push(Track::Song { project, instances });
+        Ok(())
+    }
+
+    /// Queue a noise, built now
+    pub fn push_noise(&mut self, noise: &Noise) -> Result<()> {
+        // gain, clipping and dither are applied to the whole session
+        let pcm = noise.build(&RenderOptions {
+            format: OutputFormat::I16,
+            gain: 1.0,
+            normalize: false,
+            loudness_target: None,
+            clipping: Clipping::Hard,
+            dither: Dither::None,
+            ..self.options
+        })?;
+        let frames = pcm
+            .frames::<i16>()
+            .map(|frame| frame.map(i32::from))
+            .collect();
+        self.tracks.push(Track::Noise(frames));
+        Ok(())
+    }
+
+    pub fn len(&self) -> usize {
+        self.tracks.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.tracks.is_empty()
+    }
+
+    /// Index of the track playing, or `len` after the last one
+    pub fn track(&self) -> usize {
+        self.track
+    }
+
+    /// Play on from the start of the track at `index`
+    pub fn skip_to(&mut self, index: usize) {
+        assert!(index <= self.tracks.len());
+        self.track = index;
+        self.playing = None;
+    }
+
+    /// Woices readied for the queued songs, counting shared ones once
+    pub fn cached_woices(&self) -> usize {
+        self.cache.len()
+    }
+
+    /// Render from the current track to the end, back to back
+    pub fn render(&mut self) -> Result<Pcm> {
+        let ch = usize::from(self.options.channels);
+        let options = self.options;
+        let samples = iter::from_fn(|| self.mix())
+            .flat_map(move |frame| (0..ch).map(move |c| f64::from(frame[c])));
+        options.quantize(samples)
+    }
+
+    fn start(&self, track: &Track) -> Playing {
+        match track {
+            Track::Song { project, instances } => {
+                let song = Song::Owned(project.clone());
+                let renderer = Renderer::with_instances(song, &self.options, instances.clone());
+                Playing::Song(Box::new(renderer))
+            }
+            Track::Noise(frames) => Playing::Noise(frames.clone(), 0),
+        }
+    }
+
+    /// Next frame in 16-bit scale, moving to the next track at the end of one
+    fn mix(&mut self) -> Option<[i32; MAX_CHANNEL]> {
+        loop {
+            let playing = match &mut self.playing {
+                Some(playing) => playing,
+                None => {
+                    let playing = self.start(self.tracks.get(self.track)?);
+                    self.playing.insert(playing)
+                }
+            };
+            let frame = match playing {
+                Playing::Song(renderer) => renderer.mix(),
+                Playing::Noise(frames, pos) => {
+                    let frame = frames.get(*pos).copied();
+                    *pos += 1;
+                    frame
+                }
+            };
+            if frame.is_some() {
+                return frame;
+            }
+            self.playing = None;
+            self.track += 1;
+        }
+    }
+}
+
+impl Iterator for Session {
+    type Item = [i16; MAX_CHANNEL];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let frame = self.mix()?;
+        let options = &self.options;
+        Some(frame.map(|smp| {
+            let work = options.master(f64::from(smp)) as i32;
+            work.clamp(-SAMPLING_TOP, SAMPLING_TOP) as i16
+        }))
+    }
+}
@@ -0,0 +1,91 @@
+use std::time::Duration;
+
+use super::Project;
+
+use crate::evelist::EventKind;
+
+/// Figures of a song, from `Project::stats`
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProjectStats {
+    /// Playing time up to the end or loop point
+    pub duration: Duration,
+    /// Most notes sounding at once
+    pub max_polyphony: usize,
+    pub units: Vec<UnitStats>,
+    /// Notes played with each woice
+    pub woice_notes: Vec<usize>,
+}
+
+impl ProjectStats {
+    pub fn note_count(&self) -> usize {
+        self.units.iter().map(|unit| unit.note_count).sum()
+    }
+
+    /// Indices of woices no note plays
+    pub fn unused_woices(&self) -> Vec<usize> {
+        (0..self.woice_notes.len())
+            .filter(|&w| self.woice_notes[w] == 0)
+            .collect()
+    }
+}
+
+/// Figures of one unit
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct UnitStats {
+    pub note_count: usize,
+    /// Lowest and highest keys notes start at
+    pub key_range: Option<(i32, i32)>,
+}
+
+impl Project {
+    /// Count notes, keys and woice use over the events
+    pub fn stats(&self) -> ProjectStats {
+        let mut units = vec![UnitStats::default(); self.units.len()];
+        let mut woice_notes = vec![0; self.woices.len()];
+        let mut keys = vec![EventKind::DEFAULT_KEY; self.units.len()];
+        let mut woices = vec![EventKind::DEFAULT_VOICENO as usize; self.units.len()];
+        // (clock, +1 at a note start or -1 at its end)
+        let mut edges = Vec::new();
+
+        for e in self.evelist.events() {
+            let u = usize::from(e.unit_no);
+            if u >= units.len() {
+                continue;
+            }
+            match e.kind {
+                EventKind::Key => keys[u] = e.value,
+                EventKind::VoiceNo => woices[u] = e.value as usize,
+                EventKind::On if e.value > 0 => {
+                    let unit = &mut units[u];
+                    unit.note_count += 1;
+                    unit.key_range = Some(match unit.key_range {
+                        Some((low, high)) => (low.min(keys[u]), high.max(keys[u])),
+                        None => (keys[u], keys[u]),
+                    });
+                    if let Some(notes) = woice_notes.get_mut(woices[u]) {
+                        *notes += 1;
+                    }
+                    edges.push((e.clock, 1));
+                    edges.push((e.clock + e.value, -1));
+                }
+                _ => {}
+            }
+        }
+
+        // notes ending at a clock make way for those starting there
+        edges.sort_unstable();
+        let mut polyphony = 0;
+        let mut max_polyphony = 0;
+        for (_, delta) in edges {
+            polyphony += delta;
+            max_polyphony = max_polyphony.max(polyphony);
+        }
+
+        ProjectStats {
+            duration: self.duration(),
+            max_polyphony: max_polyphony as usize,
+            units,
+            woice_notes,
+        }
+    }
+}
@@ -1,67 +1,266 @@
-mod frequency_table;
+mod convert;
+#[cfg(any(feature = "flac", feature = "ogg-encode"))]
+mod encode;
+#[cfg(feature = "hound")]
+mod hound_io;
+#[cfg(feature = "kira")]
+mod kira_io;
+mod loudness;
 mod noise_builder;
+mod speakers;
+#[cfg(feature = "symphonia")]
+mod symphonia_decode;
+mod voice_wave;
 
 use std::{
-    f64,
-    io::{Read, Write, Seek, SeekFrom},
+    borrow::Cow,
+    f64, fmt,
+    io::{Cursor, Read, Write, Seek, SeekFrom},
+    ops::{ControlFlow, Range},
+    time::Duration,
     vec::Vec,
 };
 
-use crate::error::Result;
+use crate::envelope::{Envelope, Interpolation};
+use crate::error::{at_position, ensure, ErrorKind, Result};
+use crate::io::WavWriter;
+use crate::evelist::EventKind;
+use crate::options::{ParseOptions, RenderOptions};
+use crate::progress::{Progress, Reporter};
+use crate::report::Report;
+
+#[cfg(all(feature = "ogg", not(feature = "symphonia")))]
+use lewton::inside_ogg::OggStreamReader;
+#[cfg(any(feature = "ogg", feature = "symphonia"))]
+use std::cell::OnceCell;
 
 use num_traits::FromPrimitive;
 
-use crate::descriptor::ReadBytesExt as _;
+#[cfg(feature = "spans")]
+use crate::descriptor::Spanned;
+use crate::descriptor::{
+    capacity, check_count, read_payload, read_spanned, read_struct, write_sized, ReadBytesExt as _,
+    Span, TrackedReader, WriteBytesExt as _,
+};
 use byteorder::{LittleEndian, ReadBytesExt as _, WriteBytesExt as _};
 
-use frequency_table::*;
-use noise_builder::NoiseBuilder;
+pub(crate) use loudness::integrated_loudness;
+use noise_builder::{NoiseBuilder, BASIC_SPS};
 
-pub(crate) struct Noise {
+pub(crate) use noise_builder::SMP_NUM as NOISE_TABLE_SIZE;
+
+/// Noise instrument (`.ptnoise`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Noise {
     units: Vec<NoiseUnit>,
     smp_num_44k: u32,
+    version: u32,
 }
 
 impl Noise {
     const CODE: &'static [u8] = b"PTNOISE-";
-    const VERSION: u32 = 2012_0418;
+    /// Newest revision read, and the one written
+    pub const VERSION: u32 = 2012_0418;
     const MAX_UNIT_NUM: u8 = 4;
     const LIMIT_SMP_NUM: u32 = 48000 * 10;
 
-    pub fn new<T: Read + Seek>(mut bytes: T) -> Result<Self> {
+    pub fn new<T: Read + Seek>(bytes: T) -> Result<Self> {
+        Self::parse(bytes, &ParseOptions::default())
+    }
+
+    pub fn parse<T: Read + Seek>(bytes: T, options: &ParseOptions) -> Result<Self> {
+        let mut bytes = TrackedReader::new(bytes)?;
+        let result = Self::read(&mut bytes, &mut Report::with_options(options));
+        at_position(&mut bytes, result)
+    }
+
+    /// Read a noise, reporting values it clamps and flags it drops
+    pub fn validate<T: Read + Seek>(bytes: T) -> Result<Report> {
+        let mut bytes = TrackedReader::new(bytes)?;
+        let mut report = Report::default();
+        let result = Self::read(&mut bytes, &mut report);
+        at_position(&mut bytes, result)?;
+        Ok(report)
+    }
+
+    pub(crate) fn read<T: Read + Seek>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("read_noise").entered();
         // signature
         let mut code = [0; 8];
         bytes.read_exact(&mut code)?;
-        assert_eq!(code, Self::CODE);
+        ensure(code == Self::CODE, "noise code")?;
 
         let version = bytes.read_u32::<LittleEndian>()?;
-        assert!(version <= Self::VERSION);
+        report.version("noise version", version, Self::VERSION)?;
+
+        let smp_num_44k = bytes.read_var_u32()?;
+        let smp_num_44k = report.clamp("sample count", smp_num_44k, 0, Self::LIMIT_SMP_NUM)?;
+
+        let unit_num = check_count(u32::from(bytes.read_u8()?), u32::from(Self::MAX_UNIT_NUM))?;
+
+        let mut units = Vec::with_capacity(unit_num);
+        for u in 0..unit_num {
+            units.push(report.within(format!("unit {}", u), |report| {
+                read_spanned(bytes, |bytes| NoiseUnit::read(bytes, report))
+            })?);
+        }
+
+        Ok(Self {
+            units,
+            smp_num_44k,
+            version,
+        })
+    }
+
+    /// Write as pxtone does, leaving out disabled units
+    pub(crate) fn write<W: Write>(&self, bytes: &mut W) -> Result<()> {
+        bytes.write_all(Self::CODE)?;
+        bytes.write_u32::<LittleEndian>(Self::VERSION)?;
+        bytes.write_var_u32(self.smp_num_44k)?;
+
+        let units = self.units.iter().filter(|unit| unit.enable);
+        bytes.write_u8(units.clone().count() as u8)?;
+        for unit in units {
+            unit.write(bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Noise of `units` lasting `smp_num_44k` frames at 44.1kHz; fails with `CountOverflow`
+    /// beyond four units
+    pub fn from_units(smp_num_44k: u32, units: Vec<NoiseUnit>) -> Result<Self> {
+        check_count(units.len() as u32, u32::from(Self::MAX_UNIT_NUM))?;
+        Ok(Self {
+            units,
+            smp_num_44k: smp_num_44k.min(Self::LIMIT_SMP_NUM),
+            version: Self::VERSION,
+        })
+    }
+
+    /// Revision the noise was read as, such as 20120418; `VERSION` when made in code
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn build(&self, options: &RenderOptions) -> Result<Pcm> {
+        NoiseBuilder::build(self, options)
+    }
 
-        let smp_num_44k = bytes.read_var_u32()?.min(Self::LIMIT_SMP_NUM);
+    /// `build`, calling `progress` as it goes and failing with `Cancelled` when it breaks
+    pub fn build_with_progress(
+        &self,
+        options: &RenderOptions,
+        progress: &mut dyn FnMut(Progress) -> ControlFlow<()>,
+    ) -> Result<Pcm> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("build_noise").entered();
+        let unit_nos = (0..self.units.len()).collect::<Vec<_>>();
+        let total = u64::from(self.sample_count(options.sample_rate)?);
+        let mut reporter = Reporter::new(progress, total);
+        let samples = NoiseBuilder::samples(self, &unit_nos, options, Some(&mut reporter))?;
+        reporter.finish()?;
+        options.quantize(samples)
+    }
+
+    /// Build the noise played `loops` times in a row, fading out over the last `fade_out`
+    pub fn render_looped(
+        &self,
+        options: &RenderOptions,
+        loops: u32,
+        fade_out: Duration,
+    ) -> Result<Pcm> {
+        let unit_nos = (0..self.units.len()).collect::<Vec<_>>();
+        let mut samples =
+            NoiseBuilder::samples(self, &unit_nos, options, None)?.repeat(loops as usize);
+        options.fade_out(&mut samples, fade_out);
+        options.quantize(samples)
+    }
+
+    /// Build each unit on its own
+    pub fn build_units(&self, options: &RenderOptions) -> Result<Vec<Pcm>> {
+        (0..self.units.len())
+            .map(|unit_no| NoiseBuilder::build_units(self, &[unit_no], options))
+            .collect()
+    }
+
+    pub fn units(&self) -> &[NoiseUnit] {
+        &self.units
+    }
+
+    pub fn units_mut(&mut self) -> &mut [NoiseUnit] {
+        &mut self.units
+    }
 
-        let unit_num = bytes.read_u8()?;
-        assert!(unit_num <= Self::MAX_UNIT_NUM);
+    /// Length in frames at 44.1kHz
+    pub fn sample_count_44k(&self) -> u32 {
+        self.smp_num_44k
+    }
 
-        let mut units = Vec::with_capacity(unit_num as usize);
-        for _ in 0..unit_num {
-            units.push(NoiseUnit::new(&mut bytes)?);
+    /// Length in frames at `sps`, as `build` renders it; fails with `SampleOverflow` past the
+    /// frames a `Pcm` counts
+    pub fn sample_count(&self, sps: u32) -> Result<u32> {
+        let frames = f64::from(self.smp_num_44k) / (f64::from(BASIC_SPS) / f64::from(sps));
+        let limit = u64::from(u32::MAX);
+        if frames > limit as f64 {
+            return Err(ErrorKind::SampleOverflow(frames as u64, "frames", limit).into());
         }
+        Ok(frames as u32)
+    }
+
+    pub fn duration(&self) -> Duration {
+        Duration::from_secs_f64(f64::from(self.smp_num_44k) / f64::from(BASIC_SPS))
+    }
+
+    pub fn set_sample_count_44k(&mut self, smp_num_44k: u32) {
+        self.smp_num_44k = smp_num_44k.min(Self::LIMIT_SMP_NUM);
+    }
+
+    pub(crate) fn push_unit(&mut self, unit: NoiseUnit) -> Result<()> {
+        check_count(self.units.len() as u32 + 1, u32::from(Self::MAX_UNIT_NUM))?;
+        self.units.push(unit);
+        Ok(())
+    }
+
+    pub(crate) fn pop_unit(&mut self) -> Option<NoiseUnit> {
+        self.units.pop()
+    }
 
-        Ok(Self { units, smp_num_44k })
+    /// Number of oscillator units
+    pub fn unit_count(&self) -> usize {
+        self.units.len()
     }
 
-    pub fn build(&self, ch: u16, sps: u32, bps: u16) -> Result<Pcm> {
-        NoiseBuilder::build(self, ch, sps, bps)
+    pub fn unit_enabled(&self, index: usize) -> bool {
+        self.units[index].enable
     }
+
+    /// Enable or disable a unit in `build`
+    pub fn set_unit_enabled(&mut self, index: usize, enable: bool) {
+        self.units[index].enable = enable;
+    }
+}
+
+/// How noise waves are rendered
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Quality {
+    /// Bit-exact with pxtone
+    #[default]
+    Original,
+    /// Saw and Rect main waves without aliasing
+    BandLimited,
 }
 
-struct NoiseUnit {
+/// Oscillators of a noise shaped by one envelope
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoiseUnit {
     enable: bool,
-    enves: Vec<Point>,
+    envelope: Envelope,
     pan: i8,
     main: Option<NoiseOscillator>,
     freq: Option<NoiseOscillator>,
     volu: Option<NoiseOscillator>,
+    span: Span,
 }
 
 impl NoiseUnit {
@@ -78,24 +277,56 @@ impl NoiseUnit {
     const MAX_ENVELOPE_NUM: u32 = 3;
     const LIMIT_ENVE_X: i32 = 1000 * 10;
     const LIMIT_ENVE_Y: i32 = 100;
+    const LIMIT_PAN: i8 = 100;
+    pub(crate) const ENVE_FPS: u32 = 1000;
+
+    /// `enves` holds (milliseconds, percent) segments from silence; `pan` is -100 to 100.
+    /// Fails with `CountOverflow` beyond three envelope points.
+    pub fn new(
+        enves: &[(i32, i32)],
+        pan: i8,
+        main: Option<NoiseOscillator>,
+        freq: Option<NoiseOscillator>,
+        volu: Option<NoiseOscillator>,
+    ) -> Result<Self> {
+        check_count(enves.len() as u32, Self::MAX_ENVELOPE_NUM)?;
+        let enves = enves
+            .iter()
+            .map(|&(x, y)| {
+                (
+                    x.clamp(0, Self::LIMIT_ENVE_X),
+                    y.clamp(0, Self::LIMIT_ENVE_Y),
+                )
+            })
+            .collect();
+        Ok(Self {
+            enable: true,
+            envelope: Envelope::new(Self::ENVE_FPS, enves, 0),
+            pan: pan.clamp(-Self::LIMIT_PAN, Self::LIMIT_PAN),
+            main,
+            freq,
+            volu,
+            span: Span::default(),
+        })
+    }
 
-    fn new<T: Read + Seek>(bytes: &mut T) -> Result<Self> {
+    fn read<T: Read + Seek>(bytes: &mut T, report: &mut Report) -> Result<Self> {
         let enable = true;
 
-        let flags = bytes.read_var_u32()?;
-        assert_eq!(flags & Self::FLAG_UNCOVERED, 0);
+        let flags = report.flags("flags", bytes.read_var_u32()?, Self::FLAG_UNCOVERED)?;
 
         // envelope
         let enves = if flags & Self::FLAG_ENVELOPE != 0 {
-            let enve_num = bytes.read_var_u32()?;
-            assert!(enve_num <= Self::MAX_ENVELOPE_NUM);
+            let enve_num = bytes.read_count(Self::MAX_ENVELOPE_NUM)?;
 
-            let mut enves = Vec::with_capacity(enve_num as usize);
+            let mut enves = Vec::with_capacity(enve_num);
             for _ in 0..enve_num {
-                enves.push(Point {
-                    x: bytes.read_var_i32()?.max(0).min(Self::LIMIT_ENVE_X),
-                    y: bytes.read_var_i32()?.max(0).min(Self::LIMIT_ENVE_Y),
-                });
+                let x = bytes.read_var_i32()?;
+                let y = bytes.read_var_i32()?;
+                enves.push((
+                    report.clamp("envelope time", x, 0, Self::LIMIT_ENVE_X)?,
+                    report.clamp("envelope level", y, 0, Self::LIMIT_ENVE_Y)?,
+                ));
             }
             enves
         } else {
@@ -104,45 +335,168 @@ impl NoiseUnit {
 
         // pan
         let pan = if flags & Self::FLAG_PAN != 0 {
-            bytes.read_i8()?
+            let pan = bytes.read_i8()?;
+            report.check_range("pan", pan, -Self::LIMIT_PAN, Self::LIMIT_PAN)?;
+            pan
         } else {
             0
         };
 
         // oscillator
         let main = if flags & Self::FLAG_OSC_MAIN != 0 {
-            Some(NoiseOscillator::new(bytes)?)
+            let osc = report.within("main".to_owned(), |report| {
+                read_spanned(bytes, |bytes| NoiseOscillator::read(bytes, report))
+            })?;
+            Some(osc)
         } else {
             None
         };
         let freq = if flags & Self::FLAG_OSC_FREQ != 0 {
-            Some(NoiseOscillator::new(bytes)?)
+            let osc = report.within("freq".to_owned(), |report| {
+                read_spanned(bytes, |bytes| NoiseOscillator::read(bytes, report))
+            })?;
+            Some(osc)
         } else {
             None
         };
         let volu = if flags & Self::FLAG_OSC_VOLU != 0 {
-            Some(NoiseOscillator::new(bytes)?)
+            let osc = report.within("volu".to_owned(), |report| {
+                read_spanned(bytes, |bytes| NoiseOscillator::read(bytes, report))
+            })?;
+            Some(osc)
         } else {
             None
         };
 
         Ok(Self {
             enable,
-            enves,
+            envelope: Envelope::new(Self::ENVE_FPS, enves, 0),
             pan,
             main,
             freq,
             volu,
+            span: Span::default(),
         })
     }
+
+    fn write<W: Write>(&self, bytes: &mut W) -> Result<()> {
+        let enves = self.envelope.points();
+        let oscs = [
+            (Self::FLAG_OSC_MAIN, &self.main),
+            (Self::FLAG_OSC_FREQ, &self.freq),
+            (Self::FLAG_OSC_VOLU, &self.volu),
+        ];
+
+        let mut flags = 0;
+        if !enves.is_empty() {
+            flags |= Self::FLAG_ENVELOPE;
+        }
+        if self.pan != 0 {
+            flags |= Self::FLAG_PAN;
+        }
+        for (flag, osc) in &oscs {
+            if osc.is_some() {
+                flags |= flag;
+            }
+        }
+        bytes.write_var_u32(flags)?;
+
+        if flags & Self::FLAG_ENVELOPE != 0 {
+            bytes.write_var_u32(enves.len() as u32)?;
+            for &(x, y) in enves {
+                bytes.write_var_i32(x)?;
+                bytes.write_var_i32(y)?;
+            }
+        }
+        if flags & Self::FLAG_PAN != 0 {
+            bytes.write_i8(self.pan)?;
+        }
+        for osc in oscs.iter().filter_map(|(_, osc)| osc.as_ref()) {
+            osc.write(bytes)?;
+        }
+        Ok(())
+    }
+
+    /// Levels in percent over milliseconds; the last one is held unless a sustain is set
+    pub fn envelope(&self) -> &Envelope {
+        &self.envelope
+    }
+
+    pub fn envelope_mut(&mut self) -> &mut Envelope {
+        &mut self.envelope
+    }
+
+    /// -100 (left) to 100 (right)
+    pub fn pan(&self) -> i8 {
+        self.pan
+    }
+
+    pub fn set_pan(&mut self, pan: i8) {
+        self.pan = pan.clamp(-Self::LIMIT_PAN, Self::LIMIT_PAN);
+    }
+
+    /// Oscillator sounding the unit
+    pub fn main(&self) -> Option<&NoiseOscillator> {
+        self.main.as_ref()
+    }
+
+    pub fn main_mut(&mut self) -> Option<&mut NoiseOscillator> {
+        self.main.as_mut()
+    }
+
+    pub fn set_main(&mut self, main: Option<NoiseOscillator>) {
+        self.main = main;
+    }
+
+    /// Oscillator modulating the frequency of `main`
+    pub fn freq(&self) -> Option<&NoiseOscillator> {
+        self.freq.as_ref()
+    }
+
+    pub fn freq_mut(&mut self) -> Option<&mut NoiseOscillator> {
+        self.freq.as_mut()
+    }
+
+    pub fn set_freq(&mut self, freq: Option<NoiseOscillator>) {
+        self.freq = freq;
+    }
+
+    /// Oscillator modulating the volume of `main`
+    pub fn volu(&self) -> Option<&NoiseOscillator> {
+        self.volu.as_ref()
+    }
+
+    pub fn volu_mut(&mut self) -> Option<&mut NoiseOscillator> {
+        self.volu.as_mut()
+    }
+
+    pub fn set_volu(&mut self, volu: Option<NoiseOscillator>) {
+        self.volu = volu;
+    }
+
+    /// Bytes of the file this was read from
+    #[cfg(feature = "spans")]
+    pub fn span(&self) -> Option<Range<u64>> {
+        self.span.range()
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for NoiseUnit {
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
 }
 
-struct NoiseOscillator {
+/// Wave of a noise unit; `freq` and `volu` modulate the main one
+#[derive(Clone, Debug, PartialEq)]
+pub struct NoiseOscillator {
     wave: NoiseWave,
     rev: bool,
     freq: f32,
     volu: f32,
     offset: f32,
+    span: Span,
 }
 
 impl NoiseOscillator {
@@ -150,30 +504,110 @@ impl NoiseOscillator {
     const LIMIT_VOLU: f32 = 200.0;
     const LIMIT_OFFSET: f32 = 100.0;
 
-    fn new<T: Read + Seek>(bytes: &mut T) -> Result<Self> {
-        let wave = NoiseWave::from_i32(bytes.read_var_i32()?).unwrap();
+    /// `freq` in Hz; `volu` and `offset` in percent
+    pub fn new(wave: NoiseWave, rev: bool, freq: f32, volu: f32, offset: f32) -> Self {
+        Self {
+            wave,
+            rev,
+            freq: freq.clamp(0.0, Self::LIMIT_FREQ),
+            volu: volu.clamp(0.0, Self::LIMIT_VOLU),
+            offset: offset.clamp(0.0, Self::LIMIT_OFFSET),
+            span: Span::default(),
+        }
+    }
+
+    fn read<T: Read + Seek>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        let wave = NoiseWave::from_i32(bytes.read_var_i32()?)
+            .ok_or(ErrorKind::InvalidFormat("noise wave"))?;
         let rev = bytes.read_var_u32()? != 0;
-        let freq = (bytes.read_var_f32()? / 10.0)
-            .max(0.0)
-            .min(Self::LIMIT_FREQ);
-        let volu = (bytes.read_var_f32()? / 10.0)
-            .max(0.0)
-            .min(Self::LIMIT_VOLU);
-        let offset = (bytes.read_var_f32()? / 10.0)
-            .max(0.0)
-            .min(Self::LIMIT_OFFSET);
+        let freq = bytes.read_var_i32()? as f32 / 10.0;
+        let volu = bytes.read_var_i32()? as f32 / 10.0;
+        let offset = bytes.read_var_i32()? as f32 / 10.0;
+        let freq = report.clamp("frequency", freq, 0.0, Self::LIMIT_FREQ)?;
+        let volu = report.clamp("volume", volu, 0.0, Self::LIMIT_VOLU)?;
+        let offset = report.clamp("offset", offset, 0.0, Self::LIMIT_OFFSET)?;
         Ok(Self {
             wave,
             rev,
             freq,
             volu,
             offset,
+            span: Span::default(),
         })
     }
+
+    fn write<W: Write>(&self, bytes: &mut W) -> Result<()> {
+        bytes.write_var_i32(self.wave as i32)?;
+        bytes.write_var_u32(u32::from(self.rev))?;
+        bytes.write_var_i32((self.freq * 10.0).round() as i32)?;
+        bytes.write_var_i32((self.volu * 10.0).round() as i32)?;
+        bytes.write_var_i32((self.offset * 10.0).round() as i32)?;
+        Ok(())
+    }
+
+    pub fn wave(&self) -> NoiseWave {
+        self.wave
+    }
+
+    pub fn set_wave(&mut self, wave: NoiseWave) {
+        self.wave = wave;
+    }
+
+    /// Whether the wave is played upside down
+    pub fn reverse(&self) -> bool {
+        self.rev
+    }
+
+    pub fn set_reverse(&mut self, rev: bool) {
+        self.rev = rev;
+    }
+
+    /// Hz
+    pub fn freq(&self) -> f32 {
+        self.freq
+    }
+
+    /// Set the frequency, clamped to 0-44100Hz
+    pub fn set_freq(&mut self, freq: f32) {
+        self.freq = freq.clamp(0.0, Self::LIMIT_FREQ);
+    }
+
+    /// Percent
+    pub fn volume(&self) -> f32 {
+        self.volu
+    }
+
+    /// Set the volume, clamped to 0-200%
+    pub fn set_volume(&mut self, volu: f32) {
+        self.volu = volu.clamp(0.0, Self::LIMIT_VOLU);
+    }
+
+    /// Start of the cycle in percent
+    pub fn offset(&self) -> f32 {
+        self.offset
+    }
+
+    /// Set the offset, clamped to 0-100%
+    pub fn set_offset(&mut self, offset: f32) {
+        self.offset = offset.clamp(0.0, Self::LIMIT_OFFSET);
+    }
+
+    /// Bytes of the file this was read from
+    #[cfg(feature = "spans")]
+    pub fn span(&self) -> Option<Range<u64>> {
+        self.span.range()
+    }
 }
 
-#[derive(FromPrimitive)]
-enum NoiseWave {
+#[cfg(feature = "spans")]
+impl Spanned for NoiseOscillator {
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, FromPrimitive)]
+pub enum NoiseWave {
     None,
     Sine,
     Saw,
@@ -193,94 +627,198 @@ enum NoiseWave {
     Saw8,
 }
 
-struct Voice {
+/// Synthesized instrument (`.ptvoice`)
+#[derive(Clone, Debug, PartialEq)]
+pub struct Voice {
     units: Vec<VoiceUnit>,
     x3x_basic_key: i32,
+    x3x_tuning: f32,
+    version: u32,
 }
 
 impl Voice {
     const CODE: &'static [u8] = b"PTVOICE-";
-    const VERSION: u32 = 2006_0111;
+    /// Newest revision read, and the one written
+    pub const VERSION: u32 = 2006_0111;
+    /// Units one voice holds in pxtone (`pxtnMAX_UNITCONTROLVOICE`)
+    const MAX_UNIT_NUM: u32 = 2;
+
+    pub fn new<T: Read + Seek>(bytes: T) -> Result<Self> {
+        Self::parse(bytes, &ParseOptions::default())
+    }
+
+    pub fn parse<T: Read + Seek>(bytes: T, options: &ParseOptions) -> Result<Self> {
+        let mut bytes = TrackedReader::new(bytes)?;
+        let result = Self::read(&mut bytes, &mut Report::with_options(options));
+        at_position(&mut bytes, result)
+    }
+
+    /// Read a voice, reporting flags it drops
+    pub fn validate<T: Read + Seek>(bytes: T) -> Result<Report> {
+        let mut bytes = TrackedReader::new(bytes)?;
+        let mut report = Report::default();
+        let result = Self::read(&mut bytes, &mut report);
+        at_position(&mut bytes, result)?;
+        Ok(report)
+    }
 
-    pub fn new<T: Read + Seek>(mut bytes: T) -> Result<Self> {
+    pub(crate) fn read<T: Read + Seek>(bytes: &mut T, report: &mut Report) -> Result<Self> {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("read_voice").entered();
         // signature
         let mut code = [0; 8];
         bytes.read_exact(&mut code)?;
-        assert_eq!(code, Self::CODE);
+        ensure(code == Self::CODE, "voice code")?;
 
         let version = bytes.read_u32::<LittleEndian>()?;
-        assert!(version <= Self::VERSION);
+        report.version("voice version", version, Self::VERSION)?;
         bytes.seek(SeekFrom::Current(4))?;
 
         let x3x_basic_key = bytes.read_var_i32()?;
         let work1 = bytes.read_var_u32()?;
         let work2 = bytes.read_var_u32()?;
-        assert!(work1 == 0 && work2 == 0);
-
-        let unit_num = bytes.read_var_u32()?;
-        let mut units = Vec::with_capacity(unit_num as usize);
-        for _ in 0..unit_num {
-            units.push(VoiceUnit::new(&mut bytes)?);
+        ensure(work1 == 0 && work2 == 0, "voice reserved data")?;
+
+        let unit_num = bytes.read_count(Self::MAX_UNIT_NUM)?;
+        let mut units = Vec::with_capacity(unit_num);
+        for u in 0..unit_num {
+            units.push(report.within(format!("unit {}", u), |report| {
+                read_spanned(bytes, |bytes| VoiceUnit::read(bytes, report))
+            })?);
         }
 
         Ok(Self {
             units,
             x3x_basic_key,
+            x3x_tuning: 0.0,
+            version,
+        })
+    }
+
+    pub(crate) fn write<W: Write + Seek>(&self, bytes: &mut W) -> Result<()> {
+        bytes.write_all(Self::CODE)?;
+        bytes.write_u32::<LittleEndian>(Self::VERSION)?;
+        write_sized(bytes, |bytes| {
+            bytes.write_var_i32(self.x3x_basic_key)?;
+            bytes.write_var_u32(0)?;
+            bytes.write_var_u32(0)?;
+            bytes.write_var_u32(self.units.len() as u32)?;
+            for unit in &self.units {
+                unit.write(bytes)?;
+            }
+            Ok(())
         })
     }
+
+    pub fn from_units(units: Vec<VoiceUnit>) -> Self {
+        Self {
+            units,
+            x3x_basic_key: EventKind::DEFAULT_BASICKEY,
+            x3x_tuning: 0.0,
+            version: Self::VERSION,
+        }
+    }
+
+    /// Revision the voice was read as, such as 20060111; `VERSION` when made in code
+    pub fn version(&self) -> u32 {
+        self.version
+    }
+
+    pub fn units(&self) -> &[VoiceUnit] {
+        &self.units
+    }
+
+    pub fn unit_count(&self) -> usize {
+        self.units.len()
+    }
+
+    pub fn units_mut(&mut self) -> &mut [VoiceUnit] {
+        &mut self.units
+    }
+
+    /// Key the unit played at in x3x projects
+    pub(crate) fn x3x_basic_key(&self) -> i32 {
+        self.x3x_basic_key
+    }
+
+    /// Unit tuning of x3x projects, or 0
+    pub(crate) fn x3x_tuning(&self) -> f32 {
+        self.x3x_tuning
+    }
+
+    pub(crate) fn set_x3x_tuning(&mut self, tuning: f32) {
+        self.x3x_tuning = tuning;
+    }
 }
 
-struct VoiceUnit {
+#[derive(Clone, Debug, PartialEq)]
+pub struct VoiceUnit {
     basic_key: i32,
     volu: i32,
     pan: i32,
     tuning: f32,
     flags: u32,
     wave: Option<VoiceWave>,
-    enve: Option<VoiceEnvelope>,
+    enve: Option<Envelope>,
+    span: Span,
 }
 
 impl VoiceUnit {
-    const FLAG_WAVELOOP: u32 = 0x0001;
-    const FLAG_SMOOTH: u32 = 0x0002;
-    const FLAG_BEATFIT: u32 = 0x0004;
-    const FLAG_UNCOVERED: u32 = 0xffff_fff8;
+    pub const FLAG_WAVELOOP: u32 = 0x0001;
+    pub const FLAG_SMOOTH: u32 = 0x0002;
+    pub const FLAG_BEATFIT: u32 = 0x0004;
+    pub(crate) const FLAG_UNCOVERED: u32 = 0xffff_fff8;
+
+    /// Coordinate points, at most one per step of the largest resolution
+    const MAX_COORDINATE_NUM: u32 = 256;
+    /// Overtone harmonics, beyond any the voice editor writes
+    const MAX_OVERTONE_NUM: u32 = 256;
+    /// Envelope head points, beyond any the voice editor writes
+    const MAX_ENVELOPE_NUM: u32 = 256;
+    /// Seconds between envelope points and of the release, as for noise envelopes
+    const LIMIT_ENVE_SECS: i32 = 10;
+    /// Envelope level, the most a byte of the envelope table holds
+    const LIMIT_ENVE_Y: i32 = 0xff;
 
     const DATA_FLAG_WAVE: u32 = 0x0001;
     const DATA_FLAG_ENVELOPE: u32 = 0x0002;
     const DATA_FLAG_UNCOVERED: u32 = 0xffff_fffc;
 
-    fn new<T: Read + Seek>(bytes: &mut T) -> Result<Self> {
+    fn read<T: Read + Seek>(bytes: &mut T, report: &mut Report) -> Result<Self> {
         let basic_key = bytes.read_var_i32()?;
         let volu = bytes.read_var_i32()?;
         let pan = bytes.read_var_i32()?;
-        let tuning = bytes.read_var_f32()?;
-
-        let flags = bytes.read_var_u32()?;
-        assert_eq!(flags & Self::FLAG_UNCOVERED, 0);
+        let tuning = report.finite("tuning", bytes.read_var_f32()?, 1.0)?;
 
+        let flags = report.flags("flags", bytes.read_var_u32()?, Self::FLAG_UNCOVERED)?;
         let data_flags = bytes.read_var_u32()?;
-        assert_eq!(data_flags & Self::DATA_FLAG_UNCOVERED, 0);
+        let data_flags = report.flags("data flags", data_flags, Self::DATA_FLAG_UNCOVERED)?;
 
         // wave
         let wave = if data_flags & Self::DATA_FLAG_WAVE != 0 {
-            let wave_type = VoiceWaveType::from_i32(bytes.read_var_i32()?).unwrap();
+            let wave_type = VoiceWaveType::from_i32(bytes.read_var_i32()?)
+                .ok_or(ErrorKind::InvalidFormat("voice wave type"))?;
             match wave_type {
                 VoiceWaveType::Coodinate => {
-                    let num = bytes.read_var_u32()?;
+                    let num = bytes.read_count(Self::MAX_COORDINATE_NUM)?;
                     let reso = bytes.read_var_i32()?;
-                    let mut points = Vec::with_capacity(num as usize);
+                    let mut points = Vec::with_capacity(num);
                     for _ in 0..num {
                         points.push(Point {
-                            x: i32::from(bytes.read_i8()?),
+                            x: i32::from(bytes.read_u8()?),
                             y: i32::from(bytes.read_i8()?),
                         });
                     }
-                    Some(VoiceWave::Coodinate { points, reso })
+                    // pxtone's Slim drops units of fewer points, which draw no wave
+                    if num > 1 {
+                        Some(VoiceWave::Coodinate { points, reso })
+                    } else {
+                        None
+                    }
                 }
                 VoiceWaveType::Overtone => {
-                    let num = bytes.read_var_u32()?;
-                    let mut points = Vec::with_capacity(num as usize);
+                    let num = bytes.read_count(Self::MAX_OVERTONE_NUM)?;
+                    let mut points = Vec::with_capacity(num);
                     for _ in 0..num {
                         points.push(Point {
                             x: bytes.read_var_i32()?,
@@ -289,7 +827,7 @@ impl VoiceUnit {
                     }
                     Some(VoiceWave::Overtone { points })
                 }
-                _ => unreachable!(),
+                _ => return Err(ErrorKind::InvalidFormat("voice wave type").into()),
             }
         } else {
             None
@@ -297,7 +835,7 @@ impl VoiceUnit {
 
         // envelope
         let enve = if data_flags & Self::DATA_FLAG_ENVELOPE != 0 {
-            Some(VoiceEnvelope::new(bytes)?)
+            Some(Self::read_envelope(bytes, report)?)
         } else {
             None
         };
@@ -310,180 +848,630 @@ impl VoiceUnit {
             flags,
             wave,
             enve,
+            span: Span::default(),
         })
     }
-}
-
-#[derive(FromPrimitive)]
-enum VoiceWaveType {
-    Coodinate,
-    Overtone,
-    Noise,
-    Sampling,
-    OggVorbis,
-}
-
-enum VoiceWave {
-    Coodinate { points: Vec<Point>, reso: i32 },
-    Overtone { points: Vec<Point> },
-}
 
-struct VoiceEnvelope {
-    points: Vec<Point>,
-    fps: i32,
-}
-
-impl VoiceEnvelope {
-    fn new<T: Read + Seek>(bytes: &mut T) -> Result<Self> {
-        let fps = bytes.read_var_i32()?;
-        let head_num = bytes.read_var_u32()?;
-        let body_num = bytes.read_var_u32()?; // 0
-        let tail_num = bytes.read_var_u32()?; // 1
-        assert_eq!(body_num, 0);
-        assert_eq!(tail_num, 1);
-
-        let num = head_num + body_num + tail_num;
-        let mut points = Vec::with_capacity(num as usize);
-        for _ in 0..num {
-            points.push(Point {
-                x: bytes.read_var_i32()?,
-                y: bytes.read_var_i32()?,
-            });
+    /// Write the unit; envelope sustains and curves, which the format lacks, are left out
+    fn write<W: Write>(&self, bytes: &mut W) -> Result<()> {
+        bytes.write_var_i32(self.basic_key)?;
+        bytes.write_var_i32(self.volu)?;
+        bytes.write_var_i32(self.pan)?;
+        bytes.write_var_f32(self.tuning)?;
+        bytes.write_var_u32(self.flags)?;
+
+        let mut data_flags = 0;
+        if self.wave.is_some() {
+            data_flags |= Self::DATA_FLAG_WAVE;
         }
-        Ok(Self { points, fps })
-    }
-}
-
-struct Oscillator {
-    points: Vec<Point>,
-    point_reso: i32,
-    volu: u32,
-    smp_num: u32,
-}
-
-impl Oscillator {
-    fn get_overtone(&self, index: i32) -> f64 {
-        let work = self.points.iter().fold(0.0, |acc, point| {
-            let sss = 2.0 * f64::consts::PI * f64::from(point.x) * f64::from(index)
-                / f64::from(self.smp_num);
-            acc + sss.sin() * f64::from(point.y) / f64::from(point.x) / 128.0
-        });
-        work * f64::from(self.volu) / 128.0
-    }
-
-    fn get_coodinate(&self, index: i32) -> f64 {
-        let i = self.point_reso * index / self.smp_num as i32;
-        let current = self.points.iter().position(|point| point.x <= i);
-
-        let x1;
-        let y1;
-        let x2;
-        let y2;
-
-        match current {
-            Some(0) => {
-                let first = self.points.first().unwrap();
-                x1 = first.x;
-                y1 = first.y;
-                x2 = first.x;
-                y2 = first.y;
-            }
-            Some(c) => {
-                let first = &self.points[c - 1];
-                let second = &self.points[c];
-                x1 = first.x;
-                y1 = first.y;
-                x2 = second.x;
-                y2 = second.y;
+        if self.enve.is_some() {
+            data_flags |= Self::DATA_FLAG_ENVELOPE;
+        }
+        bytes.write_var_u32(data_flags)?;
+
+        match &self.wave {
+            Some(VoiceWave::Coodinate { points, reso }) => {
+                bytes.write_var_i32(VoiceWaveType::Coodinate as i32)?;
+                bytes.write_var_u32(points.len() as u32)?;
+                bytes.write_var_i32(*reso)?;
+                for point in points {
+                    bytes.write_u8(point.x as u8)?;
+                    bytes.write_i8(point.y as i8)?;
+                }
             }
-            None => {
-                let first = self.points.first().unwrap();
-                let last = self.points.last().unwrap();
-                x1 = last.x;
-                y1 = last.y;
-                x2 = self.point_reso;
-                y2 = first.y;
+            Some(VoiceWave::Overtone { points }) => {
+                bytes.write_var_i32(VoiceWaveType::Overtone as i32)?;
+                bytes.write_var_u32(points.len() as u32)?;
+                for point in points {
+                    bytes.write_var_i32(point.x)?;
+                    bytes.write_var_i32(point.y)?;
+                }
             }
+            None => {}
         }
 
-        let work = match i - x1 {
-            0 => f64::from(y1) / 128.0,
-            n => {
-                let w = x2 - x1;
-                let h = y2 - y1;
-                f64::from(y1) + f64::from(h) * f64::from(n) / f64::from(w) / 128.0
+        if let Some(enve) = &self.enve {
+            bytes.write_var_u32(enve.fps())?;
+            bytes.write_var_u32(enve.points().len() as u32)?;
+            bytes.write_var_u32(0)?;
+            bytes.write_var_u32(1)?;
+            for &(x, y) in enve.points() {
+                bytes.write_var_i32(x)?;
+                bytes.write_var_i32(y)?;
             }
-        };
-        work * f64::from(self.volu) / 128.0
+            bytes.write_var_i32(enve.release())?;
+            bytes.write_var_i32(0)?;
+        }
+        Ok(())
     }
-}
 
-struct Point {
-    x: i32,
-    y: i32,
-}
+    pub fn basic_key(&self) -> i32 {
+        self.basic_key
+    }
 
-struct Frequency {}
+    pub fn volume(&self) -> i32 {
+        self.volu
+    }
 
-impl Frequency {
-    fn get(key: i32) -> f32 {
-        let i = ((key + 0x6000) * (FREQUENCY_PER_KEY as i32) / 0x100)
-            .max(0)
-            .min((FREQUENCY_TABLE_SIZE as i32) - 1) as usize;
-        FREQUENCY_TABLE[i]
+    pub fn pan(&self) -> i32 {
+        self.pan
     }
 
-    fn get2(key: i32) -> f32 {
-        let i = (key >> 4).max(0).min((FREQUENCY_TABLE_SIZE as i32) - 1) as usize;
-        FREQUENCY_TABLE[i]
+    pub fn tuning(&self) -> f32 {
+        self.tuning
     }
-}
 
-pub(crate) struct Pcm {
-    fmt: PcmWaveFormat,
-    smp: Vec<u8>,
-}
+    pub fn flags(&self) -> u32 {
+        self.flags
+    }
 
-pub trait Sample {
-    fn from_u8(bits: u8) -> Self;
-    fn from_i16(bits: i16) -> Self;
-}
+    pub fn set_basic_key(&mut self, basic_key: i32) {
+        self.basic_key = basic_key;
+    }
 
-impl Sample for u8 {
-    #[inline]
-    fn from_u8(bits: u8) -> Self {
-        bits
+    /// Volume in 1/128 units
+    pub fn set_volume(&mut self, volume: i32) {
+        self.volu = volume;
     }
 
-    #[inline]
-    fn from_i16(bits: i16) -> Self {
-        ((bits >> 8) as i8) as u8 ^ 0x80
+    /// Pan from 0 (left) to 128 (right)
+    pub fn set_pan(&mut self, pan: i32) {
+        self.pan = pan.clamp(0, 128);
     }
-}
 
-impl Sample for i8 {
-    #[inline]
-    fn from_u8(bits: u8) -> Self {
-        (bits ^ 0x80) as i8
+    pub fn set_tuning(&mut self, tuning: f32) {
+        self.tuning = tuning;
     }
 
-    #[inline]
-    fn from_i16(bits: i16) -> Self {
-        (bits >> 8) as i8
+    pub fn set_flags(&mut self, flags: u32) {
+        self.flags = flags & !Self::FLAG_UNCOVERED;
     }
-}
 
-impl Sample for u16 {
-    #[inline]
-    fn from_u8(bits: u8) -> Self {
-        u16::from(bits) << 8
+    pub fn wave(&self) -> Option<&VoiceWave> {
+        self.wave.as_ref()
     }
 
-    #[inline]
-    fn from_i16(bits: i16) -> Self {
-        (bits as u16) ^ 0x8000
+    pub fn wave_mut(&mut self) -> Option<&mut VoiceWave> {
+        self.wave.as_mut()
     }
-}
+
+    pub fn set_wave(&mut self, wave: Option<VoiceWave>) {
+        self.wave = wave;
+    }
+
+    /// Wave drawn as (x, y) points over `reso` steps per cycle, joined by straight lines
+    pub fn set_coordinate_wave(&mut self, points: &[(u8, i8)], reso: i32) {
+        assert!(reso > 0);
+        let points = points
+            .iter()
+            .map(|&(x, y)| Point {
+                x: i32::from(x),
+                y: i32::from(y),
+            })
+            .collect();
+        self.wave = Some(VoiceWave::Coodinate { points, reso });
+    }
+
+    /// Wave summed from (harmonic, amplitude) sines
+    pub fn set_overtone_wave(&mut self, points: &[(i32, i32)]) {
+        assert!(points.iter().all(|&(x, _)| x > 0));
+        let points = points.iter().map(|&(x, y)| Point { x, y }).collect();
+        self.wave = Some(VoiceWave::Overtone { points });
+    }
+
+    /// One cycle of the wave as 44.1kHz stereo frames
+    pub(crate) fn wave_frames(&self) -> Vec<[i16; 2]> {
+        let mut pan_volume = [64, 64];
+        if self.pan > 64 {
+            pan_volume[0] = 128 - self.pan;
+        }
+        if self.pan < 64 {
+            pan_volume[1] = self.pan;
+        }
+
+        let wave = match &self.wave {
+            Some(wave) => wave,
+            None => return vec![[0; 2]; VOICE_SMP_BODY as usize],
+        };
+        Wavetable::new(wave, self.volu as u32)
+            .levels(VOICE_SMP_BODY as usize)
+            .map(|osc| {
+                let mut frame = [0; 2];
+                for (c, smp) in frame.iter_mut().enumerate() {
+                    let work = (osc * f64::from(pan_volume[c]) / 64.0).clamp(-1.0, 1.0);
+                    *smp = (work * 32767.0) as i16;
+                }
+                frame
+            })
+            .collect()
+    }
+
+    /// Head points and the release point
+    fn read_envelope<T: Read>(bytes: &mut T, report: &mut Report) -> Result<Envelope> {
+        let fps = bytes.read_var_u32()?;
+        let head_num = bytes.read_count(Self::MAX_ENVELOPE_NUM)?;
+        let body_num = bytes.read_var_u32()?; // 0
+        let tail_num = bytes.read_var_u32()?; // 1
+        ensure(fps != 0, "voice envelope rate")?;
+        ensure(body_num == 0 && tail_num == 1, "voice envelope sections")?;
+
+        let limit_x = (fps.min(i32::MAX as u32) as i32).saturating_mul(Self::LIMIT_ENVE_SECS);
+        let mut points = Vec::with_capacity(head_num);
+        for _ in 0..head_num {
+            let x = bytes.read_var_i32()?;
+            let y = bytes.read_var_i32()?;
+            points.push((
+                report.clamp("envelope time", x, 0, limit_x)?,
+                report.clamp("envelope level", y, 0, Self::LIMIT_ENVE_Y)?,
+            ));
+        }
+        let release = report.clamp("envelope release", bytes.read_var_i32()?, 0, limit_x)?;
+        let _release_volume = bytes.read_var_i32()?;
+        Ok(Envelope::new(fps, points, release))
+    }
+
+    /// Envelope volumes per sample and release length in samples at `sps`
+    pub(crate) fn envelope_table(&self, sps: u32) -> (Vec<u8>, i32) {
+        let enve = match &self.enve {
+            Some(enve) => enve,
+            None => return (Vec::new(), 0),
+        };
+        let release = enve.release_samples(sps);
+        if enve.interpolation() != Interpolation::Linear || enve.sustain().is_some() {
+            let env = enve
+                .head_levels(sps, 1.0)
+                .into_iter()
+                .map(|level| level.clamp(0.0, 255.0) as u8)
+                .collect();
+            return (env, release);
+        }
+
+        // integer ramps of pxtone
+        let sps = f64::from(sps);
+        let fps = f64::from(enve.fps());
+        let head = enve.points();
+
+        let mut env = Vec::new();
+        if !head.is_empty() {
+            let size = head.iter().map(|&(x, _)| i64::from(x)).sum::<i64>();
+            let env_size = ((size as f64 * sps / fps) as usize).max(1);
+
+            // convert points
+            let mut offset = 0;
+            let mut points = Vec::with_capacity(head.len());
+            for (e, &(x, y)) in head.iter().enumerate() {
+                if e == 0 || x != 0 || y != 0 {
+                    offset += (f64::from(x) * sps / fps) as i32;
+                    points.push(Point { x: offset, y });
+                }
+            }
+
+            let mut e = 0;
+            let mut start = Point { x: 0, y: 0 };
+            env.reserve(env_size);
+            for s in 0..env_size as i32 {
+                while e < points.len() && s >= points[e].x {
+                    start = Point {
+                        x: points[e].x,
+                        y: points[e].y,
+                    };
+                    e += 1;
+                }
+                let volume = if e < points.len() {
+                    start.y + (points[e].y - start.y) * (s - start.x) / (points[e].x - start.x)
+                } else {
+                    start.y
+                };
+                env.push(volume as u8);
+            }
+        }
+
+        (env, release)
+    }
+
+    /// Volume over `fps` frames and release of each note
+    pub fn envelope(&self) -> Option<&Envelope> {
+        self.enve.as_ref()
+    }
+
+    pub fn envelope_mut(&mut self) -> Option<&mut Envelope> {
+        self.enve.as_mut()
+    }
+
+    pub fn set_envelope(&mut self, enve: Option<Envelope>) {
+        self.enve = enve;
+    }
+
+    /// Bytes of the file this was read from
+    #[cfg(feature = "spans")]
+    pub fn span(&self) -> Option<Range<u64>> {
+        self.span.range()
+    }
+}
+
+#[cfg(feature = "spans")]
+impl Spanned for VoiceUnit {
+    fn span_mut(&mut self) -> &mut Span {
+        &mut self.span
+    }
+}
+
+impl Default for VoiceUnit {
+    fn default() -> Self {
+        Self {
+            basic_key: EventKind::DEFAULT_BASICKEY,
+            volu: 128,
+            pan: 64,
+            tuning: EventKind::DEFAULT_TUNING,
+            flags: Self::FLAG_SMOOTH,
+            wave: None,
+            enve: None,
+            span: Span::default(),
+        }
+    }
+}
+
+#[derive(FromPrimitive)]
+enum VoiceWaveType {
+    Coodinate,
+    Overtone,
+    Noise,
+    Sampling,
+    OggVorbis,
+}
+
+/// Samples in one cycle of a voice wave at 44.1kHz
+const VOICE_SMP_BODY: u32 = 400;
+
+/// Cycle of a voice unit, drawn as points or summed from harmonics
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VoiceWave {
+    /// Points joined by straight lines over `reso` steps
+    Coodinate { points: Vec<Point>, reso: i32 },
+    /// (harmonic, amplitude) sines
+    Overtone { points: Vec<Point> },
+}
+
+impl VoiceWave {
+    /// One cycle in `resolution` samples at full volume, where 1.0 is full scale
+    pub fn render_cycle(&self, resolution: usize) -> Vec<f32> {
+        assert!(resolution > 0);
+        Wavetable::new(self, 128).sample_table(resolution)
+    }
+}
+
+/// Voice wave at a volume, sampled over a cycle of any length as voices are readied
+#[derive(Clone, Copy, Debug)]
+pub struct Wavetable<'a> {
+    wave: &'a VoiceWave,
+    volu: u32,
+}
+
+impl<'a> Wavetable<'a> {
+    /// `wave` at `volu`, which is 128 at full volume
+    pub fn new(wave: &'a VoiceWave, volu: u32) -> Self {
+        Self { wave, volu }
+    }
+
+    /// One cycle in `len` samples, where 1.0 is full scale
+    pub fn sample_table(&self, len: usize) -> Vec<f32> {
+        self.levels(len).map(|level| level as f32).collect()
+    }
+
+    /// `sample_table` before rounding to f32
+    fn levels(&self, len: usize) -> impl Iterator<Item = f64> + 'a {
+        let (points, point_reso) = match self.wave {
+            VoiceWave::Coodinate { points, reso } => (&points[..], *reso),
+            VoiceWave::Overtone { points } => (&points[..], 0),
+        };
+        let osci = Oscillator {
+            points,
+            point_reso,
+            volu: self.volu,
+            smp_num: len as u32,
+        };
+        let wave = self.wave;
+        (0..len as i32).map(move |s| match wave {
+            VoiceWave::Coodinate { .. } => osci.get_coodinate(s),
+            VoiceWave::Overtone { .. } => osci.get_overtone(s),
+        })
+    }
+}
+
+struct Oscillator<'a> {
+    points: &'a [Point],
+    point_reso: i32,
+    volu: u32,
+    smp_num: u32,
+}
+
+impl Oscillator<'_> {
+    fn get_overtone(&self, index: i32) -> f64 {
+        let work = self.points.iter().fold(0.0, |acc, point| {
+            let sss = 2.0 * f64::consts::PI * f64::from(point.x) * f64::from(index)
+                / f64::from(self.smp_num);
+            acc + sss.sin() * f64::from(point.y) / f64::from(point.x) / 128.0
+        });
+        work * f64::from(self.volu) / 128.0
+    }
+
+    fn get_coodinate(&self, index: i32) -> f64 {
+        let (first, last) = match (self.points.first(), self.points.last()) {
+            (Some(first), Some(last)) => (first, last),
+            _ => return 0.0,
+        };
+        let i = i64::from(self.point_reso) * i64::from(index) / i64::from(self.smp_num);
+        let i = i as i32;
+        let current = self.points.iter().position(|point| point.x > i);
+
+        let (x1, y1, x2, y2) = match current {
+            Some(0) => (first.x, first.y, first.x, first.y),
+            Some(c) => {
+                let first = &self.points[c - 1];
+                let second = &self.points[c];
+                (first.x, first.y, second.x, second.y)
+            }
+            None => (last.x, last.y, self.point_reso, first.y),
+        };
+
+        let work = if i == x1 {
+            f64::from(y1)
+        } else {
+            let n = f64::from(i) - f64::from(x1);
+            f64::from(y1) + f64::from(y2 - y1) * n / (f64::from(x2) - f64::from(x1))
+        };
+        work * f64::from(self.volu) / 128.0 / 128.0
+    }
+}
+
+/// Wave point, or harmonic and amplitude of an overtone
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct Point {
+    pub x: i32,
+    pub y: i32,
+}
+
+/// Sampled instrument or rendered audio
+#[derive(Clone, PartialEq, Eq)]
+pub struct Pcm {
+    fmt: PcmWaveFormat,
+    smp: Vec<u8>,
+    loop_start: u32,
+}
+
+/// Sample encoding of PCM data
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum OutputFormat {
+    U8,
+    I16,
+    I24,
+    F32,
+}
+
+impl OutputFormat {
+    const FORMAT_TAG_PCM: u16 = 1;
+    const FORMAT_TAG_IEEE_FLOAT: u16 = 3;
+    /// Tag of a `fmt ` chunk whose real tag is in the sub-format GUID
+    const FORMAT_TAG_EXTENSIBLE: u16 = 0xfffe;
+    /// Sub-format GUID after its leading tag, shared by every `KSDATAFORMAT_SUBTYPE_*`
+    const SUBFORMAT_GUID_TAIL: [u8; 14] = [
+        0x00, 0x00, 0x00, 0x00, 0x10, 0x00, 0x80, 0x00, 0x00, 0xaa, 0x00, 0x38, 0x9b, 0x71,
+    ];
+
+    /// Format of a WAV format tag and bits per sample
+    pub fn from_tag(tag: u16, bps: u16) -> Result<Self> {
+        match (tag, bps) {
+            (Self::FORMAT_TAG_PCM, 8) => Ok(OutputFormat::U8),
+            (Self::FORMAT_TAG_PCM, 16) => Ok(OutputFormat::I16),
+            (Self::FORMAT_TAG_PCM, 24) => Ok(OutputFormat::I24),
+            (Self::FORMAT_TAG_IEEE_FLOAT, 32) => Ok(OutputFormat::F32),
+            (Self::FORMAT_TAG_PCM, _) | (Self::FORMAT_TAG_IEEE_FLOAT, _) => {
+                Err(ErrorKind::UnsupportedBitDepth(bps).into())
+            }
+            _ => Err(ErrorKind::UnsupportedFormatTag(tag).into()),
+        }
+    }
+
+    fn format_tag(self) -> u16 {
+        match self {
+            OutputFormat::F32 => Self::FORMAT_TAG_IEEE_FLOAT,
+            _ => Self::FORMAT_TAG_PCM,
+        }
+    }
+
+    pub fn bits_per_sample(self) -> u16 {
+        match self {
+            OutputFormat::U8 => 8,
+            OutputFormat::I16 => 16,
+            OutputFormat::I24 => 24,
+            OutputFormat::F32 => 32,
+        }
+    }
+
+    pub fn bytes_per_sample(self) -> usize {
+        (self.bits_per_sample() / 8) as usize
+    }
+
+    /// Quantize a mixed sample in 16-bit scale and write it
+    pub(crate) fn write_sample<T: Write>(self, writer: &mut T, sample: f64) -> Result<()> {
+        const SAMPLING_TOP: f64 = i16::MAX as f64;
+        const NORMALIZE: f64 = (SAMPLING_TOP + 1.0) / SAMPLING_TOP;
+        let sample = if sample < 0.0 { sample * NORMALIZE } else { sample };
+
+        match self {
+            OutputFormat::U8 => {
+                let bits = (sample as i32).clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+                writer.write_u8(<u8 as Sample>::from_i16(bits))?;
+            }
+            OutputFormat::I16 => {
+                let bits = (sample as i32).clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+                writer.write_i16::<LittleEndian>(bits)?;
+            }
+            OutputFormat::I24 => {
+                const I24_MIN: i32 = -0x80_0000;
+                const I24_MAX: i32 = 0x7f_ffff;
+                let bits = ((sample * 256.0) as i32).clamp(I24_MIN, I24_MAX);
+                writer.write_i24::<LittleEndian>(bits)?;
+            }
+            OutputFormat::F32 => {
+                let bits = (sample / (SAMPLING_TOP + 1.0)).clamp(-1.0, 1.0) as f32;
+                writer.write_f32::<LittleEndian>(bits)?;
+            }
+        }
+        Ok(())
+    }
+
+    /// Write a 16-bit sample as it is, widened or narrowed to the format
+    pub(crate) fn write_i16<T: Write>(self, writer: &mut T, bits: i16) -> Result<()> {
+        match self {
+            OutputFormat::U8 => writer.write_u8(<u8 as Sample>::from_i16(bits))?,
+            OutputFormat::I16 => writer.write_i16::<LittleEndian>(bits)?,
+            OutputFormat::I24 => writer.write_i24::<LittleEndian>(i32::from(bits) << 8)?,
+            OutputFormat::F32 => writer.write_f32::<LittleEndian>(<f32 as Sample>::from_i16(bits))?,
+        }
+        Ok(())
+    }
+
+    /// Append a sample where 1.0 is full scale, the inverse of reading it as `f32`
+    fn write_level(self, bytes: &mut Vec<u8>, level: f64) {
+        // negative levels reach one step further, as `Sample` reads them
+        let scale = |top: f64| {
+            let top = if level < 0.0 { top + 1.0 } else { top };
+            (level * top).round().clamp(-top, top)
+        };
+        match self {
+            OutputFormat::U8 => bytes.push(scale(f64::from(i8::MAX)) as i8 as u8 ^ 0x80),
+            OutputFormat::I16 => {
+                let bits = scale(f64::from(i16::MAX)) as i16;
+                bytes.extend_from_slice(&bits.to_le_bytes());
+            }
+            OutputFormat::I24 => {
+                let bits = scale(f64::from(0x7f_ffff)) as i32;
+                bytes.extend_from_slice(&bits.to_le_bytes()[..3]);
+            }
+            OutputFormat::F32 => bytes.extend_from_slice(&(level as f32).to_le_bytes()),
+        }
+    }
+
+    /// Level `write_sample` stores for `sample` in 8 or 16 bits, back in the input scale
+    pub(crate) fn quantized(self, sample: f64) -> f64 {
+        const SAMPLING_TOP: f64 = i16::MAX as f64;
+        let mut bytes = Vec::with_capacity(2);
+        self.write_sample(&mut bytes, sample).unwrap();
+        let level = f64::from(self.read_sample::<_, i16>(&mut &bytes[..]).unwrap());
+        if level < 0.0 {
+            level * SAMPLING_TOP / (SAMPLING_TOP + 1.0)
+        } else {
+            level
+        }
+    }
+
+    fn read_sample<T: Read, S: Sample>(self, reader: &mut T) -> Result<S> {
+        Ok(match self {
+            OutputFormat::U8 => S::from_u8(reader.read_u8()?),
+            OutputFormat::I16 => S::from_i16(reader.read_i16::<LittleEndian>()?),
+            OutputFormat::I24 => S::from_i24(reader.read_i24::<LittleEndian>()?),
+            OutputFormat::F32 => S::from_f32(reader.read_f32::<LittleEndian>()?),
+        })
+    }
+}
+
+pub trait Sample {
+    fn from_u8(bits: u8) -> Self;
+    fn from_i16(bits: i16) -> Self;
+    fn from_i24(bits: i32) -> Self;
+    fn from_f32(bits: f32) -> Self;
+}
+
+impl Sample for u8 {
+    #[inline]
+    fn from_u8(bits: u8) -> Self {
+        bits
+    }
+
+    #[inline]
+    fn from_i16(bits: i16) -> Self {
+        ((bits >> 8) as i8) as u8 ^ 0x80
+    }
+
+    #[inline]
+    fn from_i24(bits: i32) -> Self {
+        ((bits >> 16) as i8) as u8 ^ 0x80
+    }
+
+    #[inline]
+    fn from_f32(bits: f32) -> Self {
+        <i8 as Sample>::from_f32(bits) as u8 ^ 0x80
+    }
+}
+
+impl Sample for i8 {
+    #[inline]
+    fn from_u8(bits: u8) -> Self {
+        (bits ^ 0x80) as i8
+    }
+
+    #[inline]
+    fn from_i16(bits: i16) -> Self {
+        (bits >> 8) as i8
+    }
+
+    #[inline]
+    fn from_i24(bits: i32) -> Self {
+        (bits >> 16) as i8
+    }
+
+    #[inline]
+    #[allow(non_upper_case_globals)]
+    fn from_f32(bits: f32) -> Self {
+        const i8_min_abs: f32 = -(i8::MIN as f32);
+        const i8_max_abs: f32 = i8::MAX as f32;
+        let bits = bits.clamp(-1.0, 1.0);
+        if bits < 0.0 { (bits * i8_min_abs) as i8 } else { (bits * i8_max_abs) as i8 }
+    }
+}
+
+impl Sample for u16 {
+    #[inline]
+    fn from_u8(bits: u8) -> Self {
+        u16::from(bits) << 8
+    }
+
+    #[inline]
+    fn from_i16(bits: i16) -> Self {
+        (bits as u16) ^ 0x8000
+    }
+
+    #[inline]
+    fn from_i24(bits: i32) -> Self {
+        ((bits >> 8) as u16) ^ 0x8000
+    }
+
+    #[inline]
+    fn from_f32(bits: f32) -> Self {
+        (<i16 as Sample>::from_f32(bits) as u16) ^ 0x8000
+    }
+}
 
 impl Sample for i16 {
     #[inline]
@@ -495,14 +1483,28 @@ impl Sample for i16 {
     fn from_i16(bits: i16) -> Self {
         bits
     }
+
+    #[inline]
+    fn from_i24(bits: i32) -> Self {
+        (bits >> 8) as i16
+    }
+
+    #[inline]
+    #[allow(non_upper_case_globals)]
+    fn from_f32(bits: f32) -> Self {
+        const i16_min_abs: f32 = -(i16::MIN as f32);
+        const i16_max_abs: f32 = i16::MAX as f32;
+        let bits = bits.clamp(-1.0, 1.0);
+        if bits < 0.0 { (bits * i16_min_abs) as i16 } else { (bits * i16_max_abs) as i16 }
+    }
 }
 
 impl Sample for f32 {
     #[inline]
     #[allow(non_upper_case_globals)]
     fn from_u8(bits: u8) -> Self {
-        const i8_min_abs: f32 = -(i8::min_value() as f32);
-        const i8_max_abs: f32 = i8::max_value() as f32;
+        const i8_min_abs: f32 = -(i8::MIN as f32);
+        const i8_max_abs: f32 = i8::MAX as f32;
         let float_i8 = f32::from((bits ^ 0x80) as i8);
         if float_i8 < 0.0 { float_i8 / i8_min_abs } else { float_i8 / i8_max_abs }
     }
@@ -510,24 +1512,59 @@ impl Sample for f32 {
     #[inline]
     #[allow(non_upper_case_globals)]
     fn from_i16(bits: i16) -> Self {
-        const i16_min_abs: f32 = -(i16::min_value() as f32);
-        const i16_max_abs: f32 = i16::max_value() as f32;
+        const i16_min_abs: f32 = -(i16::MIN as f32);
+        const i16_max_abs: f32 = i16::MAX as f32;
         let float_i16 = f32::from(bits);
         if float_i16 < 0.0 { float_i16 / i16_min_abs } else { float_i16 / i16_max_abs }
     }
+
+    #[inline]
+    #[allow(non_upper_case_globals)]
+    fn from_i24(bits: i32) -> Self {
+        const i24_min_abs: f32 = 0x80_0000 as f32;
+        const i24_max_abs: f32 = 0x7f_ffff as f32;
+        let float_i24 = bits as f32;
+        if float_i24 < 0.0 { float_i24 / i24_min_abs } else { float_i24 / i24_max_abs }
+    }
+
+    #[inline]
+    fn from_f32(bits: f32) -> Self {
+        bits
+    }
 }
 
 impl Pcm {
     const RIFF_CODE: &'static [u8] = b"RIFF";
     const WAVE_FMT_CODE: &'static [u8] = b"WAVEfmt ";
     const DATA_CODE: &'static [u8] = b"data";
+    const SMPL_CODE: &'static [u8] = b"smpl";
+    pub(crate) const WAV_HEADER_SIZE: usize = 44;
+    pub(crate) const SMPL_CHUNK_SIZE: u32 = 8 + 60;
 
-    fn new<T: Read + Seek>(mut bytes: T) -> Result<Self> {
+    /// Read a WAV file; more than two channels are mixed down to stereo
+    pub fn new<T: Read + Seek>(bytes: T) -> Result<Self> {
+        Self::parse(bytes, &ParseOptions::default())
+    }
+
+    pub fn parse<T: Read + Seek>(mut bytes: T, options: &ParseOptions) -> Result<Self> {
+        let result = Self::read(&mut bytes, &mut Report::with_options(options));
+        at_position(&mut bytes, result)
+    }
+
+    /// Read a WAV file, reporting channels it mixes down
+    pub fn validate<T: Read + Seek>(mut bytes: T) -> Result<Report> {
+        let mut report = Report::default();
+        let result = Self::read(&mut bytes, &mut report);
+        at_position(&mut bytes, result)?;
+        Ok(report)
+    }
+
+    fn read<T: Read + Seek>(bytes: &mut T, report: &mut Report) -> Result<Self> {
         // riff
         {
             let mut riff = [0; 4];
             bytes.read_exact(&mut riff)?;
-            assert_eq!(riff, Self::RIFF_CODE);
+            ensure(riff == Self::RIFF_CODE, "RIFF code")?;
         }
         bytes.seek(SeekFrom::Current(4))?;
 
@@ -535,12 +1572,13 @@ impl Pcm {
         {
             let mut wavefmt = [0; 8];
             bytes.read_exact(&mut wavefmt)?;
-            assert_eq!(wavefmt, Self::WAVE_FMT_CODE);
+            ensure(wavefmt == Self::WAVE_FMT_CODE, "WAVE code")?;
         }
         let size = bytes.read_u32::<LittleEndian>()?;
-        let fmt = PcmWaveFormat::read_chunk(&mut bytes, i64::from(size))?;
+        let (mut fmt, channel_mask) = PcmWaveFormat::read_chunk(bytes, i64::from(size))?;
 
         // data chunk (skip unnecessary chunks)
+        let mut loop_start = None;
         loop {
             let mut data = [0; 4];
             bytes.read_exact(&mut data)?;
@@ -548,90 +1586,743 @@ impl Pcm {
                 break;
             }
             let size = bytes.read_u32::<LittleEndian>()?;
-            bytes.seek(SeekFrom::Current(i64::from(size)))?;
+            if data == Self::SMPL_CODE {
+                loop_start = Self::read_smpl_chunk(bytes, size)?;
+            } else {
+                bytes.seek(SeekFrom::Current(i64::from(size) + i64::from(size % 2)))?;
+            }
         }
         let size = bytes.read_u32::<LittleEndian>()?;
-        let mut smp = Vec::with_capacity(size as usize);
+        let mut smp = Vec::with_capacity(capacity(size));
         bytes.take(u64::from(size)).read_to_end(&mut smp)?;
+        Self::drop_partial_frame(&mut smp, fmt.block_size());
 
-        Ok(Self { fmt, smp })
+        // loop written after the data; a broken tail is ignored
+        if loop_start.is_none() {
+            loop_start = Self::read_tail_loop_start(bytes, size).unwrap_or(None);
+        }
+
+        if fmt.ch > 2 {
+            report.downmixed("channels", fmt.ch)?;
+            smp = speakers::downmix(&smp, fmt.ch, channel_mask, fmt.format);
+            fmt.ch = 2;
+        }
+
+        let mut pcm = Self {
+            fmt,
+            smp,
+            loop_start: 0,
+        };
+        pcm.set_loop_start(loop_start.unwrap_or(0));
+        Ok(pcm)
     }
 
-    pub fn into_bytes(mut self) -> Vec<u8> {
-        let size = 44 + self.smp.len();
-        let mut bytes = Vec::with_capacity(size);
+    fn read_tail_loop_start<T: Read + Seek>(bytes: &mut T, data_size: u32) -> Result<Option<u32>> {
+        bytes.seek(SeekFrom::Current(i64::from(data_size % 2)))?;
+        let mut code = [0; 4];
+        while bytes.read(&mut code[..1])? == 1 {
+            bytes.read_exact(&mut code[1..])?;
+            let size = bytes.read_u32::<LittleEndian>()?;
+            if code == Self::SMPL_CODE {
+                return Self::read_smpl_chunk(bytes, size);
+            }
+            bytes.seek(SeekFrom::Current(i64::from(size) + i64::from(size % 2)))?;
+        }
+        Ok(None)
+    }
+
+    /// Start of the first loop in a `smpl` chunk body of `size` bytes
+    fn read_smpl_chunk<T: Read + Seek>(bytes: &mut T, size: u32) -> Result<Option<u32>> {
+        let mut body = Vec::with_capacity(capacity(size));
+        bytes.take(u64::from(size)).read_to_end(&mut body)?;
+        bytes.seek(SeekFrom::Current(i64::from(size % 2)))?;
+
+        let mut body = &body[..];
+        // manufacturer, product, sample period, unity note, pitch fraction, SMPTE format and offset
+        let mut header = [0; 28];
+        body.read_exact(&mut header)?;
+        let loop_num = body.read_u32::<LittleEndian>()?;
+        let _sampler_data = body.read_u32::<LittleEndian>()?;
+        if loop_num == 0 {
+            return Ok(None);
+        }
+        // cue point and loop type
+        let mut cue = [0; 8];
+        body.read_exact(&mut cue)?;
+        Ok(Some(body.read_u32::<LittleEndian>()?))
+    }
+
+    /// Raw interleaved samples as embedded in project files (mono or stereo, 8 or 16-bit)
+    pub fn from_raw(ch: u16, sps: u32, bps: u16, smp: Vec<u8>) -> Result<Self> {
+        if bps != 8 && bps != 16 {
+            return Err(ErrorKind::UnsupportedBitDepth(bps).into());
+        }
+        let format = OutputFormat::from_tag(OutputFormat::FORMAT_TAG_PCM, bps)?;
+        let fmt = PcmWaveFormat::new(ch, sps, format)?;
+        let mut smp = smp;
+        Self::drop_partial_frame(&mut smp, fmt.block_size());
+        Ok(Self {
+            fmt,
+            smp,
+            loop_start: 0,
+        })
+    }
+
+    /// Samples of a project whose woice data was left in place
+    pub(crate) fn set_raw(&mut self, mut smp: Vec<u8>) {
+        Self::drop_partial_frame(&mut smp, self.fmt.block_size());
+        self.smp = smp;
+    }
 
+    /// Cut a trailing incomplete frame, as pxtone only counts whole ones
+    fn drop_partial_frame(smp: &mut Vec<u8>, block_size: u16) {
+        smp.truncate(smp.len() - smp.len() % usize::from(block_size));
+    }
+
+    /// Quantize interleaved samples in 16-bit scale
+    pub(crate) fn from_samples<I: IntoIterator<Item = f64>>(
+        ch: u16,
+        sps: u32,
+        format: OutputFormat,
+        samples: I,
+    ) -> Result<Self> {
+        let fmt = PcmWaveFormat::new(ch, sps, format)?;
+        let mut smp = Vec::new();
+        for sample in samples {
+            format.write_sample(&mut smp, sample)?;
+        }
+        Ok(Self {
+            fmt,
+            smp,
+            loop_start: 0,
+        })
+    }
+
+    pub fn ch(&self) -> u16 {
+        self.fmt.ch
+    }
+
+    pub fn sps(&self) -> u32 {
+        self.fmt.sps
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.fmt.format
+    }
+
+    /// Channels, rate and format together, as `convert` takes them
+    pub fn wave_format(&self) -> PcmWaveFormat {
+        self.fmt
+    }
+
+    /// Number of sample frames
+    pub fn smp_num(&self) -> u32 {
+        (self.smp.len() / self.fmt.block_size() as usize) as u32
+    }
+
+    /// Frame a looping instrument or song jumps back to from the end
+    pub fn loop_start(&self) -> u32 {
+        self.loop_start
+    }
+
+    pub fn set_loop_start(&mut self, loop_start: u32) {
+        self.loop_start = loop_start.min(self.smp_num());
+    }
+
+    pub fn into_bytes(self) -> Vec<u8> {
+        let mut bytes = Cursor::new(Vec::with_capacity(Self::WAV_HEADER_SIZE + self.smp.len()));
+        self.write_wav(&mut bytes).unwrap();
+        bytes.into_inner()
+    }
+
+    /// Write as a WAV file, streaming the samples and filling in the sizes afterwards;
+    /// a loop start is written in a `smpl` chunk after the data
+    pub fn write_wav<W: Write + Seek>(&self, writer: W) -> Result<()> {
+        let PcmWaveFormat { ch, sps, format } = self.fmt;
+        let mut wav = WavWriter::new(writer, ch, sps, format)?;
+        if self.loop_start > 0 {
+            wav.set_loop_start(Some(self.loop_start));
+        }
+        wav.write_raw(&self.smp)?;
+        wav.finish()?;
+        Ok(())
+    }
+
+    pub(crate) fn write_wav_header<W: Write>(
+        writer: &mut W,
+        ch: u16,
+        sps: u32,
+        format: OutputFormat,
+        data_size: u32,
+        tail_size: u32,
+    ) -> Result<()> {
         // riff
-        bytes.write_all(Self::RIFF_CODE).unwrap();
-        bytes.write_u32::<LittleEndian>((size - 8) as u32).unwrap();
+        writer.write_all(Self::RIFF_CODE)?;
+        let riff_size =
+            (Self::WAV_HEADER_SIZE - 8) as u64 + u64::from(data_size) + u64::from(tail_size);
+        let limit = u64::from(u32::MAX);
+        if riff_size > limit {
+            return Err(ErrorKind::SampleOverflow(riff_size, "bytes", limit).into());
+        }
+        writer.write_u32::<LittleEndian>(riff_size as u32)?;
 
         // fmt
-        bytes.write_all(Self::WAVE_FMT_CODE).unwrap();
-        bytes.write_u32::<LittleEndian>(16).unwrap();
-        self.fmt.write_chunk(&mut bytes).unwrap();
+        writer.write_all(Self::WAVE_FMT_CODE)?;
+        writer.write_u32::<LittleEndian>(16)?;
+        PcmWaveFormat { ch, sps, format }.write_chunk(writer)?;
 
         // data
-        bytes.write_all(Self::DATA_CODE).unwrap();
-        bytes.write_u32::<LittleEndian>(self.smp.len() as u32).unwrap();
-        bytes.append(&mut self.smp);
+        writer.write_all(Self::DATA_CODE)?;
+        writer.write_u32::<LittleEndian>(data_size)?;
+        Ok(())
+    }
 
-        bytes
+    /// `smpl` chunk with one forward loop over `loop_start..loop_end` repeating forever
+    pub(crate) fn write_smpl_chunk<W: Write>(
+        writer: &mut W,
+        sps: u32,
+        loop_start: u32,
+        loop_end: u32,
+    ) -> Result<()> {
+        writer.write_all(Self::SMPL_CODE)?;
+        writer.write_u32::<LittleEndian>(Self::SMPL_CHUNK_SIZE - 8)?;
+        writer.write_u32::<LittleEndian>(0)?; // manufacturer
+        writer.write_u32::<LittleEndian>(0)?; // product
+        writer.write_u32::<LittleEndian>(1_000_000_000 / sps)?; // sample period in ns
+        writer.write_u32::<LittleEndian>(60)?; // unity note
+        writer.write_u32::<LittleEndian>(0)?; // pitch fraction
+        writer.write_u32::<LittleEndian>(0)?; // SMPTE format
+        writer.write_u32::<LittleEndian>(0)?; // SMPTE offset
+        writer.write_u32::<LittleEndian>(1)?; // loops
+        writer.write_u32::<LittleEndian>(0)?; // sampler data
+        writer.write_u32::<LittleEndian>(0)?; // cue point
+        writer.write_u32::<LittleEndian>(0)?; // forward
+        writer.write_u32::<LittleEndian>(loop_start)?;
+        writer.write_u32::<LittleEndian>(loop_end - 1)?; // last frame played
+        writer.write_u32::<LittleEndian>(0)?; // fraction
+        writer.write_u32::<LittleEndian>(0)?; // play count, 0 for forever
+        Ok(())
+    }
+
+    /// Frames as left and right samples, read as they are iterated; mono repeats its channel
+    pub fn frames<T: Sample + Copy>(&self) -> impl Iterator<Item = [T; 2]> + '_ {
+        let PcmWaveFormat { ch, format, .. } = self.fmt;
+        self.smp
+            .chunks_exact(self.fmt.block_size() as usize)
+            .map(move |mut frame| {
+                let left = format.read_sample(&mut frame).unwrap();
+                match ch {
+                    1 => [left, left],
+                    _ => [left, format.read_sample(&mut frame).unwrap()],
+                }
+            })
     }
 
     pub fn to_channels<T: Sample>(&self) -> Vec<Vec<T>> {
-        let PcmWaveFormat { ch, bps, .. } = self.fmt;
+        let PcmWaveFormat { ch, format, .. } = self.fmt;
         let mut channels = Vec::with_capacity(ch as usize);
-        let size = self.smp.len() / (ch as usize) / ((bps / 8) as usize);
+        let size = self.smp.len() / (ch as usize) / format.bytes_per_sample();
         for _ in 0..ch {
             channels.push(Vec::with_capacity(size));
         }
 
-        let mut bytes = &self.smp[..];
-        while !bytes.is_empty() {
+        for mut frame in self.smp.chunks_exact(self.fmt.block_size() as usize) {
             for c in channels.iter_mut() {
-                if bps == 8 {
-                    c.push(T::from_u8(bytes.read_u8().unwrap()));
-                } else {
-                    c.push(T::from_i16(bytes.read_i16::<LittleEndian>().unwrap()));
-                }
+                c.push(format.read_sample(&mut frame).unwrap());
             }
         }
 
         channels
     }
+
+    /// Bits per sample and samples as project files embed them, in 8 or 16 bits
+    pub(crate) fn to_raw(&self) -> (u16, Cow<'_, [u8]>) {
+        let format = self.fmt.format;
+        match format {
+            OutputFormat::U8 | OutputFormat::I16 => {
+                (format.bits_per_sample(), Cow::Borrowed(&self.smp))
+            }
+            _ => {
+                let mut smp = Vec::with_capacity(self.smp.len() / format.bytes_per_sample() * 2);
+                let mut bytes = &self.smp[..];
+                while !bytes.is_empty() {
+                    let bits = format.read_sample::<_, i16>(&mut bytes).unwrap();
+                    smp.write_i16::<LittleEndian>(bits).unwrap();
+                }
+                (16, Cow::Owned(smp))
+            }
+        }
+    }
+
+    /// Lowest and highest sample of every `bucket_size` frames across channels, where 1.0 is
+    /// full scale, for drawing waveform overviews; the last bucket may be shorter
+    pub fn peaks(&self, bucket_size: usize) -> Vec<(f32, f32)> {
+        assert!(bucket_size > 0);
+        let format = self.fmt.format;
+        let bucket_bytes = bucket_size * self.fmt.block_size() as usize;
+        self.smp
+            .chunks(bucket_bytes)
+            .map(|bytes| {
+                let mut peak = (f32::MAX, f32::MIN);
+                for mut sample in bytes.chunks_exact(format.bytes_per_sample()) {
+                    let smp: f32 = format.read_sample(&mut sample).unwrap();
+                    peak = (peak.0.min(smp), peak.1.max(smp));
+                }
+                peak
+            })
+            .collect()
+    }
+
+    /// Length in seconds
+    pub fn sec(&self) -> f32 {
+        self.smp_num() as f32 / self.fmt.sps as f32
+    }
+
+    /// Samples converted to 44.1kHz stereo frames
+    pub(crate) fn to_stereo_44k(&self) -> Vec<[i16; 2]> {
+        const SPS: u64 = 44100;
+
+        let channels = self.to_channels::<i16>();
+        let frames = match &channels[..] {
+            [mono] => mono.iter().map(|&s| [s, s]).collect::<Vec<_>>(),
+            [left, right] => left.iter().zip(right).map(|(&l, &r)| [l, r]).collect(),
+            _ => unreachable!(),
+        };
+
+        let sps = u64::from(self.fmt.sps);
+        if sps == SPS || frames.is_empty() {
+            return frames;
+        }
+        let size = Self::frames_44k(frames.len() as u32, self.fmt.sps);
+        (0..size)
+            .map(|a| frames[((a * sps / SPS) as usize).min(frames.len() - 1)])
+            .collect()
+    }
+
+    /// `loop_start` in 44.1kHz frames
+    pub(crate) fn loop_start_44k(&self) -> u32 {
+        Self::frames_44k(self.loop_start, self.fmt.sps) as u32
+    }
+
+    fn frames_44k(smp_num: u32, sps: u32) -> u64 {
+        (u64::from(smp_num) * 4 * 44100).div_ceil(u64::from(sps)) / 4
+    }
 }
 
-struct PcmWaveFormat {
-    ch: u16,  // 1 or 2
+impl fmt::Debug for Pcm {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("Pcm")
+            .field("ch", &self.fmt.ch)
+            .field("sps", &self.fmt.sps)
+            .field("format", &self.fmt.format)
+            .field("smp_num", &self.smp_num())
+            .field("loop_start", &self.loop_start)
+            .finish()
+    }
+}
+
+/// Ogg Vorbis instrument, kept encoded
+#[derive(Clone)]
+pub struct OggVorbis {
+    ch: u32,
     sps: u32,
-    bps: u16, // 8 or 16
+    smp_num: u32,
+    bytes: Vec<u8>,
+    #[cfg(any(feature = "ogg", feature = "symphonia"))]
+    decoded: OnceCell<Pcm>,
 }
 
-impl PcmWaveFormat {
-    fn read_chunk<T: Read + Seek>(bytes: &mut T, size: i64) -> Result<Self> {
-        assert!(size >= 16);
-        let id = bytes.read_u16::<LittleEndian>()?;
-        let ch = bytes.read_u16::<LittleEndian>()?;
+impl OggVorbis {
+    /// Frames of silence a decoded stream is padded with at most to reach its stored length
+    #[cfg(any(feature = "ogg", feature = "symphonia"))]
+    const MAX_PADDING: usize = 0x1_0000;
+
+    pub fn new(ch: u32, sps: u32, smp_num: u32, bytes: Vec<u8>) -> Self {
+        Self {
+            ch,
+            sps,
+            smp_num,
+            bytes,
+            #[cfg(any(feature = "ogg", feature = "symphonia"))]
+            decoded: OnceCell::new(),
+        }
+    }
+
+    pub(crate) fn read<T: Read + Seek>(
+        bytes: &mut T,
+        place: Option<&mut Range<u64>>,
+    ) -> Result<Self> {
+        let ch = bytes.read_u32::<LittleEndian>()?;
         let sps = bytes.read_u32::<LittleEndian>()?;
-        let byte_per_sec = bytes.read_u32::<LittleEndian>()?;
-        let block_size = bytes.read_u16::<LittleEndian>()?;
-        let bps = bytes.read_u16::<LittleEndian>()?;
-        bytes.seek(SeekFrom::Current(size - 16))?;
-        assert_eq!(id, 1); // Linear PCM
-        assert!(ch == 1 || ch == 2);
-        assert!(bps == 8 || bps == 16);
-        assert_eq!(byte_per_sec, sps * u32::from(ch) * u32::from(bps) / 8);
-        assert_eq!(block_size, ch * bps / 8);
-        Ok(Self { ch, sps, bps })
+        let smp_num = bytes.read_u32::<LittleEndian>()?;
+        let size = bytes.read_u32::<LittleEndian>()?;
+        ensure(size != 0, "Ogg Vorbis size")?;
+
+        let data = read_payload(bytes, size, place)?;
+        Ok(Self::new(ch, sps, smp_num, data))
+    }
+
+    pub(crate) fn write<W: Write>(&self, bytes: &mut W) -> Result<()> {
+        bytes.write_u32::<LittleEndian>(self.ch)?;
+        bytes.write_u32::<LittleEndian>(self.sps)?;
+        bytes.write_u32::<LittleEndian>(self.smp_num)?;
+        bytes.write_u32::<LittleEndian>(self.bytes.len() as u32)?;
+        bytes.write_all(&self.bytes)?;
+        Ok(())
+    }
+
+    pub fn ch(&self) -> u32 {
+        self.ch
+    }
+
+    pub fn sps(&self) -> u32 {
+        self.sps
+    }
+
+    pub fn smp_num(&self) -> u32 {
+        self.smp_num
+    }
+
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.bytes
+    }
+
+    /// 16-bit samples of the stream, decoded on the first call
+    #[cfg(any(feature = "ogg", feature = "symphonia"))]
+    pub fn decode(&self) -> Result<&Pcm> {
+        if let Some(pcm) = self.decoded.get() {
+            return Ok(pcm);
+        }
+        let pcm = self.decode_stream()?;
+        Ok(self.decoded.get_or_init(|| pcm))
+    }
+
+    #[cfg(all(feature = "ogg", not(feature = "symphonia")))]
+    fn decode_stream(&self) -> Result<Pcm> {
+        let mut reader =
+            OggStreamReader::new(Cursor::new(&self.bytes[..])).map_err(ErrorKind::OggVorbis)?;
+        let ch = u16::from(reader.ident_hdr.audio_channels);
+        let sps = reader.ident_hdr.audio_sample_rate;
+
+        let mut smp = Vec::new();
+        while let Some(packet) = reader.read_dec_packet_itl().map_err(ErrorKind::OggVorbis)? {
+            for sample in packet {
+                smp.write_i16::<LittleEndian>(sample)?;
+            }
+        }
+        self.fit_stored_length(&mut smp, ch);
+        Pcm::from_raw(ch, sps, 16, smp)
+    }
+
+    /// Cut or pad decoded 16-bit samples of `ch` channels to the stored length, as the original
+    /// player allocates it up front; a length far past the stream is not trusted
+    #[cfg(any(feature = "ogg", feature = "symphonia"))]
+    fn fit_stored_length(&self, smp: &mut Vec<u8>, ch: u16) {
+        let block_size = usize::from(ch) * 2;
+        let frames = (self.smp_num as usize).min(smp.len() / block_size + Self::MAX_PADDING);
+        smp.resize(frames * block_size, 0);
+    }
+}
+
+impl fmt::Debug for OggVorbis {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.debug_struct("OggVorbis")
+            .field("ch", &self.ch)
+            .field("sps", &self.sps)
+            .field("smp_num", &self.smp_num)
+            .field("bytes", &format_args!("[{} bytes]", self.bytes.len()))
+            .finish()
+    }
+}
+
+/// Compares the encoded stream; the decoding cache is ignored
+impl PartialEq for OggVorbis {
+    fn eq(&self, other: &Self) -> bool {
+        self.ch == other.ch
+            && self.sps == other.sps
+            && self.smp_num == other.smp_num
+            && self.bytes == other.bytes
+    }
+}
+
+impl Eq for OggVorbis {}
+
+/// Channels, rate and sample format of a `Pcm`, in a combination pxtone can hold
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct PcmWaveFormat {
+    ch: u16, // 1 or 2
+    sps: u32,
+    format: OutputFormat,
+}
+
+impl PcmWaveFormat {
+    /// Mono or stereo at a rate whose byte rate a WAV header can hold
+    pub fn new(ch: u16, sps: u32, format: OutputFormat) -> Result<Self> {
+        if ch != 1 && ch != 2 {
+            return Err(ErrorKind::UnsupportedChannels(ch).into());
+        }
+        let fmt = Self { ch, sps, format };
+        if sps == 0 || u64::from(sps) * u64::from(fmt.block_size()) > u64::from(u32::MAX) {
+            return Err(ErrorKind::UnsupportedSampleRate(sps).into());
+        }
+        Ok(fmt)
+    }
+
+    /// `new` with the format of a WAV format tag and bits per sample
+    pub fn from_tag(tag: u16, ch: u16, sps: u32, bps: u16) -> Result<Self> {
+        Self::new(ch, sps, OutputFormat::from_tag(tag, bps)?)
+    }
+
+    pub fn ch(&self) -> u16 {
+        self.ch
+    }
+
+    pub fn sps(&self) -> u32 {
+        self.sps
+    }
+
+    pub fn format(&self) -> OutputFormat {
+        self.format
+    }
+
+    const EXTENSIBLE_SIZE: i64 = 40;
+
+    /// The format, of any number of channels, and the speakers of its channels
+    fn read_chunk<T: Read + Seek>(bytes: &mut T, size: i64) -> Result<(Self, u32)> {
+        ensure(size >= 16, "wave format size")?;
+        let (mut id, ch, sps, byte_per_sec, block_size, bps): (u16, u16, u32, u32, u16, u16) =
+            read_struct(bytes)?;
+        let mut channel_mask = speakers::default_channel_mask(ch);
+        let mut read = 16;
+        if id == OutputFormat::FORMAT_TAG_EXTENSIBLE {
+            ensure(size >= Self::EXTENSIBLE_SIZE, "wave format size")?;
+            // valid bits are left to the container size
+            let (_cb_size, _valid_bps, mask, tag): (u16, u16, u32, u16) = read_struct(bytes)?;
+            if mask.count_ones() == u32::from(ch) {
+                channel_mask = mask;
+            }
+            let mut guid_tail = [0; 14];
+            bytes.read_exact(&mut guid_tail)?;
+            ensure(
+                guid_tail == OutputFormat::SUBFORMAT_GUID_TAIL,
+                "wave sub-format",
+            )?;
+            id = tag;
+            read = Self::EXTENSIBLE_SIZE;
+        }
+        bytes.seek(SeekFrom::Current(size - read))?;
+        // Linear PCM or IEEE float
+        let format = OutputFormat::from_tag(id, bps)?;
+        // more channels are mixed down once the samples are read
+        Self::new(ch.min(2), sps, format)?;
+        let fmt = Self { ch, sps, format };
+        ensure(
+            u32::from(block_size) == u32::from(ch) * u32::from(bps / 8),
+            "wave block size",
+        )?;
+        ensure(
+            u64::from(byte_per_sec) == u64::from(sps) * u64::from(block_size),
+            "wave byte rate",
+        )?;
+        Ok((fmt, channel_mask))
     }
 
     fn write_chunk<T: Write>(&self, writer: &mut T) -> Result<()> {
-        writer.write_u16::<LittleEndian>(1)?;
+        writer.write_u16::<LittleEndian>(self.format.format_tag())?;
         writer.write_u16::<LittleEndian>(self.ch)?;
         writer.write_u32::<LittleEndian>(self.sps)?;
-        writer.write_u32::<LittleEndian>(self.sps * u32::from(self.ch) * u32::from(self.bps) / 8)?;
-        writer.write_u16::<LittleEndian>(self.ch * self.bps / 8)?;
-        writer.write_u16::<LittleEndian>(self.bps)?;
+        writer.write_u32::<LittleEndian>(self.byte_per_sec())?;
+        writer.write_u16::<LittleEndian>(self.block_size())?;
+        writer.write_u16::<LittleEndian>(self.format.bits_per_sample())?;
         Ok(())
     }
+
+    /// Bytes of one frame
+    pub fn block_size(&self) -> u16 {
+        self.ch * (self.format.bits_per_sample() / 8)
+    }
+
+    fn byte_per_sec(&self) -> u32 {
+        self.sps * u32::from(self.block_size())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const FORMATS: [OutputFormat; 4] = [
+        OutputFormat::U8,
+        OutputFormat::I16,
+        OutputFormat::I24,
+        OutputFormat::F32,
+    ];
+
+    #[test]
+    fn wave_format_round_trips() {
+        for &ch in &[1, 2] {
+            for &format in &FORMATS {
+                let fmt = PcmWaveFormat::new(ch, 44100, format).unwrap();
+                assert_eq!(
+                    usize::from(fmt.block_size()),
+                    usize::from(ch) * format.bytes_per_sample()
+                );
+
+                let mut bytes = Vec::new();
+                fmt.write_chunk(&mut bytes).unwrap();
+                assert_eq!(bytes.len(), 16);
+                let (read, mask) = PcmWaveFormat::read_chunk(&mut Cursor::new(bytes), 16).unwrap();
+                assert_eq!(read, fmt);
+                assert_eq!(mask, speakers::default_channel_mask(ch));
+            }
+        }
+    }
+
+    #[test]
+    fn samples_round_trip() {
+        let levels = [-1.0, -0.5, -0.25, 0.0, 0.25, 0.5, 1.0];
+        for &format in &FORMATS {
+            let mut bytes = Vec::new();
+            for &level in &levels {
+                format.write_level(&mut bytes, level);
+            }
+            assert_eq!(bytes.len(), levels.len() * format.bytes_per_sample());
+
+            let mut reader = &bytes[..];
+            for &level in &levels {
+                let read: f32 = format.read_sample(&mut reader).unwrap();
+                let step = 1.0 / 2f64.powi(i32::from(format.bits_per_sample()) - 1);
+                let error = (f64::from(read) - level).abs();
+                assert!(error <= step, "{:?}", format);
+            }
+            assert!(reader.is_empty());
+        }
+    }
+
+    #[test]
+    fn wav_round_trips() {
+        let samples = (0..64).map(|i| f64::from(i * 1000 - 32000));
+        for &ch in &[1, 2] {
+            for &format in &FORMATS {
+                let pcm = Pcm::from_samples(ch, 22050, format, samples.clone()).unwrap();
+                assert_eq!(pcm.smp_num(), 64 / u32::from(ch));
+
+                let bytes = pcm.clone().into_bytes();
+                let data = 64 * format.bytes_per_sample();
+                assert_eq!(bytes.len(), Pcm::WAV_HEADER_SIZE + data);
+                let read = Pcm::new(Cursor::new(bytes)).unwrap();
+                assert_eq!(read.wave_format(), pcm.wave_format());
+                assert_eq!(read, pcm);
+            }
+        }
+    }
+
+    #[test]
+    fn partial_frames_are_dropped() {
+        let pcm = Pcm::from_raw(1, 44100, 16, vec![0; 2001]).unwrap();
+        assert_eq!(pcm.smp_num(), 1000);
+        assert_eq!(pcm.to_channels::<i16>()[0].len(), 1000);
+        assert_eq!(pcm.peaks(300).len(), 4);
+
+        let samples = (0..64).map(|i| f64::from(i * 1000 - 32000));
+        let pcm = Pcm::from_samples(2, 22050, OutputFormat::I16, samples).unwrap();
+        let mut bytes = pcm.clone().into_bytes();
+        bytes.extend_from_slice(&[0x7f; 3]);
+        let data = 64 * 2 + 3;
+        bytes[40..44].copy_from_slice(&(data as u32).to_le_bytes());
+        let read = Pcm::new(Cursor::new(bytes)).unwrap();
+        assert_eq!(read, pcm);
+        assert_eq!(read.to_channels::<i16>()[1].len(), 32);
+    }
+
+    #[test]
+    fn rejects_too_many_noise_units() {
+        let unit = || NoiseUnit::new(&[(0, 100)], 0, None, None, None).unwrap();
+        let mut noise = Noise::from_units(4410, (0..4).map(|_| unit()).collect()).unwrap();
+        let err = noise.push_unit(unit()).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::CountOverflow(..)));
+        let err = Noise::from_units(4410, (0..5).map(|_| unit()).collect()).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::CountOverflow(..)));
+
+        let enves = vec![(1, 100); NoiseUnit::MAX_ENVELOPE_NUM as usize + 1];
+        let err = NoiseUnit::new(&enves, 0, None, None, None).unwrap_err();
+        assert!(matches!(err.kind(), ErrorKind::CountOverflow(..)));
+    }
+
+    #[test]
+    fn coordinate_waves_need_two_points() {
+        let read = |num: usize| {
+            let mut unit = VoiceUnit::default();
+            let points = [(0, 64), (100, -64)];
+            unit.set_coordinate_wave(&points[..num], 200);
+            let mut bytes = Cursor::new(Vec::new());
+            Voice::from_units(vec![unit]).write(&mut bytes).unwrap();
+            bytes.set_position(0);
+            Voice::new(bytes).unwrap().units()[0].wave().cloned()
+        };
+        assert_eq!(read(0), None);
+        assert_eq!(read(1), None);
+        assert!(read(2).is_some());
+
+        let empty = VoiceWave::Coodinate {
+            points: vec![],
+            reso: 200,
+        };
+        assert!(empty.render_cycle(400).iter().all(|&s| s == 0.0));
+        let wide = VoiceWave::Coodinate {
+            points: vec![Point { x: 0, y: 64 }, Point { x: 100, y: -64 }],
+            reso: i32::MAX,
+        };
+        assert_eq!(wide.render_cycle(400).len(), 400);
+    }
+
+    #[test]
+    fn wavetables_scale_with_volume() {
+        let wave = VoiceWave::Overtone {
+            points: vec![Point { x: 1, y: 128 }, Point { x: 3, y: 32 }],
+        };
+        let full = Wavetable::new(&wave, 128).sample_table(64);
+        assert_eq!(full, wave.render_cycle(64));
+        let half = Wavetable::new(&wave, 64).sample_table(64);
+        for (half, full) in half.iter().zip(&full) {
+            assert!((half * 2.0 - full).abs() < 1e-6);
+        }
+        assert!(Wavetable::new(&wave, 128).sample_table(0).is_empty());
+    }
+
+    #[test]
+    fn envelopes_are_clamped() {
+        let mut unit = VoiceUnit::default();
+        unit.set_coordinate_wave(&[(0, 64), (100, -64)], 200);
+        let points = vec![(i32::MAX, i32::MAX), (-1, i32::MIN), (i32::MAX, 64)];
+        unit.set_envelope(Some(Envelope::new(1000, points, i32::MAX)));
+        let mut bytes = Cursor::new(Vec::new());
+        Voice::from_units(vec![unit]).write(&mut bytes).unwrap();
+        bytes.set_position(0);
+
+        let voice = Voice::new(bytes).unwrap();
+        let enve = voice.units()[0].envelope().unwrap();
+        assert_eq!(enve.points(), &[(10000, 255), (0, 0), (10000, 64)]);
+        assert_eq!(enve.release(), 10000);
+        let (table, release) = voice.units()[0].envelope_table(44100);
+        assert_eq!(table.len(), 882000);
+        assert_eq!(release, 441000);
+    }
+
+    #[test]
+    #[cfg(all(any(feature = "ogg", feature = "symphonia"), feature = "ogg-encode"))]
+    fn ogg_vorbis_padding_is_capped() {
+        let samples = (0..4410).map(|i| f64::from(i % 100 * 300));
+        let pcm = Pcm::from_samples(1, 44100, OutputFormat::I16, samples).unwrap();
+        let mut bytes = Vec::new();
+        pcm.write_ogg(&mut bytes, 0.5).unwrap();
+
+        let short = OggVorbis::new(1, 44100, 1000, bytes.clone());
+        assert_eq!(short.decode().unwrap().smp_num(), 1000);
+        let long = OggVorbis::new(1, 44100, 5000, bytes.clone());
+        assert_eq!(long.decode().unwrap().smp_num(), 5000);
+        let huge = OggVorbis::new(1, 44100, u32::MAX, bytes);
+        let frames = huge.decode().unwrap().smp_num() as usize;
+        assert!(frames <= 4410 * 2 + OggVorbis::MAX_PADDING);
+    }
 }
@@ -0,0 +1,42 @@
+//! Conversion of sampled audio between channel counts, rates and sample formats
+
+use super::{Pcm, PcmWaveFormat};
+
+use crate::dsp::{ResampleQuality, Resampler};
+
+impl Pcm {
+    /// The audio in `target`: mono is duplicated or stereo averaged, the rate is converted with
+    /// a windowed sinc and samples are requantized; the loop start keeps its time
+    pub fn convert(&self, target: &PcmWaveFormat) -> Pcm {
+        let (from, to) = (self.fmt, *target);
+
+        let mut samples = Vec::with_capacity(self.smp_num() as usize * usize::from(to.ch));
+        for [left, right] in self.frames::<f32>() {
+            match to.ch {
+                1 => samples.push((left + right) / 2.0),
+                _ => samples.extend([left, right]),
+            }
+        }
+
+        if from.sps != to.sps {
+            let mut resampler = Resampler::new(to.ch, from.sps, to.sps, ResampleQuality::Sinc);
+            resampler.push(&samples);
+            resampler.flush();
+            samples = vec![0.0; resampler.available() * usize::from(to.ch)];
+            resampler.pull(&mut samples);
+        }
+
+        let mut smp = Vec::with_capacity(samples.len() * to.format.bytes_per_sample());
+        for &level in &samples {
+            to.format.write_level(&mut smp, f64::from(level));
+        }
+        let loop_start = u64::from(self.loop_start) * u64::from(to.sps) / u64::from(from.sps);
+        let mut pcm = Pcm {
+            fmt: to,
+            smp,
+            loop_start: 0,
+        };
+        pcm.set_loop_start(loop_start as u32);
+        pcm
+    }
+}
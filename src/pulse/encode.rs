@@ -0,0 +1,97 @@
+//! Compressed exports of rendered audio
+
+use std::io::Write;
+
+#[cfg(feature = "flac")]
+use byteorder::{LittleEndian, ReadBytesExt as _};
+
+#[cfg(feature = "flac")]
+use super::OutputFormat;
+use super::{Pcm, PcmWaveFormat};
+use crate::error::{ErrorKind, Result};
+
+impl Pcm {
+    /// Write as a FLAC file; 32-bit float samples are not supported
+    #[cfg(feature = "flac")]
+    pub fn write_flac<W: Write>(&self, mut writer: W) -> Result<()> {
+        use flacenc::component::BitRepr as _;
+        use flacenc::error::Verify as _;
+
+        let PcmWaveFormat { ch, sps, format } = self.fmt;
+        let mut bytes = &self.smp[..];
+        let mut samples = Vec::with_capacity(self.smp.len() / format.bytes_per_sample());
+        while !bytes.is_empty() {
+            samples.push(match format {
+                OutputFormat::U8 => i32::from((bytes.read_u8()? ^ 0x80) as i8),
+                OutputFormat::I16 => i32::from(bytes.read_i16::<LittleEndian>()?),
+                OutputFormat::I24 => bytes.read_i24::<LittleEndian>()?,
+                OutputFormat::F32 => {
+                    return Err(ErrorKind::UnsupportedOutputFormat(format, "FLAC").into());
+                }
+            });
+        }
+
+        let config = flacenc::config::Encoder::default()
+            .into_verified()
+            .map_err(|(_, err)| ErrorKind::Flac(err.to_string()))?;
+        let source = flacenc::source::MemSource::from_samples(
+            &samples,
+            ch as usize,
+            format.bits_per_sample() as usize,
+            sps as usize,
+        );
+        let stream = flacenc::encode_with_fixed_block_size(&config, source, config.block_size)
+            .map_err(|err| ErrorKind::Flac(err.to_string()))?;
+
+        let mut sink = flacenc::bitsink::ByteSink::new();
+        stream.write(&mut sink).unwrap();
+        writer.write_all(sink.as_slice())?;
+        Ok(())
+    }
+
+    /// Write as an Ogg Vorbis file at `quality` from -0.2 to 1.0; a loop start is tagged as
+    /// `LOOPSTART` and `LOOPLENGTH`
+    #[cfg(feature = "ogg-encode")]
+    pub fn write_ogg<W: Write>(&self, writer: W, quality: f32) -> Result<()> {
+        use std::num::{NonZeroU32, NonZeroU8};
+        use vorbis_rs::{VorbisBitrateManagementStrategy, VorbisEncoderBuilder};
+
+        // fixed so that the same input encodes to the same bytes
+        const STREAM_SERIAL: i32 = 0x7074_6f6e;
+        const BLOCK_SIZE: usize = 4096;
+
+        assert!((-0.2..=1.0).contains(&quality));
+        let error = |err: vorbis_rs::VorbisError| ErrorKind::OggVorbisEncode(err.to_string());
+        let PcmWaveFormat { ch, sps, .. } = self.fmt;
+        let channels = self.to_channels::<f32>();
+        let smp_num = self.smp_num() as usize;
+
+        let mut builder = VorbisEncoderBuilder::new_with_serial(
+            NonZeroU32::new(sps).unwrap(),
+            NonZeroU8::new(ch as u8).unwrap(),
+            writer,
+            STREAM_SERIAL,
+        );
+        builder.bitrate_management_strategy(VorbisBitrateManagementStrategy::QualityVbr {
+            target_quality: quality,
+        });
+        if self.loop_start > 0 {
+            // the tags game engines read
+            let loop_length = self.smp_num() - self.loop_start;
+            builder
+                .comment_tags([
+                    ("LOOPSTART", self.loop_start.to_string()),
+                    ("LOOPLENGTH", loop_length.to_string()),
+                ])
+                .map_err(error)?;
+        }
+        let mut encoder = builder.build().map_err(error)?;
+        for start in (0..smp_num).step_by(BLOCK_SIZE) {
+            let end = (start + BLOCK_SIZE).min(smp_num);
+            let block = channels.iter().map(|c| &c[start..end]).collect::<Vec<_>>();
+            encoder.encode_audio_block(&block).map_err(error)?;
+        }
+        encoder.finish().map_err(error)?;
+        Ok(())
+    }
+}
@@ -0,0 +1,89 @@
+//! `Pcm` through hound's WAV reader and writer
+
+use std::io::{Read, Seek, Write};
+
+use byteorder::{LittleEndian, ReadBytesExt as _, WriteBytesExt as _};
+use hound::{SampleFormat, WavReader, WavSpec, WavWriter};
+
+use super::{speakers, OutputFormat, Pcm, PcmWaveFormat};
+use crate::error::{ErrorKind, Result};
+
+impl From<PcmWaveFormat> for WavSpec {
+    fn from(fmt: PcmWaveFormat) -> Self {
+        WavSpec {
+            channels: fmt.ch,
+            sample_rate: fmt.sps,
+            bits_per_sample: fmt.format.bits_per_sample(),
+            sample_format: match fmt.format {
+                OutputFormat::F32 => SampleFormat::Float,
+                _ => SampleFormat::Int,
+            },
+        }
+    }
+}
+
+impl Pcm {
+    /// Read the samples of `reader`; more than two channels are mixed down to stereo
+    pub fn from_hound_reader<R: Read>(mut reader: WavReader<R>) -> Result<Self> {
+        let spec = reader.spec();
+        let format = match (spec.sample_format, spec.bits_per_sample) {
+            (SampleFormat::Int, 8) => OutputFormat::U8,
+            (SampleFormat::Int, 16) => OutputFormat::I16,
+            (SampleFormat::Int, 24) => OutputFormat::I24,
+            (SampleFormat::Float, 32) => OutputFormat::F32,
+            (_, bps) => return Err(ErrorKind::UnsupportedBitDepth(bps).into()),
+        };
+        let ch = spec.channels;
+        PcmWaveFormat::new(ch.min(2), spec.sample_rate, format)?;
+
+        let mut smp = Vec::with_capacity(reader.len() as usize * format.bytes_per_sample());
+        match format {
+            OutputFormat::U8 => {
+                for sample in reader.samples::<i8>() {
+                    smp.push(sample.map_err(ErrorKind::Hound)? as u8 ^ 0x80);
+                }
+            }
+            OutputFormat::I16 => {
+                for sample in reader.samples::<i16>() {
+                    smp.write_i16::<LittleEndian>(sample.map_err(ErrorKind::Hound)?)?;
+                }
+            }
+            OutputFormat::I24 => {
+                for sample in reader.samples::<i32>() {
+                    smp.write_i24::<LittleEndian>(sample.map_err(ErrorKind::Hound)?)?;
+                }
+            }
+            OutputFormat::F32 => {
+                for sample in reader.samples::<f32>() {
+                    smp.write_f32::<LittleEndian>(sample.map_err(ErrorKind::Hound)?)?;
+                }
+            }
+        }
+        if ch > 2 {
+            smp = speakers::downmix(&smp, ch, speakers::default_channel_mask(ch), format);
+        }
+
+        Ok(Self {
+            fmt: PcmWaveFormat::new(ch.min(2), spec.sample_rate, format)?,
+            smp,
+            loop_start: 0,
+        })
+    }
+
+    /// Append the samples to `writer`, which has to be opened with the spec of `wave_format`
+    pub fn write_hound<W: Write + Seek>(&self, writer: &mut WavWriter<W>) -> Result<()> {
+        assert_eq!(writer.spec(), WavSpec::from(self.fmt));
+        let format = self.fmt.format;
+        let mut bytes = &self.smp[..];
+        while !bytes.is_empty() {
+            let written = match format {
+                OutputFormat::U8 => writer.write_sample((bytes.read_u8()? ^ 0x80) as i8),
+                OutputFormat::I16 => writer.write_sample(bytes.read_i16::<LittleEndian>()?),
+                OutputFormat::I24 => writer.write_sample(bytes.read_i24::<LittleEndian>()?),
+                OutputFormat::F32 => writer.write_sample(bytes.read_f32::<LittleEndian>()?),
+            };
+            written.map_err(ErrorKind::Hound)?;
+        }
+        Ok(())
+    }
+}
@@ -0,0 +1,23 @@
+//! `Pcm` as kira's static sounds
+
+use std::sync::Arc;
+
+use kira::sound::static_sound::{StaticSoundData, StaticSoundSettings};
+use kira::Frame;
+
+use super::Pcm;
+
+impl Pcm {
+    /// The samples as a sound kira keeps in memory, played with `settings`
+    pub fn to_static_sound_data(&self, settings: StaticSoundSettings) -> StaticSoundData {
+        StaticSoundData {
+            sample_rate: self.sps(),
+            frames: self
+                .frames::<f32>()
+                .map(|[left, right]| Frame::new(left, right))
+                .collect::<Arc<[_]>>(),
+            settings,
+            slice: None,
+        }
+    }
+}
@@ -0,0 +1,130 @@
+//! Loudness of rendered audio, after ITU-R BS.1770 and EBU R128
+
+use super::Pcm;
+
+/// Second order IIR filter
+struct Biquad {
+    b: [f64; 3],
+    a: [f64; 2],
+    z: [f64; 2],
+}
+
+impl Biquad {
+    fn process(&mut self, x: f64) -> f64 {
+        let y = self.b[0] * x + self.z[0];
+        self.z[0] = self.b[1] * x - self.a[0] * y + self.z[1];
+        self.z[1] = self.b[2] * x - self.a[1] * y;
+        y
+    }
+}
+
+/// K-weighting: a shelf for the head followed by a high pass, designed for `sps`
+fn k_weighting(sps: u32) -> [Biquad; 2] {
+    let sps = f64::from(sps);
+
+    let k = (std::f64::consts::PI * 1_681.974_450_955_533 / sps).tan();
+    let q = 0.707_175_236_955_419_6;
+    let vh = 10f64.powf(3.999_843_853_973_347 / 20.0);
+    let vb = vh.powf(0.499_666_774_154_541_6);
+    let a0 = 1.0 + k / q + k * k;
+    let shelf = Biquad {
+        b: [
+            (vh + vb * k / q + k * k) / a0,
+            2.0 * (k * k - vh) / a0,
+            (vh - vb * k / q + k * k) / a0,
+        ],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        z: [0.0; 2],
+    };
+
+    let k = (std::f64::consts::PI * 38.135_470_876_024_44 / sps).tan();
+    let q = 0.500_327_037_323_877_3;
+    let a0 = 1.0 + k / q + k * k;
+    let high_pass = Biquad {
+        b: [1.0, -2.0, 1.0],
+        a: [2.0 * (k * k - 1.0) / a0, (1.0 - k / q + k * k) / a0],
+        z: [0.0; 2],
+    };
+
+    [shelf, high_pass]
+}
+
+/// Gated integrated loudness in LUFS of interleaved samples where 1.0 is full scale;
+/// negative infinity when shorter than a 400ms block or silent
+pub(crate) fn integrated_loudness<I>(samples: I, ch: usize, sps: u32) -> f64
+where
+    I: IntoIterator<Item = f64>,
+{
+    const ABSOLUTE_GATE: f64 = -70.0;
+    const RELATIVE_GATE: f64 = -10.0;
+
+    // 100ms steps, four to a block
+    let step = (sps / 10) as usize;
+    let mut filters = (0..ch).map(|_| k_weighting(sps)).collect::<Vec<_>>();
+    let mut step_power = 0.0;
+    let mut step_powers = Vec::new();
+    let mut frame_samples = 0;
+    for (i, smp) in samples.into_iter().enumerate() {
+        let [shelf, high_pass] = &mut filters[i % ch];
+        let smp = high_pass.process(shelf.process(smp));
+        step_power += smp * smp;
+        frame_samples += 1;
+        if frame_samples == step * ch {
+            step_powers.push(step_power / step as f64);
+            step_power = 0.0;
+            frame_samples = 0;
+        }
+    }
+
+    let blocks = step_powers
+        .windows(4)
+        .map(|steps| steps.iter().sum::<f64>() / 4.0)
+        .collect::<Vec<_>>();
+    let loudness = |power: f64| -0.691 + 10.0 * power.log10();
+    let gated_mean = |gate: f64| {
+        let gated = blocks.iter().filter(|&&power| loudness(power) > gate);
+        let (sum, count) = gated.fold((0.0, 0), |(sum, count), power| (sum + power, count + 1));
+        if count == 0 {
+            0.0
+        } else {
+            sum / count as f64
+        }
+    };
+
+    let relative_gate = loudness(gated_mean(ABSOLUTE_GATE)) + RELATIVE_GATE;
+    loudness(gated_mean(relative_gate.max(ABSOLUTE_GATE)))
+}
+
+impl Pcm {
+    /// Root mean square of all samples, where 1.0 is full scale
+    pub fn rms(&self) -> f32 {
+        let format = self.fmt.format;
+        let mut bytes = &self.smp[..];
+        let mut sum = 0.0;
+        let mut count = 0u64;
+        while !bytes.is_empty() {
+            let smp: f32 = format.read_sample(&mut bytes).unwrap();
+            sum += f64::from(smp) * f64::from(smp);
+            count += 1;
+        }
+        if count == 0 {
+            return 0.0;
+        }
+        (sum / count as f64).sqrt() as f32
+    }
+
+    /// Integrated loudness in LUFS as EBU R128 measures it; negative infinity when shorter
+    /// than 400ms or silent
+    pub fn loudness(&self) -> f64 {
+        let format = self.fmt.format;
+        let mut bytes = &self.smp[..];
+        let samples = std::iter::from_fn(|| {
+            if bytes.is_empty() {
+                return None;
+            }
+            let smp: f32 = format.read_sample(&mut bytes).unwrap();
+            Some(f64::from(smp))
+        });
+        integrated_loudness(samples, usize::from(self.fmt.ch), self.fmt.sps)
+    }
+}
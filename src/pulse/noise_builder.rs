@@ -1,120 +1,199 @@
 mod noise_table;
 
-use byteorder::{LittleEndian, WriteBytesExt as _};
+use std::mem;
 
-use super::{Frequency, Noise, NoiseOscillator, NoiseUnit, NoiseWave, Pcm, PcmWaveFormat, Sample as _};
+use super::{Noise, NoiseOscillator, NoiseUnit, NoiseWave, Pcm, Quality};
 use noise_table::*;
 
-use crate::error::Result;
+pub(crate) use noise_table::SMP_NUM;
 
-const BASIC_SPS: u32 = 44100;
+use crate::error::{ErrorKind, Result};
+use crate::options::{Downmix, PanLaw, RenderOptions};
+use crate::pitch::Frequency;
+use crate::progress::Reporter;
+
+pub(super) const BASIC_SPS: u32 = 44100;
 const BASIC_FREQUENCY: u32 = 100;
 const KEY_TOP: u32 = 0x3200;
 
-const SAMPLING_TOP: f64 = i16::max_value() as f64;
+const SAMPLING_TOP: f64 = i16::MAX as f64;
+const ENVE_TOP: f64 = 100.0;
+
+/// Frames each unit renders at a time before mixing
+const BLOCK_SIZE: usize = 256;
+
+/// Residual of a unit step at phase 0, for a phase advancing `dt` per sample
+fn poly_blep(t: f64, dt: f64) -> f64 {
+    if dt <= 0.0 {
+        0.0
+    } else if t < dt {
+        let x = t / dt;
+        x + x - x * x - 1.0
+    } else if t > 1.0 - dt {
+        let x = (t - 1.0) / dt;
+        x * x + x + x + 1.0
+    } else {
+        0.0
+    }
+}
 
 pub(super) struct NoiseBuilder {}
 
 impl NoiseBuilder {
-    pub(super) fn build(noise: &Noise, ch: u16, sps: u32, bps: u16) -> Result<Pcm> {
-        assert!(ch == 1 || ch == 2);
-        assert!(sps == 11025 || sps == 22050 || sps == 44100 || sps == 48000);
-        assert!(bps == 8 || bps == 16);
-        let smp_num = ((f64::from(noise.smp_num_44k) / (f64::from(BASIC_SPS) / f64::from(sps)))
-            as u32
-            * (u32::from(bps) / 8)
-            * u32::from(ch)) as usize;
-        let mut units = noise
-            .units
+    pub(super) fn build(noise: &Noise, options: &RenderOptions) -> Result<Pcm> {
+        let unit_nos = (0..noise.units.len()).collect::<Vec<_>>();
+        Self::build_units(noise, &unit_nos, options)
+    }
+
+    /// Mix only the units at `unit_nos` over the length of `noise`
+    pub(super) fn build_units(
+        noise: &Noise,
+        unit_nos: &[usize],
+        options: &RenderOptions,
+    ) -> Result<Pcm> {
+        options.quantize(Self::samples(noise, unit_nos, options, None)?)
+    }
+
+    /// Interleaved samples in 16-bit scale, before gain and quantizing
+    pub(super) fn samples(
+        noise: &Noise,
+        unit_nos: &[usize],
+        options: &RenderOptions,
+        mut reporter: Option<&mut Reporter>,
+    ) -> Result<Vec<f64>> {
+        options.validate_noise()?;
+        let (ch, sps) = (options.channels, options.sample_rate);
+        let smp_num = Self::sample_len(noise, ch, sps)?;
+        options.check_length((smp_num / ch as usize) as u64)?;
+        let custom_tables;
+        let tables = match options.noise_table_size as usize {
+            SMP_NUM => WaveTables::original(),
+            smp_num => {
+                custom_tables = WaveTables::new(smp_num);
+                &custom_tables
+            }
+        };
+        let mut units = unit_nos
             .iter()
-            .map(|unit| NoiseBuilderUnit::new(&unit, sps))
+            .map(|&unit_no| {
+                let seed = options.random_seed.map(|seed| RandomSeed { seed, unit_no });
+                let unit = &noise.units[unit_no];
+                let mut unit = NoiseBuilderUnit::new(unit, options, seed, tables);
+                unit.pan = Self::output_pan(unit.pan, options);
+                unit
+            })
             .collect::<Vec<_>>();
-        let mut smp = Vec::with_capacity(smp_num);
-
-        while smp.len() < smp_num {
-            let sample_and_pans = units
-                .iter_mut()
-                .map(|unit| (unit.get_sample(), unit.pan))
-                .collect::<Vec<_>>();
-            for i in 0..ch {
-                let sample = {
-                    let sample_f64 = sample_and_pans
-                        .iter()
-                        .fold(0.0, |acc, (sample, pan)| acc + sample * pan[i as usize]);
-                    if sample_f64 < 0.0 {
-                        const NORMALIZE: f64 = (SAMPLING_TOP + 1.0) / SAMPLING_TOP;
-                        ((sample_f64 * NORMALIZE) as i32).max(i32::from(i16::min_value())) as i16
-                    } else {
-                        ((sample_f64 as i32).min(i32::from(i16::max_value()))) as i16
-                    }
-                };
-                if sps == 8 {
-                    smp.write_u8(u8::from_i16(sample))?;
-                } else {
-                    smp.write_i16::<LittleEndian>(sample)?;
+        units.retain(|unit| unit.enable);
+
+        // each unit renders a block of its own samples, then the block is panned into the
+        // output in a loop the compiler can vectorize; units add up in the same order as
+        // mixing frame by frame, so the result is identical
+        let mut smp = vec![0.0; smp_num];
+        let mut block = [0.0; BLOCK_SIZE];
+        for (i, frames) in smp.chunks_mut(BLOCK_SIZE * ch as usize).enumerate() {
+            if let Some(reporter) = &mut reporter {
+                if !reporter.report((i * BLOCK_SIZE) as u64) {
+                    break;
                 }
             }
+            let block = &mut block[..frames.len() / ch as usize];
+            for unit in &mut units {
+                for sample in block.iter_mut() {
+                    *sample = unit.get_sample();
+                }
+                match ch {
+                    1 => Self::mix_block::<1>(frames, block, unit.pan),
+                    _ => Self::mix_block::<2>(frames, block, unit.pan),
+                }
+            }
+        }
+
+        Ok(smp)
+    }
+
+    /// Interleaved samples of `noise` at `sps`, counted in u64 so that no length, rate or
+    /// channel count wraps
+    fn sample_len(noise: &Noise, ch: u16, sps: u32) -> Result<usize> {
+        let samples = u64::from(noise.sample_count(sps)?) * u64::from(ch);
+        let limit = (isize::MAX as usize / mem::size_of::<f64>()) as u64;
+        if samples > limit {
+            return Err(ErrorKind::SampleOverflow(samples, "samples", limit).into());
+        }
+        Ok(samples as usize)
+    }
+
+    /// Level of each output channel for a unit panned to `pan`, downmixed or upmixed as
+    /// `options` choose
+    fn output_pan(pan: [f64; 2], options: &RenderOptions) -> [f64; 2] {
+        match (options.channels, options.downmix) {
+            (1, Downmix::Original) => pan,
+            (1, downmix) => [downmix.apply(pan[0], pan[1]), 0.0],
+            _ => pan.map(|level| level * options.upmix.level()),
         }
+    }
 
-        Ok(Pcm { fmt: PcmWaveFormat { ch, sps, bps }, smp })
+    /// Add `block` panned into the interleaved `frames` of `CH` channels
+    fn mix_block<const CH: usize>(frames: &mut [f64], block: &[f64], pan: [f64; 2]) {
+        for (frame, &sample) in frames.chunks_exact_mut(CH).zip(block) {
+            for (out, pan) in frame.iter_mut().zip(&pan) {
+                *out += sample * pan;
+            }
+        }
     }
 }
 
-struct NoiseBuilderUnit {
+struct NoiseBuilderUnit<'a> {
     enable: bool,
+    band_limited: bool,
     pan: [f64; 2],
-    enves: Vec<NoiseBuilderPoint>,
-    enve_index: usize,
-    enve_mag_start: f64,
-    enve_mag_margin: f64,
-    enve_count: u32,
-    main: NoiseBuilderOscillator,
-    freq: NoiseBuilderOscillator,
-    volu: NoiseBuilderOscillator,
+    enves: Vec<f64>,
+    enve_sustain: f64,
+    enve_count: usize,
+    main: NoiseBuilderOscillator<'a>,
+    freq: NoiseBuilderOscillator<'a>,
+    volu: NoiseBuilderOscillator<'a>,
 }
 
-impl NoiseBuilderUnit {
-    fn new(unit: &NoiseUnit, sps: u32) -> Self {
+impl<'a> NoiseBuilderUnit<'a> {
+    fn new(
+        unit: &NoiseUnit,
+        options: &RenderOptions,
+        seed: Option<RandomSeed>,
+        tables: &'a WaveTables,
+    ) -> Self {
+        let sps = options.sample_rate;
         let enable = unit.enable;
-        let pan = match unit.pan {
-            0 => [1.0, 1.0],
-            x if x < 0 => [1.0, (100.0 + f64::from(x)) / 100.0],
-            x => [(100.0 + f64::from(x)) / 100.0, 1.0],
+        let band_limited = options.quality == Quality::BandLimited;
+        let pan = match (options.pan_law, unit.pan) {
+            (PanLaw::Original, 0) => [1.0, 1.0],
+            (PanLaw::Original, x) if x < 0 => [1.0, (100.0 + f64::from(x)) / 100.0],
+            (PanLaw::Original, x) => [(100.0 - f64::from(x)) / 100.0, 1.0],
+            (pan_law, x) => pan_law.levels(f64::from(x) / 100.0),
         };
-        let enves = unit
-            .enves
-            .iter()
-            .map(|enve| NoiseBuilderPoint {
-                smp: (sps as i32) * enve.x / 1000,
-                mag: f64::from(enve.y) / 100.0,
-            })
-            .collect::<Vec<_>>();
-        let enve_index = 0;
-        let enve_mag_start = 0.0;
-        let enve_mag_margin = 0.0;
+        let enves = unit.envelope.head_levels(sps, ENVE_TOP);
+        let enve_sustain = unit.envelope.sustain_level(ENVE_TOP);
         let enve_count = 0;
         let main = if let Some(osc) = &unit.main {
-            NoiseBuilderOscillator::new(&osc, OscillatorKind::Main, sps)
+            NoiseBuilderOscillator::new(osc, OscillatorKind::Main, sps, seed, tables)
         } else {
-            NoiseBuilderOscillator::empty(OscillatorKind::Main)
+            NoiseBuilderOscillator::empty(OscillatorKind::Main, tables)
         };
         let freq = if let Some(osc) = &unit.freq {
-            NoiseBuilderOscillator::new(&osc, OscillatorKind::Freq, sps)
+            NoiseBuilderOscillator::new(osc, OscillatorKind::Freq, sps, seed, tables)
         } else {
-            NoiseBuilderOscillator::empty(OscillatorKind::Freq)
+            NoiseBuilderOscillator::empty(OscillatorKind::Freq, tables)
         };
         let volu = if let Some(osc) = &unit.volu {
-            NoiseBuilderOscillator::new(&osc, OscillatorKind::Volu, sps)
+            NoiseBuilderOscillator::new(osc, OscillatorKind::Volu, sps, seed, tables)
         } else {
-            NoiseBuilderOscillator::empty(OscillatorKind::Volu)
+            NoiseBuilderOscillator::empty(OscillatorKind::Volu, tables)
         };
         Self {
             enable,
+            band_limited,
             pan,
             enves,
-            enve_index,
-            enve_mag_start,
-            enve_mag_margin,
+            enve_sustain,
             enve_count,
             main,
             freq,
@@ -128,20 +207,20 @@ impl NoiseBuilderUnit {
         }
 
         // main
-        let mut work = self.main.get_sample();
+        let mut work = if self.band_limited {
+            let freq = self.freq.get_sample() as i32;
+            let step = self.main.increment * f64::from(Frequency::get(freq));
+            self.main.get_sample_band_limited(step)
+        } else {
+            self.main.get_sample()
+        };
 
         // volume
         let vol = self.volu.get_sample();
         work *= (vol + SAMPLING_TOP) / (SAMPLING_TOP + SAMPLING_TOP);
 
         // envelope
-        if self.enve_index < self.enves.len() {
-            work *= self.enve_mag_start
-                + (self.enve_mag_margin * f64::from(self.enve_count)
-                    / f64::from(self.enves[self.enve_index].smp));
-        } else {
-            work *= self.enve_mag_start;
-        }
+        work *= self.enves.get(self.enve_count).copied().unwrap_or(self.enve_sustain);
 
         // increment
         let freq = self.freq.get_sample() as i32;
@@ -150,37 +229,17 @@ impl NoiseBuilderUnit {
         self.freq.increment(self.freq.increment);
         self.volu.increment(self.volu.increment);
 
-        if self.enve_index < self.enves.len() {
+        if self.enve_count < self.enves.len() {
             self.enve_count += 1;
-            let current = &self.enves[self.enve_index];
-            if (self.enve_count as i32) >= current.smp {
-                self.enve_count = 0;
-                self.enve_mag_start = current.mag;
-                self.enve_mag_margin = 0.0;
-                self.enve_index += 1;
-                while self.enve_index < self.enves.len() {
-                    let enve = &self.enves[self.enve_index];
-                    self.enve_mag_margin = enve.mag - self.enve_mag_start;
-                    if enve.smp != 0 {
-                        break;
-                    }
-                    self.enve_mag_start = enve.mag;
-                    self.enve_index += 1;
-                }
-            }
         }
 
         work
     }
 }
 
-struct NoiseBuilderPoint {
-    smp: i32,
-    mag: f64,
-}
-
-struct NoiseBuilderOscillator {
+struct NoiseBuilderOscillator<'a> {
     kind: OscillatorKind,
+    tables: &'a WaveTables,
     wave: NoiseBuilderWave,
     rev: bool,
     increment: f64,
@@ -194,10 +253,11 @@ enum OscillatorKind {
     Freq,
 }
 
-impl NoiseBuilderOscillator {
-    fn empty(kind: OscillatorKind) -> Self {
+impl<'a> NoiseBuilderOscillator<'a> {
+    fn empty(kind: OscillatorKind, tables: &'a WaveTables) -> Self {
         Self {
             kind,
+            tables,
             wave: NoiseBuilderWave::None,
             rev: false,
             increment: 0.0,
@@ -206,7 +266,17 @@ impl NoiseBuilderOscillator {
         }
     }
 
-    fn new(osc: &NoiseOscillator, kind: OscillatorKind, sps: u32) -> Self {
+    fn new(
+        osc: &NoiseOscillator,
+        kind: OscillatorKind,
+        sps: u32,
+        seed: Option<RandomSeed>,
+        tables: &'a WaveTables,
+    ) -> Self {
+        let source = match seed {
+            Some(seed) => RandomSource::seeded(seed, &kind),
+            None => RandomSource::Table { index: 0 },
+        };
         let wave = match &osc.wave {
             NoiseWave::None => NoiseBuilderWave::None,
             NoiseWave::Sine => NoiseBuilderWave::Raw {
@@ -247,20 +317,22 @@ impl NoiseBuilderOscillator {
             NoiseWave::Saw8 => NoiseBuilderWave::Raw {
                 kind: RawKind::Saw8,
             },
-            random => NoiseBuilderWave::init_random(random, osc.offset),
+            random => NoiseBuilderWave::init_random(random, osc.offset, source),
         };
         let rev = osc.rev;
+        let smp_num = tables.smp_num() as f64;
         let increment = (f64::from(BASIC_SPS) / f64::from(sps))
-            * (f64::from(osc.freq) / f64::from(BASIC_FREQUENCY));
-        let volu = f64::from(osc.volu) / 100.0;
+            * (f64::from(osc.freq) / f64::from(BASIC_FREQUENCY))
+            * (smp_num / SMP_NUM as f64);
+        // percentages are divided in f32, as pxtone divides them
+        let volu = f64::from(osc.volu / 100.0);
         let offset = match osc.wave {
-            NoiseWave::Random | NoiseWave::Random2 => {
-                f64::from(SMP_NUM as u32) * f64::from(osc.offset) / 100.0
-            }
-            _ => 0.0,
+            NoiseWave::Random | NoiseWave::Random2 => 0.0,
+            _ => smp_num * f64::from(osc.offset / 100.0),
         };
         Self {
             kind,
+            tables,
             wave,
             rev,
             increment,
@@ -273,16 +345,17 @@ impl NoiseBuilderOscillator {
         let offset = self.offset as i32;
         let mut work = if let OscillatorKind::Main = self.kind {
             if offset >= 0 {
-                f64::from(self.wave.get_sample(offset as u32))
+                f64::from(self.wave.get_sample(self.tables, offset as u32))
             } else {
                 0.0
             }
         } else {
-            f64::from(self.wave.get_sample(offset as u32))
+            f64::from(self.wave.get_sample(self.tables, offset as u32))
         };
         if let OscillatorKind::Freq = self.kind {
             if let NoiseBuilderWave::Raw { .. } = self.wave {
-                work *= f64::from(KEY_TOP) / SAMPLING_TOP;
+                // in integers, as pxtone scales it, so the key is truncated
+                work = f64::from(KEY_TOP as i32 * work as i32 / SAMPLING_TOP as i32);
             }
         }
 
@@ -292,31 +365,46 @@ impl NoiseBuilderOscillator {
         work * self.volu
     }
 
+    /// `get_sample` with the edges of Saw and Rect smoothed by polyBLEP
+    fn get_sample_band_limited(&self, step: f64) -> f64 {
+        let smp_num = self.tables.smp_num() as f64;
+        let t = self.offset / smp_num;
+        let dt = (step / smp_num).min(0.5);
+        let blep = match &self.wave {
+            NoiseBuilderWave::Raw { kind: RawKind::Saw } => poly_blep(t, dt),
+            NoiseBuilderWave::Raw { kind: RawKind::Rect } => {
+                // phase where the Rect table falls
+                let edge = (self.tables.smp_num() / 2) as f64 / smp_num;
+                poly_blep(t, dt) - poly_blep((t - edge).rem_euclid(1.0), dt)
+            }
+            _ => 0.0,
+        };
+
+        let rev = if self.rev { -1.0 } else { 1.0 };
+        self.get_sample() + rev * blep * SAMPLING_TOP * self.volu
+    }
+
     fn increment(&mut self, increment: f64) {
-        let mut offset = self.offset + increment;
-        if offset > f64::from(SMP_NUM as u32) {
-            let temp = offset - f64::from(SMP_NUM as u32);
-            offset = if temp > 0.0 { temp } else { 0.0 };
-        }
-        self.offset = offset;
-
-        if let NoiseBuilderWave::Random {
-            start,
-            margin,
-            index,
-            ..
-        } = &mut self.wave
-        {
-            let next_start = i32::from(NOISE_TABLE_RANDOM[*index]);
-            let mut next_index = *index;
-            if next_index >= SMP_NUM_RAND {
-                next_index = 0
+        let smp_num = self.tables.smp_num() as f64;
+        self.offset += increment;
+        // only past the end, as pxtone does, so a random value landing on the end is
+        // held for one more sample
+        if self.offset > smp_num {
+            self.offset -= smp_num;
+            if self.offset >= smp_num {
+                self.offset = 0.0;
             }
-            let next_margin = i32::from(NOISE_TABLE_RANDOM[next_index]) - next_start;
 
-            *start = next_start;
-            *margin = next_margin;
-            *index = next_index;
+            if let NoiseBuilderWave::Random {
+                start,
+                margin,
+                source,
+                ..
+            } = &mut self.wave
+            {
+                *start += *margin;
+                *margin = source.next() - *start;
+            }
         }
     }
 }
@@ -330,10 +418,59 @@ enum NoiseBuilderWave {
         kind: RandomKind,
         start: i32,
         margin: i32,
-        index: usize,
+        source: RandomSource,
     },
 }
 
+/// User seed of a noise and the unit it is drawn for
+#[derive(Clone, Copy)]
+struct RandomSeed {
+    seed: u32,
+    unit_no: usize,
+}
+
+enum RandomSource {
+    /// pxtone's own table, read from `index`
+    Table { index: usize },
+    /// xorshift32
+    Seeded { state: u32 },
+}
+
+impl RandomSource {
+    fn seeded(seed: RandomSeed, kind: &OscillatorKind) -> Self {
+        let stream = seed.unit_no as u32 * 3
+            + match kind {
+                OscillatorKind::Main => 0,
+                OscillatorKind::Freq => 1,
+                OscillatorKind::Volu => 2,
+            };
+        // keep the streams of each oscillator apart and the state non-zero
+        let state = (seed.seed ^ stream.wrapping_mul(0x9e37_79b9)).max(1);
+        RandomSource::Seeded { state }
+    }
+
+    /// Next level, advancing the source
+    fn next(&mut self) -> i32 {
+        match self {
+            RandomSource::Table { index } => {
+                let value = i32::from(random_table()[*index]);
+                *index += 1;
+                if *index >= SMP_NUM_RAND {
+                    *index = 0;
+                }
+                value
+            }
+            RandomSource::Seeded { state } => {
+                *state ^= *state << 13;
+                *state ^= *state >> 17;
+                *state ^= *state << 5;
+                i32::from((*state >> 16) as u16 as i16)
+            }
+        }
+    }
+}
+
+#[derive(Clone, Copy)]
 enum RawKind {
     Sine,
     Saw,
@@ -351,55 +488,152 @@ enum RawKind {
     Saw8,
 }
 
+impl RawKind {
+    const ALL: [RawKind; 14] = [
+        RawKind::Sine,
+        RawKind::Saw,
+        RawKind::Rect,
+        RawKind::Saw2,
+        RawKind::Rect2,
+        RawKind::Tri,
+        RawKind::Rect3,
+        RawKind::Rect4,
+        RawKind::Rect8,
+        RawKind::Rect16,
+        RawKind::Saw3,
+        RawKind::Saw4,
+        RawKind::Saw6,
+        RawKind::Saw8,
+    ];
+}
+
+/// How pxtone steps between random values, by its names for them
 enum RandomKind {
-    Saw,  // Random
-    Rect, // Random2
+    Saw,  // Random: slopes to the next value over a cycle
+    Rect, // Random2: holds each value for a cycle
 }
 
 impl NoiseBuilderWave {
-    fn init_random(kind: &NoiseWave, offset: f32) -> Self {
+    fn init_random(kind: &NoiseWave, offset: f32, mut source: RandomSource) -> Self {
         let kind = match kind {
-            NoiseWave::Random => RandomKind::Rect,
-            NoiseWave::Random2 => RandomKind::Saw,
+            NoiseWave::Random => RandomKind::Saw,
+            NoiseWave::Random2 => RandomKind::Rect,
             _ => unreachable!(),
         };
-        let index = (f64::from(SMP_NUM_RAND as u32) * f64::from(offset) / 100.0) as usize;
+        if let RandomSource::Table { index } = &mut source {
+            // an offset of 100 starts over, where pxtone reads past its table
+            let start = f64::from(SMP_NUM_RAND as u32) * f64::from(offset / 100.0);
+            *index = start as usize % SMP_NUM_RAND;
+        }
         NoiseBuilderWave::Random {
             kind,
             start: 0,
-            margin: i32::from(NOISE_TABLE_RANDOM[index as usize]),
-            index,
+            margin: source.next(),
+            source,
         }
     }
 
-    fn get_sample(&self, offset: u32) -> i32 {
+    fn get_sample(&self, tables: &WaveTables, offset: u32) -> i32 {
         match self {
             NoiseBuilderWave::None => 0,
-            NoiseBuilderWave::Raw { kind } => match kind {
-                RawKind::Sine => i32::from(NOISE_TABLE_SINE[offset as usize]),
-                RawKind::Saw => i32::from(NOISE_TABLE_SAW[offset as usize]),
-                RawKind::Rect => i32::from(NOISE_TABLE_RECT[offset as usize]),
-                RawKind::Saw2 => i32::from(NOISE_TABLE_SAW2[offset as usize]),
-                RawKind::Rect2 => i32::from(NOISE_TABLE_RECT2[offset as usize]),
-                RawKind::Tri => i32::from(NOISE_TABLE_TRI[offset as usize]),
-                RawKind::Rect3 => i32::from(NOISE_TABLE_RECT3[offset as usize]),
-                RawKind::Rect4 => i32::from(NOISE_TABLE_RECT4[offset as usize]),
-                RawKind::Rect8 => i32::from(NOISE_TABLE_RECT8[offset as usize]),
-                RawKind::Rect16 => i32::from(NOISE_TABLE_RECT16[offset as usize]),
-                RawKind::Saw3 => i32::from(NOISE_TABLE_SAW3[offset as usize]),
-                RawKind::Saw4 => i32::from(NOISE_TABLE_SAW4[offset as usize]),
-                RawKind::Saw6 => i32::from(NOISE_TABLE_SAW6[offset as usize]),
-                RawKind::Saw8 => i32::from(NOISE_TABLE_SAW8[offset as usize]),
-            },
+            // an offset landing on the end of the table has not wrapped yet, where pxtone
+            // reads one past it; the wave starts over there
+            NoiseBuilderWave::Raw { kind } => {
+                let offset = offset as usize % tables.smp_num();
+                i32::from(tables.get(*kind, offset))
+            }
             NoiseBuilderWave::Random {
                 kind,
                 start,
                 margin,
                 ..
             } => match kind {
-                RandomKind::Rect => *start + *margin * (offset as i32) / (SMP_NUM as i32),
-                RandomKind::Saw => *start,
+                RandomKind::Saw => *start + *margin * (offset as i32) / (tables.smp_num() as i32),
+                RandomKind::Rect => *start,
             },
         }
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::NoiseBuilder;
+    use crate::error::{Error, ErrorKind};
+    use crate::options::RenderOptions;
+    use crate::pulse::{Noise, NoiseOscillator, NoiseUnit, NoiseWave, OutputFormat, Pcm};
+    use std::io::Cursor;
+
+    const FORMATS: [OutputFormat; 4] = [
+        OutputFormat::U8,
+        OutputFormat::I16,
+        OutputFormat::I24,
+        OutputFormat::F32,
+    ];
+
+    fn drum_bass() -> Noise {
+        let bytes = include_bytes!("../../resources/drum_bass1.ptnoise");
+        Noise::new(Cursor::new(&bytes[..])).unwrap()
+    }
+
+    fn random_noise(offset: f32) -> Noise {
+        let main = NoiseOscillator::new(NoiseWave::Random, false, 100.0, 100.0, offset);
+        let unit = NoiseUnit::new(&[(0, 100)], 0, Some(main), None, None).unwrap();
+        Noise::from_units(4410, vec![unit]).unwrap()
+    }
+
+    #[test]
+    fn random_offset_wraps() {
+        let options = RenderOptions::default();
+        let full = random_noise(100.0).build(&options).unwrap();
+        let zero = random_noise(0.0).build(&options).unwrap();
+        assert_eq!(full, zero);
+    }
+
+    #[test]
+    fn counts_samples_at_the_limits() {
+        let mut noise = random_noise(0.0);
+        noise.set_sample_count_44k(u32::MAX);
+        let frames = noise.sample_count(48000).unwrap();
+        assert_eq!(frames, 522448);
+        let len = NoiseBuilder::sample_len(&noise, 2, 48000).unwrap();
+        assert_eq!(len, 2 * 522448);
+        let options = RenderOptions::new(2, 48000, OutputFormat::I16);
+        assert_eq!(noise.build(&options).unwrap().smp_num(), frames);
+
+        let overflow = |err: Error| matches!(err.kind(), ErrorKind::SampleOverflow(..));
+        assert!(overflow(noise.sample_count(u32::MAX).unwrap_err()));
+        let len = NoiseBuilder::sample_len(&noise, u16::MAX, u32::MAX);
+        assert!(overflow(len.unwrap_err()));
+    }
+
+    #[test]
+    fn builds_each_format() {
+        let noise = drum_bass();
+        for &ch in &[1, 2] {
+            let reference = noise
+                .build(&RenderOptions::new(ch, 44100, OutputFormat::I16))
+                .unwrap();
+            let reference = reference.frames::<f32>().collect::<Vec<_>>();
+            for &format in &FORMATS {
+                let pcm = noise.build(&RenderOptions::new(ch, 44100, format)).unwrap();
+                assert_eq!(pcm.format(), format);
+                assert_eq!(pcm.ch(), ch);
+                assert_eq!(pcm.smp_num() as usize, reference.len());
+
+                let bytes = pcm.clone().into_bytes();
+                let data = reference.len() * usize::from(ch) * format.bytes_per_sample();
+                assert_eq!(bytes.len(), Pcm::WAV_HEADER_SIZE + data);
+
+                // one step of the coarser of the two formats apart
+                let bits = format.bits_per_sample().min(16);
+                let step = 1.0 / 2f32.powi(i32::from(bits) - 1);
+                for (frame, expected) in pcm.frames::<f32>().zip(&reference) {
+                    for (level, expected) in frame.iter().zip(expected) {
+                        assert!((level - expected).abs() <= step, "{:?}", format);
+                    }
+                }
+                assert_eq!(Pcm::new(Cursor::new(bytes)).unwrap(), pcm);
+            }
+        }
+    }
+}
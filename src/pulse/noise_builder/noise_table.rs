@@ -1,3924 +1,141 @@
-pub(super) const SMP_NUM: usize = (super::BASIC_SPS / super::BASIC_FREQUENCY) as usize;
-pub(super) const SMP_NUM_RAND: usize = 44100;
-
-pub(super) static NOISE_TABLE_SINE: [i16; SMP_NUM] = [
-    0, 466, 933, 1400, 1866, 2332, 2797, 3262, 3726, 4190, 4652, 5114, 5574, 6034, 6492, 6949,
-    7405, 7859, 8311, 8762, 9211, 9658, 10103, 10546, 10987, 11426, 11862, 12296, 12727, 13156,
-    13582, 14006, 14427, 14844, 15259, 15670, 16079, 16484, 16886, 17284, 17679, 18070, 18458,
-    18842, 19222, 19598, 19970, 20338, 20702, 21062, 21417, 21768, 22115, 22457, 22795, 23128,
-    23456, 23780, 24099, 24413, 24721, 25025, 25324, 25618, 25906, 26189, 26467, 26740, 27007,
-    27269, 27525, 27775, 28020, 28259, 28493, 28720, 28942, 29158, 29368, 29572, 29770, 29962,
-    30148, 30328, 30501, 30669, 30830, 30985, 31134, 31276, 31412, 31542, 31665, 31782, 31892,
-    31996, 32093, 32184, 32269, 32346, 32418, 32482, 32540, 32592, 32637, 32675, 32706, 32731,
-    32750, 32761, 32766, 32765, 32756, 32741, 32720, 32691, 32657, 32615, 32567, 32512, 32451,
-    32383, 32308, 32227, 32140, 32046, 31945, 31838, 31724, 31604, 31478, 31345, 31206, 31060,
-    30908, 30750, 30586, 30415, 30239, 30056, 29867, 29672, 29471, 29264, 29051, 28832, 28607,
-    28377, 28140, 27898, 27651, 27397, 27138, 26874, 26604, 26329, 26049, 25763, 25472, 25175,
-    24874, 24568, 24256, 23940, 23619, 23293, 22962, 22627, 22287, 21942, 21593, 21240, 20882,
-    20521, 20154, 19784, 19410, 19032, 18650, 18264, 17875, 17482, 17085, 16685, 16282, 15875,
-    15465, 15052, 14636, 14217, 13795, 13370, 12942, 12512, 12079, 11644, 11206, 10767, 10325,
-    9881, 9434, 8986, 8537, 8085, 7632, 7177, 6721, 6263, 5804, 5344, 4883, 4421, 3958, 3494, 3030,
-    2565, 2099, 1633, 1166, 700, 233, -233, -700, -1166, -1633, -2099, -2565, -3030, -3494, -3958,
-    -4421, -4883, -5344, -5804, -6263, -6721, -7177, -7632, -8085, -8537, -8986, -9434, -9881,
-    -10325, -10767, -11206, -11644, -12079, -12512, -12942, -13370, -13795, -14217, -14636, -15052,
-    -15465, -15875, -16282, -16685, -17085, -17482, -17875, -18264, -18650, -19032, -19410, -19784,
-    -20154, -20521, -20882, -21240, -21593, -21942, -22287, -22627, -22962, -23293, -23619, -23940,
-    -24256, -24568, -24874, -25175, -25472, -25763, -26049, -26329, -26604, -26874, -27138, -27397,
-    -27651, -27898, -28140, -28377, -28607, -28832, -29051, -29264, -29471, -29672, -29867, -30056,
-    -30239, -30415, -30586, -30750, -30908, -31060, -31206, -31345, -31478, -31604, -31724, -31838,
-    -31945, -32046, -32140, -32227, -32308, -32383, -32451, -32512, -32567, -32615, -32657, -32691,
-    -32720, -32741, -32756, -32765, -32766, -32761, -32750, -32731, -32706, -32675, -32637, -32592,
-    -32540, -32482, -32418, -32346, -32269, -32184, -32093, -31996, -31892, -31782, -31665, -31542,
-    -31412, -31276, -31134, -30985, -30830, -30669, -30501, -30328, -30148, -29962, -29770, -29572,
-    -29368, -29158, -28942, -28720, -28493, -28259, -28020, -27775, -27525, -27269, -27007, -26740,
-    -26467, -26189, -25906, -25618, -25324, -25025, -24721, -24413, -24099, -23780, -23456, -23128,
-    -22795, -22457, -22115, -21768, -21417, -21062, -20702, -20338, -19970, -19598, -19222, -18842,
-    -18458, -18070, -17679, -17284, -16886, -16484, -16079, -15670, -15259, -14844, -14427, -14006,
-    -13582, -13156, -12727, -12296, -11862, -11426, -10987, -10546, -10103, -9658, -9211, -8762,
-    -8311, -7859, -7405, -6949, -6492, -6034, -5574, -5114, -4652, -4190, -3726, -3262, -2797,
-    -2332, -1866, -1400, -933, -466,
-];
+use std::f64::consts::PI;
+use std::sync::OnceLock;
 
-pub(super) static NOISE_TABLE_SAW: [i16; SMP_NUM] = [
-    32767, 32618, 32469, 32321, 32172, 32023, 31875, 31726, 31578, 31429, 31280, 31132, 30983,
-    30835, 30686, 30537, 30389, 30240, 30092, 29943, 29794, 29646, 29497, 29349, 29200, 29051,
-    28903, 28754, 28606, 28457, 28308, 28160, 28011, 27863, 27714, 27565, 27417, 27268, 27120,
-    26971, 26822, 26674, 26525, 26377, 26228, 26079, 25931, 25782, 25634, 25485, 25336, 25188,
-    25039, 24891, 24742, 24593, 24445, 24296, 24148, 23999, 23850, 23702, 23553, 23405, 23256,
-    23107, 22959, 22810, 22661, 22513, 22364, 22216, 22067, 21918, 21770, 21621, 21473, 21324,
-    21175, 21027, 20878, 20730, 20581, 20432, 20284, 20135, 19987, 19838, 19689, 19541, 19392,
-    19244, 19095, 18946, 18798, 18649, 18501, 18352, 18203, 18055, 17906, 17758, 17609, 17460,
-    17312, 17163, 17015, 16866, 16717, 16569, 16420, 16272, 16123, 15974, 15826, 15677, 15529,
-    15380, 15231, 15083, 14934, 14786, 14637, 14488, 14340, 14191, 14043, 13894, 13745, 13597,
-    13448, 13299, 13151, 13002, 12854, 12705, 12556, 12408, 12259, 12111, 11962, 11813, 11665,
-    11516, 11368, 11219, 11070, 10922, 10773, 10625, 10476, 10327, 10179, 10030, 9882, 9733, 9584,
-    9436, 9287, 9139, 8990, 8841, 8693, 8544, 8396, 8247, 8098, 7950, 7801, 7653, 7504, 7355, 7207,
-    7058, 6910, 6761, 6612, 6464, 6315, 6167, 6018, 5869, 5721, 5572, 5424, 5275, 5126, 4978, 4829,
-    4681, 4532, 4383, 4235, 4086, 3937, 3789, 3640, 3492, 3343, 3194, 3046, 2897, 2749, 2600, 2451,
-    2303, 2154, 2006, 1857, 1708, 1560, 1411, 1263, 1114, 965, 817, 668, 520, 371, 222, 74, -74,
-    -222, -371, -520, -668, -817, -965, -1114, -1263, -1411, -1560, -1708, -1857, -2006, -2154,
-    -2303, -2451, -2600, -2749, -2897, -3046, -3194, -3343, -3492, -3640, -3789, -3937, -4086,
-    -4235, -4383, -4532, -4681, -4829, -4978, -5126, -5275, -5424, -5572, -5721, -5869, -6018,
-    -6167, -6315, -6464, -6612, -6761, -6910, -7058, -7207, -7355, -7504, -7653, -7801, -7950,
-    -8098, -8247, -8396, -8544, -8693, -8841, -8990, -9139, -9287, -9436, -9584, -9733, -9882,
-    -10030, -10179, -10327, -10476, -10625, -10773, -10922, -11070, -11219, -11368, -11516, -11665,
-    -11813, -11962, -12111, -12259, -12408, -12556, -12705, -12854, -13002, -13151, -13299, -13448,
-    -13597, -13745, -13894, -14043, -14191, -14340, -14488, -14637, -14786, -14934, -15083, -15231,
-    -15380, -15529, -15677, -15826, -15974, -16123, -16272, -16420, -16569, -16717, -16866, -17015,
-    -17163, -17312, -17460, -17609, -17758, -17906, -18055, -18203, -18352, -18501, -18649, -18798,
-    -18946, -19095, -19244, -19392, -19541, -19689, -19838, -19987, -20135, -20284, -20432, -20581,
-    -20730, -20878, -21027, -21175, -21324, -21473, -21621, -21770, -21918, -22067, -22216, -22364,
-    -22513, -22661, -22810, -22959, -23107, -23256, -23405, -23553, -23702, -23850, -23999, -24148,
-    -24296, -24445, -24593, -24742, -24891, -25039, -25188, -25336, -25485, -25634, -25782, -25931,
-    -26079, -26228, -26377, -26525, -26674, -26822, -26971, -27120, -27268, -27417, -27565, -27714,
-    -27863, -28011, -28160, -28308, -28457, -28606, -28754, -28903, -29051, -29200, -29349, -29497,
-    -29646, -29794, -29943, -30092, -30240, -30389, -30537, -30686, -30835, -30983, -31132, -31280,
-    -31429, -31578, -31726, -31875, -32023, -32172, -32321, -32469, -32618,
-];
+use super::RawKind;
 
-pub(super) static NOISE_TABLE_RECT: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767,
-];
+/// Samples in one cycle of the wave tables pxtone uses
+pub(crate) const SMP_NUM: usize = (super::BASIC_SPS / super::BASIC_FREQUENCY) as usize;
+pub(super) const SMP_NUM_RAND: usize = 44100;
 
-pub(super) static NOISE_TABLE_SAW2: [i16; SMP_NUM] = [
-    0, 7446, 14751, 21780, 28405, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32747, 32171, 31676,
-    31273, 30970, 30769, 30665, 30649, 30708, 30824, 30977, 31145, 31306, 31439, 31524, 31545,
-    31489, 31348, 31119, 30802, 30405, 29937, 29412, 28847, 28261, 27673, 27103, 26569, 26087,
-    25669, 25325, 25059, 24872, 24760, 24716, 24729, 24783, 24863, 24952, 25031, 25084, 25095,
-    25052, 24944, 24767, 24517, 24197, 23811, 23370, 22885, 22370, 21841, 21315, 20807, 20332,
-    19903, 19530, 19221, 18979, 18802, 18689, 18631, 18619, 18640, 18679, 18722, 18753, 18759,
-    18726, 18643, 18503, 18301, 18036, 17710, 17329, 16902, 16438, 15952, 15456, 14966, 14496,
-    14058, 13663, 13321, 13036, 12810, 12644, 12533, 12470, 12446, 12448, 12463, 12479, 12481,
-    12456, 12394, 12284, 12121, 11901, 11623, 11291, 10909, 10486, 10033, 9562, 9086, 8618, 8172,
-    7758, 7387, 7065, 6799, 6588, 6433, 6327, 6265, 6236, 6230, 6233, 6233, 6217, 6173, 6091, 5962,
-    5781, 5545, 5255, 4913, 4525, 4101, 3650, 3186, 2719, 2264, 1832, 1434, 1080, 775, 525, 328,
-    185, 89, 32, 7, 0, 0, -7, -32, -89, -185, -328, -525, -775, -1080, -1434, -1832, -2264, -2719,
-    -3186, -3650, -4101, -4525, -4913, -5255, -5545, -5781, -5962, -6091, -6173, -6217, -6233,
-    -6233, -6230, -6236, -6265, -6327, -6433, -6588, -6799, -7065, -7387, -7758, -8172, -8618,
-    -9086, -9562, -10033, -10486, -10909, -11291, -11623, -11901, -12121, -12284, -12394, -12456,
-    -12481, -12479, -12463, -12448, -12446, -12470, -12533, -12644, -12810, -13036, -13321, -13663,
-    -14058, -14496, -14966, -15456, -15952, -16438, -16902, -17329, -17710, -18036, -18301, -18503,
-    -18643, -18726, -18759, -18753, -18722, -18679, -18640, -18619, -18631, -18689, -18802, -18979,
-    -19221, -19530, -19903, -20332, -20807, -21315, -21841, -22370, -22885, -23370, -23811, -24197,
-    -24517, -24767, -24944, -25052, -25095, -25084, -25031, -24952, -24863, -24783, -24729, -24716,
-    -24760, -24872, -25059, -25325, -25669, -26087, -26569, -27103, -27673, -28261, -28847, -29412,
-    -29937, -30405, -30802, -31119, -31348, -31489, -31545, -31524, -31439, -31306, -31145, -30977,
-    -30824, -30708, -30649, -30665, -30769, -30970, -31273, -31676, -32171, -32747, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -28405, -21780, -14751,
-    -7446,
-];
+const SAMPLING_TOP: i32 = i16::MAX as i32;
 
-pub(super) static NOISE_TABLE_RECT2: [i16; SMP_NUM] = [
-    0, 3724, 7384, 10918, 14268, 17382, 20213, 22726, 24893, 26697, 28129, 29195, 29907, 30288,
-    30368, 30184, 29779, 29199, 28492, 27705, 26886, 26078, 25319, 24643, 24076, 23637, 23340,
-    23188, 23179, 23304, 23547, 23891, 24310, 24781, 25276, 25769, 26236, 26655, 27007, 27277,
-    27455, 27537, 27522, 27416, 27226, 26967, 26654, 26305, 25938, 25573, 25229, 24921, 24665,
-    24473, 24350, 24303, 24331, 24430, 24595, 24814, 25074, 25363, 25664, 25962, 26241, 26489,
-    26692, 26842, 26931, 26957, 26920, 26822, 26669, 26471, 26238, 25983, 25719, 25459, 25218,
-    25007, 24836, 24714, 24646, 24635, 24681, 24781, 24930, 25118, 25337, 25574, 25817, 26055,
-    26273, 26462, 26613, 26716, 26768, 26767, 26712, 26606, 26456, 26269, 26055, 25825, 25591,
-    25365, 25159, 24983, 24846, 24756, 24716, 24730, 24795, 24909, 25067, 25259, 25477, 25708,
-    25941, 26165, 26366, 26536, 26665, 26746, 26774, 26749, 26671, 26543, 26372, 26167, 25938,
-    25696, 25454, 25224, 25019, 24850, 24725, 24651, 24633, 24673, 24768, 24916, 25108, 25336,
-    25588, 25851, 26112, 26358, 26575, 26752, 26878, 26946, 26952, 26894, 26774, 26596, 26370,
-    26105, 25814, 25513, 25216, 24940, 24698, 24505, 24372, 24308, 24317, 24402, 24561, 24786,
-    25069, 25397, 25754, 26122, 26483, 26817, 27105, 27331, 27480, 27541, 27508, 27378, 27153,
-    26840, 26453, 26008, 25524, 25027, 24541, 24092, 23708, 23412, 23225, 23166, 23246, 23471,
-    23839, 24344, 24968, 25690, 26478, 27297, 28105, 28858, 29508, 30006, 30306, 30363, 30137,
-    29594, 28708, 27459, 25841, 23854, 21512, 18835, 15857, 12619, 9170, 5566, 1866, -1866, -5566,
-    -9170, -12619, -15857, -18835, -21512, -23854, -25841, -27459, -28708, -29594, -30137, -30363,
-    -30306, -30006, -29508, -28858, -28105, -27297, -26478, -25690, -24968, -24344, -23839, -23471,
-    -23246, -23166, -23225, -23412, -23708, -24092, -24541, -25027, -25524, -26008, -26453, -26840,
-    -27153, -27378, -27508, -27541, -27480, -27331, -27105, -26817, -26483, -26122, -25754, -25397,
-    -25069, -24786, -24561, -24402, -24317, -24308, -24372, -24505, -24698, -24940, -25216, -25513,
-    -25814, -26105, -26370, -26596, -26774, -26894, -26952, -26946, -26878, -26752, -26575, -26358,
-    -26112, -25851, -25588, -25336, -25108, -24916, -24768, -24673, -24633, -24651, -24725, -24850,
-    -25019, -25224, -25454, -25696, -25938, -26167, -26372, -26543, -26671, -26749, -26774, -26746,
-    -26665, -26536, -26366, -26165, -25941, -25708, -25477, -25259, -25067, -24909, -24795, -24730,
-    -24716, -24756, -24846, -24983, -25159, -25365, -25591, -25825, -26055, -26269, -26456, -26606,
-    -26712, -26767, -26768, -26716, -26613, -26462, -26273, -26055, -25817, -25574, -25337, -25118,
-    -24930, -24781, -24681, -24635, -24646, -24714, -24836, -25007, -25218, -25459, -25719, -25983,
-    -26238, -26471, -26669, -26822, -26920, -26957, -26931, -26842, -26692, -26489, -26241, -25962,
-    -25664, -25363, -25074, -24814, -24595, -24430, -24331, -24303, -24350, -24473, -24665, -24921,
-    -25229, -25573, -25938, -26305, -26654, -26967, -27226, -27416, -27522, -27537, -27455, -27277,
-    -27007, -26655, -26236, -25769, -25276, -24781, -24310, -23891, -23547, -23304, -23179, -23188,
-    -23340, -23637, -24076, -24643, -25319, -26078, -26886, -27705, -28492, -29199, -29779, -30184,
-    -30368, -30288, -29907, -29195, -28129, -26697, -24893, -22726, -20213, -17382, -14268, -10918,
-    -7384, -3724,
-];
+/// One cycle of each raw wave, synthesized as pxtone does at startup
+pub(super) struct WaveTables {
+    smp_num: usize,
+    waves: Vec<Vec<i16>>,
+}
 
-pub(super) static NOISE_TABLE_TRI: [i16; SMP_NUM] = [
-    0, 297, 595, 893, 1191, 1489, 1787, 2085, 2383, 2680, 2978, 3276, 3574, 3872, 4170, 4468, 4766,
-    5063, 5361, 5659, 5957, 6255, 6553, 6851, 7149, 7447, 7744, 8042, 8340, 8638, 8936, 9234, 9532,
-    9830, 10127, 10425, 10723, 11021, 11319, 11617, 11915, 12213, 12511, 12808, 13106, 13404,
-    13702, 14000, 14298, 14596, 14894, 15191, 15489, 15787, 16085, 16383, 16681, 16979, 17277,
-    17575, 17872, 18170, 18468, 18766, 19064, 19362, 19660, 19958, 20255, 20553, 20851, 21149,
-    21447, 21745, 22043, 22341, 22639, 22936, 23234, 23532, 23830, 24128, 24426, 24724, 25022,
-    25319, 25617, 25915, 26213, 26511, 26809, 27107, 27405, 27703, 28000, 28298, 28596, 28894,
-    29192, 29490, 29788, 30086, 30383, 30681, 30979, 31277, 31575, 31873, 32171, 32469, 32767,
-    32469, 32171, 31873, 31575, 31277, 30979, 30681, 30383, 30086, 29788, 29490, 29192, 28894,
-    28596, 28298, 28000, 27703, 27405, 27107, 26809, 26511, 26213, 25915, 25617, 25319, 25022,
-    24724, 24426, 24128, 23830, 23532, 23234, 22936, 22639, 22341, 22043, 21745, 21447, 21149,
-    20851, 20553, 20255, 19958, 19660, 19362, 19064, 18766, 18468, 18170, 17872, 17575, 17277,
-    16979, 16681, 16383, 16085, 15787, 15489, 15191, 14894, 14596, 14298, 14000, 13702, 13404,
-    13106, 12808, 12511, 12213, 11915, 11617, 11319, 11021, 10723, 10425, 10127, 9830, 9532, 9234,
-    8936, 8638, 8340, 8042, 7744, 7447, 7149, 6851, 6553, 6255, 5957, 5659, 5361, 5063, 4766, 4468,
-    4170, 3872, 3574, 3276, 2978, 2680, 2383, 2085, 1787, 1489, 1191, 893, 595, 297, 0, -297, -595,
-    -893, -1191, -1489, -1787, -2085, -2383, -2680, -2978, -3276, -3574, -3872, -4170, -4468,
-    -4766, -5063, -5361, -5659, -5957, -6255, -6553, -6851, -7149, -7447, -7744, -8042, -8340,
-    -8638, -8936, -9234, -9532, -9830, -10127, -10425, -10723, -11021, -11319, -11617, -11915,
-    -12213, -12511, -12808, -13106, -13404, -13702, -14000, -14298, -14596, -14894, -15191, -15489,
-    -15787, -16085, -16383, -16681, -16979, -17277, -17575, -17872, -18170, -18468, -18766, -19064,
-    -19362, -19660, -19958, -20255, -20553, -20851, -21149, -21447, -21745, -22043, -22341, -22639,
-    -22936, -23234, -23532, -23830, -24128, -24426, -24724, -25022, -25319, -25617, -25915, -26213,
-    -26511, -26809, -27107, -27405, -27703, -28000, -28298, -28596, -28894, -29192, -29490, -29788,
-    -30086, -30383, -30681, -30979, -31277, -31575, -31873, -32171, -32469, -32767, -32471, -32176,
-    -31881, -31586, -31291, -30995, -30700, -30405, -30110, -29815, -29519, -29224, -28929, -28634,
-    -28339, -28043, -27748, -27453, -27158, -26863, -26567, -26272, -25977, -25682, -25387, -25091,
-    -24796, -24501, -24206, -23911, -23615, -23320, -23025, -22730, -22435, -22139, -21844, -21549,
-    -21254, -20959, -20663, -20368, -20073, -19778, -19483, -19187, -18892, -18597, -18302, -18007,
-    -17711, -17416, -17121, -16826, -16531, -16235, -15940, -15645, -15350, -15055, -14759, -14464,
-    -14169, -13874, -13579, -13283, -12988, -12693, -12398, -12103, -11807, -11512, -11217, -10922,
-    -10627, -10331, -10036, -9741, -9446, -9151, -8855, -8560, -8265, -7970, -7675, -7379, -7084,
-    -6789, -6494, -6199, -5903, -5608, -5313, -5018, -4723, -4427, -4132, -3837, -3542, -3247,
-    -2951, -2656, -2361, -2066, -1771, -1475, -1180, -885, -590, -295,
-];
+impl WaveTables {
+    /// Tables with `smp_num` samples per cycle
+    pub(super) fn new(smp_num: usize) -> Self {
+        let waves = RawKind::ALL.iter().map(|&kind| Self::wave(kind, smp_num)).collect();
+        Self { smp_num, waves }
+    }
 
-pub(super) static NOISE_TABLE_RECT3: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-];
+    /// Tables of `SMP_NUM` samples, built on first use
+    pub(super) fn original() -> &'static Self {
+        static TABLES: OnceLock<WaveTables> = OnceLock::new();
+        TABLES.get_or_init(|| Self::new(SMP_NUM))
+    }
 
-pub(super) static NOISE_TABLE_RECT4: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767,
-];
+    pub(super) fn smp_num(&self) -> usize {
+        self.smp_num
+    }
 
-pub(super) static NOISE_TABLE_RECT8: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767,
-];
+    pub(super) fn get(&self, kind: RawKind, offset: usize) -> i16 {
+        self.waves[kind as usize][offset]
+    }
 
-pub(super) static NOISE_TABLE_RECT16: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-];
+    fn wave(kind: RawKind, n: usize) -> Vec<i16> {
+        let top = SAMPLING_TOP;
+        match kind {
+            RawKind::Sine => Self::overtones(n, &[1]),
+            RawKind::Saw => {
+                let work = f64::from(top) + f64::from(top);
+                (0..n)
+                    .map(|s| (f64::from(top) - work * s as f64 / n as f64) as i16)
+                    .collect()
+            }
+            RawKind::Rect => Self::steps(n, &[(2, top), (1, -top)]),
+            RawKind::Saw2 => Self::overtones(n, &(1..=16).collect::<Vec<_>>()),
+            RawKind::Rect2 => Self::overtones(n, &[1, 3, 5, 7, 9, 11, 13, 15]),
+            RawKind::Tri => Self::triangle(n),
+            RawKind::Rect3 => Self::steps(n, &[(3, top), (1, -top)]),
+            RawKind::Rect4 => Self::steps(n, &[(4, top), (1, -top)]),
+            RawKind::Rect8 => Self::steps(n, &[(8, top), (1, -top)]),
+            RawKind::Rect16 => Self::steps(n, &[(16, top), (1, -top)]),
+            RawKind::Saw3 => Self::levels(n, &[top, 0, -top]),
+            RawKind::Saw4 => Self::levels(n, &[top, top / 3, -top / 3, -top]),
+            RawKind::Saw6 => Self::levels(
+                n,
+                &[top, top - top * 2 / 5, top / 5, -top / 5, -top + top * 2 / 5, -top],
+            ),
+            RawKind::Saw8 => Self::levels(
+                n,
+                &[
+                    top,
+                    top - top * 2 / 7,
+                    top - top * 4 / 7,
+                    top / 7,
+                    -top / 7,
+                    -top + top * 4 / 7,
+                    -top + top * 2 / 7,
+                    -top,
+                ],
+            ),
+        }
+    }
 
-pub(super) static NOISE_TABLE_SAW3: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0,
-    0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767,
-];
+    /// Sum of the harmonics `xs`, each at 1/x of the fundamental
+    fn overtones(n: usize, xs: &[i32]) -> Vec<i16> {
+        (0..n)
+            .map(|s| {
+                let work = xs.iter().fold(0.0, |work, &x| {
+                    let sss = 2.0 * PI * f64::from(x) * s as f64 / n as f64;
+                    work + sss.sin() * 128.0 / f64::from(x) / 128.0
+                });
+                (work.clamp(-1.0, 1.0) * f64::from(SAMPLING_TOP)) as i16
+            })
+            .collect()
+    }
 
-pub(super) static NOISE_TABLE_SAW4: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922,
-    10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, 10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922, -10922,
-    -10922, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767,
-];
+    fn triangle(n: usize) -> Vec<i16> {
+        let n = n as i32;
+        let points = [(0, 0), (n / 4, 128), (n * 3 / 4, -128), (n, 0)];
+        (0..n)
+            .map(|i| {
+                let c = points.iter().position(|&(x, _)| x > i).unwrap();
+                let ((x1, y1), (x2, y2)) = (points[c - 1], points[c]);
+                let work = f64::from(y1)
+                    + f64::from(y2 - y1) * f64::from(i - x1) / f64::from(x2 - x1);
+                let work = work * 128.0 / 128.0 / 128.0;
+                (work * f64::from(SAMPLING_TOP)) as i16
+            })
+            .collect()
+    }
 
-pub(super) static NOISE_TABLE_SAW6: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 19661, 19661, 19661, 19661, 19661,
-    19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661,
-    19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661,
-    19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661,
-    19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661,
-    19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661, 19661,
-    19661, 19661, 19661, 19661, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553,
-    6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553,
-    6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553,
-    6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553,
-    6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, 6553, -6553,
-    -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553,
-    -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553,
-    -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553,
-    -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553,
-    -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553,
-    -6553, -6553, -6553, -6553, -6553, -6553, -6553, -6553, -19661, -19661, -19661, -19661, -19661,
-    -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661,
-    -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661,
-    -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661,
-    -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661,
-    -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661,
-    -19661, -19661, -19661, -19661, -19661, -19661, -19661, -19661, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-];
+    /// `(divisor, level)`: each level lasts until `n / divisor`
+    fn steps(n: usize, steps: &[(usize, i32)]) -> Vec<i16> {
+        let mut wave = Vec::with_capacity(n);
+        for &(divisor, level) in steps {
+            wave.resize((n / divisor).max(wave.len()), level as i16);
+        }
+        wave
+    }
 
-pub(super) static NOISE_TABLE_SAW8: [i16; SMP_NUM] = [
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767, 32767,
-    32767, 32767, 32767, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405,
-    23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405,
-    23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405,
-    23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405, 23405,
-    23405, 23405, 23405, 23405, 23405, 23405, 14043, 14043, 14043, 14043, 14043, 14043, 14043,
-    14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043,
-    14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043,
-    14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043,
-    14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 14043, 4681, 4681, 4681, 4681, 4681,
-    4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681,
-    4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681,
-    4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681, 4681,
-    4681, 4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681,
-    -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681,
-    -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681,
-    -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681, -4681,
-    -4681, -4681, -4681, -4681, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043,
-    -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043,
-    -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043,
-    -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043,
-    -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -14043, -23405,
-    -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405,
-    -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405,
-    -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405,
-    -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405, -23405,
-    -23405, -23405, -23405, -23405, -23405, -23405, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767, -32767,
-    -32767, -32767,
-];
+    /// Levels of equal length
+    fn levels(n: usize, levels: &[i32]) -> Vec<i16> {
+        let mut wave = Vec::with_capacity(n);
+        for (i, &level) in levels.iter().enumerate() {
+            wave.resize(n * (i + 1) / levels.len(), level as i16);
+        }
+        wave
+    }
+}
 
-pub(super) static NOISE_TABLE_RANDOM: [i16; SMP_NUM_RAND] = [
-    -13108, 4113, -8740, -4627, -13877, -18249, -32126, 14651, -16965, -2314, -20044, -21848,
-    23644, 1031, 25440, 26471, -14390, 12591, -1544, 10027, 8993, 19530, 27503, -17993, 9765,
-    -8993, 1027, -7456, -7194, -14650, -21334, 28787, 7453, -28531, -21843, 14907, -5916, 7971,
-    1800, 11046, 11826, 22617, -29818, -8221, 26987, 20041, -19273, 3, -17740, -18502, 28274,
-    11302, -26470, -16188, 24153, 7455, 30843, -25963, 4115, -22358, -16968, 25190, 7712, -31104,
-    -24412, 9255, -13372, -5137, -19529, -22881, 22106, -1540, 22096, 19536, -24414, -3348, -29037,
-    32641, 5134, -28781, -24157, 13873, -11049, 2059, -7460, -6166, -14646, -19282, 30843, 10541,
-    -22367, -12591, 29303, 18497, -18246, -1280, -17741, -19531, 26734, 8988, -30069, -22611,
-    14386, -8225, 4376, -2064, 2057, -1537, 2050, 258, 1033, 2821, 3599, 5145, 10274, 15164, 24163,
-    -24679, -771, -26980, -26221, 12080, -15416, -1806, -17732, -20813, 28521, 7198, -30837,
-    -22109, 11825, -11049, 2051, -9508, -8222, -16710, -25442, 22619, -1548, 20306, 17993, -25707,
-    -8479, 30330, 23381, -12335, 9771, -779, 8227, 6173, 16440, 21848, -28523, -4635, 31358, 25448,
-    -6435, 17738, 10028, 30316, -26467, 2319, -21343, -20299, 22109, 4615, 25704, 28534, -8493,
-    18766, 8488, 30314, -28009, 265, -24429, -25439, 13373, -8496, 3347, -7189, -272, -9246,
-    -11558, -17234, -30577, 15685, -11067, 2578, -10274, -3871, -16440, -22096, 30825, 6434,
-    -29807, -19548, 13631, -7192, 10009, 267, 9256, 13093, 19799, 31872, -10295, 18772, 7457,
-    30054, -30830, -1796, -29056, 31879, 1803, -28029, -29031, 7201, -17750, -13354, -32634, 23628,
-    -11556, 10287, 3067, 10804, 12086, 27225, -28775, -3335, -28030, 31621, 1806, -27774, -28518,
-    7204, -16724, -12070, -31089, 26967, -6417, 18000, 16173, 32133, -19780, 16688, -4877, 9006,
-    8464, 15940, 21599, -23662, -3593, -30315, -29573, 4118, -28260, -19807, 15684, -6673, 13091,
-    4633, 15429, 24142, -27750, -5903, -29828, 28020, -3848, 27742, 22109, -17470, 7954, -11046,
-    -4877, -12863, -19270, 31618, 15408, -19785, -6162, -23142, -30579, 10030, -17489, -8734,
-    -28263, 31599, 2061, 31875, -28540, 1805, -28265, -23400, 12086, -12589, 2558, -11816, -10533,
-    -19544, -31862, 12855, -15947, -4877, -22354, -24171, 17226, -8220, 11811, 1806, 12597, 17208,
-    28020, -21328, 9242, -13616, -5394, -16715, -23639, 24162, 2818, 25705, 27503, -10033, 15940,
-    4887, 23377, 26734, -16445, 12584, -5136, 6173, 3332, 8485, 10542, 21322, 30844, -14645, 18239,
-    2574, 19793, 24407, -22356, 776, -19285, -19529, 25192, 7958, 32385, -26723, 7702, -19531,
-    -13359, -31105, 20562, -11818, 10274, -1799, 6945, 6676, 13621, 18767, -31618, -12851, 19282,
-    7961, 27498, -31862, -2834, 30840, 26221, -6690, 19276, 10801, 32117, -22873, 7204, -13374,
-    -6425, -22094, -26224, 16963, -11301, 7702, -3599, 1808, 505, 2313, 523, 5131, 5654, 8490,
-    16439, 24929, -26463, 762, -25701, -27234, 14641, -12338, -248, -10546, -10539, -23635, -32134,
-    10022, -24407, -12345, 28784, 14144, -20313, -6169, -29032, 32630, 3598, -31603, -25710, 7968,
-    -19782, -9519, -29811, 24166, -3095, 20306, 15171, -27254, -12848, 23139, 13096, -29811,
-    -19010, 19265, -256, 16714, 19008, -30069, -13356, 24406, 10795, -32375, -19285, 13366, -7704,
-    7702, -257, 5405, 7188, 12593, 17741, 32374, -15421, 14658, 1533, 16191, 15429, -31621, -16192,
-    15173, 1532, 16705, 15687, -30594, -14907, 17230, 4873, 22358, 24426, -16202, 8224, -10528,
-    247, -10281, -12584, -20570, 32382, 9262, -21342, -12080, 29309, 19779, -16193, 526, -12862,
-    -12081, -28258, 28002, 255, 24942, 28002, -12082, 12350, 3073, 16188, 15691, -30852, -14396,
-    16463, 4872, 22355, 23402, -16974, 7193, -13351, -3353, -16194, -23117, 29030, 6423, 32138,
-    -24170, 8223, -19007, -7979, -26986, 27511, 3330, 31096, 31366, -269, 31097, 27768, -3611,
-    23902, 17231, -21088, -4112, -28515, -29312, 7454, -24918, -14149, 25959, 8750, -27257, -19017,
-    15947, 756, 16193, 13634, -31884, -18760, 11322, -3358, 7199, 271, 11805, 11311, 19546, -30344,
-    -11563, 19804, 12576, 31870, -24915, 11035, -14135, -6925, -17235, -24415, 20061, -274, 19277,
-    15178, -26746, -12078, 22632, 14889, -28270, -17461, 23885, 6169, 26229, -29058, -3084, 29314,
-    30310, -5912, 20063, 18231, -26987, -13091, 29283, 16447, -23886, -3614, -27500, 30342, 6667,
-    -28272, -25685, 15149, -10026, 788, -5413, -4115, -14118, -14408, -28016, 18522, -5414, 13363,
-    3359, 21057, 24671, -24398, 4353, -19535, -19772, 30054, 10792, -29025, -14408, 22358, 3615,
-    30053, -31613, -5895, 31853, 26213, -11550, 18233, 6938, 21346, 31854, -12081, 15693, 7182,
-    23385, 26487, -12094, 14648, -1527, 16691, 15419, 28285, -18262, 10023, -11809, 1785, -10024,
-    -11809, -18518, -30327, 13121, -13636, -515, -17976, -14921, 32639, 13893, -15179, -1286,
-    -20545, -18006, 26985, 4899, -29572, -24673, 6956, -13382, -6426, -24398, -26489, 14649,
-    -16175, 2810, -13365, -14890, -24175, 26471, -2040, 28511, 26471, -14634, 15662, 1028, 12865,
-    17718, 30583, -21060, 13093, -7712, 1301, -2586, -1030, -7951, -5156, -12852, -22343, -31370,
-    12078, -23372, -7469, -30841, 23146, -3615, 19276, 11581, -30344, -19018, 11839, -2589, 8740,
-    1816, 15401, 16707, 27773, -16211, 10797, -9494, 5893, -4111, -2554, -2075, -5139, -11549,
-    -12098, -24157, 24946, 5379, 30070, 31114, 239, 31098, 27002, -2846, 23646, 16465, -20580,
-    -4625, -29795, -29575, 5656, -28254, -17753, 18764, -3325, 20284, 16194, 32398, -12099, 19279,
-    3100, 27479, 29559, -12578, 21826, 8228, 26229, -26234, -1025, -31339, -27519, 5658, -25686,
-    -15183, 23392, 4384, -32660, -29551, -756, -25207, -27238, 9011, -12872, -5136, -22343, -22124,
-    19794, -6410, 18484, 10799, 25458, -24179, -252, -28256, -23408, 12342, -14636, 2807, -13359,
-    -14122, -22636, 27248, 1042, -32146, -32634, -3070, -30604, 30332, -3842, 31335, 25963, -11553,
-    19000, 5917, 21857, 32364, -12845, 16204, 7949, 22878, 27512, -10556, 15426, 1555, 21826,
-    21851, -25174, 1523, -25181, -26973, 17972, -10276, 4126, -1305, 1291, -3329, 2808, -2051,
-    -2814, -20, -4364, -7954, -7473, -16957, -28000, 25424, -3851, 17748, 18742, -30066, -15149,
-    24911, 8742, 30083, -22121, 6687, -18749, -7472, -27751, 26998, 4093, 29561, 30339, -791,
-    27763, 23657, -9016, 12601, 270, 18226, 16456, 31367, -12358, 16714, 1041, 23365, 22111,
-    -23375, 4347, -21323, -20291, 29277, 6691, -32628, -20582, 9776, -13611, 1521, -14640, -15924,
-    -25464, 21598, -6416, 20027, 11061, 28793, -20837, 5151, -17726, -7730, -28516, 27250, 3835,
-    28025, 29820, -2591, 23914, 19283, -16984, -1272, -20296, -16213, 25457, 7204, -27265, -23631,
-    12345, -5421, 3099, -4362, 4603, -3840, -1278, 748, -4355, -5647, -4392, -13864, -20296,
-    -28550, 12865, -17470, 1006, -20289, -21068, 29534, 4641, 32645, -22895, 5670, -18500, -7475,
-    -30310, 26476, 1777, 23918, 24420, -11588, 8242, -4366, 9231, 275, 8741, 14371, 18522, 32128,
-    -9531, 17752, 7456, 30818, -32107, -2054, -28806, 29831, 260, -29835, 31116, 261, -28294,
-    32658, 3089, -23669, -25425, 14912, -4138, 5674, 6, 12310, 7216, 17996, 31842, -20798, 9259,
-    -4654, -751, -7190, -1056, -13601, -16442, -23158, 20581, -4107, 23104, 13642, -30065, -9793,
-    20068, 9000, -29583, -26193, 8230, -10823, -8203, -20811, -21874, 17241, -6163, 17963, 6190,
-    22878, -29583, -12315, 22108, 16678, -32105, -16957, 23104, 792, 22621, 30043, -18227, 10286,
-    -1056, 3620, 1034, 11794, 7218, 17482, 31840, -21824, 8231, -6198, -3833, -11816, -8254,
-    -25935, 29562, 11022, -30562, -21325, 20789, -5891, 12858, 14107, 21865, -31604, -2599, 25978,
-    21339, -10824, 4905, -7704, 4597, -8717, -5905, -7482, -18997, -28264, 25415, -8204, 15171,
-    14107, 24178, -29291, 2028, -32363, -32375, 7683, -29537, -23894, 18735, -9749, 6691, 3828,
-    5929, 7462, 20276, 23148, -24407, 5627, -23370, -20038, 28758, 4130, 30848, -23928, 2075,
-    -23638, -14933, 21865, 5147, -31639, -31592, 265, -24187, -29022, 10032, -11595, -6663, -20808,
-    -20076, 19552, -2819, 23873, 15954, -27749, -4655, 27777, 21082, -9282, 6190, -4877, 8453,
-    -2035, 4633, 9738, 9016, 16969, -32411, -20797, 10288, -3114, 1564, -3335, 5625, -3576, 264,
-    4339, -1263, 1036, 7679, 2850, 8489, 19244, 21868, -26464, 3310, -29019, -27749, 16418, -16941,
-    -2563, -12109, -20282, 31105, 18218, -21568, -5390, -19818, -30563, 13115, -10053, -2549,
-    -14642, -9796, -30048, 23652, 1255, 19297, 18512, -20077, -7175, -29547, -29072, 1307, -29805,
-    -20848, 9018, -13615, 3054, -16426, -15157, -24188, 20326, -5392, 22074, 10817, 31616, -15963,
-    9533, -7450, 8968, -4603, 3345, 5883, 3108, 7971, 18219, 20070, -28267, -1057, 29837, 27760,
-    -544, 20586, 19022, -18278, -6142, -25440, -23932, 9279, -15418, 1512, -20791, -20044, 32096,
-    5167, -28783, -16221, 13392, -3084, 17448, 7224, 24672, -26500, -8968, 29813, 27985, -14623,
-    12852, 5625, 11592, 16707, -29842, -20020, 14909, 2540, 10564, 12339, 30553, -29529, 4, -21620,
-    -28501, 14140, -6201, 799, -6422, 2538, -11024, -9506, -12625, -29271, 22620, 1510, 16990,
-    17480, -22906, -12566, 28789, 24383, -19249, 3604, -7230, -10511, -19526, -21622, 17503, -5649,
-    20014, 7480, 26219, -23677, -4599, -29551, -25990, 2855, -24155, -13140, 20846, 6686, -29589,
-    -30298, 4374, -17254, -20275, 26477, 14872, -31327, -17985, 24639, -487, 22622, 30550, -19249,
-    9516, -1063, 1313, -1536, 8703, -229, 6689, 15385, 14934, 28534, -13143, 7996, -6677, 9989,
-    -4084, 4375, 8961, 6196, 13627, 28493, -30556, -3593, -25734, 29069, 1805, -25992, -31327,
-    6432, -15970, -16678, 30848, 23095, -18478, 2322, -6976, -11539, -21065, -23424, 14162, -11557,
-    11530, -6657, 2323, 4847, 540, 2837, 12557, 8764, 18771, -28821, -16680, 17230, 9730, 20585,
-    27510, -8261, 12619, 1553, 23607, 18530, -26204, 1762, -31072, -32115, 11529, -26961, -18237,
-    29263, 4651, 31364, -20596, 4138, -18753, -5690, -31328, 26223, 4076, 23414, 25195, -7747,
-    10308, 266, 20009, 13135, 30849, -12117, 11337, -2820, 17697, 7482, 23394, -25480, -9481,
-    28535, 28234, -15907, 10032, 3561, 6453, 7719, 23607, 24186, -20038, 13328, -13595, -2562,
-    -7232, -16679, -26206, 31576, -1516, 27765, -30362, -9227, 23397, 23095, -25419, -4874, -21623,
-    32664, 8491, -15456, -13340, -31601, 29520, -8201, 18259, 18983, 31377, -18236, 21811, -2291,
-    16460, 22839, -31847, -12068, 30036, 12358, -25947, -5174, 28550, 20571, -7745, 6706, -3589,
-    11532, 1823, 11060, 21298, 26238, -20295, 14103, -12057, -505, -4402, -10772, -17724, -20336,
-    21611, -1276, 28495, 21354, -17982, 11277, -12315, -3333, -7998, -16941, -27234, 29011, -3834,
-    22882, 26698, -21311, 2837, -10569, -13087, -26461, -31643, 2077, -32116, -22134, 5676, -18753,
-    -5172, -29790, 28279, 6650, 29064, -32117, 5108, 32662, -30061, 10506, -25165, -16954, 31067,
-    8503, -28006, -11853, 19812, 5919, -31900, -31846, -506, -24447, -30818, 7976, -14682, -12571,
-    -29803, 31322, -4347, 24425, 28238, -18483, 6950, -3118, -2034, -7957, -1576, -15398, -19779,
-    -26762, 13130, -16182, 5364, -16683, -13869, -22392, 23410, -1533, 30037, 22639, -15155, 15389,
-    -5632, 7462, 9735, 11587, 19027, -27017, -13600, 22369, 16674, -31848, -17724, 23614, 535,
-    21854, 30039, -18998, 8491, -2602, 279, -4618, 3567, -6661, -5389, -4400, -15399, -22094,
-    -29843, 7989, -23894, -8255, 27522, 17227, -12882, -1776, -16698, -10569, 32149, 19540, -5687,
-    7478, -250, 15388, 8763, 22366, -26247, -10256, 26993, 24897, -19766, 2836, -8515, -11799,
-    -22864, -26248, 10304, -18239, 481, -23878, -25692, 24126, -7431, 14145, 14874, 23409, -29803,
-    1767, 31890, 31107, 5622, 31119, -31345, 7935, -29020, -23635, 20786, -8204, 9777, 9478, 14155,
-    20828, -22648, -6920, -32628, -31643, -3836, 26997, 31066, -12318, 15433, 11020, 21863, 29568,
-    -6200, 18523, 9008, -29845, -25682, 6439, -10828, -9234, -23887, -24706, 12098, -16178, 4336,
-    -16687, -15921, -24448, 20322, -7441, 20786, 8500, 26226, -22905, -1780, -27745, -21620, 11071,
-    -13354, 5623, -12831, -10013, -15194, -30307, 17230, -5172, 6703, -1275, 13333, 6703, 17486,
-    32094, -21311, 7978, -5173, -2806, -10784, -5430, -21824, -30059, 21813, -13601, 5152, -34,
-    -493, -3587, 4336, -4862, -3331, -33, -8974, -11812, -12626, -30048, 20057, -1576, 12616, 8235,
-    29521, 31891, -6929, -32159, 20583, -14126, 14617, -5375, 6692, 9477, 10559, 17486, -29331,
-    -17455, 15945, 6906, 17241, 21342, -18538, -3062, -24405, -19052, 16214, -5388, 18986, 7733,
-    24424, -25219, -6660, 31107, 32607, -7432, 22370, 23098, -25423, -5130, -22648, 32403, 6950,
-    -18023, -16428, 28025, 19757, -22854, -6157, -21106, -32363, 9007, -15196, -11289, -29800,
-    32607, -2294, 26998, -32672, -10519, 18775, 16416, 30601, -22089, 16417, -10007, 2585, 739,
-    -1012, -4098, 2796, -5382, -6411, -4143, -14634, -22602, -29586, 9268, -23888, -6970, 30343,
-    19803, -7485, 7728, -3328, 12305, 4387, 13377, 25669, -31080, -8726, -32156, 20064, -15152,
-    12307, -7180, 1812, 2283, -241, -1529, 5881, 17, 2583, 10250, 8498, 15433, 31581, -22857, 5154,
-    -9798, -8979, -22602, -23676, 14923, -12323, 10250, -6153, 272, 2025, -1784, -3840, 2282,
-    -5383, -7181, -4914, -15920, -24914, 32352, 3613, 32140, -22133, 5927, -19776, -6199, -30310,
-    25457, 3053, 24175, 23658, -9798, 9270, -3843, 13077, 4644, 14661, 26955, -28510, -4615,
-    -25730, 30601, 1811, -25474, -28253, 8494, -11854, -7950, -23374, -23419, 14153, -12581, 9222,
-    -7694, -2043, -2087, -8465, -14122, -14937, 32142, 13635, -11854, -2810, -18234, -13139, 29573,
-    12864, -14939, -6665, -25429, -23934, 11583, -15921, 3823, -16688, -16435, -25218, 19293,
-    -9240, 17703, 3873, 18516, 30039, -21571, 5153, -8513, -7950, -20033, -20078, 20835, -2814,
-    25926, 18522, -24403, 2025, -26968, -28258, 17960, -14633, -244, -7227, -11806, -22603, -26759,
-    11839, -18235, 1255, -21315, -23375, 28241, 531, 25712, -31898, -10521, 19802, 16676, 32398,
-    -19777, 19761, -3841, 12350, 15649, 24429, -29028, 2542, -30056, -31084, 11281, -23118, -15407,
-    -31895, 14919, -20291, 1259, -22347, -24403, 25161, -2558, 19288, 23105, -26203, -6413, -26496,
-    29567, -245, -30094, 32137, -1273, -28552, 32651, 784, -25726, -28002, 8238, -13134, -7956,
-    -24915, -26241, 11320, -18491, -541, -22347, -26458, 23361, -6157, 13379, 13852, 24426, -31083,
-    -27, 31366, 27514, 230, 24684, 21089, -12878, 4896, -11552, 230, -14637, -17977, -25984, 18260,
-    -11039, 13596, -759, 9522, 15138, 21600, -32113, -4138, 25970, 18517, -14419, 528, -16951,
-    -9793, -30569, 22114, -1570, 16464, 11834, -30098, -22344, 9779, -5170, 274, -7956, -287,
-    -12833, -16180, -21618, 23148, -1531, 29012, 22891, -16438, 13593, -7180, 3353, 3569, 2587,
-    3096, 13078, 11839, 21857, -24445, -6923, 30853, 31325, -7438, 20317, 20274, -28770, -12066,
-    31840, 15949, -21062, 2028, -22859, -24146, 25416, -2556, 19545, 23874, -25687, -5128, -24185,
-    32653, 5153, -20845, -19262, 21859, 9482, 28026, -31598, 3314, -31599, -31855, 8712, -26203,
-    -21061, 24647, 526, 21858, 28759, -17979, 7210, -4139, -245, -7954, -1569, -12838, -17977,
-    -24185, 20059, -7441, 18993, 8237, 24170, -26754, -5899, 29568, 30044, -8984, 17490, 14881,
-    29566, -24659, 11027, -16182, -8725, -19042, -30317, 12607, -11590, -1789, -16949, -12618,
-    -32372, 16976, -9021, 4895, -7441, 3830, -6671, -6156, -6707, -15923, -25945, 29788, 783,
-    27511, -31122, -6671, 24428, 23877, -20036, 271, -13390, -15924, 32397, 22848, -12841, 5927,
-    -284, 2838, -1527, 7941, 3609, 7725, 17964, 22884, -28513, 1002, -30316, 32397, 8456, -27233,
-    -22602, 21821, -3076, 14665, 17709, 30334, -21573, 14626, -8732, 1559, -1053, -1535, -6923,
-    -2338, -11301, -17974, -23155, 22367, -4868, 23364, 16456, -29541, -7220, 26480, 15435, -17501,
-    -4361, -25942, -24183, 13116, -14892, 4345, -12842, -12322, -19299, 31620, 8496, -19300,
-    -13099, 29057, 22078, -16441, 1302, -8764, -9502, -22856, -25983, 14657, -15661, 5372, -12329,
-    -11292, -17501, -30833, 12867, -11591, -1020, -16946, -11591, -30832, 18778, -5424, 10804,
-    1045, 18734, 17229, 31628, -9794, 19029, 4900, 31069, -32372, -5638, -31125, 25968, -9237,
-    23361, 11319, 30855, -16732, 11063, -9239, 8199, -3845, 529, 3315, 1039, 529, 8198, 5922,
-    10295, 22847, 30337, -16177, 20535, 1553, 18518, 26446, -23377, -757, -17759, -21321, 22631,
-    7685, 27766, 31626, 232, 29308, 25715, -4138, 18772, 10809, -29325, -21321, 10810, -3626, 4124,
-    -3583, 7426, 783, 4384, 12052, 13376, 21603, -23672, -5129, 32655, -31125, -1531, 28800,
-    -31382, -5387, 24432, 25930, -17724, 3616, -6968, -5902, -17715, -16477, 28794, 7472, -21875,
-    -16953, 21608, 12050, 31363, -27223, 11280, -17983, -11803, -22901, 28792, 791, -28813, -30062,
-    1306, -21361, -22095, 16470, 2026, 16456, 12872, -28558, -17726, 13387, 3567, 14914, 12616,
-    -30101, -19525, 9790, -1575, 5920, -1776, 12304, 8233, 14672, 31065, -22094, 2851, -10828,
-    -10272, -27475, -29332, 6434, -29018, -14169, 19799, -491, 27723, 24682, -18996, 13846, -7445,
-    281, 1252, -763, -5887, 1766, -6161, -10770, -8771, -21581, 28809, 15388, -23124, -14111,
-    -29330, 20310, -15140, 12820, -3850, 2595, 6651, 7716, 7992, 23613, 30075, -18223, 19502, -252,
-    12875, 20273, 31873, -19765, 19503, -1282, 11591, 17704, 28530, -25932, 9738, -16704, -13596,
-    -23415, 28015, -2031, -32667, 30328, -8714, 28244, 19020, -24392, 1259, -23643, -28504, 19764,
-    -8995, 4394, 2030, 6169, 1824, 14623, 16192, 24440, -18274, 5656, -18738, -6452, -25955, 27009,
-    7684, -31353, -29789, 10769, -19275, -14626, -27781, 22874, -10772, 17967, 6940, 19297, 32102,
-    -14392, 11845, 3574, 15164, 12873, -31379, -18761, 9276, -3110, 5656, -3575, 8456, 4371, 6962,
-    17708, 24160, -29533, 1003, -29040, 31634, 8714, -25443, -22594, 23364, 515, 18269, 24649,
-    -22873, -4090, -21098, -25443, 13130, -6193, 6427, -5632, 6915, 773, 2078, 8971, 10539, 13900,
-    30559, -21587, 3107, -12105, -9508, -27733, -30866, 6427, -30304, -17502, 16965, -6147, 16938,
-    10026, 21609, -27781, -6937, 25208, 24391, -16447, 2079, -7993, -6424, -20537, -20586, 23903,
-    -2804, 27474, 24160, -19767, 10513, -9509, -5117, -8506, -13878, -28504, 29274, 515, 23924,
-    30559, -11308, 13131, 7943, 21074, 22897, -15445, 7197, -14113, -796, -15419, -22080, -31379,
-    11567, -25422, -7735, 31614, 18269, -9278, 7971, -6662, 7429, -254, 1820, 7686, 8741, 11072,
-    25933, -29296, -8718, -32149, 23904, -13345, 16169, 2059, 13383, 21052, -31866, -15659, 23366,
-    6942, 25718, -27521, -2568, 30602, -32147, -2055, 26234, 29534, -10023, 14156, 9488, 23644,
-    27777, -8760, 18762, 4647, 29019, -32125, -8461, 30305, 21589, -18742, 7947, -10795, -7948,
-    -13898, -21846, 24692, 7691, 32638, -30307, 6921, -22876, -21055, 25940, 5395, 26490, -29316,
-    -2316, 28804, 30823, -5144, 20324, 19515, -24677, -10517, -31114, 24925, -11289, 17461, 7192,
-    19808, 30825, -13883, 11842, 2040, 14902, 11842, 30824, -21850, 3619, -13896, -9257, -28763,
-    31851, 4108, 30604, -26489, 4880, -26709, -17494, 21843, -752, 25426, 25184, -19771, 9493,
-    -9513, -4865, -10553, -14653, -30051, 24657, -4374, 15183, 14634, 31092, -24910, 9752, -13628,
-    -8976, -19289, -26735, 14412, -8753, 6934, -6664, 3585, -1549, -3065, -1299, -2834, -9233,
-    -8752, -16455, -30307, 22089, -6433, 10301, 7183, 19524, 21352, -21345, 1792, -24653, -19546,
-    22867, -1780, 24402, 24152, -21827, 5385, -14657, -14117, -25969, 27235, -3580, 26460, 24665,
-    -19001, 8214, -8747, -5123, -11575, -14658, -30823, 22350, -6178, 11327, 7444, 21321, 23920,
-    -18000, 8215, -14375, -3865, -16200, -24655, 26976, 4361, 27002, -31878, -2836, 26232, 25691,
-    -11318, 9528, 761, 12584, 8500, 23634, -31107, -12318, 24406, 14383, -31337, -14659, 21580,
-    2331, 26461, 30832, -12833, 20294, 9501, 25460, -28280, -780, 31886, -32135, 2047, 30858,
-    -30336, 2562, -32109, -27252, 7960, -23372, -13117, 30577, 13380, -19029, -4119, -27483,
-    -29052, 10531, -22601, -9520, -30846, 21090, -6951, 15159, 4128, 22347, 27495, -19774, 10526,
-    -7973, -1783, -6951, -7459, -18745, -23399, 24667, -3068, 24404, 22611, -22601, 2560, -18511,
-    -20031, 29289, 10788, -29284, -16201, 21326, 1300, 25176, 27751, -16434, 13612, -1292, 8240,
-    9243, 19268, 24431, -19542, 6419, -16948, -8234, -23907, 29570, 7958, -26478, -22345, 18753,
-    -1807, 12866, 13099, 28005, -28512, 1534, -24938, -27484, 14899, -10290, 274, -7976, -5407,
-    -17973, -21340, 28518, 2588, -32135, -27252, 1304, -23398, -19799, 17239, 246, 19780, 14926,
-    -28025, -10804, 21352, 13353, -28281, -20283, 19522, 2045, 16212, 20807, -25712, -10260, 31859,
-    24404, -14373, 12071, 759, 7730, 10529, 21319, 26748, -15429, 14124, -6150, 9759, 6414, 11583,
-    19782, -31366, -16174, 19526, 6157, 21348, 29035, -12348, 12097, 1535, 16437, 13382, 31604,
-    -17745, 9014, -6691, 4873, -6408, 506, -3352, -7436, -9003, -13889, -27482, 25950, 1274, 22634,
-    25693, -14404, 6444, -5920, 3074, -7436, -2322, -7463, -14375, -19798, -31878, 9270, -20313,
-    -8748, 31630, 25177, -6179, 13898, 10014, 26717, 31631, -4893, 29288, 19295, -14403, 7187,
-    -12061, -2324, -12345, -19514, -29309, 18753, -15146, 5902, -6949, -5637, -10546, -13888,
-    -29024, 24664, -1810, 18009, 18239, -26483, -13089, 27749, 17465, -24912, -5662, -28024, 27260,
-    1277, 31087, 27774, -4635, 25434, 16209, -21598, -3094, -29537, -30336, 7958, -26968, -16715,
-    23893, 2588, 29031, -31877, -7436, 28518, 23122, -18231, 6931, -9005, -6409, -13629, -17743,
-    29829, 13871, -19286, -9750, -27506, 30830, -1012, 31348, -32650, -5382, 28779, 25947, -14635,
-    12332, 503, 9010, 10533, 22348, 29056, -13112, 18494, 1557, 21326, 25433, -22602, 3851, -15946,
-    -15920, -31101, 21320, -13351, 8479, -1812, 2842, 1540, 7697, 5412, 13619, 22346, 32140,
-    -10540, 24660, 10295, -29816, -16461, 15179, -262, 17722, 13380, 32377, -16974, 11068, -4376,
-    9242, 531, 11558, 14639, 21862, -27250, -2838, 30858, 29805, -2068, 22892, 22609, -16975, 534,
-    -14401, -10807, -30563, 26206, -1042, 19554, 20552, -21860, -6918, -26993, -30341, 2592,
-    -25709, -19547, 14415, -2837, 14893, 6191, 23634, -32396, -14627, 20808, 9496, 24694, -29051,
-    -1042, 29578, 30831, -1557, 23154, 23892, -14665, 2852, -9263, -2586, -18479, -18515, 32367,
-    7222, -23142, -12095, 23414, 14124, -23918, -16679, 27489, 14890, -29787, -12347, 27227, 8250,
-    -27254, -15179, 16218, 3844, 24142, 21101, -17488, 7438, -16680, -6437, -19547, -32614, 16180,
-    -12609, -3315, -13119, -12609, -32613, 23119, -5414, 10565, 7956, 22856, 23672, -16203, 11549,
-    -11539, 2560, -4644, -8969, -11318, -15952, 31381, 17724, -11841, -1258, -10804, -7472, -25416,
-    -30593, 14117, -23361, -6949, -25975, 25727, 2303, 32365, 27783, -2838, 29025, 19302, -14404,
-    8723, -12311, -783, -9524, -16937, -23656, 28513, -1774, 29544, 31340, -11027, 22863, 15406,
-    32149, -15431, 20033, -1519, 21064, 22860, -27477, -2067, -26484, 31120, 7186, -23915, -22594,
-    21322, 2299, 17756, 22350, -21860, -5631, -25196, -27257, 6963, -17744, -7211, -31330, 29545,
-    2041, 25211, 29802, -6698, 16474, 12326, -32656, -26960, 8215, -14410, -12825, -25195, 31851,
-    26, -31364, -27003, 284, -24169, -19550, 14677, -2068, 16689, 7481, 27230, -26745, -6911,
-    -30596, 32109, -5883, 29286, 27483, -15907, 14381, 2810, 10051, 15666, 30052, -26958, 5644,
-    -16724, -18220, -32649, 19257, -20277, 1276, -14411, -20020, -32391, 17715, -21306, -1551,
-    -18522, -26703, 22351, 239, 15960, 18239, -26747, -15138, 25436, 14888, -31587, -14914, 23370,
-    2081, 27491, -31629, -10513, 25179, 19001, -27476, -6690, -30086, 22640, -5406, 21059, 9533,
-    -32649, -19291, 7221, -9520, 1527, -14368, -10291, -21089, 27781, 9242, -24688, -21821, 21322,
-    3582, 18529, 24406, -18521, -746, -16972, -13638, 28296, 16953, -15952, -5885, -19542, -21092,
-    18017, -525, 21572, 14162, -26997, -8755, 22644, 16694, -21863, -12309, -31622, 25940, -12567,
-    15668, 7436, 16474, 26205, -18522, 798, -15174, -10041, -32355, 25690, -2075, 16220, 16695,
-    -27776, -18476, 21579, 7948, 22387, 32630, -5674, 19561, 16182, -24693, -15906, 26977, 16171,
-    -29528, -11317, 29536, 11079, -22626, -6702, 28813, 24406, -7217, 9539, 4617, 19511, 16478,
-    -27252, -5419, 24960, 21836, -13130, 546, -10034, -3878, -22327, -23655, 25164, -6907, 20807,
-    19510, 32157, -11319, 26193, 6714, -29824, -17755, 9542, -5153, 9489, -4080, 8469, 9489, 9798,
-    22347, -28291, -14104, 25946, 16942, -30553, -10806, 29022, 10311, -23143, -7987, 26246, 21319,
-    -12871, 33, -9523, -4390, -22583, -23658, 24395, -7934, 19776, 16942, 28303, -16976, 16172,
-    -8964, 10268, 6149, 8512, 17721, 31078, -24642, 9241, -10301, -8965, -16716, -20581, 20334,
-    2559, 27993, 22647, -12091, 15401, -4596, 13610, 13859, 19819, -29053, -4389, 24189, 22605,
-    -13642, 803, -9779, -3876, -22070, -22886, 25680, -5622, 23118, 22596, -27982, -2326, -25463,
-    29587, 7184, -23665, -24641, 20035, 750, 12625, 16180, -31376, -23356, 13354, -4392, 547,
-    -1040, 5118, -4593, 3330, 4603, -738, 6415, 11542, 9542, 23634, -26495, -11276, 30060, 24649,
-    -18987, 7702, -5165, -5879, -9004, -8763, -26182, 32631, 12569, -28496, -13887, 29018, 6971,
-    -27252, -14416, 15453, 3332, 24905, 19822, -18514, 7173, -19501, -10033, -23924, 23419, 2046,
-    31075, 24961, -6950, 23366, 8256, -31109, -17498, 8514, -5924, 7690, -6650, 4100, 2806, -1510,
-    4101, 7946, 3887, 14638, 23880, 30358, -8493, 26965, 10312, -25199, -9787, 22135, 15408,
-    -22638, -15645, 30058, 19768, -23870, -1297, -20067, -29524, 18750, -5419, 4916, 2558, 12829,
-    6972, 22861, -30348, -15902, 22091, 11544, 25475, -25712, 5119, -28753, -20829, 21054, -7936,
-    15923, 13087, 21105, -28539, -2334, 26503, 26974, -6704, 11855, 7956, 25421, 24962, -12348,
-    17969, -2795, 17979, 20539, 30358, -11834, 23624, 3630, 30314, -26492, -4594, -28026, -27520,
-    1575, -22630, -15955, 18281, 5641, 29277, 26248, -6696, 24652, 9286, -28028, -13642, 14941,
-    4869, 25165, 21109, -15692, 10517, -13845, 243, -8502, -16929, -22116, 31591, 805, -29570,
-    -23665, 3376, -16464, -7988, 31904, 27741, -536, 17770, 21059, -21097, -9473, -27000, -30863,
-    -1763, -29056, -25209, 1836, -19548, -12102, 24196, 15919, -19556, -13327, -29313, 28761,
-    -9987, 22089, 17967, 30876, -13378, 23108, 550, 27228, -32148, -14100, 22603, 14113, 27791,
-    -20317, 12829, -16158, -14, -11072, -19756, -27513, 23367, -12561, 13866, 6405, 12111, 21576,
-    -26749, -13333, 28259, 20026, -25156, -2325, -22636, 32670, 12839, -14927, -9993, -22370,
-    -27263, 7998, -16460, -3362, -27982, -28539, 14115, -22329, -5409, -22893, 29329, 9241, -21866,
-    -20530, 25690, 10260, 28300, -24426, 8719, -23102, -11833, -30345, 15963, -11577, 8721, -9996,
-    1531, -4130, -9739, -11319, -16723, 30354, 16181, -14411, -5626, -17487, -18523, 22131, 6158,
-    -32402, 31897, 2046, -26748, -32097, 8986, -18011, -16420, -32391, 21825, -17706, 5904, -6447,
-    -7683, -12600, -14928, 30868, 17470, -11588, -1514, -11572, -7476, -26443, -32389, 12314,
-    -27215, -13371, 30305, 9794, -23652, -8503, 25986, 19268, -14672, -3055, -15942, -13387, 28557,
-    16955, -14159, -5110, -17484, -16729, 23418, 8474, -27524, -26955, 12587, -7993, -3567, -10030,
-    -7222, -25412, -31104, 15395, -23614, -6689, -24183, 26759, 4106, -28296, -32095, 6420, -19045,
-    -20530, 26981, 13081, 32412, -19023, 19764, -6910, 13874, 13339, 19818, -31359, -5166, 21361,
-    17215, -20330, -10765, -30330, 31071, -6910, 24926, 24646, -23359, 1797, -14677, -20275, 30839,
-    17449, -24388, -6684, -24442, 27270, 3083, -28298, -32355, 4883, -20332, -22589, 22360, 7167,
-    22387, 29299, -6455, 15449, 8739, 31838, -32354, -771, -25730, 31640, 5655, -20591, -22331,
-    22104, 7679, 22388, 29557, -5686, 16221, 10025, -31130, -28755, 4886, -15454, -18218, 30844,
-    21041, -21046, -1025, -13911, -22331, 28274, 14103, -30299, -17216, 25926, 1570, 26731, -29330,
-    -9739, 25447, 23613, -23361, -1024, -16480, -24389, 23392, 7164, 23671, 29560, -4145, 18275,
-    12855, -25991, -20276, 17739, 6134, 16733, 21337, -18796, -4855, -25181, -21366, 11594, -11047,
-    8962, -9225, -1538, -2603, -11281, -15159, -18280, 24957, 5402, -26762, -28500, 8744, -11086,
-    -9482, -22353, -23165, 12878, -11817, 9476, -9226, -1536, -2347, -10768, -14900, -17253, 26498,
-    7460, -22908, -22333, 18255, 4848, 16218, 19026, -21367, -9226, 32648, 32347, -7171, 22626,
-    24380, -24905, -3075, -19310, -28760, 14916, -4919, 3367, -3847, 8446, -2287, 3864, 10502,
-    7736, 15943, 32604, -23619, 6435, -8004, -8199, -19008, -18027, 21871, 1039, 32345, 26754,
-    -9242, 26692, 10820, -30574, -10574, 17503, 4379, 31317, 28811, -7958, 30033, 15190, -22608,
-    1763, -27730, -28262, 18469, -16423, -505, -8003, -15138, -25691, -31904, 1311, 32648, -22652,
-    3111, -21581, -9545, 27270, 15685, -13401, -5112, -20553, -16485, 21103, 2578, -32420, 28299,
-    -6161, 31318, 17762, -18241, 8702, -16934, -10017, -18026, 30098, 10287, -15971, -13079,
-    -31090, 30547, -7683, 20569, 22066, -29786, -10015, -30876, 17760, -15156, 11274, -10512,
-    -1534, -3376, -11540, -17211, -20081, 21614, -763, 29521, 22128, -15927, 14616, -7686, 4635,
-    5620, 3880, 7205, 19755, 20585, -27491, 1765, -32101, -32631, 9219, -29532, -22608, 21556,
-    -6917, 12089, 13332, 19811, 30593, -6972, 17756, 8234, -31131, -28762, 2838, -17254, -20281,
-    24941, 13330, 32661, -22605, 18471, -9489, 5667, 4849, 5161, 6695, 20526, 21866, -26459, 4078,
-    -27736, -26973, 19242, -12831, 2841, -1320, -3835, -8725, -3890, -17970, -25430, 30806, 21,
-    27512, -29333, -7176, 25457, 26951, -18228, 4898, -4405, -4863, -13093, -9031, -27479, 25201,
-    6903, 26749, 29827, 221, 24693, 21089, -10574, 4905, -9239, 4847, -10002, -8725, -9802, -24137,
-    28027, 12815, -30049, -20804, 23353, -2807, 16976, 22839, -30821, -11552, 31578, 14926, -22347,
-    995, -26452, -28772, 18472, -15145, -244, -7229, -12318, -23117, -27275, 10299, -20291, -1832,
-    -27223, -32370, 14103, -23112, -12324, -27531, 20836, -9755, 18731, 4131, 20057, 31838, -18486,
-    10292, -289, 4903, 1554, 14617, 11071, 22628, -23677, -6149, 32395, -31130, -3836, 27255,
-    31579, -11547, 16462, 13075, 24947, -31084, 2024, 31886, 30340, 4851, 30601, 31882, 5108,
-    32400, -31598, 8707, -27226, -22089, 23871, -2554, 17747, 22843, -29026, -9753, -31384, 20319,
-    -14380, 13079, -5126, 4383, 6653, 7211, 10294, 24900, 31369, -12837, 25672, 9010, 31367,
-    -18019, 9268, -11811, 4598, -11293, -9755, -14163, -27998, 20315, -543, 15437, 11834, -30870,
-    -23371, 7980, -7741, -4352, -15408, -12110, -32108, 18003, -6200, 6958, -2558, 12305, 4902,
-    14147, 26954, -29280, -5386, -27016, 28289, -1788, -31385, 27518, -6672, 28241, 16724, -23121,
-    999, -26967, -28518, 17191, -15917, -1532, -10309, -16431, -29545, 26700, -7180, 16460, 16420,
-    28800, -23376, 12309, -14892, -5643, -13905, -23373, 25198, 8455, 30083, -30058, 6400, -26973,
-    -23633, 21050, -5643, 12092, 12569, 21856, 31110, -6450, 21600, 11835, -25726, -16951, 19289,
-    8713, 25197, 30340, -3624, 23656, 16462, -18788, -5386, -27999, -26755, 7722, -22603, -8251,
-    31367, 19546, -7738, 8238, -3071, 12052, 5411, 14148, 26444, -28514, -5385, -27269, 29312,
-    -1273, -30867, 29826, -4101, 32100, 24429, -11812, 18737, 3355, 19542, 29017, -20547, 5665,
-    -8507, -6412, -17979, -18016, 25971, 4895, -28040, -26715, 7466, -12364, -8468, -24402, -25985,
-    11579, -17721, 744, -20547, -23118, 28501, 1813, 27254, -29839, -6155, 26227, 26702, -15922,
-    7210, -1827, 1813, -3329, 5370, -1785, 270, 5626, 2071, 4382, 13593, 14150, 24428, -19818, 530,
-    -22348, -14678, 24175, 6437, -27529, -25427, 9265, -8512, -3838, -15665, -11853, -32108, 18260,
-    -5943, 7472, -1787, 13590, 6958, 17488, 32351, -20542, 8494, -3888, -495, -7698, -33, -12831,
-    -16179, -20850, 23407, -759, 30808, 24949, -12839, 20015, 2076, 19286, 29267, -22083, 4124,
-    -9799, -10775, -23889, -26504, 10043, -19521, -1318, -26194, -30572, 16930, -18742, -4872,
-    -15709, -25681, 21086, 3566, 19552, 20058, -17766, -3064, -23890, -18794, 17497, -4102, 21300,
-    11843, 30593, -15195, 9788, -7702, 9736, -3577, 3864, 7937, 6446, 12088, 26184, -32619, -8730,
-    31582, 17497, -18497, 6396, -17456, -13100, -23416, 23665, -1792, 29013, 21866, -16442, 12309,
-    -9233, 1036, -1057, -5121, -8473, -6454, -20027, -28776, 23873, -9748, 11575, 8967, 15952,
-    22369, -20075, -2552, -25177, -20589, 14925, -7959, 13851, 1047, 12858, 20790, 28803, -17983,
-    17450, -5123, 10032, 11539, 17236, 26480, -15190, 6700, -10530, 2801, -12319, -11558, -17502,
-    31886, 12344, -14676, -6922, -23893, -24185, 12868, -13357, 6142, -11805, -7703, -13133,
-    -25426, 24937, 6142, 26489, 30591, -1826, 23920, 20054, -14677, 277, -16185, -9023, -30563,
-    24165, 743, 19553, 18511, -20332, -7176, -29548, -29584, 1049, -30320, -22131, 7475, -16186,
-    -1571, -23622, -26723, 22331, -10002, 10544, 7682, 12871, 18768, -26757, -13344, 23395, 17191,
-    -30050, -14899, 27472, 7473, -32376, -18018, 9787, -9761, 6656, -8205, -3079, -4909, -13088,
-    -19527, -26240, 14669, -12846, 7943, -9748, -3080, -6963, -14888, -23126, -32149, 5416, -27753,
-    -16472, 16211, -1026, 20795, 14669, -30582, -10303, 19296, 8483, -31892, -28764, 4115, -18529,
-    -19769, 26218, 12569, -31849, -20300, 19252, -5893, 12084, 12056, 19550, 30331, -9790, 15696,
-    4631, 26447, 26233, -14131, 17967, -1010, 15682, 20537, 31629, -14645, 22594, 3359, 24933,
-    -31634, -11291, 21336, 15655, 32656, -18500, 19511, -3325, 14911, 16941, 27772, -22098, 10774,
-    -15149, -5650, -15954, -25429, 22878, 2550, 21603, 22878, -15955, 2843, -14132, -6189, -24656,
-    -31865, 14115, -21830, -8735, -25720, 27001, 261, 32362, 28543, -5651, 27737, 18006, -20558,
-    2294, -22344, -20815, 26967, 2072, 28529, -30345, -5896, 28530, 27224, -13607, 12597, 3836,
-    12608, 15424, -32659, -21060, 10542, -5418, 1044, -5394, 751, -8723, -8992, -12870, -25942,
-    25704, 4863, 26487, 30330, -3619, 22376, 17737, -20068, -6666, -28009, -29320, 3872, -26468,
-    -17241, 17237, -769, 21568, 16209, -28269, -6960, 25462, 17992, -16727, -3836, -21073, -19554,
-    19809, -256, 24908, 19552, -21331, 3577, -22854, -19532, 28250, 3618, 31868, -24950, 1563,
-    -23132, -16469, 20325, 4111, 29791, 28292, -7198, 26194, 13386, -25446, -6960, 27265, 20815,
-    -12101, 2594, -8742, -793, -15910, -15938, -26493, 16730, -8743, 13087, -2032, 12075, 15143,
-    21098, -28275, -2077, 28809, 27752, -3620, 17502, 14902, -27522, -19250, 19529, 5889, 19043,
-    25697, -15186, 3881, -10285, -794, -17964, -17738, -30092, 10821, -17996, -1565, -26701,
-    -26991, 17454, -16422, 2052, -8505, -13338, -21078, -28551, 9022, -18509, -3622, -29271,
-    -31873, 10257, -28501, -17224, 25421, 1312, 28008, -30606, -9483, 26467, 22594, -23105, 510,
-    -16985, -23105, 26211, 8716, 28552, -27503, 6404, -27219, -20050, 23367, -2804, 21328, 23624,
-    -26449, -2060, -23664, -31589, 11048, -15441, -10258, -25189, -30347, 4135, -25447, -16212,
-    17757, 2310, 25422, 21612, -17737, 8975, -14627, -4887, -14669, -25421, 26211, 5635, 26236,
-    32636, -1819, 24952, 23898, -11586, 6192, -4374, 6663, -3832, 3851, 4864, 2850, 8734, 16429,
-    19298, -28789, -4646, 25981, 22355, -12100, 3880, -6945, 2036, -11284, -7973, -14412, -28760,
-    23639, -21, 16988, 18242, -24951, -13339, 28266, 20282, -23363, -2061, -20324, -28760, 17472,
-    -5933, 4909, 252, 10516, 4138, 15929, 25422, -30815, -4118, -29833, 24955, -3348, 26452, 16474,
-    -20825, 495, -26960, -24680, 18486, -12569, 7447, -277, 540, 1793, 7433, 2596, 11559, 19255,
-    24184, -20567, 8462, -18480, -8488, -22378, 28295, 7447, -24949, -23877, 17985, -792, 10563,
-    11046, 26964, 31380, -5917, 30563, 18016, -15427, 7434, -14368, -5404, -15182, -26961, 24923,
-    2808, 21356, 25694, -13641, 5423, -6433, 3836, -9227, -3606, -8243, -18479, -24937, 26710,
-    -4858, 23637, 23369, -24905, 250, -20065, -26190, 20811, -534, 13647, 14643, -32146, -24133,
-    10532, -8246, -4600, -11571, -10816, -29272, 26723, 3062, 22900, 27237, -9789, 10308, 1794,
-    17967, 12621, 31863, -15187, 9281, -4376, 10515, -1257, 10788, 15141, 18789, -30076, -5677,
-    22388, 18241, -19042, -8196, -25963, -28294, 3884, -22880, -13131, 21875, 10274, -27267,
-    -24643, 14901, -3367, 3629, 1537, 11796, 5428, 18499, 30557, -24385, 7192, -10308, -11021,
-    -20564, -24700, 12367, -11313, 7684, -11279, -2575, -7479, -17704, -24163, 30044, -1770, 29294,
-    -31637, -9738, 24926, 21563, -26187, -3859, -23926, 30611, 7450, -21100, -20790, 24156, 9741,
-    27012, -28273, 5115, -30043, -24418, 17195, -13853, 3597, -3881, -6914, -10795, -11334, -28759,
-    25443, 3315, 22128, 25443, -11335, 7223, -3857, 9741, -1002, 8994, 14367, 16731, 31353, -11077,
-    13391, 2569, 22590, 18274, -24417, 488, -30814, -30071, 11026, -25675, -14394, 31587, 10563,
-    -22876, -6193, 29582, 23899, -5680, 11079, 5909, 23618, 22387, -19021, 9741, -16165, -5914,
-    -15959, -28758, 21329, -1054, 13135, 12591, 32356, -27729, 4882, -15962, -18220, 31354, 20019,
-    -21048, -1029, -15447, -23361, 26728, 9997, 30095, -25444, 11026, -20793, -9767, -24440, 24954,
-    514, 31843, 25982, -7711, 24391, 10305, -30585, -14160, 14161, 256, 20792, 14418, -30071,
-    -9278, 19302, 10279, -29325, -25931, 10280, -8766, -5627, -14393, -13135, 30868, 17733, -9795,
-    543, -8997, -1314, -17961, -19020, -29841, 9025, -20306, -4141, -32352, 29553, 4597, 26245,
-    31352, -544, 22648, 22614, -12624, 1575, -10284, -1059, -20013, -20307, -32670, 3889, -27761,
-    -16222, 12628, -2319, 17704, 6460, 25694, -25987, -9218, 31606, 29783, -12817, 17986, 12564,
-    22135, -29817, -287, 26762, 27495, -3629, 14941, 12332, -30358, -26951, 8992, -9799, -9732,
-    -19021, -20593, 16997, -2831, 22071, 10315, -32130, -13910, 10316, -2319, 15647, 4148, 21325,
-    -32413, -20268, 14130, 1768, 6718, 9761, 24384, 24965, -14912, 17703, -6390, 12588, 13848,
-    17511, 32634, -7741, 15713, 9247, -32671, -32604, 1281, -23163, -31062, 12076, -10571, -7931,
-    -17737, -17253, 21111, 4623, -31132, 29592, -775, -28304, 27022, -262, -30616, 25223, -4118,
-    29010, 15457, -19539, 3824, -25150, -19796, 28240, -992, 28778, -30100, -10502, 26209, 23357,
-    -24895, -263, -17763, -26951, 22097, 2797, 15969, 20041, -21876, -10760, -31616, 30810, -9476,
-    22099, 20273, -31579, -10541, 30811, 11855, -21850, -2600, 32416, 30836, 5367, 27533, -31616,
-    3568, 28818, -32130, 4339, 29075, -31102, 5624, 31388, -27504, 11279, -24640, -12341, -29841,
-    14939, -13627, 8197, -13590, -4118, -11078, -23356, 32377, 15651, -25413, -8487, -27525, 21619,
-    -4376, 23363, 11082, -29306, -12104, 15966, 5647, 27988, 25475, -10288, 21307, 2859, 26206,
-    -30351, -12305, 24665, 18480, -30296, -10031, 31074, 13138, -19284, -281, -27725, -25966,
-    17710, -16161, 3334, -6707, -11278, -16455, -21613, 19563, -265, 25163, 16993, -21340, 1519,
-    -27726, -24167, 19253, -12564, 8474, 1776, 2600, 6161, 14626, 13137, 29548, -16986, 4657,
-    -10289, 234, -17960, -15686, -28036, 13909, -11832, 7432, -12050, -2323, -9273, -19246, -26224,
-    25166, -8453, 18753, 15400, 27013, -21083, 10775, -17193, -4378, -16981, -28244, 22351, -1048,
-    14163, 15155, -31118, -23103, 13100, -4648, 1057, -1551, 4862, -4340, 2562, 3833, -1256, 4362,
-    8716, 5683, 16184, 27477, -29270, -8, -23923, -31326, 12072, -13644, -8967, -21081, -24438,
-    12622, -10031, 7946, -9225, 507, -3363, -9996, -11829, -16470, 30097, 15157, -14672, -6911,
-    -20053, -21354, 16734, -2835, 19254, 9024, 30318, -20839, 1829, -16715, -9531, 31385, 24149,
-    -4392, 11597, 9500, 26962, 28302, -7977, 25935, 9798, -27253, -11845, 18023, 8728, 32616,
-    -32607, 2304, -24183, -30294, 13099, -10820, -6392, -15172, -15189, 26505, 13356, -19045,
-    -14359, -31619, 26188, -13846, 13872, 6656, 12368, 20554, -25984, -13590, 27237, 20277, -25927,
-    -4375, -23927, 29329, 6677, -22900, -24128, 19528, 2286, 13909, 17215, -27527, -18217, 20557,
-    9225, 22132, 32122, -4397, 19820, 16188, -22388, -14105, 29553, 22588, -21045, 1798, -11852,
-    -17704, -29556, 25671, -11280, 14136, 10251, 17247, 27243, -13651, 6197, -7454, 6139, -8710,
-    -2571, -4141, -14107, -18248, -25215, 14678, -10282, 11281, -6397, 5139, 5883, 3627, 9765,
-    20532, 22902, -21847, 7940, -21047, -12852, -27269, 18275, -8484, 16166, 542, 17473, 24390,
-    -30813, -5658, -30351, 22387, -6944, 21308, 7224, 29807, -22640, -229, -21594, -15958, 20589,
-    5906, 32615, 31126, -520, -29065, 28556, 1022, -30093, 29070, 508, -30093, 28556, -7, -31377,
-    26757, -2835, 29277, 19047, -15172, 8975, -13337, -2322, -10814, -20276, -29050, 21055, -14880,
-    7960, -1820, -1001, -1036, 3064, -5113, -9, -277, -7426, -5663, -8244, -21047, -27251, 22083,
-    -12053, 11815, 4863, 9793, 16441, 31334, -24646, 8218, -11073, -9740, -19538, -23923, 15190,
-    -7203, 13087, -1002, 13615, 17713, 24698, -21595, 7948, -20022, -10544, -25976, 22641, -1550,
-    25426, 17501, -20569, 1268, -25676, -22368, 21572, -6916, 16441, 13605, 24181, -25965, 2297,
-    -29533, -25451, 14377, -16684, -522, -13636, -19768, -31619, 17719, -19255, 250, -15435,
-    -20540, 31091, 14121, -25424, -9773, -31882, 18781, -11316, 10525, -5636, 6419, 3843, 5672,
-    11045, 19777, 26232, -17997, 11040, -11292, 1279, -7208, -10264, -16197, -23656, 21348, -778,
-    23120, 18007, -22624, -2067, -29281, -29563, 9242, -24656, -13629, 29546, 11582, -22368, -8491,
-    30087, 23636, -9263, 9528, 2305, 14638, 12098, 28776, -21857, 1819, -17743, -13119, 29319,
-    18495, -14662, -1778, -14145, -12863, -32618, 22350, -6953, 9532, 4874, 17976, 16985, -28280,
-    -7725, 23411, 17981, -20319, -8458, -26737, -31370, 1309, -27766, -22632, 8763, -11319, 1270,
-    -16424, -12604, -25458, 21099, -1554, 22860, 14931, -24685, -6439, 27782, 24403, -9781, 7737,
-    1272, 12579, 6966, 22860, -32140, -16165, 20291, 7696, 21357, 32113, -8496, 16732, 11296,
-    31853, -29272, 5386, -19806, -21305, 26975, 9750, 30095, -23141, 10779, -18737, -5408, -20575,
-    -32358, 15153, -13380, -4858, -15688, -16721, 26497, 12326, -22633, -16937, 28261, 15404,
-    -28246, -10547, 30568, 13646, -18772, -1301, -26703, -25454, 17204, -14625, 4874, -5671, -7172,
-    -10803, -13895, -31073, 22608, -4130, 11848, 9758, 26196, 29324, -7976, 25683, 11077, -26481,
-    -11069, 21101, 12327, -27518, -22076, 17982, 752, 11849, 14641, 31335, -26445, 6675, -14670,
-    -14880, -28020, 27736, -6914, 22097, 20283, -29531, -7973, -32659, 18529, -12600, 10519, -8201,
-    3593, 238, -2290, -777, 1524, -5374, -2320, -3359, -11799, -13628, -21092, 24696, 5134, -31116,
-    -32102, 3593, -23664, -26191, 16701, -4390, 5936, 2566, 13857, 10048, 24925, -25208, -6658,
-    -31101, -32404, -4345, 29552, 30562, -11542, 19530, 13343, 27008, -24675, 7433, -22852, -14909,
-    32620, 12101, -20050, -3104, -29019, -31358, 10004, -26964, -16195, 26967, 5162, -32387,
-    -22635, 4649, -16711, -7472, -30303, 29036, 3579, 26495, 31349, -2847, 22127, 20555, -17754,
-    -3830, -20309, -19039, 19558, 1794, 26707, 21871, -15683, 11288, -10770, 1538, -3877, -8714,
-    -11826, -15185, 32150, 17730, -10046, 1054, -7972, -1308, -16165, -16453, -27008, 15190,
-    -10543, 10002, -7171, 3851, 2291, -489, 2567, 7688, 3880, 12333, 21823, 27781, -15167, 17969,
-    -3574, 15160, 16941, 25981, -21849, 9232, -18482, -8485, -22122, 29064, 7707, -23665, -21823,
-    20558, 4091, 18784, 23385, -18012, -748, -18250, -13643, 27523, 14390, -18013, -9743, -27501,
-    -31634, 281, -30843, -24952, 3366, -20821, -11845, 26240, 15160, -18271, -9741, -27502, -31378,
-    26, -30587, -24696, 3368, -20308, -11075, 27013, 16958, -15445, -5883, -20308, -20071, 17762,
-    -1034, 22593, 14164, -27249, -7220, 23417, 17727, -18272, -8195, -25192, -27267, 5427, -20310,
-    -8763, 28558, 21325, -9278, 3887, -3606, 6401, -5366, 2820, 3830, -1511, 3849, 8713, 4657,
-    14900, 25932, -32609, -5147, -31636, 20848, -9003, 17710, 802, 20552, 27219, -25670, 3334,
-    -16216, -20787, 30063, 15396, -27727, -10801, -32663, 14422, -16456, 3832, -20274, -14657,
-    -29321, 13908, -13373, 5890, -14878, -6948, -16726, -31069, 19781, -5933, 6198, 2305, 14113,
-    8768, 24921, -26237, -8966, 32118, 28762, -12051, 18241, 11800, 22901, -29305, -794, 28042,
-    28778, -2851, 18277, 16956, -24183, -14877, 27751, 18994, -26186, -5917, -26238, 25986, 1279,
-    -32406, 27014, -3862, 28762, 17505, -17484, 5376, -19248, -12087, -26235, 20074, -4121, 20798,
-    9537, 32630, -18524, 6711, -9263, 2294, -14364, -9520, -19294, 29327, 12583, -18781, -13593,
-    -30079, 26709, -10510, 18239, 12574, 23928, -26994, 1780, -32099, -28279, 9748, -25161, -13373,
-    31337, 11334, -20570, -4901, -32356, 30574, 2809, 26498, 31602, -2846, 21616, 21065, -18010,
-    -4341, -20056, -19552, 18533, 1532, 24910, 19047, -19029, 4608, -21561, -14403, -31629, 12364,
-    -16460, 240, -23360, -20315, 25941, -1515, 27231, 29796, -15394, 16952, 5638, 15960, 24148,
-    -21348, -4086, -22884, -22890, 12877, -7208, 9494, -4600, 7699, 6924, 7993, 17722, 29540,
-    -24904, 7186, -13638, -13082, -24425, 32109, 1054, -29823, -24689, 4139, -17745, -9526, 31125,
-    24404, -5672, 11337, 8470, 24397, 25472, -12862, 16945, -3313, 16437, 17459, 26756, -18516,
-    12320, -13081, 2045, -6956, -11796, -16202, -23918, 18531, -2582, 19774, 10307, -32395, -18263,
-    7738, -7210, 4098, -9997, -2584, -9266, -18735, -24686, 25430, -6142, 22603, 19776, -29787,
-    -6696, 32113, 18787, -11066, 10526, -6915, 6926, 2816, 3622, 9753, 16180, 19813, -26228, -3610,
-    29323, 29028, -4125, 18273, 17463, -26485, -15652, 26459, 14122, -31330, -14148, 23118, 2595,
-    29028, -30853, -8200, 29543, 24403, -17710, 9498, -4897, -1775, -3867, -2327, -12569, -12091,
-    -21345, 25725, 7185, -29056, -28246, 10784, -13637, -9228, -20570, -25973, 12618, -10805, 5383,
-    -11542, -3609, -11836, -21565, -30851, 16435, -20281, -1296, -18517, -25678, 23891, 1529,
-    19555, 23634, -19032, -1519, -18001, -16205, 25210, 11555, -25201, -19766, 22864, 6668, 23667,
-    32630, -5669, 20841, 17467, -23403, -12056, 32117, 23886, -15398, 10273, -1045, 3108, 3848,
-    11291, 9019, 22095, -30087, -14112, 22867, 13090, 30092, -20824, 13348, -13086, 1537, -7214,
-    -11287, -17481, -24433, 18012, -5146, 16946, 6190, 24666, -30600, -11544, 24667, 17203, -29021,
-    -10543, 29797, 13899, -20310, -2586, -28506, -29562, 11293, -23624, -10801, -30855, 18525,
-    -10545, 11295, -4606, 8474, 7183, 10557, 19525, -32139, -17714, 17213, 3070, 15183, 19783,
-    -27000, -12317, 27494, 18747, -24140, -4118, -24943, 31630, 7962, -22374, -19257, 24925, 9238,
-    29573, -25705, 7183, -22857, -14654, 31085, 12096, -21080, -5924, -31594, 29293, 1015, 25718,
-    28008, -8495, 14668, 7448, 25686, 28289, -10286, 21318, 6187, 29035, -26999, -3065, -28534,
-    -28284, 3618, -22881, -15948, 21352, 7189, 32111, -31591, 2050, -25716, -29021, 12074, -12867,
-    -6148, -17995, -20063, 22123, 3080, 29538, 27263, -7715, 23628, 10558, -30075, -15437, 14414,
-    508, 19002, 13900, -31104, -13124, 15443, 3849, 23627, 21611, -18768, 6923, -17455, -9002,
-    -22632, 28292, 7190, -25974, -24394, 16443, -3616, 6962, 4621, 16173, 14929, 32377, -13640,
-    12617, 508, 17715, 12103, 31348, -17495, 7478, -8232, 3837, -10770, -5148, -11583, -23106,
-    32632, 13861, -25163, -9517, -30600, 19299, -9261, 13863, -1519, 14384, 16690, 25209, -21597,
-    7182, -20025, -10803, -27513, 21610, -3608, 21062, 11844, -30080, -15176, 14415, 2045, 19520,
-    15700, -27511, -8751, 23154, 17208, -21859, -10771, -30080, 28000, -7945, 22350, 17720, -31076,
-    -11061, 26459, 9788, -26739, -13891, 19041, 7700, 30056, 31891, -1039, -31624, 27008, -1811,
-    28002, 20326, -14148, 8728, -11030, 759, -7721, -12572, -17488, -27510, 14928, -9522, 7701,
-    -7176, 3330, -1296, -3577, -2068, -3095, -10773, -11063, -19286, 29577, 13096, -20058, -12572,
-    -30080, 25689, -9746, 18238, 11297, 24435, -27509, -269, 32403, -31107, 4101, -32106, -25710,
-    10270, -20285, -7720, -25710, 27261, 3846, -31879, 32658, 2819, -27254, -29280, 10787, -15433,
-    -9491, -23394, -29825, 7472, -20568, -10036, 29832, 21581, -10808, 5418, -3350, 5128, -3578,
-    3590, 3072, 1562, 6674, 11296, 12870, 26206, -23400, -2550, -23910, -23400, 12871, -8234, 7442,
-    -5892, 3590, 759, -752, 1792, 4100, 1047, 6932, 11039, 13126, 25950, -23400, -2551, -24166,
-    -23657, 12613, -9004, 6414, -7435, 1020, -3610, -7435, -9260, -13890, -27995, 25436, 502,
-    21093, 23380, -18003, 277, -15686, -12349, 32146, 21837, -8238, 7989, 2047, 13351, 9788, 25434,
-    -26999, -7175, -32134, 29542, -7947, 23380, 18748, -28508, -7975, 32113, 19038, -12345, 9498,
-    -7692, 3591, -1041, -2551, -1807, -1298, -8205, -7718, -12863, -25681, 28777, 6156, 30088,
-    -27507, 5386, -26711, -19540, 21835, -2296, 21324, 21578, -26969, -3606, -28280, 29315, 2820,
-    -30851, -32366, 3849, -25712, -26198, 14901, -8237, 2074, -4633, 502, -8721, -6689, -12605,
-    -23884, 30577, 9498, -29796, -18768, 19522, -3582, 17470, 16438, 29828, -17740, 14383, -7182,
-    8476, 3589, 8495, 13359, 24149, -31598, -6174, 29804, 20060, -14142, 7703, -9754, -521, -8745,
-    -12581, -19796, -30847, 11578, -17484, -4376, -25430, -28021, 13615, -17721, -2321, -18767,
-    -24403, 24151, 1279, 22115, 25179, -16712, 4897, -9775, -3348, -16948, -18256, 31862, 9781,
-    -21598, -10287, 29571, 21579, -12601, 4643, -5408, 1021, -8722, -5151, -12343, -21829, -31622,
-    13615, -22087, -5922, -26734, 28800, 4616, -30590, -30054, 7187, -21082, -17975, 28519, 12329,
-    -28513, -14144, 24409, 6440, -32392, -24422, 4642, -17230, -11058, -32623, 24405, -6433, 13382,
-    9499, 24921, 29830, -8235, 23380, 10555, -28796, -16456, 15439, 2044, 19268, 16467, -26741,
-    -8489, 25206, 19777, -18513, -4092, -19545, -21597, 19039, 758, 21837, 17240, -23144, -3864,
-    -32618, 32369, 2047, 28806, -31368, -267, 28036, 31084, -3866, 21098, 20547, -21086, -6659,
-    -24685, -28539, 6192, -19032, -10035, 30094, 23374, -9008, 7736, 2299, 13095, 8764, 25429,
-    -28283, -9484, 31084, 24660, -16167, 11553, -1299, 3624, 5385, 12579, 11334, 26973, -23659,
-    -3572, -24171, -24173, 10307, -10551, 3327, -14109, -7467, -18261, -32613, 17977, -11066, -230,
-    -7981, -4641, -19762, -21088, 28256, 28, 31854, -30084, -5626, -32140, 31340, -7940, 26715,
-    22345, -23361, 2300, -17491, -22076, 29029, 10523, -32614, -19031, 17206, -8200, 11811, 6926,
-    12617, 22348, -27256, -11028, 29802, 22089, -19510, 4874, -11066, -12057, -21083, -29570, 9018,
-    -18257, -5669, -30046, 32116, 5640, 31891, -25710, 9496, -21824, -10033, -28797, 21096, -5151,
-    18750, 7989, 29544, -25198, -1520, -23913, -22628, 13130, -6438, 9242, -3062, 9240, 8728,
-    12358, 24146, -26482, -7946, -31623, 28517, -8461, 22606, 16695, -31335, -12090, 24406, 7216,
-    -31109, -21598, 7474, -11064, -1295, -17969, -16204, -31878, 11844, -16719, -2580, -25164,
-    -24429, 18238, -11801, 9497, 247, 4134, 7441, 14125, 15956, -32395, -13889, 13387, 2814, 18751,
-    15700, -27770, -9520, 22126, 15921, -24684, -14883, 29029, 16951, -25421, -5410, -28281, 25980,
-    1015, 29545, 24695, -7981, 19009, 5163, 27742, -30336, -8459, 30056, 23892, -17198, 9754,
-    -4894, -1006, -2840, -1296, -10001, -8237, -15688, -29790, 23118, -3867, 13131, 12324, 28515,
-    -30817, 759, -26998, -32359, 8984, -20060, -17196, 30830, 16949, -23622, -4123, -24685, 30863,
-    8728, -22630, -19767, 25434, 8982, 28806, -25453, 6413, -24395, -15687, 28259, 7217, -27510,
-    -17488, 14928, 246, 17979, 12615, -32137, -16717, 10817, -2840, 10527, 1822, 15664, 20036,
-    29835, -12350, 19780, 1565, 24915, 28775, -17711, 14379, -782, 7477, 10010, 20292, 24182,
-    -17747, 8985, -14627, -2327, -14659, -22851, 31341, 10785, -29020, -14920, 23636, 3106, 30312,
-    -30078, -5632, -32140, 29804, -7946, 25173, 19267, -26451, -3869, -28535, 27777, 2813, 32375,
-    29833, 243, 31861, 26749, -3356, 24923, 16212, -20576, -2834, -29020, -28029, 10017, -23367,
-    -9525, -31617, 19039, -8498, 11561, -2293, 13348, 12075, 20323, -29058, -7715, 23408, 19773,
-    -21080, -6662, -23917, -29304, 6960, -18264, -10029, 31633, 25684, -6689, 13130, 10521, 25436,
-    30092, -5928, 25694, 13901, -21606, -6175, 31635, 29795, -2321, 21099, 23113, -19284, -2802,
-    -17751, -18513, 22642, 8464, -32135, -30301, 7180, -20571, -20021, 28769, 11298, -31844,
-    -16721, 19266, -3831, 19260, 17724, 30864, -13123, 19781, 538, 24399, 26977, -20280, 10522,
-    -7463, -3317, -6955, -7977, -21307, -25459, 21065, -10514, 14121, 5902, 14158, 23630, -25453,
-    -7688, -29826, 30317, -5375, 28257, 25177, -17712, 10525, -4637, 23, -1299, 1275, -5889, -1299,
-    -4893, -12057, -13635, -23397, 22639, 2813, 27747, 24695, -9524, 17211, 1822, 22858, 26720,
-    -21823, 8467, -11061, -8459, -16205, -22369, 21097, 2299, 25691, 22125, -14150, 10015, -9745,
-    3585, -3865, -5890, -6695, -10290, -22595, -29825, 15411, -19769, -1298, -19027, -25680, 23889,
-    505, 19039, 22604, -21598, -4605, -23143, -25453, 11330, -10808, 2562, -13601, -7724, -19540,
-    -32619, 16692, -13887, -2806, -13378, -14144, 32404, 21575, -9262, 6448, 757, 9500, 4392,
-    17462, 24149, -29790, -2071, -29821, 27779, 1784, 31603, 27522, -2586, 26721, 18270, -16465,
-    3335, -18740, -11325, -28790, 19811, -4644, 16187, 5933, 26710, -31873, -10773, 27225, 17472,
-    -26194, -4387, -29816, 25978, 753, 27496, 22894, -10556, 12848, -3064, 14374, 11820, 21094,
-    -28032, -6428, 25721, 23883, -15167, 3106, -7216, -3345, -16426, -14926, -30587, 14158, -11329,
-    3339, -13600, -5161, -18506, -29277, 22853, -5914, 11074, 10260, 22099, 26494, -11843, 15161,
-    -2548, 17713, 15675, 27778, -16983, 11050, -11288, 4863, -6170, -6662, -7987, -14394, -27736,
-    28251, 770, 23921, 29536, -11824, 12357, 5378, 18245, 18268, -24178, -5400, 30348, 29793,
-    -4630, 19298, 19513, -25705, -12057, 32364, 21327, -17455, 8207, -7973, -5632, -9270, -13627,
-    -28762, 27482, 251, 21868, 26454, -15684, 4650, -6444, -264, -13083, -8757, -20310, 30094,
-    14374, -19283, -11284, -26232, 29805, -2803, 31337, 30319, -10000, 24399, 16184, -30818,
-    -10554, 25694, 9275, -26232, -15427, 17757, 6665, 26207, 26752, -8242, 20040, 5678, 30308,
-    -28020, -4088, -27518, -30076, 1567, -23664, -20567, 14675, -792, 15158, 7736, 28249, -28276,
-    -6657, -29833, 30321, -6143, 29278, 24410, -18223, 11032, -5661, -1260, -2076, -1806, -10512,
-    -7473, -16455, -30558, 23368, -5405, 11078, 10518, 23636, 27269, -9786, 19268, 2597, 26965,
-    31347, -14109, 22083, 9759, 25212, -25720, 1278, -31072, -24949, 11045, -20279, -4389, -23393,
-    31379, 12831, -19796, -13340, -28546, 25180, -9486, 20029, 12073, 26237, -22891, 4621, -23880,
-    -14924, 27752, 7218, -25976, -17738, 15957, 3065, 20042, 17242, -23407, -5145, 30864, 30564,
-    -2833, 21356, 23368, -19282, -2545, -16982, -17997, 23927, 10775, -29049, -24904, 16173, -6691,
-    2597, 752, 5389, -745, 9234, 10529, 13133, 28252, -22111, -745, -18266, -16971, 23414, 11033,
-    -28794, -24646, 16431, -5665, 3626, 2552, 8728, 4140, 17458, 24148, -31070, -2332, -31107,
-    24957, -1305, 25692, 17247, -17497, 1791, -22846, -15955, 28520, 5425, -26236, -19026, 12879,
-    -537, 13872, 5940, 25677, -32389, -14107, 24650, 12073, 29583, -18270, 12588, -12567, 5376,
-    -5661, -7170, -7731, -13371, -27987, 29278, 2821, 25469, -32146, -5147, 21358, 21311, -21082,
-    -6912, -22894, -28021, 7481, -15185, -5919, -28499, -29063, 9759, -26444, -11330, 29292, 10822,
-    -19812, -7460, 30869, 29019, -3863, 17506, 19253, -26737, -15134, 29020, 15926, -27985, -6704,
-    32632, 18533, -8761, 11302, -4855, 12057, 8732, 13649, 27991, -22366, -1771, -18527, -18768,
-    20846, 7688, 30319, 30612, 1006, -32133, 27014, 492, 29291, 22388, -8247, 15671, 29, 21565,
-    23124, -28242, 748, -25964, -32611, 12571, -18255, -13079, -25979, 28263, -5112, 28506, 25179,
-    -18991, 11288, -5663, -1771, -2334, -2065, -11794, -8759, -18513, 30869, 17456, -14916, -5111,
-    -14927, -17743, 25216, 12573, -25197, -20274, 24910, 7186, 24701, -28804, -1553, 27529, 30821,
-    -4381, 18535, 18999, -24942, -13848, 31336, 20548, -21302, 3837, -14405, -18218, -28288, 22090,
-    -13593, 12577, 2300, 7482, 13862, 24659, 31126, -5671, 28515, 15449, -17237, 1273, -23359,
-    -17751, 27231, 2085, -31630, -26740, -485, -22635, -20315, 14936, -534, 16952, 8768, 30820,
-    -23398, -484, -18782, -16716, 22133, 10517, -30081, -27469, 12831, -11578, -6908, -13641,
-    -17489, 26246, 13602, -22373, -16931, 30822, 17206, -25413, -3617, -25970, 28044, 6664, -27513,
-    -28754, 13604, -11580, -6137, -13382, -15949, 28045, 16431, -17235, -8964, -22119, -27258,
-    7999, -14924, -3100, -26439, -25204, 17718, -15646, 6152, -5414, -7678, -9012, -12610, -30037,
-    26969, 1268, 19822, 25170, -16209, 291, -11583, -6957, -27465, -30087, 12319, -26438, -9784,
-    -32142, 14940, -12612, 6153, -14874, -4131, -15435, -27981, 26710, 2555, 20850, 27995, -12866,
-    6459, -1562, 8467, -1766, 11546, 13350, 16481, -30860, -10809, 15197, 9233, 28255, 28818,
-    -3618, 28770, 16482, -15184, 4613, -18986, -9273, -25199, 22649, 2806, 28515, 22906, -8760,
-    16951, -225, 22081, 24661, -26954, 3063, -21086, -26183, 23367, 245, 15452, 20797, -26227,
-    -13590, 30564, 20034, -22843, 2037, -17746, -23614, 28766, 8212, 29328, -23406, 8727, -22074,
-    -8757, -28281, 21103, -2333, 21065, 11337, -28034, -14402, 15450, 6148, 24148, 22646, -13642,
-    11299, -9738, 6406, -782, -2027, 2037, 2560, -2799, 4607, 4103, 1570, 10518, 14383, 17761,
-    -28547, -8491, 21103, 17457, -24426, -14364, 31336, 19522, -21818, 2295, -16973, -21818, 31080,
-    11812, -29529, -13382, 24920, 4653, -31373, -24425, 2598, -16982, -12089, 29070, 21826, -12090,
-    2086, -4904, -268, -13077, -8245, -18772, 30614, 16942, -15175, -6394, -16469, -20058, 20849,
-    5891, 29800, 27531, -3105, 27231, 15966, -16984, 1788, -23356, -16213, 28517, 4144, -27265,
-    -20571, 9285, -5421, 6159, -7678, 4602, -781, -4850, 490, -1810, -9990, -5935, -13375, -27980,
-    30046, 4616, 26247, -28808, -11, 28047, -31635, -783, 24193, 29275, -9008, 11087, 7944, 22346,
-    21110, -16215, 7955, -17185, -3365, -17745, -30035, 23621, -3354, 11087, 13598, 28000, 32418,
-    748, -29055, 28049, 4860, -29312, 31904, 8202, -21860, -22838, 26193, 6925, 24193, -29063,
-    -1300, 25993, 30048, -5670, 14943, 14628, -31885, -26692, 12059, -10298, -7930, -13128, -16723,
-    25995, 14372, -20579, -15897, -31631, 22598, -18468, 8720, -4903, -5874, -6187, -7216, -23093,
-    -25719, 21569, -13585, 12319, 3835, 6719, 14889, 26708, 32162, -2331, -30860, 22910, -3360,
-    24140, 11345, -25206, -9271, 21369, 16943, -22379, -15126, 32621, 22340, -20010, 6665, -8245,
-    -11015, -15180, -21095, 19826, 3067, 27993, 21625, -11583, 14887, -6132, 13090, 11803, 15713,
-    31851, -13127, 9289, 753, 14887, 6205, 25682, -28804, -12557, 28510, 20798, -25408, -275,
-    -21093, -30548, 18230, -7473, 1322, -1561, 4607, -6645, 2808, 1009, -5874, -20, -1304, -11014,
-    -7473, -13897, -31060, 25424, -791, 14688, 18742, -27006, -18209, 24911, 11802, 27023, -22121,
-    9747, -21809, -7472, -24691, 23938, 4093, 32621, 27279, -791, 30823, 20597, -9016, 15661,
-    -2791, 17970, 19259, 28049, -13128, 18746, -3563, 20283, 20545, 31903, -7988, 27485, 10572,
-    -22124, -7982, 26250, 23623, -11838, 2350, -3878, 2298, -11015, -3107, -10552, -23094, -28036,
-    17976, -19240, 4347, -11323, -16156, -22124, 30826, -479, -29834, -26743, -222, -21610, -18262,
-    16484, 3833, 23887, 18540, -17499, 4356, -22068, -12102, -31110, 13399, -11846, 4358, -16158,
-    -5935, -19543, 31388, 17710, -13633, -4849, -12617, -14661, 29333, 20537, -12606, -1250, -7991,
-    -6181, -23352, -23668, 21576, -11017, 16169, 8212, 15711, 29533, -17232, 3376, -7991, -1555,
-    -18726, -14416, -30082, 11858, -12104, 2815, -18469, -9534, -25198, 21624, 2802, 27231, 20853,
-    -11077, 12326, -7932, 10769, 5387, 7231, 18993, 28774, -26694, 8200, -15689, -16414, -26238,
-    25689, -9219, 22080, 15666, 29331, -14929, 16952, -6393, 16169, 12326, 20335, -27265, -4380,
-    25476, 26706, -10549, 7487, 2804, 13096, 7230, 26191, -29310, -11789, 30047, 21063, -22841,
-    3833, -16203, -20785, -31633, 15923, -23870, -2592, -23912, 30872, 12315, -19544, -15389,
-    -29833, 23119, -14619, 13345, 1787, 7227, 13859, 24146, 30100, -6445, 26460, 12110, -21866,
-    -6951, 28559, 26708, -7209, 11084, 8975, 23374, 23934, -13128, 13866, -7678, 11288, 6670, 9798,
-    21568, -31110, -17702, 21569, 6927, 20591, 32363, -9522, 14681, 10004, 28000, 29844, -2847,
-    30057, 19050, -11329, 10526, -8708, 6663, 1016, -227, 5379, 8212, 5941, 18743, 27744, -26699,
-    5380, -18004, -20274, 31338, 14379, -27214, -8755, 32627, 16477, -12097, 7185, -12052, -532,
-    -10034, -17706, -23405, 26975, -3571, 27739, 26718, -17964, 12834, -2325, 3369, 5124, 11553,
-    9537, 25170, -27769, -9739, 31853, 25174, -15394, 13350, 1272, 7737, 12579, 23631, 29325,
-    -9010, 23375, 7480, -30856, -20316, 7224, -9012, 1273, -14879, -9526, -21600, 27270, 9750,
-    -25456, -22846, 21059, 1529, 15448, 20802, -25971, -12309, 30826, 21832, -19763, 5384, -10809,
-    -12310, -20059, -28799, 9793, -15691, -2328, -25159, -24172, 19775, -11282, 11553, 3841, 8764,
-    15665, 27999, -28502, 2558, -22374, -26446, 19521, -3100, 9536, 9241, 22857, 25213, -14661,
-    14377, -6914, 10013, 6924, 10562, 20036, -31113, -17452, 19266, 5639, 18785, 26719, -16207,
-    4137, -9520, -1558, -17708, -16716, -30599, 11591, -16203, -787, -23875, -21857, 23629, -5114,
-    21320, 20031, -30815, -7979, 30312, 15703, -16461, 2813, -20278, -14405, -31368, 13133, -14920,
-    1529, -20021, -15177, -32138, 11591, -16977, -2326, -26188, -24944, 17464, -14110, 6669, -4126,
-    -4343, -5154, -6182, -18221, -21088, 29542, 1569, -30855, -25971, 1570, -20576, -15691, 21874,
-    10008, -30084, -27471, 11551, -12095, -7939, -16719, -20833, 20589, 3327, 27741, 23673, -10552,
-    16691, -1257, 19004, 21317, -32355, -7468, 29028, 14420, -18263, -528, -26186, -22889, 19776,
-    -10253, 13093, 6155, 12363, 22088, -27770, -12567, 28514, 19262, -24390, -1813, -23143, -31586,
-    14122, -14149, -6657, -17746, -21088, 20072, 2300, 25687, 21357, -15177, 9240, -12312, 244,
-    -9008, -15139, -21087, 32370, 4908, -24943, -16975, 16988, 3328, 23631, 20329, -18261, 5128,
-    -19508, -11065, -27768, 20328, -3870, 19008, 8763, 31596, -22627, 2339, -16208, -11319, 31124,
-    23885, -7722, 9023, 5381, 17464, 15705, -28287, -9522, 20332, 14890, -26999, -19504, 22858,
-    6669, 22387, -32655, -6953, 18533, 15405, -28028, -20018, 21060, 4612, 18532, 26714, -16720,
-    2599, -10296, -4127, -22073, -22375, 24658, -5368, 23115, 21317, -28499, -3357, -28541, 26243,
-    1783, 31341, 25729, -4386, 24403, 12622, -24176, -8494, 25216, 21057, -15948, -2797, -14410,
-    -13892, 29329, 19772, -12865, -1254, -9784, -7468, -25412, -28545, 15149, -21301, -1817,
-    -19803, -29525, 20543, -5412, 6971, 5894, 16690, 14424, -30087, -11838, 15196, 7693, 26969,
-    26247, -7985, 22087, 5687, 32364, -23660, 34, -18781, -14922, 22908, 12831, -25717, -21811,
-    22598, 4867, 18795, 28252, -14409, 4918, -4646, 4097, -9219, -277, -5926, -14873, -15954,
-    -27257, 13655, -8502, 8468, -8449, 4864, -15, -3822, 1009, 1013, -6649, -791, -3870, -13331,
-    -12356, -22117, 22393, 5121, 31339, 27790, -1562, 29798, 19566, -11072, 11809, -7934, 8975,
-    4356, 4916, 14372, 22603, 28560, -9273, 22347, 4659, 32361, -25456, -1766, -21867, -20573,
-    14426, -537, 16694, 7487, 30046, -25198, -4078, -23411, -24684, 8516, -10048, 1274, -17699,
-    -10305, -25454, 20852, 1774, 25176, 18025, -15960, 4360, -20270, -9535, -27765, 19566, -1569,
-    19782, 9543, -29326, -17998, 9287, -1571, 9246, -1251, 15135, 15414, 21879, -21103, 2051,
-    -27467, -18276, 20813, -5879, 22074, 17215, 31129, -10052, 21842, 3630, -32669, -28274, -3823,
-    -24702, -27760, 4659, -15451, -10027, 31388, 29011, -4117, 15969, 19502, -28790, -18213, 25928,
-    8990, 26248, -22903, 4365, -26953, -15193, 24155, 547, 32352, -31872, -8191, -32413, 25697,
-    -15131, 17961, 3595, 13396, 24386, -26989, -10763, -30612, 24926, -13591, 18220, 5394, 15964,
-    28243, -20564, -227, -13906, -13368, 30357, 23874, -10285, 5429, 2285, 8734, 2859, 18733,
-    22612, -32351, -2599, 31351, 20592, -6198, 14904, 546, 23100, 24156, -26440, 5367, -20563,
-    -23356, 29012, 6166, 27273, -24702, 2826, -29526, -19305, 16705, -9995, 13850, 3855, 10565,
-    21560, 32125, -18991, 20019, 1028, 14162, 22075, -29299, -14109, 28758, 14649, -28759, -7480,
-    29042, 14932, -14677, -256, -21563, -14934, 28529, 6965, -22902, -16447, 19302, 9995, 29042,
-    32152, 2799, -30840, 30610, 6911, -28270, -28244, 15907, -12337, -3571, -9023, -12594, -28757,
-    31070, 2313, 26498, -29840, -3342, 25214, 28757, -11310, 10052, 5883, 16190, 14678, -27528,
-    -12595, 17763, 12308, 30581, -30297, 7169, -22363, -22844, 26959, 4880, 24444, -29582, -4373,
-    23931, 26188, -14397, 3886, -3626, 1025, -10251, -2341, -12082, -22073, -27270, 16703, -17962,
-    5627, -11825, -13593, -18788, -31871, 7482, -17504, -9512, 30870, 28243, -5658, 14680, 15907,
-    31607, -25927, 12310, -12342, -7937, -13904, -20566, 23161, 8970, -31875, -30810, 8971, -20054,
-    -18988, 32359, 15156, -25671, -4650, -28791, 24445, 1775, 27750, 21875, -9791, 13359, -4083,
-    15396, 12588, 20589, -26239, -4375, 27272, 29017, -7717, 13395, 11798, 26978, 30871, -1567,
-    30834, 21362, -6965, 15672, 802, 23104, 25181, -25156, 6400, -17226, -18731, -29837, 18498,
-    -18989, 5630, -11829, -13849, -19813, -32132, 5941, -20071, -12600, 24960, 18480, -20311,
-    -9736, -24182, -32133, 1316, -24697, -21596, 11083, -4138, 8475, -3824, 11026, 8732, 11853,
-    26960, -25193, -6394, -25212, -30076, 2088, -21358, -17740, 18023, 6913, 26721, 25219, -6966,
-    19783, 4402, 31070, -28534, -6135, -27784, -32389, -3308, -28812, -30590, -2537, -26242,
-    -27249, 3375, -16734, -11829, 28048, 23359, -12344, 1835, -3114, 507, -11787, -3885, -14142,
-    -27207, 31582, 5905, 28562, -23674, 6163, -26181, -12623, 27752, 6459, -23675, -16196, 16740,
-    8194, 26209, 25478, -6199, 20299, 5175, -32157, -25962, -1764, -19821, -20565, 15970, 3566,
-    20556, 14942, -21878, -5916, 28307, 30551, -5403, 15458, 18215, -30333, -21808, 21300, 1278,
-    12888, 22071, -28792, -16411, 27983, 13357, 31905, -12624, 20811, -1249, 27212, 27493, -20011,
-    14877, -3349, 2093, 6395, 10273, 7233, 25156, -31362, -15641, 25928, 12072, 28820, -17249,
-    13101, -13073, 7168, -4120, -6133, -3113, -7461, -19754, -20075, 27492, -1764, -32668, -32647,
-    -8960, 31069, 23894, -19498, 11281, -6177, -4077, -3369, -5406, -17955, -16476, -32391, 7489,
-    -17762, -8233, 30106, 29013, -4122, 15201, 18219, -29566, -21037, 21818, 3331, 15714, 25930,
-    -21342, -5103, -19560, -22113, 14173, -800, 15668, 5178, 28241, -29822, -11271, 31583, 22607,
-    -20781, 8711, -9520, -10244, -13134, -20828, 22139, 7941, -32651, 31391, 5371, -25969, -30033,
-    15909, -11064, -4846, -9535, -11321, -30546, 30044, 2814, 23168, 32357, -6696, 15716, 15395,
-    -30855, -25405, 15396, -6184, -989, -1053, 1784, -9470, -1311, -7211, -18722, -19558, 30826,
-    1068, -27012, -22374, 5695, -9794, -529, -21033, -14677, -32140, 8009, -16991, -5412, 32168,
-    -31640, 4098, 27284, -27014, 3585, 31652, -23159, 11553, -21806, -3113, -22114, 30109, 15135,
-    -17232, -12297, -22644, -31881, 811, -23930, -20059, 11092, -1572, 12325, 298, 20273, 23376,
-    -32342, -1316, -31108, 22657, -546, 24406, 13405, -19565, -3865, 31396, -29845, 3846, 29082,
-    -24448, 6674, -27974, -13140, 26207, 2867, -28047, -23395, 3639, -11086, -5662, -27458, -24450,
-    15413, -19492, 4592, -13115, -18978, -23678, 24665, -9469, 23611, 15927, 29338, -11856, 19012,
-    -3045, 24382, 22867, -28232, 3051, -23651, -30545, 19500, -9260, 40, -805, 1021, -10240, -549,
-    -9259, -20263, -20852, 25951, -5357, 29264, 25437, -21035, 12817, -6433, -4072, -2090, -4377,
-    -16922, -12884, -28021, 14176, -5175, 10531, -5100, 14101, 10531, 14432, -31903, -15941, 7237,
-    222, 8989, -1245, 16414, 16699, 22913, -17254, 6934, -20265, -4661, -23906, 27024, 11788,
-    -25449, -23606, 24896, 2565, 17771, 28751, -17739, 1067, -8002, -5660, -23862, -20852, 22097,
-    -8956, 21811, 14130, 25996, -16740, 10276, -16154, 2793, -12341, -19238, -23164, 24154, -8701,
-    23868, 16187, 30620, -10314, 21071, 1322, 31063, -32386, -10758, 30807, 20814, -23095, 6135,
-    -16195, -19240, -27275, 19786, -16414, 11277, -4117, -2021, 1768, 1023, -6390, 2539, -2576,
-    -9217, -4143, -12085, -25408, -29843, 11560, -27208, -7998, 31350, 14427, -11854, 3338, -17186,
-    -5943, -22619, 28304, 13590, -22877, -17957, 32352, 15160, -26439, -3629, -29558, 23934, 2282,
-    26726, 20593, -10312, 10536, -8192, 10249, 2312, 4401, 14618, 19274, 25732, -12625, 13107,
-    -7679, 13333, 5654, 11082, 24641, -29813, -13077, 30296, 17219, -25671, -802, -26728, 30356,
-    11278, -23902, -20274, 28755, 8481, 29841, -19819, 9767, -17192, -30, -17732, -24902, 30297,
-    4885, 28297, -24959, 2573, -29016, -19048, 16452, -8971, 14621, 4630, 13131, 24901, -28524,
-    -9743, -31382, 23391, -13856, 16165, 1289, 11844, 19763, 30587, -20796, 16166, -5395, 4906,
-    6142, 10283, 10560, 27473, -28268, -6660, -28553, 29558, -4861, 31072, 25446, -14628, 16938,
-    1545, 13128, 20793, -32380, -16942, 22079, 4372, 21351, 31588, -13362, 12871, 5630, 17736,
-    18011, -23669, -6423, 29834, 29531, -6681, 16985, 16424, -32382, -21823, 17196, -4627, 6449,
-    7687, 14391, 15958, -29322, -13109, 16730, 9486, 26726, 29837, -3108, 26984, 17501, -14931,
-    2570, -18481, -9791, -28527, 21098, -1054, 19534, 12360, -27012, -15162, 16987, 8455, 25187,
-    27267, -6452, 20305, 7478, -30868, -23900, 4138, -12622, -8994, -28501, -30355, 6170, -30815,
-    -17505, 16451, -7429, 15907, 7713, 17500, 32098, -16703, 9020, -543, 7457, 539, 15391, 14910,
-    23926, -19305, 3346, -22079, -11338, 30589, 13131, -14166, -2565, -23106, -18021, 22879, -1518,
-    29011, 25963, -16682, 16676, -1281, 9020, 15134, 23134, 31893, -3114, 27504, 18015, -12367,
-    4118, -14369, -2601, -18755, -27476, 26955, -2051, 18529, 24128, -24154, -6401, -23160, -30836,
-    5165, -18021, -14131, 26754, 20273, -19529, -6142, -18021, -25183, 15447, -1831, 12341, 3625,
-    24126, 26476, -21819, 12562, -10277, -4856, -7228, -13104, -27472, -32671, 4373, 30353, -22905,
-    6173, -23362, -9284, 31360, 15446, -10570, 3091, -13854, -2603, -18497, -27475, 27724, -1792,
-    19557, 25925, -21839, -2545, -16224, -20554, 22128, 9734, 30332, -32100, 6393, -27237, -27474,
-    18730, -10019, 1826, -33, 263, -6656, 2023, -6163, -11025, -9028, -21583, 28040, 14617, -24154,
-    -16422, -32671, 15168, -24133, -1060, -26723, 31123, 12305, -23383, -17708, 32095, 13112,
-    -26704, -5942, 31360, 19043, -7228, 10030, -3574, 14361, 9002, 17243, -31386, -15928, 11847,
-    4080, 14142, 11847, -31387, -21325, 6194, -6716, -2307, -15908, -9800, -27493, 21358, 2536,
-    22109, 17760, -16997, -1278, -25160, -17768, 20568, -4086, 25152, 19026, -27988, -292, -30575,
-    28039, 6390, 32134, 31894, 7418, -28519, -27731, 17956, -11815, -745, -3890, -6675, -17450,
-    -15455, 30591, 8251, -17769, -11558, 29069, 26436, -11816, 7225, 4590, 10030, 7225, 26435,
-    31875, -14621, 26179, 9773, 28812, -18026, 8746, -16165, 1507, -16698, -22076, -30104, 11316,
-    -25418, -5432, 32391, 20329, -3891, 13888, 3367, 26435, 27252, -18479, 17698, -3076, 7737,
-    13586, 19283, 25984, -11344, 12345, -5885, 15385, 7205, 15960, 32090, -19781, 5424, -5177,
-    -2304, -14366, -7490, -24406, 26755, 11529, -29547, -24903, 20011, -6932, 5939, 8188, 12087,
-    13135, -31134, -20039, 6968, -3636, 1037, -9739, 734, -11300, -17706, -19826, 25709, -1258,
-    -31905, 30078, -8712, 30291, 19284, -22591, 5619, -19267, -20278, -30875, 12088, -25162, -4404,
-    -32116, 22641, -550, 19286, 12361, -24709, -15153, 19044, 13071, 29565, -29530, 8960, -22865,
-    -20535, 30806, 7976, 32407, -16483, 13374, -9229, 12560, 781, 7476, 16672, 21598, 32405, -3118,
-    26482, 17499, -12885, 1554, -16941, -6717, -26973, 26236, 8189, 31110, -31847, 8189, -26973,
-    -24394, 22839, -4615, 12359, 16414, 25968, -29019, 5620, -26204, -26449, 21298, -7701, 7477,
-    8447, 13374, 15701, -27791, -14640, 16730, 10760, 25195, 29580, -2091, 24939, 16473, -15199,
-    -1532, -23106, -15713, 23912, 1824, -30620, -31601, -3316, -25737, -31858, 1311, -21112,
-    -22606, 14933, 2018, 14146, 9279, -32421, -25947, 28, -15974, -18751, 23416, 14610, -30060,
-    -22845, 22321, -2819, 11852, 18723, 28535, -25928, 12042, -15671, -11279, -17770, -30834, 9282,
-    -12117, -4620, -24642, -19827, 19282, -8195, 20267, 10287, 23159, -22910, -1792, -32097,
-    -24709, 6690, -25159, -9289, 28793, 12364, -14944, -4875, -27214, -22654, 13373, -16421, 6388,
-    -12328, -13080, -16228, -31603, 10565, -11603, -3333, -22331, -16229, 24681, 1057, -30108,
-    -31346, -3569, -25225, -31089, 1572, -19572, -20295, 17764, 7670, 23139, 22904, -9293, 11061,
-    -6138, 15123, 6435, 13908, 30543, -23635, -998, -14433, -17981, 25217, 17436, -25178, -15647,
-    -30880, 16714, -21816, 4844, -19267, -22073, -31650, 9518, -29527, -10319, 23140, 5426, -27025,
-    -24149, 6712, -7237, -3075, -18217, -11092, -31859, 14680, -6724, 5151, -9223, 6384, -5644,
-    -7166, -2355, -12326, -22586, -24457, 15688, -16419, 9725, -9499, -7680, -6724, -17209, -31838,
-    26944, -7444, 11340, 14351, 23396, 29587, -2098, 24939, 14681, -15206, -3075, -26696, -19061,
-    17229, -9992, 17692, 5150, 14937, 30542, -22607, -226, -12378, -15154, 29844, 25145, -12842,
-    3888, 1757, 3350, -3309, 10496, 4892, 7228, 22575, 27508, -23613, 14095, -11558, -5879, -7237,
-    -15156, -30808, 29772, -2821, 18281, 25660, -23125, -6391, -19316, -27237, 10058, -6724, 1549,
-    -13845, -1841, -17726, -28237, 30028, -250, 21108, 31313, -14900, 7488, 3299, 9002, 3376,
-    23088, 24679, -26694, 8696, -19783, -20012, -29340, 14399, -23611, 1244, -24152, -31578, 20006,
-    -13102, -2022, -4924, -8476, -22325, -20601, 21080, -8447, 22833, 12856, 27019, -15461, 9773,
-    -14103, 5871, -10017, -12561, -12633, -26979, 17509, 731, 16455, 8771, -30110, -23124, 3632,
-    -9037, -7190, -25152, -21887, 16712, -13845, 13067, -2308, 1834, 9982, 10286, 11343, 32084,
-    -23639, -736, -13920, -16186, 26250, 20519, -20042, -8959, -18546, -28780, 8775, -9295, -1795,
-    -20780, -11865, 31616, 10061, -12894, -4108, -26947, -20090, 17224, -12556, 15378, 1547, 7490,
-    19747, 25962, -29262, 7411, -23126, -25150, 27715, 1290, 19825, 31570, -15416, 6719, 2014,
-    7458, 37, 18205, 16967, 25737, -12122, 12085, -9217, 13323, 2576, 6974, 20005, 25449, -29007,
-    6898, -23639, -25666, 26431, -766, 16740, 26174, -23897, -6904, -20601, -28780, 6975, -11350,
-    -5650, -26435, -21630, 16196, -14614, 11782, -3852, -1506, 4843, 2317, -2021, 10241, 7200,
-    8516, 25661, -32379, -15643, 27204, 10541, 29075, -16230, 11570, -13075, 8186, -6164, -6649,
-    -3123, -11047, -22840, -24197, 17224, -15388, 11271, -5137, -2537, 1762, -1540, -8704, -809,
-    -10278, -20012, -20855, 23904, -5877, 27462, 20820, -25924, 4332, -22357, -26695, 25664, -1541,
-    15198, 22837, -27756, -13844, -32675, 18762, -22583, 5105, -17733, -21298, -30361, 13622,
-    -25154, -2862, -28526, 25733, 6133, 31356, 29074, 3820, 32384, 27789, 3563, 30842, 25990, 222,
-    25702, 17509, -13400, 3344, -18216, -5947, -25183, 26246, 9988, -30067, -28239, 15900, -12849,
-    -5365, -9544, -15419, 32158, 25409, -8224, 8515, 8961, 17476, 17767, -21623, -3856, 31132,
-    -29590, 1542, 28818, -26506, 2057, 32672, -22137, 10025, -20272, -1577, -22614, -32351, 19241,
-    -13620, -2795, -7745, -11050, -27210, -29590, 8226, -29524, -12628, 22619, 1831, -32161,
-    -31095, -6136, -28306, 30329, -6393, -32675, 25703, -15132, 19241, 3344, 14680, 26694, -24927,
-    -6394, -22651, -29810, 4915, -15970, -11820, 29331, 26436, -10279, 7487, 6389, 13366, 11085,
-    -31905, -21330, 3376, -8519, -5653, -23352, -19570, 22104, -6647, 24892, 17735, -31834, -4664,
-    28273, 14684, -12889, 775, -20784, -10319, -32378, 14169, -8264, 4375, -12304, 2017, -11817,
-    -18215, -20342, 25449, -3309, 31830, 26991, -14875, 21551, 5146, 18792, -32163, -14901, 10312,
-    5102, 13884, 10826, -31136, -21840, 4145, -7750, -5135, -21555, -16745, 25706, 291, -29339,
-    -30578, -3307, -23685, -28522, 4404, -13663, -10789, 31904, 31570, -3337, 18798, 25916, -21842,
-    -5617, -17004, -23641, 15201, 2271, 16452, 9033, -29341, -21328, 4922, -5441, -1539, -17180,
-    -7754, -25954, 21628, 6895, 27503, 24198, -2615, 20308, 7493, -26260, -20042, 8779, 468, 7972,
-    -2016, 17431, 14140, 21371, -18550, 1291, -27204, -14438, 22109, -2275, 31309, 27249, -16668,
-    21801, 3348, 15714, 30282, -21325, -734, -10839, -13358, 31649, 29511, -5906, 13660, 18974,
-    31359, -25148, 17176, -8992, -2017, -44, -3081, -13325, -5441, -19786, 30109, 21288, -14904,
-    -4072, -8011, -12848, -31314, 32339, 260, 22399, -31912, -10278, 12891, 13578, 25959, 29082,
-    471, 29043, 19059, -6469, 11825, -5100, 17690, 11825, 19315, -23431, -4881, 26769, -32683,
-    -6424, 15719, 20260, -29812, -20262, 26172, 5655, 21372, -27799, -6682, 20345, 24373, -20818,
-    -7411, -17519, -24930, 12122, -1843, 10024, -2785, 18204, 15164, 22658, -16749, 5399, -21805,
-    -5441, -28011, 21629, 4839, 25703, 20087, -8526, 10541, -8441, 13320, 3859, 6979, 22058, 28017,
-    -25661, 13321, -13105, -10240, -12380, -23385, 19316, 7152, 25703, 22400, -6213, 15167, -1502,
-    24885, 22363, -28488, 5096, -24412, -29516, 22573, -7708, 4410, 7923, 11568, 9036, 31824,
-    -25441, -4328, -18549, -23642, 12635, 469, 12339, 2098, 25912, 27245, -23089, 15376, -8223,
-    -3813, -816, -5139, -16920, -10839, -28269, 15463, -1331, 13367, 1071, 26168, 26474, -23859,
-    14090, -10279, -7410, -6214, -14134, -31568, 31309, -514, 19320, 30281, -15935, 2616, -1589,
-    772, -12292, 211, -12336, -23600, -24461, 17220, -18461, 10235, -8481, -9722, -6728, -16705,
-    30628, 25398, -9510, 4158, 6379, 10537, 5186, 27453, 32639, -17174, 26940, 9766, 25231, -19064,
-    5912, -24372, -6985, -31867, 15464, -4673, 10026, -5868, 15888, 9255, 14178, -30373, -16960,
-    6983, 2009, 8227, -985, 18972, 17222, 25229, -11355, 12854, -9467, 15117, 4630, 9037, 25397,
-    -32122, -17435, 27454, 8999, 25998, -19064, 5659, -23605, -6471, -31606, 17259, -2617, 12857,
-    40, 24882, 23137, -27717, 7406, -22096, -24890, 30280, 3605, 23940, -26261, -4106, 24969,
-    32593, -9504, 12634, 14860, 26219, 30624, 3038, 32387, 24970, 3552, 27247, 20344, -6215, 12599,
-    -4072, 20257, 14655, 24712, -14439, 8488, -15896, 4323, -13358, -18980, -20863, 23908, -6901,
-    28482, 19796, -26948, 4324, -24409, -29775, 22572, -8733, 3894, 6637, 9001, 5693, 26169, 30332,
-    -18980, 22572, 2062, 14944, 28226, -23896, -5616, -18292, -25438, 11861, -2102, 7974, -4074,
-    15375, 9516, 15201, -29344, -15928, 10319, 6122, 14656, 10833, -28317, -19269, 7750, 467, 6432,
-    -3302, 14860, 9773, 14688, -29345, -16442, 9549, 5093, 12857, 7750, 32592, -26979, -4843,
-    -19837, -26465, 8779, -5446, 1293, -14353, -820, -17468, -28488, 31820, 1037, 22912, -29347,
-    -8730, 17259, 20769, -29548, -18979, 28994, 7975, 27024, -18552, 6177, -22065, -3903, -28518,
-    23425, 7148, 28023, 25481, 209, 23140, 13659, -16497, -5388, -31830, -24978, 6178, -28490,
-    -10072, 24169, 4407, -24465, -22863, 8263, -1850, 3353, -8443, 7916, -3587, -5872, 3547, -5130,
-    -11783, -4163, -18751, 32422, 26421, -9243, 6723, 10486, 14659, 14690, -23182, -11042, 20602,
-    22565, -24664, -12809, -24723, 25709, -9725, 28734, 16714, -30543, -1079, 31364, 19830, -1337,
-    15688, 3896, -32692, -31601, -9468, -27809, 25454, -12810, 25649, 10034, 25483, -17014, 5409,
-    -21550, -3136, -28001, 24454, 9714, 30853, 30622, 9200, -29029, -29774, 19738, -13096, -3559,
-    -3650, -10269, -24119, -21383, 16974, -14354, 15370, -2045, 3380, 14085, 14660, 18800, -19326,
-    -3331, 32679, -23438, 6436, -26947, -7761, 27768, 10062, -14701, -7699, -32600, -27294, 2582,
-    30879, -19070, 8494, -20266, 1234, -22347, -30803, 25136, -8727, 6464, 10743, 14147, 14945,
-    -23439, -11554, 20343, 21794, -26204, -14610, -28064, 20057, -17952, 14600, -5902, -1503, 5091,
-    1038, -3817, 9717, 3095, 3122, 18712, 19029, 28051, -5961, 19030, 3379, -30377, -30058, -4845,
-    -22153, -30058, 3380, -13673, -13353, 28310, 27962, -12069, 5438, 6630, 9263, 5438, 27961,
-    30594, -17436, 26163, 5922, 21885, -24724, -5644, 24713, 32074, -11299, 10065, 12027, 19542,
-    20859, -11875, 6179, -16151, 3289, -15667, -22833, -25495, 14403, -21292, 6117, -17980, -22063,
-    -27293, 13375, -23863, 2263, -24405, -32087, 21539, -13098, -1760, -2363, -6673, -19236,
-    -13414, 30336, 6722, -15728, -11556, 27797, 28991, -11043, 7238, 9201, 14144, 12635, -25752,
-    -15412, 13407, 11256, 22368, 22659, -7249, 12860, -5355, 20765, 12860, 22915, -16501, 3609,
-    -23347, -6478, 32651, 15718, -3652, 8751, -5357, 16909, 8237, 14946, -28838, -17207, 9036,
-    5600, 11321, 6466, 31557, -30828, -9982, -27040, 25199, -12296, 26418, 10807, 27025, -14189,
-    9266, -14868, 7914, -10524, -12555, -9819, -25944, 19828, 7400, 23658, 21113, -7250, 10038,
-    -7158, 16395, 5412, 12117, 31044, -26200, -5102, -17787, -26714, 11090, -1854, 5156, -6644,
-    12538, 1559, 4407, 19991, 20063, 30364, -1084, 24690, 13916, -12650, -3580, -25920, -15220,
-    19551, -5360, 28471, 18266, -28234, 4313, -28766, 31648, 16907, -21571, -14099, -21900, 24947,
-    -6389, 32328, 21349, -21039, 13825, -11549, -7160, -5194, -16689, -31318, 31044, -4354, 17000,
-    26161, -26200, -9729, -22669, 29313, -3047, -26010, 32654, -3047, -22669, -29541, 3636, -12390,
-    -12579, 30622, 31558, -6926, 14432, 21021, 32138, -22577, 22821, -3072, 9549, 19737, 26226,
-    -29773, 9714, -23119, -23605, 31817, 5152, 27024, -20355, 3354, -26691, -10332, 24943, 4921,
-    -22412, -21061, 12118, 4573, 13121, 7749, -31151, -26972, -2788, -16245, -22603, 16488, 7656,
-    20574, 18030, -13162, 1043, -22064, -7251, 32141, 14945, -4425, 6185, -8186, 12280, -497, 1838,
-    15621, 13124, 18800, -19332, -4867, 31137, -24986, 1816, 32421, -17019, 10812, -15897, 9196,
-    -11291, -11785, -9051, -25426, 21369, 10224, 27003, 27537, 3285, 26232, 19827, -5197, 9785,
-    -5103, 18962, 9014, 18541, -23701, -10005, 22140, 26415, -21571, -5102, -12393, -22085, 21113,
-    13564, 30087, -31830, 12793, -23627, -20779, -30126, 10041, -29775, -5454, 25462, 10318,
-    -15221, -9748, 30622, -30127, -4351, 21113, 31297, -17716, 3381, 456, -754, -10498, 3284,
-    -11549, -18465, -15734, 27002, 1068, -22931, -26198, 5952, -5456, -4095, -20006, -9311, 31629,
-    11863, -6999, 19, -17180, -2116, -24396, 28824, 19473, -22084, -12811, -20105, 27775, -2531,
-    -25502, 32658, -3045, -21133, -29023, 5180, -8798, -8463, -27716, -21134, 11841, -19493, 7394,
-    -16944, -19750, -21904, 19037, -12812, 20760, 3103, 14173, 31811, -24397, -2532, -12394,
-    -19771, 23426, 18190, -28510, -20265, 31041, 6186, 27537, -17533, 5159, -21809, -2370, -29279,
-    24452, 9709, 29061, 29335, 7396, 31631, 29592, 10223, -30821, -30033, 18962, -15916, -6645,
-    -8281, -19771, 27794, 22303, -20029, -7672, -13421, -25683, 16487, 5340, 17237, 12632, -21132,
-    -13090, 21114, 22559, -26198, -13839, -25757, 21605, -14097, 21533, 3101, 14944, 32070, -22857,
-    -733, -9565, -14633, 31393, 30785, -7438, 13147, 19734, 29056, -26946, 15880, -14636, -9212,
-    -10078, -22860, 22143, 13309, 31882, -30800, 14852, -19263, -14866, -20614, 26741, -4329,
-    -29609, 28283, -11526, 30017, 15176, -30288, -1852, 29826, 18029, -4166, 10038, -4074, 19479,
-    11580, 21369, -19072, -1784, -30546, -18815, 12095, -16155, 9456, -10779, -10758, -8277,
-    -23115, 24709, 14854, -29798, -24379, 24364, -3585, 11089, 20509, 28283, -26434, 14599, -14895,
-    -9986, -12386, -25432, 18028, 5347, 20315, 15972, -16499, -3587, -30031, -20868, 11577, -18981,
-    5347, -16694, -21037, -25236, 16203, -18468, 10231, -11297, -10501, -9558, -23119, 23424,
-    12545, -32372, -29262, 15887, -15925, -9473, -13668, -25691, 16742, 3037, 17229, 10831, -25491,
-    -17210, 13145, 8176, 18771, 17257, -17268, -2561, -29774, -20095, 13117, -16668, 8690, -10528,
-    -11528, -10071, -24149, 21626, 9718, 28794, 28822, 4321, 30593, 25224, 2522, 25196, 18028,
-    -10072, 5151, -14356, 3036, -14125, -20524, -22664, 19543, -12301, 18972, 3866, 13913, 29509,
-    -24919, -4591, -17780, -25176, 13400, 210, 10805, 1835, 24625, 23655, -26436, 9205, -20036,
-    -20011, -28317, 14403, -22839, 3295, -22349, -27979, 26683, -3846, 13657, 21286, 32648, -20782,
-    23086, 9, 14170, 25399, -28262, -11788, -29085, 22368, -15387, 17691, 9, 9285, 20004, 26994,
-    -26953, 10496, -18497, -16416, -24713, 22367, -10506, 21806, 9260, 23161, -23170, -2049, 32157,
-    -25483, 4634, -28754, -14175, 20312, -1769, 28488, 24424, -20274, 13840, -8474, -2540, -1324,
-    -5904, -15133, -11347, -28520, 17764, -811, 14658, 5942, 30800, -31089, -8194, -29338, 25709,
-    -11279, 24120, 10546, 27271, -18029, 6692, -18477, -2095, -23377, -32612, 19237, -15925, -4084,
-    -10319, -16953, 30869, 23606, -13356, 2600, -811, -762, -9223, -40, -11813, -19503, -21371,
-    22112, -6910, 25147, 15687, -32097, -6465, 24169, 10309, -20858, -13354, 23674, 20520, -23892,
-    -11022, -24969, 26995, -5625, 31315, 23140, -18476, 14354, -6417, 287, 3816, 1808, -2027, 9727,
-    5150, 7482, 22577, 27509, -22845, 14354, -10786, -4083, -5179, -11557, -24386, -26253, 12602,
-    -21046, 1247, -22094, -28242, 24635, -5647, 11338, 15126, 24679, 32155, 734, 31104, 24188,
-    -809, 21339, 12880, -21627, -10787, 25217, 24120, -17984, -2025, -10319, -14129, -32608, 28489,
-    -5649, 14425, 18466, 31616, -23869, 17182, -7707, 805, 2789, 2574, -3308, 8957, 4374, 4916,
-    18980, 22621, -32350, -39, 31617, 23163, -811, 20567, 11341, -23428, -13872, 19566, 15894,
-    -31606, -24382, 19493, -6164, 4404, 8441, 11570, 11086, -32680, -22869, 807, -11607, -12075,
-    32419, 30799, -3338, 17771, 24888, -23642, -8700, -21887, -31352, 2352, -18290, -16703, 20343,
-    14350, -31353, -27203, 17435, -10023, -3044, -2612, -5911, -18978, -14434, 31869, 6980, -15977,
-    -9252, 29597, 31055, -4884, 15206, 21032, -29043, -18976, 27972, 9251, 26513, -19317, 7196,
-    -22576, -4925, -27756, 22400, 5355, 27500, 22400, -4926, 16964, 1583, 29512, 30585, -15894,
-    25401, 8997, 24198, -21631, 1802, -29774, -17262, 17480, -9728, 18462, 7714, 16486, -30626,
-    -15160, 9805, 5611, 14396, 10062, -30113, -21071, 4152, -5699, -2567, -18721, -10068, -29809,
-    15204, -3130, 10799, -2787, 19487, 15425, 24712, -13924, 9258, -14611, 6123, -10018, -13840,
-    -12638, -28008, 14945, -1588, 11572, 39, 23341, 21595, -30545, 2781, -29549, 28823, 11005,
-    -27493, -26433, 23085, -4878, 8007, 14604, 21336, 25740, -6985, 17225, 40, 28995, 27505,
-    -19236, 19744, -1023, 8521, 18973, 26219, -30544, 7151, -24668, -27717, 24371, -4366, 9550,
-    16404, 25189, 31138, 2012, 32385, 23942, 2012, 25189, 16746, -12381, 3345, -19236, -4671,
-    -25182, 25483, 11521, -29552, -28231, 18718, -10278, -2016, -1329, -4110, -15894, -9039,
-    -25698, 20344, 5867, 25446, 20858, -8012, 11826, -6642, 16404, 8742, 14946, -30628, -16702,
-    7751, 2525, 9256, 1326, 22057, 22363, -31571, 2268, -30323, 27026, 8179, -31351, 31909, 11778,
-    -22614, -21291, 32596, 10540, -32600, -11095, 20821, -475, 31311, 29816, -14354, 26172, 10798,
-    27280, -16748, 9257, -16926, 3042, -15159, -21552, -26256, 16453, -18983, 7926, -12332, -13586,
-    -15718, -30579, 10059, -10065, -1281, -20781, -11607, 31873, 10831, -12122, -2566, -24378,
-    -16234, 23649, -2276, 32083, 28532, -14356, 24631, 9000, 24451, -21630, 1291, -29264, -17518,
-    16969, -9219, 17950, 6946, 16481, -31909, -17213, 7744, 987, 6946, -738, 16408, 13885, 21878,
-    -19573, 265, -27468, -17003, 18770, -6394, 22576, 13887, 28558, -12891, 13117, -7680, 15637,
-    5407, 13394, 29001, -25691, -4596, -20087, -27233, 10311, -6467, 1039, -13078, -1584, -17722,
-    -26956, 31313, 1297, 25215, -28569, -6414, 22903, 26944, -18494, 545, -7239, -9499, -24898,
-    -23687, 14146, -17446, 7411, -12840, -13334, -15719, -31858, 10054, -11094, -3845, -23099,
-    -16234, 23398, -997, -32425, 29309, -11021, 28743, 14917, -29526, -4154, 28796, 16992, -9038,
-    4639, -11794, 3556, -11553, -15392, -16490, 30339, 6454, -18033, -14894, 24959, 20775, -22862,
-    -9737, -22144, 30595, 801, -23430, -25689, 8512, -6212, -1016, -15133, -5184, -23632, 28815,
-    16148, -23633, -15390, -28313, 18773, -17190, 12038, -7957, -3825, -1327, -7957, -17189,
-    -14691, 30851, 8255, -15720, -10270, 31386, 31826, -4874, 18537, 24373, -24921, -8963, -23429,
-    30849, -996, -25228, -28519, 3374, -14435, -13356, 29075, 26429, -12072, 5432, 4326, 7718,
-    3119, 21802, 22881, -29778, 4069, -27749, -32605, 15892, -18498, -11531, -19574, 32646, 4147,
-    -18033, -15671, 22652, 17691, -26723, -18212, 31056, 11314, -32091, -10322, 21338, 2091,
-    -31397, -31091, -6133, -26514, 31104, -4591, -28313, 30847, -6647, -30626, 26478, -13073,
-    23860, 9002, 24192, -21887, 265, -30037, -19317, 13887, -13590, 10497, -5133, -3052, 2016,
-    -2821, -9220, -2096, -13101, -23612, -26768, 13371, -21557, 1760, -21582, -27982, 25662, -3850,
-    13397, 19237, 31359, -23355, 17439, -6936, 1833, 4588, 5401, 1319, 16410, 16709, 24449, -14688,
-    8486, -14617, 3560, -12332, -17187, -20084, 26990, -1510, -30621, 32130, -6907, -30878, 26476,
-    -12562, 23094, 9257, 24446, -22653, 263, -30040, -20597, 13114, -14878, 7417, -9246, -9224,
-    -9545, -20554, 28042, 16413, -22611, -13593, -27534, 22879, -11795, 19499, 6174, 18788, -32159,
-    -14901, 11336, 5106, 14912, 12878, -29076, -17728, 11337, 2535, 12342, 7482, 28749, -30835,
-    -9481, -31646, 22879, -15907, 15387, -1795, 6197, 12817, 17994, 23416, -15711, 6430, -16421,
-    -1576, -19527, -28243, 26181, -3337, 15449, 20527, -30580, -17448, 25668, 7200, 25728, -24448,
-    5, -31328, -23163, 9515, -20278, -2603, -24666, 31637, 15131, -20298, -11797, -24190, 28019,
-    -2802, -32414, 28790, -9999, 26441, 14912, -30303, -7741, 25707, 11846, -20078, -10017, 29066,
-    26954, -11046, 9278, 6393, 14141, 13904, -29331, -16957, 12363, 3822, 14655, 11592, -30874,
-    -20812, 6710, -5432, -508, -13080, -4918, -19783, -31841, 22582, -10789, 4398, 2535, 5403, 543,
-    14871, 13884, 21360, -21367, -1537, -30554, -23166, 10286, -20275, -1064, -23124, -31583,
-    19754, -13359, -1256, -5690, -8476, -21816, -21367, 20823, -7939, 21554, 12085, 26499, -18282,
-    6432, -18735, -3633, -24408, 30610, 14872, -21839, -13852, -27276, 22623, -11283, 19500, 6432,
-    19557, -31387, -13615, 13904, 8449, 20823, 22642, -13911, 6946, -13340, 1767, -13358, -17966,
-    -23419, 22366, -7173, 22843, 13885, 30863, -13138, 15685, -3319, 20016, 14657, 29063, -14166,
-    12602, -6919, 13078, 3864, 11842, 23101, 32648, -14887, 24901, 7719, 27775, -22902, 2323,
-    -25169, -15706, 21856, 1560, 30811, 29566, -9749, 26957, 14403, -28511, -6968, 26997, 15694,
-    -15450, -3072, -22857, -18534, 20830, -2040, 26185, 20830, -22601, 5625, -20291, -18746,
-    -31897, 11578, -24144, -5426, 32396, 23145, -2600, 16720, 10295, -30871, -24401, 6184, -10312,
-    -7953, -22600, -22648, 16463, -10265, 13848, -243, 9525, 16932, 22887, -29797, 741, -32626,
-    29571, 4596, 30597, 31113, 3825, 31368, 31113, 4596, 32139, 32655, 6909, -29542, -26713, 16676,
-    -13352, -1012, -6969, -11296, -22600, -26501, 13120, -17461, 3055, -17721, -18746, -29327,
-    14148, -19004, 2285, -20034, -21574, 30813, 5924, -32369, -19560, 10037, -12838, 4085, -12323,
-    -11553, -17246, -32369, 12606, -12878, -3842, -20290, -17247, 24429, 3612, -30355, -30313,
-    1043, -21875, -24402, 15179, -1573, 9781, 4128, 21814, 22117, -25685, 4338, -25172, -24914,
-    23100, -5384, 13381, 15647, 25713, -28511, 4853, -26973, -26455, 19503, -10012, 4901, 2540,
-    4381, 2331, 14362, 13633, 23405, -20848, -759, -26197, -19306, 16718, -6923, 17190, 6952,
-    20062, -31127, -14380, 15694, 8709, 21343, 25717, -11081, 11321, -4096, 14620, 7209, 17749,
-    32353, -18749, 9269, -1830, 3869, -2297, 9222, 3355, 8497, 19502, 24429, -25685, 6395, -22860,
-    -20545, 29526, 5411, 31112, -21618, 5669, -19519, -6455, -30054, 25457, 3054, 24431, 23915,
-    -9540, 10040, -3071, 14619, 7213, 18517, -32156, -17974, 11836, 1768, 9269, 7467, 24641, 27773,
-    -16692, 18731, -2297, 12864, 18217, 27001, -23888, 10508, -17205, -10267, -20332, 31112, 7210,
-    -19819, -16434, 25458, 16419, -27229, -14635, 30812, 12607, -25687, -5940, 30084, 20574, -7483,
-    9011, -2043, 14363, 8240, 19288, -30613, -15405, 15948, 7938, 20061, 24429, -13651, 6698,
-    -10268, 3826, -10522, -10011, -13393, -27484, 21344, 1256, 18520, 16461, -23160, -10779, 28027,
-    24643, -16691, 4127, -4914, -4612, -13606, -10568, -27999, 22889, 2796, 21860, 20576, -15195,
-    1301, -17719, -8513, -30567, 22631, 225, 18521, 14921, -23934, -13348, 24174, 18986, -26456,
-    -11550, -30101, 19805, -14121, 13334, -4612, 4642, 7680, 8752, 12352, 28754, -28000, -3582,
-    -23932, -31084, 6185, -16994, -14379, 29573, 23099, -16179, 2075, -5944, -7184, -18228, -17252,
-    26741, 4389, -25991, -24917, 9273, -6974, -1272, -13601, -6203, -23374, 30604, 15900, -22347,
-    -11802, -25734, 24685, -6149, 26696, 17232, -26453, -1061, -31084, 28546, 5878, 30854, 31887,
-    5621, -31598, -30822, 11276, -22861, -16430, -31386, 14405, -21571, 740, -24146, -27996, 21044,
-    -10012, 6187, 4081, 7208, 6444, 21557, 24941, -23883, 8708, -17980, -14117, -24702, 23912,
-    -5380, 25672, 17487, -26712, -2085, -31857, 27259, 2798, 26997, 25460, -5684, 16461, 6442,
-    30553, -31856, -5638, -30099, 26484, -7695, 25929, 14919, -28513, -6454, 26999, 16720, -14422,
-    -1528, -19775, -13908, 28028, 10295, -19563, -13093, 28800, 23357, -16949, 2073, -6971, -8468,
-    -20029, -20592, 21345, -3838, 25412, 18004, -26455, -546, -30826, 29829, 7164, -32368, -29539,
-    11534, -21575, -14376, -28301, 19289, -13092, 13592, -3071, 6441, 10765, 13891, 20576, -23674,
-    -6413, 31114, 32096, -5386, 22120, 24129, -22092, -2809, -17506, -23120, 20065, 4596, 21856,
-    21607, -14423, 4124, -14889, -3115, -21319, -29024, 22843, -9241, 8757, 7422, 13119, 15696,
-    -28816, -16180, 15440, 7421, 19801, 22122, -15453, 3354, -16944, -5430, -25944, 29317, 11533,
-    -28001, -21313, 24127, -502, 18780, 26183, -23633, -2551, -18279, -23890, 18267, 2538, 17745,
-    15183, -24448, -12325, 23408, 19243, -25690, -11802, -29587, 21342, -13345, 15647, -504, 10043,
-    17189, 24682, -28765, 3568, -27747, -29279, 15905, -15669, -5120, -13394, -20809, 25978, 12564,
-    -29034, -21825, 21817, -1793, 14414, 19761, 32645, -18740, 20790, 520, 15955, 23360, -27751,
-    -9746, -30867, 23393, -12574, 17194, 3090, 15439, 24904, -26723, -6664, -27267, 30075, -2038,
-    -31379, 30589, -5380, 31074, 24164, -14633, 15141, -1023, 9783, 14370, 22878, -32623, -4135,
-    27248, 18778, -13645, 3348, -14377, -5164, -21581, -30825, 18995, -13615, 1045, -6450, -7190,
-    -18230, -19300, 26221, 2331, -30609, -30063, 19, -23414, -25180, 11842, -6453, 3349, -7949,
-    2286, -7703, -10262, -11335, -23637, 25719, 8712, 32646, -29023, 9998, -20555, -15402, -29837,
-    18767, -15660, 8972, -7963, -3837, -5935, -11047, -21827, -27009, 15425, -16174, 5117, -12332,
-    -11805, -18527, -31607, 10812, -14930, -5393, -25168, -24696, 14397, -14889, 5374, -10790,
-    -10006, -15186, -26467, 19293, -1309, 16454, 10555, -32407, -23382, 4902, -12105, -8733,
-    -25938, -28296, 9772, -23369, -7222, -32376, 21093, -4653, 14400, 4902, 26187, 29049, -15145,
-    20534, 3349, 19293, 29272, -19011, 5416, -6710, -3334, -15144, -11593, -28777, 20066, -1571,
-    16200, 9529, -32412, -25178, 2591, -14937, -14641, 30348, 23357, -13871, 3621, -2345, -1020,
-    -9230, -2345, -13870, -22080, -28045, 13116, -20539, 483, -22351, -27478, 23357, -6161, 11331,
-    12820, 22366, 29321, -6199, 21082, 9018, -27531, -20553, 11332, -1061, 7976, 795, 17186, 15686,
-    26752, -14683, 9519, -11029, 6906, -6673, -5888, -4146, -12584, -22850, -27019, 13117, -19767,
-    1766, -20551, -24650, 28495, 1295, 24180, -31901, -10271, 17499, 15388, 30592, -25421, 13076,
-    -14385, -7174, -13909, -23123, 22639, 7422, 28021, 29578, -32, 27251, 21354, -8771, 10033,
-    -4604, 13589, 6435, 14414, 29009, -24663, -1520, -18023, -22093, 19555, 5878, 22883, 22896,
-    -11342, 8749, -8203, 8706, -2303, 793, 6906, 4894, 6190, 19499, 22884, -28763, 2537, -29031,
-    -32104, 12561, -22093, -15142, -29330, 18514, -16171, 9993, -8473, -4091, -4914, -11300,
-    -21824, -25474, 15943, -14886, 8452, -8474, -5377, -6711, -14128, -26194, 32354, 4120, 31374,
-    -22902, 6177, -21570, -8253, -32373, 20065, -4913, 12347, 2589, 22586, 22370, -25425, 4596,
-    -23634, -23883, 25414, -1275, 19294, 25414, -23378, -3065, -19048, -24663, 16725, -288, 13632,
-    8244, 29781, -30316, -5635, -28301, 28795, -4607, 31838, 24426, -14117, 17704, 782, 13896,
-    22073, -32372, -14889, 25415, 7721, 28801, -21874, 3867, -22087, -11080, 29054, 13894, -15193,
-    -4614, -24142, -21361, 16718, -8723, 15135, 3097, 14407, 24644, -29800, -8981, -31896, 21344,
-    -14122, 13852, -3330, 6697, 9997, 13889, 20061, -24956, -7700, 28800, 27730, -11556, 11839,
-    6913, 16457, 19035, -23414, -6674, 30858, 30814, -5904, 20065, 20791, -26465, -10519, -30609,
-    22623, -12576, 16167, 1806, 13638, 21564, -32119, -14890, 24392, 7717, 28029, -23925, 2064,
-    -25686, -17757, 19798, -1785, 23878, 19798, -25430, 234, -27491, -30827, 12828, -20039, -10781,
-    -25465, 27250, -1786, 30819, 26993, -11039, 21054, 7975, 25969, -26492, -2563, -32370, -29833,
-    1293, -31600, -25207, 6434, -21578, -10044, 31364, 18515, -10302, 5408, -7444, 3320, -6929,
-    -6159, -7988, -16952, -27490, 26194, -3846, 19543, 20797, -27491, -9499, -32145, 21597, -13098,
-    13089, -2049, 8235, 10776, 17226, 25197, -18523, 4634, -16439, -7215, -25949, 29822, 8463,
-    -29291, -23378, 17202, -7961, 6436, 3066, 7717, 7978, 20285, 26478, -21578, 9235, -13873,
-    -7443, -17236, -26209, 19286, -2588, 14913, 9520, 29023, -28778, -2816, -27004, -31605, 3867,
-    -22893, -20811, 18517, 2807, 19539, 19031, -21866, -4620, -30056, -29576, 4119, -28772, -19553,
-    15171, -7442, 12574, 3092, 12861, 20798, 31619, -15924, 20285, 2321, 20056, 26967, -20553,
-    3609, -12099, -10530, -25689, -31374, 6433, -27746, -16468, 19027, -247, 23625, 21083, -23378,
-    2551, -23122, -23121, 23883, -1534, 19799, 22855, -24922, -4617, -25204, -31861, 5921, -21350,
-    -17469, 23912, 11033, -32376, -24148, 13347, -12331, -2045, -10041, -13616, -26717, 29538,
-    1291, 28024, -31886, -5392, 25198, 24141, -17472, 3354, -9528, -7449, -20547, -23406, 20308,
-    -6413, 18230, 10542, 25712, -24947, -766, -28773, -25204, 10029, -17980, -3616, -23381, -29802,
-    16688, -14644, -1017, -11326, -13873, -28259, 27739, -1795, 22629, 25169, -18758, 2841, -11327,
-    -9506, -24658, -29574, 10284, -22860, -7986, -32121, 21859, -5417, 14912, 5925, 25937, 30332,
-    -12837, 22340, 7973, 26998, -25720, -508, -29543, -25206, 9002, -19264, -5417, -26721, 30338,
-    8462, -28521, -23119, 18486, -6163, 9008, 7435, 15168, 19288, -26490, -8477, 26999, 23112,
-    -16445, 2842, -8758, -6936, -19774, -21865, 22877, -3069, 24653, 20564, -24144, 1266, -23898,
-    -26457, 19771, -7451, 8240, 5379, 13109, 14408, 32107, -19531, 8241, -6445, 1031, -9494, -3618,
-    -14132, -21830, -31117, 11569, -23373, -6959, -31607, 23145, -3362, 18253, 11066, -30862,
-    -21326, 9268, -6448, 1035, -9238, -2593, -13871, -20289, -28550, 14657, -17463, 2805, -16698,
-    -17463, -28806, 17227, -14894, 7433, -9246, -5128, -9529, -16442, -29286, 24653, -6163, 14920,
-    13602, 27247, -28257, 3836, -25696, -25430, 19000, -7450, 7725, 4865, 11825, 12865, 29280,
-    -24156, 1044, -18267, -17988, 24946, 11803, -29297, -21829, 19000, -3084, 11326, 12832, 24158,
-    32400, -4388, 27757, 18779, -14155, 4114, -14376, -5417, -20558, -30310, 19513, -11307, 3616,
-    -2591, 260, -6666, -1306, -8992, -14633, -18525, 31358, 8498, -20325, -12847, 27774, 20282,
-    -18245, -2809, -15699, -19273, 25719, 11801, -28526, -21570, 20540, -1285, 14155, 17970, 32125,
-    -20541, 16429, -3857, 7217, 8205, 15932, 18782, -25977, -6685, 27264, 25424, -12083, 7476, 494,
-    8735, 3364, 17199, 21328, 32662, -6446, 26726, 14415, -19040, -4115, -29275, -28035, 8736,
-    -25164, -11073, 29554, 12616, -17756, -4885, -28761, -28036, 8994, -24907, -10303, 30326,
-    14158, -15187, -1029, -22336, -17500, 25700, 2080, -31636, -29556, -2032, -25468, -27500, 6193,
-    -14932, -8739, -30301, -32665, 2570, 29066, -27525, 1286, -32359, -24698, 7969, -22594, -8250,
-    -31609, 19812, -5167, 13625, 2593, 23103, 24676, -23622, 7684, -16703, -14884, -25212, 24675,
-    -6147, 24648, 17736, -28507, -4651, 31358, 21352, -6451, 13626, 1820, 22076, 22621, -26194,
-    3058, -24411, -26708, 20792, -6936, 8246, 7685, 15166, 17241, -26754, -10278, 22639, 18736,
-    -24671, -11800, -30351, 22875, -13086, 15654, 2058, 12357, 20280, 32127, -18484, 19253, 259,
-    14412, 20281, -31353, -16172, 23366, 6684, 25205, -28292, -3597, 28547, 30305, -6939, 18011,
-    16427, -31098, -20026, 19512, -259, 13643, 18484, 32637, -20025, 17457, -1803, 9789, 12831,
-    23640, 30606, -6445, 24926, 12616, -22894, -9513, 27009, 22596, -14911, 1310, -8246, -5916,
-    -20792, -21353, 24411, -3573, 26193, 23640, -22078, 6662, -14141, -13854, -23150, 29807, 282,
-    -30347, -28790, -232, -23922, -22879, 12105, -5419, 7706, -4344, 8717, 5393, 7735, 18483,
-    27238, -26190, 6148, -18767, -18994, 32620, 14901, -24135, -4389, -27504, 27523, 4864, -31874,
-    32406, 5122, -26478, -27476, 15917, -9774, -233, -5672, -4120, -16167, -15952, -30334, 12875,
-    -12869, 1536, -17453, -11327, -27505, 20584, -2076, 19528, 11332, -29576, -17224, 12361, 493,
-    13874, 7992, 27221, -29303, -8457, -32660, 25439, -13341, 16943, 4622, 15700, 25167, -23649,
-    -4603, -23407, -26990, 9019, -12871, -2832, -22078, -19810, 24668, -1518, 28250, 27752, -15654,
-    16943, 2309, 13387, 20541, -30588, -15912, 23626, 8734, 26750, -25462, 2053, -28764, -22121,
-    15161, -12060, 7436, -3859, -1779, -1303, -2317, -8975, -6957, -15167, -27479, 27225, 767,
-    22637, 27739, -14140, 7989, -1561, 7193, 22, 12060, 12847, 19297, -28547, -8485, 22639, 18999,
-    -22878, -9744, -28032, 28780, -5118, 28252, 24154, -18740, 9749, -7716, -3833, -7214, -9772,
-    -22851, -28288, 15672, -18226, 1782, -15169, -18997, -30086, 17728, -17713, 3840, -12343,
-    -13858, -22631, 30577, 2591, -28543, -24422, 6961, -13381, -4890, -24136, -24946, 17984,
-    -12572, 9237, -1550, 1822, 4097, 7959, 6191, 17975, 26206, -27220, 2812, -22368, -25421, 21317,
-    -1809, 13388, 15149, 31087, -25420, 8982, -13633, -10771, -21344, -29310, 8762, -17233, -5666,
-    -29274, -31625, 7442, -30303, -19546, 18237, -7174, 14123, 9499, 18012, 30571, -14403, 10303,
-    -785, 11813, 5163, 20546, 28004, -22851, 8468, -11833, -9230, -18003, -24683, 16985, -4383,
-    14897, 4649, 23116, 30060, -18225, 15150, -525, 8505, 11295, 22605, 27780, -11836, 18494, 538,
-    22602, 25690, -23364, 5641, -14918, -15397, -27255, 25689, -7431, 21063, 16437, 31890, -14404,
-    20036, 22, 23118, 25690, -22338, 6157, -13376, -12829, -23655, 31857, 2592, -28282, -22885,
-    8504, -11321, -12, -17453, -14405, -29053, 15958, -9780, 8728, -6917, 4871, 760, -235, 3330,
-    5900, 3620, 12325, 18750, 25465, -18516, 9499, -14372, -2068, -14145, -21568, 32628, 13355,
-    -24653, -8493, -31106, 20837, -7209, 15413, 3104, 21832, 26721, -22083, 7698, -12345, -9747,
-    -19287, -26994, 14155, -9779, 6161, -8463, 759, -5919, -10005, -13119, -21339, 26233, 7699,
-    -29564, -26710, 11812, -12603, -5636, -15944, -19285, 25462, 8472, -29052, -25425, 13099,
-    -9521, -1523, -9004, -7722, -21826, -27508, 19007, -13346, 7446, -2840, -495, -1550, 1016,
-    -5379, -2578, -5152, -12575, -15942, -25712, 19037, -4635, 16952, 7472, 26719, -28795, -6921,
-    31860, 27489, -10777, 18497, 10270, 24432, -29049, -2067, 29830, 29548, -3353, 21350, 19782,
-    -21344, -6407, -26221, -29568, 4902, -22881, -14919, 22636, 9502, -30083, -25681, 11302,
-    -10809, -4863, -14142, -15435, 30604, 16699, -14408, -3320, -16198, -15693, 28035, 13872,
-    -19549, -11287, -29561, 28768, -6148, 23640, 21572, -25424, -2832, -24431, -32363, 9762,
-    -18521, -13859, -31615, 24142, -12318, 12334, 4096, 11840, 16446, 32366, -21314, 11307, -5672,
-    790, -4372, 754, -8463, -7199, -11582, -23626, 30838, 11292, -27996, -16194, 25171, 4387,
-    30323, -27001, -1524, -27760, -25459, 7472, -16967, -5670, -27737, -32387, 9237, -27995,
-    -17738, 23373, 790, 25438, 29798, -15145, 15673, 4098, 15181, 20299, -26486, -10777, 29038,
-    21831, -19002, 3339, -11838, -12834, -24417, 32110, 3358, -29558, -22375, 9013, -12597, 242,
-    -16945, -15938, -29313, 15695, -12598, 6412, -10521, -3089, -10550, -17974, -27504, 23118,
-    -8466, 15417, 10011, 21603, 32379, -8494, 19805, 12076, -30340, -22344, 13362, -5412, 3615,
-    -1032, 5898, 531, 7449, 11295, 14409, 26724, -21088, 1046, -18767, -14406, 27518, 14387,
-    -20061, -10519, -29560, 29027, -5123, 24669, 23116, -22086, 1540, -16721, -19516, 29554, 13863,
-    -26199, -12081, 30826, 14665, -19535, -1300, -25170, -25960, 17976, -12064, 6167, -2072, -241,
-    -2058, 1527, -4611, -2829, -3870, -10779, -14394, -21603, 25459, 4111, -32141, -32110, 1285,
-    -26745, -29540, 8996, -16209, -11293, -28012, 30566, -1527, 28529, 31337, -9495, 21077, 15917,
-    -32112, -16960, 20544, 14, 20048, 24142, -24916, -1284, -22375, -27229, 15422, -7727, 3870,
-    -4112, 3839, -4098, -514, -787, -5126, -6168, -7469, -17462, -25186, 26713, -2299, 24159,
-    25685, -19262, 5913, -9269, -6926, -16960, -19806, 25200, 4629, -31372, -30313, 2831, -22892,
-    -23631, 17738, -1048, 12865, 10542, 28507, -30312, -3080, -28547, 30084, 262, -30090, 31883,
-    263, -28035, -31597, 4119, -21868, -21574, 20054, 4346, 20575, 22881, -16215, 2586, -15414,
-    -6963, -26712, 30076, 9229, -30311, -22867, 17968, -8724, 7204, 4346, 7725, 10031, 23621,
-    29827, -14128, 21309, 3356, 22880, 31846, -14635, 15171, 6146, 17747, 21853, -20326, -2299,
-    -24665, -21354, 15692, -7447, 13600, 2328, 14398, 22081, 32654, -12331, 25423, 9267, -32121,
-    -17754, 11581, -7193, 9233, -2041, 6172, 8976, 11323, 19279, -30089, -14635, 19537, 9747,
-    25714, -31350, -791, 29570, 27504, -3362, 20062, 15425, -24694, -13349, 25963, 17969, -25429,
-    -8990, -29319, 23402, -7192, 21055, 10038, 30073, -20580, 5413, -15932, -5674, -25941, -32380,
-    12060, -24400, -13105, 32621, 15436, -17989, 2038, -20031, -18503, 31337, 8754, -25700, -12611,
-    22890, 10024, -28032, -22343, 14651, -2847, 7214, 3857, 16171, 15438, 31099, -13899, 12355,
-    -1799, 15401, 8757, 24158, -27776, -8463, 29042, 25424, -15660, 9254, -1306, 3103, 1287, 9745,
-    6187, 15422, 26964, -27995, -1541, -24436, -30822, 9768, -15699, -10776, -27240, -32661, 790,
-    -32381, -26236, 1819, -24672, -17498, 18011, 258, 23879, 18782, -23130, 1263, -27222, -26214,
-    17455, -13859, 3086, -5163, -7177, -13105, -14672, 32659, 17222, -9790, 2077, -8223, -281,
-    -14114, -14905, -23154, 21867, -1542, 25935, 18783, -20818, 3576, -22852, -19276, 28763, 3877,
-    -32641, -23409, 3621, -19278, -10302, 29836, 20044, -10046, 3623, -5658, 3576, -8457, -4116,
-    -7218, -17709, -24162, 29020, -1518, 28267, 32104, -11285, 21329, 15399, 30863, -18764, 17199,
-    -7175, 10279, 8204, 13128, 21587, -25721, -9489, 30326, 25937, -14373, 11309, 2292, 8501,
-    10538, 24394, 29832, -11565, 23367, 6702, 30069, -23665, 1049, -22361, -16212, 21353, 5396,
-    32104, 31890, -1287, -29833, 28806, -517, -32402, 27007, -4630, 26967, 16727, -20822, 496,
-    -25936, -24420, 19515, -10260, 10020, 4351, 9016, 14132, 27738, -29021, -518, -25204, -31077,
-    10020, -16467, -11802, -27759, 30565, -2550, 28525, 30565, -11546, 19274, 12318, 26747, -26216,
-    4866, -25940, -20819, 22857, -2553, 20559, 22086, -27226, -4885, -28286, 28030, 255, 32110,
-    28030, -4886, 26714, 17493, -20564, 500, -24399, -23134, 21318, -5896, 15932, 13351, 25458,
-    -26217, 2557, -27485, -24418, 16693, -11295, 5653, -2327, -500, -2572, 244, -5898, -5399,
-    -8237, -17206, -25188, 26202, -2557, 23900, 24403, -20548, 3855, -13378, -12838, -26471, 29542,
-    -245, 29042, 32112, -7442, 24160, 20033, -24148, -4625, -25713, 32393, 6170, -23658, -20293,
-    20820, 3842, 22112, 25189, -14920, 7464, -7966, 2814, -7957, -5653, -10550, -19008, -30068,
-    19520, -13098, 5657, -4126, -1275, -6166, -4126, -13097, -17988, -27770, 16973, -11307, 8726,
-    -5131, 2830, 1015, 1295, 1545, 6155, 5150, 10540, 19005, 26995, -20301, 9754, -12842, -3853,
-    -13890, -20038, 30843, 13610, -23123, -10278, -30851, 22367, -8994, 15668, 4634, 20047, 26976,
-    -20553, 5913, -12090, -8217, -21072, -26739, 15685, -11564, 6416, -6933, -1027, -5920, -8732,
-    -15162, -21854, 26735, 4371, -32135, -29549, 3087, -23912, -22610, 17994, -1811, 14143, 11312,
-    28515, -27749, -510, -25199, -27749, 11313, -13121, -3848, -18499, -19032, 25965, 5403, -30598,
-    -27235, 5918, -17492, -13614, 32390, 22601, -12330, 7976, -274, 5662, 3093, 13090, 14143,
-    24938, -22120, 523, -23637, -18779, 20570, -250, 24655, 21855, -20811, 5124, -17982, -14643,
-    -28800, 19798, -10532, 12836, 9, 11570, 15149, 24424, -27238, 757, -28776, -29294, 10781,
-    -20553, -11047, -28540, 23909, -5651, 21063, 13372, -31866, -15689, 15686, -513, 17723, 14915,
-    32383, -15688, 14145, -1543, 14897, 10804, 25956, -26481, -3075, -29556, -30336, 3094, -26987,
-    -21598, 14146, -6942, 9244, -504, 9250, 10786, 17486, 28782, -17228, 8749, -7714, 2820, -7444,
-    -3859, -9773, -16182, -25190, 25694, -2047, 24412, 23895, -19524, 4881, -12858, -10272, -22875,
-    -31362, 9004, -21848, -11059, 30079, 19530, -13887, 2838, -10284, -5406, -18750, -23391, 25435,
-    -1017, 25183, 26206, -16952, 9764, -4893, 1811, -2317, 1790, -3587, -1032, -2579, -6671, -8485,
-    -13116, -24661, 28524, 5903, 31622, -27246, 6161, -23635, -16709, 26722, 7463, -30331, -21338,
-    11062, -9001, 3336, -8215, -3604, -10799, -16953, -26477, 23126, -5646, 18500, 13874, 30334,
-    -20308, 10791, -11302, 510, -10027, -11302, -20564, -31101, 12086, -17995, -5144, -25179,
-    -29303, 11819, -19269, -6430, -25189, 32132, 7963, -24676, -18498, 23127, 5394, 26991, -32386,
-    -4630, 26735, 22870, -14911, 5919, -7972, -1033, -11300, -11313, -21593, 30335, 9762, -24164,
-    -16697, 25440, 10018, -32118, -21335, 13103, -10017, 3596, -5146, -3335, -8226, -10286, -20297,
-    -30328, 16186, -15672, 514, -13628, -14644, -28527, 23895, -5907, 17478, 13101, 29559, -23386,
-    7448, -16703, -9765, -25448, 29558, 3600, -31103, -28268, 5400, -21338, -16703, 26475, 11302,
-    -28269, -17987, 20555, 2058, 21848, 25181, -19017, 5144, -12343, -7709, -21327, -27506, 16193,
-    -12333, 5135, -7453, -3338, -9771, -13364, -24155, 29037, 4627, -32637, -26990, 5399, -22101,
-    -15682, 26988, 10796, -26477, -16446, 21848, 6677, 28015, -31609, -2319, 30843, 27759, -5404,
-    21335, 15166, -27250, -13104, 24162, 12843, -29296, -17473, 20297, 2059, 21591, 25180, -19530,
-    4630, -13115, -9250, -23640, -31105, 10026, -22099, -10288, 32129, 20821, -10546, 9000, -2311,
-    8474, 4888, 12852, 19525, 31102, -15419, 17213, 519, 17477, 19526, -29808, -10537, 26466,
-    14654, -24416, -8487, 31103, 22616, -10287, 10544, 257, 12586, 11058, 23644, -29049, -7190,
-    29042, 23637, -14387, 8740, -3607, 3348, -514, 4619, 2320, 6939, 11044, 16198, 27242, -20311,
-    4891, -15165, -8489, -25949, 31353, 7189, -29034, -21590, 16442, -6933, 9509, 4106, 12085,
-    16191, 29806, -21069, 8482, -10802, -3850, -15162, -17227, 31617, 13880, -17999, -5649, -24413,
-    -28022, 11571, -16961, -3350, -22096, -25956, 19524, -7962, 10797, 4875, 14397, 18507, -30592,
-    -13360, 20564, 9244, 28788, -28524, 2049, -27240, -26211, 13615, -13106, -767, -12343, -13620,
-    -27238, 26208, -1285, 23393, 23638, -18505, 3348, -13372, -10024, -25436, 31861, 6425, -29035,
-    -20825, 15421, -6934, 10017, 2828, 11570, 15928, 27243, -23640, 4878, -18762, -15159, 32635,
-    17476, -16445, 1796, -14394, -13618, -27502, 24671, -3596, 21330, 17989, -26727, -8483, 30326,
-    21333, -13367, 7711, -5911, 2055, -3856, -2056, -5912, -7968, -14135, -22103, 29298, 6940,
-    -29043, -22103, 13880, -7713, 5912, -2056, 4111, 1800, 5911, 7966, 13622, 21588, -30071, -8738,
-    26472, 17989, -21075, -3341, -24416, -27757, 13108, -14394, -1286, -16190, -17221, 32125,
-    14394, -18507, -4113, -23385, -26988, 15163, -12335, 3083, -8997, -6424, -15421, -21590, 28015,
-    6425, -30586, -24671, 10024, -13882, -4368, -18760, -22363, 23903, 1030, 25953, 26473, -13620,
-    13618, -257, 12596, 13104, 25700, -27497, -1032, -28784, -30581, 6936, -23645, -17474, 24927,
-    7453, 31870, -25703, 5912, -20046, -13624, 31356, 17477, -15938, 774, -15164, -13625, -29809,
-    22102, -6687, 14140, 7453, 22868, 29046, -13622, 16444, 1547, 18246, 20813, -27752, -6684,
-    31865, 23906, -9255, 15161, 4631, 20557, 25698, -20556, 5652, -14139, -9762, -23646, -32643,
-    7972, -24161, -15424, 24421, 9507, -30588, -22611, 12592, -8744, 2063, -6171, -2833, -11044,
-    -13367, -23136, 26993, 4367, -32646, -30319, 2826, -25708, -24922, 14906, -7976, 4635, -3086,
-    3334, -2048, 1541, 1534, 780, 2569, 5389, 5663, 11307, 19010, 28022, -18249, 11558, -8731,
-    2827, -3864, -3077, -7196, -8233, -17469, -25957, 24150, -3592, 20048, 18496, -28522, -10536,
-    28263, 16197, -21331, -3349, -26465, -30069, 10787, -20812, -10280, -29562, 24164, -5398,
-    20041, 13113, -32127, -17739, 13885, -3344, 11561, 6432, 18758, 26210, -22353, 4367, -16711,
-    -14129, -30585, 22097, -10018, 12079, 3336, 14140, 17476, -32645, -16444, 16192, 1279, 16196,
-    17220, -30590, -14645, 19791, 6676, 25447, 31613, -6946, 23392, 15936, -24423, -9762, 30586,
-    22609, -13361, 8228, -3093, 3860, -254, 5646, 4117, 8998, 15155, 22878, -28268, -3350, 32388,
-    28273, -2580, 23908, 20563, -18515, 8, -19017, -16459, 27765, 10796, -24170, -15669, 24932,
-    12068, -30576, -19273, 18237, -2821, 14396, 14125, 26991, -25440, 3846, -22869, -20043, 24664,
-    3346, 27245, 32631, -6935, 24676, 19781, -22099, -3338, -23652, -28010, 12854, -13116, -1282,
-    -15673, -14915, -31608, 17738, -11575, 4888, -7707, -524, -9761, -11305, -18771, -31606, 14139,
-    -14917, -2308, -18500, -18258, 27248, 7715, -27768, -21583, 14655, -3868, 9002, 3604, 15921,
-    17740, 32131, -12350, 17741, 3861, 25172, 26993, -14901, 15407, -1535, 12342, 14122, 24679,
-    -28265, -271, -30576, -32377, 5898, -28264, -23896, 16436, -8990, 5661, -14, 3862, 2063, 9495,
-    9773, 17483, 30826, -19012, 9774, -5413, 2321, -4877, 1270, -5647, -6162, -8239, -16441,
-    -26465, 26200, -2050, 22110, 23630, -21326, 9, -17492, -19013, 26481, 11293, -29037, -20294,
-    19775, -1539, 15431, 17462, 32128, -18751, 16692, -2569, 11063, 11809, 22617, 31366, -8238,
-    22618, 11320, -28028, -17218, 16975, 3583, 20048, 20316, -21347, -1541, -26458, -24174, 14394,
-    -13095, 4869, -8481, -6927, -12093, -19275, 30853, 14893, -19790, -8212, -24942, 32382, 4125,
-    -25714, -21589, 14663, -3356, 11052, 4126, 19003, 22874, -27229, -530, -28269, -32369, 8723,
-    -23901, -18748, 26457, 7454, 30596, -23916, 6170, -20806, -11066, -32637, 18773, -10039, 7714,
-    -5130, 6154, 4, 3608, 7182, 9770, 14402, 27742, -24412, 525, -20062, -20557, 21857, 5125,
-    26217, 28282, -7212, 20050, 9778, -31628, -22870, 7723, -10812, -4109, -18491, -18265, 27760,
-    5925, -27261, -22356, 12094, -5417, 5402, -3585, 6407, 1547, 4639, 10776, 14140, 21601, -25205,
-    -4879, 31882, 31593, -3081, 24687, 26196, -15418, 6698, -3875, 1803, -5897, 752, -6165, -9238,
-    -10813, -21071, 29827, 13346, -23128, -13607, -32400, 18764, -17206, 5638, -12078, -10010,
-    -18263, -28783, 14920, -9783, 4372, -8726, -274, -10020, -13609, -19549, 31358, 8494, -21349,
-    -13875, 26742, 17202, -22357, -8725, -27002, 29044, -1529, 31595, 29301, -7955, 25171, 16451,
-    -26974, -6698, 30844, 21086, -9526, 10285, -2302, 12063, 8486, 17744, 30310, -18757, 8493,
-    -5929, 1034, -7700, -2331, -11816, -16952, -24433, 22366, -4617, 21829, 15427, -30575, -11068,
-    21853, 8490, -30858, -24408, 7720, -12098, -6418, -21321, -23149, 19026, -6673, 16688, 7975,
-    22368, -30858, -10530, 21598, 15403, -30320, -17467, 21829, 2577, 22111, 28768, -16442, 9776,
-    -2331, 5405, 524, 10519, 9003, 16972, 30565, -20039, 7721, -7473, -2048, -12326, -9529, -24150,
-    29052, 9747, -28777, -21835, 19514, -4106, 12348, 12832, 23650, -32114, -3874, 27763, 20829,
-    -12099, 6690, -8214, 3322, -6932, -6415, -8757, -17212, -28774, 24140, -6419, 14661, 12832,
-    25963, -29801, 753, -30578, 32651, 6408, -27752, -24404, 17460, -7964, 6181, 2553, 7714, 6952,
-    19001, 24933, -24917, 4096, -21586, -20805, 26970, 5400, 29310, -27001, 1289, -28517, -23403,
-    12341, -13612, 2555, -12332, -12327, -21089, 30845, 7206, -23660, -17729, 21342, 7438, 27760,
-    32393, -1558, 29560, 25197, -6699, 16968, 7464, 28767, -30835, -4873, -31628, 27505, -6673,
-    24657, 16454, -26720, -6441, 30590, 21854, -9012, 10802, -506, 14376, 11830, 24166, -25460,
-    -3334, -31089, -30343, 2064, -30319, -24175, 8747, -17213, -4386, -24149, -30320, 15147,
-    -17468, -4106, -17749, -24150, 21852, 1783, 21340, 21338, -18778, 10, -20298, -16208, 26225,
-    8487, -26489, -20807, 16455, 239, 13889, 12343, 30822, -25176, 3606, -16725, -15924, 30592,
-    19513, -17981, -1019, -14155, -17724, 31107, 18228, -18496, -2818, -16724, -21837, 24425, 7178,
-    29563, -31345, 2809, -30576, -30317, 8978, -23124, -16696, 29796, 11315, -26720, -11325, 25451,
-    11831, -23919, -14128, 24939, 15146, -27236, -14640, 27740, 11315, -28776, -13381, 21339, 5663,
-    31337, -30576, -1790, -28031, -31861, 3094, -24177, -23123, 15431, -2847, 10289, 4637, 20026,
-    22368, -25947, 1522, -26720, -28003, 15658, -14385, -1788, -11328, -15156, -29544, 25681,
-    -5648, 16718, 15915, 31103, -21833, 13860, -9248, 1042, -3361, -3594, -10780, -9529, -21584,
-    30598, 13859, -22099, -12065, -29574, 22877, -10267, 16945, 5658, 19288, 29281, -17987, 7724,
-    -5673, 776, -8212, -2846, -12588, -18749, -26747, 18510, -11297, 11548, -1280, 7208, 10263,
-    16196, 23399, -21606, 263, -24148, -19550, 20053, -2303, 22085, 17997, -28004, -5672, 29820,
-    21598, -9528, 9775, -2304, 12061, 7462, 17228, 29280, -21323, 5407, -11071, -7959, -21835,
-    -24949, 16457, -11042, 10005, -3077, 4123, 5636, 7974, 10805, 23369, 32389, -12583, 24141,
-    9773, 31364, -20064, 9260, -13099, 497, -14642, -16440, -27002, 20054, -8988, 14891, 3863,
-    16969, 24657, -25950, -3078, -25458, -30576, 7717, -19034, -13357, 31105, 21573, -14643, 4635,
-    -5928, -3078, -11556, -10554, -23895, 28537, 8722, -29807, -23635, 15919, -8991, 4123, -788,
-    1805, -1789, 4096, 777, 2323, 7180, 7973, 12603, 24656, -29807, -7701, 31853, 22622, -13356,
-    12836, -1795, 8491, 10266, 17737, 25453, -18776, 5402, -15669, -6697, -23896, 32648, 12322,
-    -21841, -11814, -30340, 22107, -10273, 14894, 3346, 16455, 22861, -27495, -6419, -31109, 26733,
-    -5906, 23377, 16196, -27238, -8492, 28276, 18509, -15946, 778, -16188, -12605, -30833, 21078,
-    -6695, 12088, 4373, 19776, 21854, -24926, 244, -26977, -27753, 13866, -15927, -3081, -16203,
-    -21324, 26989, 8470, -31862, -24412, 11812, -14130, -3338, -15173, -20041, 29302, 11556,
-    -25953, -15417, 26206, 9514, -30581, -19027, 14398, -5139, 11044, 4375, 15164, 21324, -30578,
-    -9509, 26979, 15940, -22617, -5147, -29549, 30840, 2821, 32131, -30584, 2822, -29037, -26215,
-    11304, -15931, -4627, -19793, -25440, 20303, -4117, 14911, 10794, 26980, -29037, -2057, -30074,
-    32130, 2056, -30075, -29294, 5912, -21852, -17215, 25959, 10274, -30323, -20559, 15929, -5395,
-    9769, 5649, 14908, 19792, -29561, -10279, 24676, 15672, -25443, -10791, 30322, 19276, -16703,
-    3338, -13365, -10792, -23647, 31097, 6685, -26989, -20304, 17223, -2061, 14907, 11826, 28008,
-    -25957, 776, -23651, -23130, 17225, -4120, 12595, 6945, 21580, 28015, -17471, 12329, -5397,
-    5147, 1791, 6683, 6689, 15412, 21846, -30063, -6177, 28786, 20824, -13631, 6428, -8733, -10,
-    -9763, -11303, -18771, -31094, 14141, -14403, -1282, -17470, -16202, 30844, 12857, -19030,
-    -7193, -28263, -32651, 3602, -30834, -24427, 9000, -16957, -5152, -23639, -30321, 14381,
-    -17215, -4364, -19029, -24668, 20309, -1554, 17225, 14141, -31110, -18499, 14142, -1042, 11315,
-    8488, 23373, 30076, -13872, 19519, 3862, 21851, 29028, -16442, 10801, -2071, 6690, 2834, 13349,
-    14143, 25707, -21861, 1551, -21840, -16464, 24682, 6688, -30086, -25948, 7717, -13896, -8729,
-    -24665, -29059, 9262, -21582, -7985, -32372, 23394, -4388, 15946, 9773, 30564, -28259, 265,
-    -22894, -25689, 14658, -5676, 5667, -2049, 8718, 3354, 10287, 18741, 25713, -22867, 7691,
-    -18236, -12330, -25976, 24170, -3336, 25169, 18773, -22869, 240, -25689, -26724, 17203, -12326,
-    3347, -4644, -4102, -10531, -10298, -23634, 29819, 10520, -27747, -19012, 22857, 1295, 22622,
-    27997, -17467, 8745, -4387, 1553, -4364, 1525, -5644, -5649, -7213, -15667, -24410, 29539,
-    2324, 30588, -28544, -1017, -30836, -27773, 3867, -24926, -16979, 20316, 2317, 26968, 25970,
-    -13618, 16432, -502, 14910, 18488, 30338, -17730, 16433, -4102, 11056, 10779, 19285, 28789,
-    -13637, 12347, -2310, 13607, 8492, 21334, -32140, -13611, 18765, 8724, 24939, 32643, -4384,
-    25454, 20050, -16207, 783, -16189, -11581, -31085, 22105, -4900, 13635, 7970, 25940, 30340,
-    -10021, 24399, 10808, -30839, -15951, 14921, -1285, 17461, 12351, 29812, -19548, 6184, -13109,
-    -3100, -20544, -23389, 25428, -2297, 23386, 24914, -21316, 3598, -13638, -14120, -28013, 27483,
-    -4355, 22618, 22343, -24145, -2312, -22632, -28514, 13880, -10554, -500, -11564, -7984, -23373,
-    -31867, 14376, -21061, -7195, -24431, 30340, 5399, -25717, -23888, 15166, -4387, 6954, 1802,
-    13346, 11323, 23904, -25719, -5640, -32379, 32107, -3842, 26990, 27738, -14123, 12085, 2808,
-    11578, 12856, 29279, -26716, 778, -20838, -23375, 19283, 1264, 17232, 16456, -26493, -13352,
-    23396, 15399, -29801, -16697, 24138, 4381, 26479, -29576, -6157, 27508, 26451, -14382, 9519,
-    493, 7207, 5150, 17712, 20057, -30317, -4905, 27254, 19799, -12873, 3611, -11557, -2336,
-    -17463, -22094, 31589, 5925, -30062, -18527, 13122, -7190, 11287, 272, 10029, 15656, 21860,
-    -29550, -2335, 29571, 25706, -4649, 16722, 10543, -32406, -26198, 5147, -14931, -14119, -31090,
-    26447, -8723, 15429, 12826, 24430, -30575, -25, 30856, 28536, 232, 24688, 22625, -11848, 6442,
-    -7446, 5372, -6409, -3077, -3366, -10778, -16184, -20842, 24175, 1293, 31843, 28801, -6932,
-    27989, 16722, -22610, 233, -26712, -28264, 16425, -15919, -1535, -11589, -17204, -30833, 23364,
-    -11294, 9775, 4602, 10552, 12859, 29531, -26971, 10, -20586, -24401, 17744, -27, 13637, 10805,
-    31327, -27484, 783, -19561, -22858, 19802, 4340, 20062, 21087, -16992, -241, -20548, -13394,
-    27259, 10550, -20077, -13862, 28027, 21815, -19774, -1785, -13909, -19774, 28028, 15904,
-    -25429, -13350, -31384, 16977, -17977, 6396, -15406, -12580, -20846, 28285, 3869, -25987,
-    -25943, 9781, -8512, -2812, -15149, -10311, -29540, 21860, 226, 18006, 14407, -25218, -14891,
-    21347, 14361, 31883, -23372, 16161, -10781, 1045, -1831, -4356, -10777, -7228, -21575, 32143,
-    18473, -18235, -4608, -14938, -22861, 22892, 7936, 27768, 30859, 997, 28796, 24948, -3887,
-    17746, 9014, -30616, -24917, 4903, -11599, -10011, -26965, -28561, 6695, -26966, -11856, 23144,
-    6188, -27534, -24916, 7731, -8260, -4099, -17969, -13143, 30854, 12101, -13401, -4870, -24136,
-    -19826, 18004, -7432, 19497, 8495, 22637, -25479, -6412, 28035, 30548, -10268, 14415, 13072,
-    24427, 31634, -550, 27769, 21354, -7233, 10551, -2548, 17183, 11065, 22638, -22653, -3585,
-    -32103, -26508, 3355, -28763, -16228, 16720, -5119, 20781, 11837, 27263, -17256, 5927, -16429,
-    -1322, -22086, -28508, 24122, -8466, 10301, 11015, 17491, 23151, -15714, 3357, -17457, -4920,
-    -26712, 28804, 11272, -29540, -23368, 21553, -5640, 10558, 13843, 20831, 29319, -6461, 19033,
-    7217, -30106, -26714, 3106, -14173, -14892, 30606, 25149, -13351, 5678, 2018, 4126, 24, 13840,
-    10294, 18014, -27538, -13094, 18529, 15125, 30339, -26447, 13327, -16180, -9228, -16228,
-    -28516, 14417, -4664, 6438, -4602, 11271, 3354, 8505, 21294, 26484, -23878, 11786, -15152,
-    -9486, -15713, -28259, 15444, -3635, 8494, -1262, 16412, 11835, 22382, -22139, -3328, -31332,
-    -25480, 5154, -25936, -11602, 24173, 6961, -24967, -21831, 12873, 733, 9781, 4649, 24120,
-    24944, -22337, 12042, -13865, -7688, -12373, -23631, 23667, 9216, 29568, -32617, 6132, -29800,
-    -29533, 15128, -17465, -8202, -16997, -28259, 14415, -4919, 6181, -4604, 10502, 2583, 7475,
-    18983, 23143, -29020, 3049, -29286, -31847, 13073, -21834, -14371, -27790, 20315, -12830,
-    15645, -246, 10044, 17958, 25197, -27736, 5622, -24919, -24652, 23870, -3332, 14928, 19501,
-    32134, -19511, 20273, -1534, 13129, 19245, 30334, -21567, 16162, -7190, 3107, 3568, 4890, 2593,
-    15133, 15941, 25209, -16736, 6433, -15913, -1830, -20038, -27478, 25670, -3848, 15957, 19759,
-    -31605, -17711, 23615, 4119, 22124, -31898, -11559, 16214, 12050, 26734, -32617, 1513, -32634,
-    28550, 3312, 30332, 27779, -30, 25964, 20069, -11853, 6176, -11287, 2540, -10787, -13857,
-    -17249, 32390, 9531, -15965, -8474, -30304, -31128, 2064, 30862, -24960, 3607, -26708, -15451,
-    20827, 21, 28753, 26224, -15914, 17960, -505, 12100, 19245, 29050, -22596, 13849, -10787,
-    -2549, -5941, -10530, -22081, -25216, 16199, -14372, 8967, -7190, -3834, -3884, -9503, -18997,
-    -21360, 23394, -3577, 26957, 21595, -22339, 6397, -17727, -16685, -27527, 19539, -13088, 13081,
-    -1537, 6189, 11282, 16196, 22123, -20587, 6, -25681, -19045, 19025, -4865, 20535, 13885, 29830,
-    -15446, 12344, -7437, 11027, 1550, 8497, 16167, 22624, -30825, -2081, 30335, 24174, -4652,
-    16972, 8240, 31842, -28004, -498, -21872, -24920, 14409, -3626, 7978, 17, 15135, 12347, 23147,
-    -22902, -2816, -30053, -25729, 6694, -23115, -9281, 29825, 16464, -11852, 1042, -14635, -6198,
-    -24658, 30855, 13592, -24659, -14892, -32411, 14663, -21318, 486, -24402, -27486, 20533,
-    -10268, 6440, 3313, 6438, 5926, 19504, 22115, -27742, 1514, -29543, -31854, 11024, -23890,
-    -16691, 31585, 11834, -25687, -7223, 29311, 18518, -10822, 4126, -10011, 1001, -12580, -14894,
-    -20844, 26228, 2069, -30354, -31855, -244, -25214, -29028, 7724, -14164, -10010, -27999,
-    -30869, 3098, -31341, -21103, 9267, -15151, 1257, -17719, -19777, -30611, 11323, -22348, -4140,
-    -30568, 27768, 4341, 28029, 29310, -1057, 23918, 19801, -14422, 789, -16438, -8254, -29537,
-    24940, 3054, 23149, 23398, -11339, 6959, -6930, 7424, -4607, 267, 3311, -1523, -1018, 5110,
-    -1009, 1296, 7937, 4388, 9520, 21558, 26233, -20550, 13078, -12062, -2045, -6712, -13347,
-    -23119, -29071, 8756, -23120, -6969, 30602, 20828, -6456, 9272, 11, 17188, 12099, 26482,
-    -19050, 2077, -19523, -9541, 30862, 18771, -7743, 5163, -4875, 8193, -2548, 3350, 8707, 6447,
-    12859, 27211, -31076, -6160, -29586, 24180, -7446, 24129, 11073, -32119, -13651, 13901, -1536,
-    19760, 12359, 30589, -15193, 9276, -7192, 9224, -4089, 3860, 7167, 4907, 10799, 23101, 27780,
-    -15930, 18990, -3061, 14654, 18733, 27522, -20556, 13851, -12315, 6, -5169, -10773, -17727,
-    -21360, 20839, -2051, 25673, 18012, -23126, 1772, -26964, -26467, 18735, -13087, 4118, -2084,
-    -3577, -7191, -3883, -16684, -22097, -31896, 5933, -27238, -14420, 18013, 2318, 27471, 23924,
-    -15416, 15393, -5633, 8230, 9482, 12357, 20309, -25985, -11031, 26735, 22589, -21312, -764,
-    -15191, -21055, 27250, 13080, -30051, -19011, 23104, -753, 20311, 26188, -23627, 266, -16476,
-    -20800, 25710, 11795, -32366, -23121, 16679, -10522, 3352, -285, -1269, -4359, 1258, -7181,
-    -8728, -9279, -22087, 31365, 15908, -22088, -8985, -24698, 28028, 525, -30353, 31883, -1531,
-    -28554, 31626, 12, -27013, -30826, 4382, -19304, -18747, 23915, 12308, 32653, -24145, 15393,
-    -12067, -500, -5682, -9497, -19004, -21616, 21601, -3585, 24646, 17746, -26459, -2083, -32112,
-    28026, 2800, 27256, 26741, -4654, 18262, 10293, -29841, -23373, 8752, -7226, -2555, -13351,
-    -8511, -25942, 27513, 8966, 32654, -27486, 12308, -18748, -10010, -21873, 30083, 4640, -23673,
-    -22603, 15435, 228, 12093, 8496, 27984, -32626, -8467, 31583, 19546, -17977, 8454, -12838,
-    -7954, -14162, -25431, 22373, 3828, 22886, 23144, -12621, 6953, -8983, 4856, -7697, -6156,
-    -7223, -16949, -27487, 27730, -3328, 21087, 24389, -23375, -2557, -19302, -25174, 17490, -799,
-    13121, 8752, 29013, -31341, -5898, -30354, 25714, -7955, 24389, 12864, -31343, -11849, 18519,
-    3610, 29014, 28799, -10783, 24646, 10038, 31879, -16989, 10810, -8729, 8456, -4098, 1553, 4086,
-    1814, 3095, 11539, 10809, 19543, -28554, -12836, 21086, 14880, 32396, -21320, 17451, -7184,
-    6952, 6399, 10036, 13120, 29786, -25945, 271, -18789, -21833, 21089, 6397, 24171, 26743, -7482,
-    15691, 4384, 27470, 28284, -13607, 21817, 4640, 22887, -30869, -11552, 19290, 14878, 30853,
-    -23630, 14108, -12582, -2555, -8252, -13867, -26199, 32355, 3096, 31626, -23929, 4382, -23117,
-    -11850, 26999, 11579, -19818, -11809, 30084, 25415, -13352, 7983, 2027, 6695, 4642, 18732,
-    20059, -30825, -3371, 27770, 20319, -9797, 6697, -6925, 7423, -3583, 15, 4338, 273, 786, 8964,
-    5670, 10809, 24384, 31113, -13864, 24899, 6955, 28284, -22647, 1302, -24660, -15708, 20578,
-    1555, 30038, 27003, -11810, 22843, 6443, 26226, -25217, -3837, -32114, -28301, 276, -30830,
-    -22904, 6702, -18752, -4400, -28507, 30079, 9222, -31335, -24663, 16933, -12575, 1553, -3372,
-    -6664, -13096, -12110, -30051, 20315, -1831, 13384, 8493, 30037, -32106, -5129, -29330, 25977,
-    -6158, 27469, 16211, -24406, -545, -30306, 32135, 9479, -29022, -22093, 21816, -5122, 13889,
-    16162, 25461, -26718, 6139, -25169, -21835, 25672, -754, 22113, 28499, -19259, 6180, -5684,
-    -4095, -12839, -9539, -26968, 25969, 6652, 28031, 31623, 1769, 28802, 27511, -1573, 21093,
-    16460, -20078, -8463, -31856, -32414, -3580, 26227, 30552, -13347, 13635, 8193, 17493, 22116,
-    -18022, -497, -22089, -14681, 24176, 5925, -27275, -25940, 8496, -9029, -5123, -18232, -14940,
-    27774, 8754, -20338, -16174, 24689, 17185, -27997, -15147, 30807, 11325, -27484, -7744, 25718,
-    13894, -17254, -7950, -29539, -28819, 2588, -30311, -19053, 11327, -11551, 8447, -7949, -3583,
-    -2862, -11290, -18232, -20852, 21607, -3326, 26951, 18780, -23630, 3821, -24654, -24658, 24639,
-    -4609, 15950, 19756, 31371, -18489, 21042, -1783, 15179, 21556, 32655, -15405, 25155, 5670,
-    27000, -24961, -2297, -31083, -25475, 4643, -24402, -11854, 24690, 9266, -23420, -18744, 19547,
-    8963, 24175, 29313, -3888, 20835, 13122, -23164, -14632, 23660, 17443, -28768, -15405, 29523,
-    9783, -30055, -12112, 18779, 2842, 30036, 28288, -11037, 25411, 9784, 31625, -15967, 10813,
-    -8469, 10249, -3066, 3868, 8707, 7985, 13377, 29267, -27482, -1786, -21363, -27739, 12864,
-    -6715, 1304, -8726, 739, -12832, -15408, -20335, 24948, 1298, -31130, 30859, -3586, -30358,
-    26747, -6671, 27726, 16210, -24405, -545, -30050, 32136, 9736, -28509, -21578, 22844, -3580,
-    16459, 20274, 32143, -15924, 23359, 2845, 23654, -31897, -12833, 18001, 12308, 25974, -30059,
-    3056, -31338, -31087, 9996, -25171, -17980, 29015, 6955, -32116, -18531, 10554, -10272, 6657,
-    -7695, -3333, -4908, -12321, -19524, -25725, 16207, -11558, 10514, -4869, 3350, 4602, 4127,
-    6434, 16681, 19290, -31860, -6450, 23146, 14401, -21614, -11293, 30079, 25161, -14121, 8235,
-    745, 5155, 3095, 14880, 14150, 26225, -18531, 3614, -17467, -7223, -29025, 26738, 4599, 27002,
-    29051, -2598, 21863, 16715, -19818, -7693, -30316, -30869, -240, 31622, -27014, 18, -29546,
-    -22388, 8757, -15926, -29, -21055, -23379, 28242, -238, 25709, 32611, -12061, 18000, 13079,
-    26489, -28518, 5112, -27996, -25434, 18991, -10778, 5408, 1771, 2844, 1810, 11794, 9269, 18258,
-    -30869, -16946, 14661, 5111, 15437, 17488, -25216, -12063, 24942, 20274, -24400, -7441, -24701,
-    29314, 1298, -27529, -30311, 4126, -18535, -18489, 24687, 13848, -30826, -20803, 21302, -3327,
-    14150, 18218, 28798, -22345, 13593, -12067, -2555, -7482, -13352, -24914, -31126, 6181, -28770,
-    -15449, 17747, -1528, 23359, 18261, -27486, -2085, 32140, 26485, 485, 23145, 20060, -14936,
-    1044, -17207, -8768, -30310, 23143, 484, 19292, 16461, -22133, -10007, 29826, 27469, -12321,
-    11323, 6908, 14151, 17234, -26246, -13092, 22118, 16931, -30312, -17461, 25413, 4127, 25715,
-    -28044, -6154, 27258, 28754, -13094, 11325, 6137, 13892, 15694, -28045, -15921, 16980, 8964,
-    22629, 27003, -7999, 15434, 2845, 26439, 25714, -17973, 15646, -5642, 5159, 7678, 9522, 12355,
-    30037, -26459, -1523, -19822, -24660, 15954, -291, 12093, 6702, 27465, 30597, -12574, 26438,
-    10294, 31887, -14940, 13122, -6408, 14874, 4641, 15180, 27981, -26200, -2810, -20850, -27485,
-    12611, -6459, 2072, -8722, 1766, -11036, -13605, -16481, 31370, 10554, -15197, -8723, -28510,
-    -28818, 4128, -29025, -16482, 15694, -4868, 18986, 9783, 24944, -22649, -2296, -28770, -22906,
-    9270, -17206, 225, -21571, -24916, 26954, -2553, 20831, 26183, -22857, -500, -15452, -20287,
-    25972, 13590, -30054, -20289, 22843, -1527, 17491, 23614, -28256, -8467, -29328, 23916, -8982,
-    22074, 9267, 28026, -21103, 2843, -21320, -11337, 28544, 14147, -15450, -5638, -24403, -22646,
-    14152, -11554, 9738, -5896, 527, 2027, -1527, -3071, 2798, -4098, -4870, -1828, -10523, -15921,
-    -19304, 26486, 3612, -28043, -28256, 5412, -15194, -13607, 32655, 26698, -9753, 12610, 10507,
-    19802, 25974, -12110, 10294, -5896, 11793, 2327, 10295, 20017, 26742, -22602, 11280, -14637,
-    -7182, -14934, -25431, 21346, 3056, 21087, 20318, -16991, -244, -21060, -14164, 26742, 8753,
-    -22646, -17463, 21347, 11279, 29311, -29026, 7425, -24661, -21316, 26444, 2068, 24687, -31896,
-    -10269, 19291, 15907, 32393, -21316, 17707, -6159, 7213, 7684, 12602, 15951, -30353, -16697,
-    13896, 4085, 15686, 15181, -27784, -14898, 18009, 9996, 25965, 31116, -1570, 27251, 20836,
-    -10309, 7977, -6922, 7940, -1533, 1817, 7169, 6691, 9270, 22846, 29821, -17459, 18992, -763,
-    13639, 19506, 31105, -19515, 17965, -3335, 9785, 12825, 21080, 29060, -9021, 18254, 4388,
-    29272, 31875, -9234, 29016, 17997, -23113, 1260, -23638, -26968, 21050, -7448, 8757, 7429,
-    14911, 17495, -27010, -10790, 22636, 17966, -25954, -13088, 32359, 18251, -19771, 4346, -16445,
-    -16944, -27779, 19793, -12576, 12572, -769, 6958, 11544, 17992, 24691, -17498, 6428, -15660,
-    -3877, -20557, -29024, 21310, -8479, 7986, 5118, 12339, 12612, 30561, -23128, 2333, -14930,
-    -13362, 31889, 24392, -9765, 9017, 5373, 13880, 13643, -31893, -18760, 9018, -3367, 4886,
-    -4347, 6914, 1802, 3106, 11283, 13624, 19297, -26240, -7708, 25723, 24390, -15933, 2337, -6966,
-    -5139, -18480, -16989, 29557, 6193, -22901, -17218, 18787, 8454, 26986, 28810, -2855, 25445,
-    15960, -16991, -1541, -25417, -19818, 19791, -6657, 20019, 12852, 26496, -19303, 6428, -18995,
-    -5682, -25697, 28037, 9225, -29039, -25934, 17193, -9251, 1567, -799, 3, -6916, -28, -7964,
-    -14112, -15191, -30323, 13902, -9281, 3346, -11800, -1314, -14644, -21823, -29327, 12856,
-    -22081, -2085, -25951, 31890, 13079, -22097, -14628, -29840, 19538, -15657, 10511, -6421,
-    -1521, -1312, -4108, -11030, -8508, -20813, 30605, 16422, -19529, -8717, -21871, -31608, 6447,
-    -18531, -13104, 28036, 21562, -16703, -1262, -11335, -13362, -30817, 27987, -3340, 18272,
-    21562, -25957, -10770, -30352, 24159, -12313, 17966, 5398, 17499, 29017, -19275, 3622, -9278,
-    -5911, -21564, -21100, 22617, -4859, 24133, 19019, -28504, -3110, -32124, 24182, -1312, 22105,
-    14673, -21873, -7965, 29323, 28243, -8480, 13133, 11538, 24416, 29324, -4911, 23903, 12362,
-    -22131, -10279, 26241, 23102, -16448, -487, -9795, -10537, -27472, -30869, 6940, -30814,
-    -16734, 17478, -6142, 18476, 11824, 23670, -22902, 3, -29274, -22131, 13111, -15140, 5112,
-    -11048, -12056, -16219, -29295, 13902, -8253, 4374, -9744, 1771, -9248, -13342, -15705, -30322,
-    13644, -9538, 2576, -12572, -2856, -17213, -25679, 29784, 2320, 26749, -29327, -4363, 26236,
-    29013, -11817, 11331, 6910, 16711, 17756, -23674, -7448, 28294, 28241, -10276, 11590, 8709,
-    19279, 21613, -17249, 3089, -20280, -9796, -31606, 18014, -5942, 10287, -1776, 16161, 12600,
-    22896, -22390, -1535, -29790, -23675, 10031, -19254, -1573, -23122, -30305, 19759, -12586,
-    1308, -3373, -4105, -13598, -9798, -25436, 24182, 6907, 29049, 29836, 1510, 29306, 24696,
-    -3374, 19027, 9533, -28561, -21323, 9277, -3376, 3351, -6145, 5877, -2818, -3317, 2536, -3076,
-    -6915, -1576, -10786, -18737, -21108, 23396, -4088, 27723, 21340, -22593, 7163, -17725, -16682,
-    -26247, 20312, -11800, 16417, 2322, 13129, 23356, -31346, -13600, 28240, 12345, -30306, -10311,
-    22369, 6703, -28559, -24406, 6961, -9285, -4874, -20024, -16738, 26224, 3621, -27276, -26205,
-    5935, -11600, -8215, -26190, -25735, 11061, -20794, -1063, -24662, -31845, 17699, -16696,
-    -5373, -13399, -21322, 24440, 11788, -31603, -26190, 16158, -12072, -2545, -6202, -10787,
-    -23619, -25991, 13886, -18480, 3822, -16698, -19251, -27789, 16456, -17453, 7164, -12329,
-    -11285, -15709, -29034, 14673, -6201, 6177, -5889, 8193, 9, 2592, 10506, 10803, 15699, -31129,
-    -17725, 10817, 1253, 9775, 5163, 23098, 25966, -22337, 11534, -12843, -7174, -12367, -21581,
-    25723, 11792, -29806, -23879, 19246, -6163, 6963, 8195, 13883, 15958, -28300, -13617, 17244,
-    11022, 27246, 31893, 999, 31872, 26496, 228, 25704, 19557, -12880, 5402, -13598, -801, -15929,
-    -22850, -31384, 9772, -27477, -10310, 25964, 9789, -22133, -14129, 23154, 16675, -27237,
-    -16682, 29012, 10800, -31589, -13394, 18768, -492, 25671, 23394, -22081, 8453, -15158, -12315,
-    -20588, 31103, 4905, -22388, -19013, 18270, 6653, 23393, 24181, -10567, 11829, -4604, 14620,
-    8231, 17241, -32669, -17213, 9789, 227, 8231, 2593, 18474, 19282, 31891, -6713, 23138, 10560,
-    -23933, -15413, 20070, 12562, 30847, -28247, 10250, -19527, -15397, -27529, 21080, -12314,
-    15906, 2062, 12358, 21560, 32388, -17198, 22075, 3347, 20067, 30299, -16700, 7989, -1571, 4633,
-    -2549, 9224, 4890, 8759, 20789, 27763, -22339, 12309, -11560, -4862, -9537, -15929, -31076,
-    25416, -6935, 12616, 12566, 24162, 30863, -3626, 25962, 16471, -15963, -1023, -22851, -16734,
-    24421, 1822, -31898, -31606, -4089, -28300, 31617, -2804, -29328, 31874, -3575, -29842, 30589,
-    -5374, 32610, 25706, -13085, 19761, 5146, 19297, 31583, -16186, 9532, 742, 8744, 3621, 19760,
-    21851, -29790, -544, -32119, 27008, 2540, 27763, 24438, -5685, 16713, 5163, 29781, -32632,
-    -8716, 31838, 21082, -18226, 10251, -9760, -5375, -7740, -14900, -28505, 29526, -765, 22896,
-    29526, -14644, 8762, 1769, 9001, 4650, 21301, 24421, -25934, 6138, -21326, -21308, 30297, 7459,
-    31891, -18791, 11315, -13086, 5625, -9246, -9231, -11337, -22353, 26236, 11023, -29807, -24394,
-    18220, -7449, 4906, 4598, 8229, 6962, 22331, 28018, -21052, 13851, -8221, -491, -1827, -3338,
-    -11285, -7738, -20043, 31635, 18477, -16189, -4088, -13392, -18245, 27524, 16164, -22358,
-    -12569, -28297, 24160, -10257, 20278, 9511, 23924, -25726, -2312, 31378, -30095, 773, 30349,
-    -28039, 1545, -32104, -24184, 8228, -21311, -6708, -29294, 24179, 1516, 24420, 20581, -13905,
-    5146, -13859, -2083, -17727, -24910, 29529, 2834, 27518, -28554, -2821, 29061, -32666, -5135,
-    22380, 23875, -20556, -2292, -16218, -19785, 23923, 10768, -31865, -26707, 13339, -14133,
-    -6404, -14417, -21586, 23923, 8457, 31870, -30819, 6916, -24158, -22852, 24136, 1029, 19810,
-    26449, -19532, 1307, -12360, -11308, -29533, 30560, 772, 25722, 32359, -7710, 18784, 16939,
-    -29813, -18739, 22594, 3855, 20839, 30304, -14393, 10046, 1519, 11565, 7219, 24649, 31868,
-    -14884, 22594, 7710, 24694, -27522, -2828, 29321, 32103, -3857, 22126, 23879, -19021, -1518,
-    -14929, -15937, 28295, 17968, -18508, -6915, -20068, -26218, 12875, -7733, 5652, -8201, 3062,
-    -4629, -7687, -6961, -14138, -27219, 29534, 2825, 26494, -30862, -3858, 24696, 26193, -13882,
-    5936, -2336, 4110, -4602, 5374, 1027, 281, 7173, 7709, 8762, 22336, 31353, -17967, 18996, 1284,
-    14415, 21309, -29557, -14113, 27221, 13363, -30562, -11844, 23130, 5676, -31120, -25444, 3107,
-    -16472, -13365, 29579, 22079, -13623, 2081, -5422, -3086, -15138, -12104, -26987, 19815, -797,
-    19018, 11591, -28297, -16706, 13648, 3828, 17476, 14419, -26756, -12337, 19303, 13851, -32127,
-    -25416, 14623, -10283, -3056, -6709, -9255, -23359, -25984, 16703, -16421, 6657, -8999, -9482,
-    -12361, -21078, 24957, 9999, -29560, -26701, 15140, -10286, -2542, -6963, -8230, -22588,
-    -24953, 19270, -12823, 12057, 765, 5682, 12057, 19269, 24186, -16471, 8990, -14366, 235,
-    -12856, -19506, -27007, 20298, -13339, 12059, 251, 5680, 11031, 18241, 22642, -19553, 4364,
-    -21564, -12100, -32644, 14417, -12872, 2310, -16682, -9017, -25189, 25210, 5376, 31351, 30607,
-    1778, -32386, 28808, 1778, 31351, 27009, -1821, 25698, 17757, -16471, 1541, -20795, -13644,
-    31097, 11588, -16986, -5398, -28504, -28037, 8995, -24907, -10047, 30327, 14415, -14674, -514,
-    -21308, -15702, 28271, 6449, -24441, -18247, 16473, 4857, 21075, 19557, -18274, 773, -23621,
-    -16218, 24932, 2594, -31125, -29296, -1261, -23672, -25698, 9791, -8767, 4, -14883, -7739,
-    -23897, 27780, 11023, -27753, -22850, 21818, -1797, 13646, 18734, 31870, -21307, 17193, -4369,
-    6194, 8455, 14649, 16474, -27783, -11309, 19559, 14880, -30842, -22847, 18222, -4115, 6967,
-    9227, 16959, 19046, -23156, -3345, 31640, -30866, 1539, 29069, -28553, 1026, 31124, -27011,
-    4368, -29273, -18530, 17733, -7172, 16681, 9509, 20070, -29837, -9767, 19557, 15910, -29814,
-    -20279, 21308, 1284, 16472, 23621, -25188, -7687, -27265, 30839, -2547, -31634, 31610, -5889,
-    31076, 25442, -14628, 15914, 1541, 12100, 18741, 31096, -21054, 14887, -5657, 3620, 3064, 7194,
-    4648, 16942, 22100, -32104, -4904, 28783, 18269, -13129, 5140, -13344, -2849, -16448, -24652,
-    29791, 4884, 29575, -25722, 3343, -27224, -18526, 19021, -4351, 20025, 14909, 30344, -14928,
-    14396, -4867, 14629, 8742, 19291, -32403, -14132, 14666, 5634, 19535, 20834, -20067, -254,
-    -24656, -19810, 20050, -4096, 21054, 15938, -32624, -11586, 20051, 4385, 29791, -32635, -6924,
-    31077, 22878, -15406, 12317, -4109, 4128, 4864, 8227, 9011, 22083, 30329, -17204, 17715, -255,
-    13380, 17715, 30585, -21316, 13604, -7967, 1302, -2075, -1028, -7693, -4131, -12079, -20800,
-    -28289, 16192, -16432, 4351, -12336, -12320, -20321, 32640, 7984, -20322, -12593, 28031, 20028,
-    -17477, -2295, -15182, -17477, 28032, 15145, -22104, -11804, -29573, 24414, -9749, 18745, 9251,
-    23661, -28544, -4628, 27774, 27226, -10026, 12355, 6409, 19529, 21093, -20834, 769, -24655,
-    -19806, 21330, -3067, 22343, 19531, -27997, -4386, -32383, 24432, -3616, 20561, 12610, -27775,
-    -15420, 17751, 6921, 24672, 27003, -9271, 17477, 3616, 25938, 29299, -14889, 19000, 3856,
-    18521, 26967, -20303, 2074, -13384, -11565, -29794, 29022, -772, 23150, 27223, -14908, 6960,
-    -2848, 4112, -4092, 5120, 1028, 1048, 7176, 8224, 10300, 23624, -31612, -13088, 25681, 12593,
-    -32107, -14669, 18505, -1010, 22340, 21075, -26711, -791, -28012, 32143, 8976, -24672, -20286,
-    25168, 4627, 25460, -30859, -5654, 24433, 23369, -17734, 790, -12099, -11309, -28508, 30564,
-    2056, 27775, -30860, -3085, 26491, 28251, -10539, 12357, 6663, 19530, 20838, -20323, 770,
-    -24653, -19038, 21845, -2294, 24396, 22102, -23883, 3065, -20818, -22598, 26710, 4112, 26232,
-    -30602, -4370, 25719, 25939, -13623, 7216, -1562, 5654, -1009, 9490, 8481, 13126, 26452,
-    -25958, -4607, -25720, -30327, 4389, -20838, -16449, 22894, 11545, -30842, -24652, 14887,
-    -9255, 22, -4133, -3601, -13599, -12100, -25189, 22382, 2549, 25441, 22125, -12615, 9765,
-    -8460, 6405, -1545, -1006, 2550, 2054, -1007, 5892, 5395, 5932, 16172, 22614, -32105, -4646,
-    29040, 19039, -12357, 6682, -10775, 1008, -9767, -13859, -18781, -32640, 9015, -18525, -9510,
-    32146, 27736, -5399, 16727, 16428, -31871, -21053, 17457, -2831, 8761, 10775, 20556, 25466,
-    -14669, 11562, -8717, 7435, -262, 1308, 5636, 8219, 7990, 20799, 30064, -20538, 13861, -5147,
-    2594, 2038, 6162, 2080, 12832, 16442, 23154, -21350, 3079, -24136, -16467, 25953, 3621, -31117,
-    -26476, 1823, -19553, -16710, 22898, 11288, -30075, -25162, 15144, -8488, 26, -3362, -1806,
-    -12053, -8759, -19282, 30610, 16428, -16713, -7170, -19038, -24423, 15190, -4133, 12587, 1569,
-    19511, 22610, -30300, -2335, -31360, 24956, -794, 25182, 17503, -16986, 1282, -22334, -15187,
-    28525, 6708, -24183, -16965, 17503, 6658, 24926, 24699, -9791, 15418, -1259, 20279, 19530,
-    -32357, -6707, 26727, 13390, -19044, -5399, -31328, -30352, 4111, 32665, -22385, 10280, -18480,
-    -1825, -20560, -28760, 22591, -6169, 9792, 9998, 20045, 23413, -15703, 7710, -14368, -283,
-    -14906, -21564, -30095, 13622, -22593, -2596, -25699, 31121, 11797, -22873, -17196, 31587,
-    14136, -25678, -5422, -31610, 22639, -2596, 19278, 10817, -28811, -18759, 11846, -28, 10798,
-    4650, 22588, 26218, -22850, 10253, -13362, -9229, -15961, -25955, 17500, -1570, 14910, 7220,
-    29270, -30066, -6916, -30097, 27503, -8459, 25674, 16195, -29277, -6452, 28532, 16470, -13649,
-    1291, -17713, -9537, -29035, 21609, -286, 19283, 13642, -25216, -13614, 21096, 14877, -31348,
-    -22081, 19247, -4364, 9018, 11794, 19537, 25466, -13393, 10543, -8460, 8968, -1023, 2335, 8197,
-    9257, 11844, 27986, -26981, -4861, -24957, -31093, 3621, -20332, -17986, 21098, 10252, 30330,
-    -31074, 6397, -25697, -25420, 21304, -4881, 10048, 12052, 21590, 27267, -9794, 16708, 539,
-    24387, 24161, -23363, 7683, -16190, -14882, -24442, 25702, -5116, 27216, 21590, -22850, 5371,
-    -17989, -18738, -30352, 15936, -20281, 2031, -18760, -22594, 30302, 7198, 31890, -20328, 10797,
-    -14886, 2032, -13619, -16942, -24696, 23133, -6663, 22080, 14652, 31887, -13387, 17480, -753,
-    22337, 20564, -27225, -1051, -29551, 30344, 6403, -29809, -27996, 13086, -15675, -7179, -17754,
-    -25698, 17494, -2849, 13625, 6186, 25421, 30587, -14118, 21824, 6686, 24175, -29320, -6165,
-    25461, 24651, -16189, 3617, -6962, -4110, -16172, -14672, -31609, 14155, -11589, 1546, -14888,
-    -7477, -23640, 29574, 11799, -25183, -18229, 27734, 8485, 31629, -19812, 10542, -13605, 2548,
-    -12332, -14119, -21096, 29046, 3615, -27265, -24925, 8756, -10304, -2823, -17972, -14930,
-    31359, 11584, -16473, -6164, -27737, -27781, 8743, -23883, -9020, 31103, 17238, -10820, 4633,
-    -10777, 232, -12330, -16688, -22898, 24165, -3324, 26961, 21852, -21058, 6659, -15929, -13605,
-    -23924, 26477, -1783, 30304, 26991, -12321, 20025, 6174, 22374, -31633, -10789, 19034, 13600,
-    31359, -24657, 11802, -13875, -6153, -15183, -22356, 23917, 6406, 29558, 31884, 752, 31871,
-    28543, -277, 27246, 22889, -10301, 11313, -3069, 13344, 9000, 18519, 32619, -15673, 12866,
-    2549, 14140, 12609, 32104, -22098, 5671, -10817, -6421, -21828, -22639, 19794, -7180, 17969,
-    9514, 23403, -27264, -5136, 28801, 29020, -8735, 15695, 12315, 27245, -30566, 2035, -29296,
-    -31851, 9489, -22872, -17973, 29536, 11053, -29282, -13384, 22105, 4386, 31591, -30324, -3324,
-    -28548, -32637, -240, -27777, -28782, 4387, -19040, -15418, 26233, 16170, -23643, -12318,
-    -30861, 21847, -13604, 13088, -771, 7472, 11546, 19018, 25719, -15954, 9510, -11034, 3322,
-    -7967, -9235, -12612, -22102, 26232, 8720, -30584, -26454, 12833, -13366, -5123, -14409,
-    -19277, 27260, 12063, -25703, -18230, 25428, 7708, 28801, -25202, 3854, -25428, -17749, 22359,
-    530, 26969, 27499, -15148, 16176, 1028, 13379, 18232, 31611, -19518, 15663, -3600, 7983, 7953,
-    16446, 20319, -25201, -4372, 31628, 30826, -2317, 23919, 25172, -15425, 4902, -6698, -776,
-    -12574, -9525, -21079, 29832, 12578, -21851, -14373, -32654, 19784, -17715, 5384, -10801,
-    -10262, -18003, -26735, 15953, -7467, 9761, -2552, 10524, 9247, 15181, 27743, -21337, 1561,
-    -16206, -13370, 30860, 21060, -12086, 3619, -4642, 508, -9489, -5156, -13370, -23881, 32110,
-    9504, -29022, -15693, 21841, 1048, 26969, 29037, -14630, 18232, 4622, 18009, 26456, -20051,
-    1305, -14666, -12341, -32362, 24913, -6174, 13129, 11035, 25694, 31119, -4643, 27751, 17498,
-    -15952, 2566, -18741, -11840, -29816, 18525, -6701, 12334, 278, 17457, 18245, 30347, -12099,
-    18503, 1049, 24652, 25956, -20283, 10518, -9255, -4348, -8758, -12596, -26964, 30821, 4367,
-    29833, -26491, 3597, -27994, -19552, 17990, -6407, 16173, 9766, 21349, -29831, -8482, 22378,
-    18486, -24417, -10776, -30858, 24157, -11291, 16946, 5910, 18521, 28511, -18249, 5672, -8242,
-    -2315, -15402, -13382, -28529, 18780, -5159, 13621, 3617, 22083, 25700, -22598, 7692, -14651,
-    -11804, -22120, 31867, 4902, -24177, -19020, 17239, 3065, 20559, 18524, -21608, -2829, -29792,
-    -27776, 8223, -24653, -11585, 29298, 12613, -18525, -5912, -29792, -30604, 5140, -30564,
-    -20324, 14393, -10776, 8462, -2314, 1048, 3835, 4883, 3618, 13601, 17219, 25720, -17497, 7968,
-    -14374, -1306, -16190, -22341, 32105, 9254, -28767, -14413, 21591, 2588, 29534, 31357, -9235,
-    27222, 17222, -25427, -3105, -29552, 28544, 4348, 31872, 31885, 3577, -31094, -31852, 7690,
-    -24927, -21572, 23882, 1545, 21347, 27737, -17217, 6185, -5932, -768, -11035, -6703, -18758,
-    -29796, 22082, -8479, 9013, 5634, 14137, 15181, -31118, -16447, 13126, 2035, 14651, 11841,
-    31847, -22358, 4389, -12359, -8480, -26194, -29064, 9768, -24396, -9018, 31357, 17239, -11075,
-    5144, -10776, 234, -11562, -16173, -22125, 26218, -753, 31075, 29302, -9749, 24908, 14139,
-    -30824, -11330, 22107, 6442, -31377, -26210, 3359, -16986, -14902, 28803, 19766, -17987, -3322,
-    -15444, -19786, 25206, 11285, -29810, -23625, 17711, -6424, 5932, 5374, 10796, 10815, 27476,
-    -27755, -5634, -27779, 31613, -1522, -29835, -31867, -1522, -27779, -29811, 2591, -21355,
-    -19274, 19297, 5888, 24930, 25208, -9533, 15165, 22, 21307, 20819, -29020, -2081, -31866,
-    25979, 489, 25703, 20582, -12876, 6686, -11545, 1517, -11048, -14886, -19814, 29816, 4647,
-    -24698, -21071, 14157, -284, 12598, 6704, 26187, 31616, -13343, 24903, 10285, 29833, -18788,
-    9515, -14373, 1773, -14130, -17457, -25212, 21337, -8720, 18737, 8487, 22634, -28295, -7191,
-    25205, 24134, -17472, 1562, -9535, -9248, -24138, -27011, 13112, -18999, 489, -19785, -24396,
-    27475, 1804, 24434, 32358, -10019, 17239, 13340, 29559, -27737, 7687, -20815, -18228, 32103,
-    13110, -25168, -6448, -32636, 21607, -5164, 15168, 5159, 26447, 30331, -13603, 22593, 7715,
-    25718, -26238, -1795, 32658, -28808, 2575, -30823, -22383, 10800, -15918, 748, -16700, -20287,
-    -31377, 12342, -23115, -5163, -30063, 26230, 2033, 26478, 24431, -8762, 13629, 787, 20536,
-    19283, -29797, -4394, 29050, 20576, -9535, 8491, -4869, 9742, 2323, 8495, 16938, 22883, -29285,
-    -282, -32372, 29312, 3316, 29823, 29569, 232, 26996, 23658, -8507, 12091, 14, 18735, 15689,
-    30854, -12363, 15176, -758, 21048, 16975, -30828, -7223, 23915, 13377, -21359, -11552, 29055,
-    24388, -15408, 5155, -3113, -1529, -8467, -2856, -14893, -21574, -29327, 11065, -21832, -3627,
-    -29284, 29055, 7167, 32397, -29542, 9995, -23117, -16692, 32612, 12350, -23889, -4654, -32368,
-    25199, -29, 21090, 17746, -19305, -5639, -28514, -26758, 6184, -23889, -10310, 27002, 13377,
-    -17507, -8465, -29542, -30357, 1302, -32370, -23418, 5158, -21320, -8512, 30859, 19287, -7485,
-    6702, -3588, 10764, 2076, 10290, 20016, 25206, -22864, 9737, -17972, -10785, -21617, 28289,
-    4122, -25730, -26453, 10548, -8255, -2808, -13868, -9026, -27994, 25711, 5623, 26234, 29052,
-    -2345, 21352, 16202, -19822, -8975, -31857, -32672, -4349, 25455, 29266, -15915, 10036, 2537,
-    7473, 6695, 22583, 24178, -22090, 10248, -16687, -9754, -18536, 32401, 10550, -14425, -8720,
-    -26715, -27275, 6701, -23889, -9028, 27519, 15176, -14426, -4606, -22347, -18538, 19296, -2558,
-    25153, 17240, -26203, -548, -32361, 29567, 5877, 29834, 32651, 5620, 32661, -30315, 10761,
-    -24909, -17208, 31579, 9016, -27746, -10570, 21610, 8235, -27276, -24651, 10549, -5432, -749,
-    -9241, -1320, -16426, -20806, -28562, 10303, -21064, -2091, -29275, 31365, 10760, -29276,
-    -21321, 23354, -3833, 16716, 21298, 32404, -14639, 25925, 5676, 29051, -22649, 537, -24407,
-    -15710, 19299, 1294, 29008, 24182, -14641, 17701, -3061, 12345, 17444, 23924, -26463, 5622,
-    -26706, -23379, 23356, -5633, 15173, 17445, 27263, -23378, 11535, -16943, -7958, -17506,
-    -30564, 14916, -7998, 1563, -8730, 484, -13601, -15412, -21618, 23151, -763, 29783, 23665,
-    -14128, 16677, -2807, 11830, 16163, 22893, -28520, 1514, -32106, -32632, 7683, -29794, -24151,
-    18221, -10520, 5406, 1772, 2588, 2065, 11538, 9013, 18256, -31382, -17716, 13888, 3313, 12611,
-    13374, -32411, -23627, 6693, -9539, -7436, -20035, -20076, 20835, -2302, 25928, 19036, -23377,
-    3055, -24912, -24662, 23102, -5895, 14147, 15392, 25459, -27745, 4855, -26970, -25175, 20276,
-    -8724, 8237, 6654, 11066, 14405, 32611, -22345, 6696, -8254, -5383, -17462, -15450, 28799,
-    9524, -19563, -13864, 28029, 21815, -19262, -1783, -13395, -18748, 29058, 17960, -21833, -8208,
-    -22646, 31367, 4386, -22133, -21062, 17751, 4595, 19031, 19036, -19564, -3843, -28252, -24190,
-    9779, -19001, -1317, -23888, -29795, 19758, -13352, 1561, -3631, -5385, -14116, -11341, -28772,
-    20323, -34, 16719, 11585, -28562, -20547, 11072, -550, 6697, 792, 16669, 13636, 24950, -17770,
-    3100, -19770, -7490, -31595, 21351, -809, 15952, 10043, -29851, -24398, 5932, -8521, -7179,
-    -21310, -18544, 21092, -3063, 27974, 20321, -22596, 7671, -19515, -17199, -27024, 16723,
-    -15401, 10757, -8979, -3578, -3122, -11035, -19512, -21112, 20577, -5635, 24122, 14152, -32107,
-    -8775, 20064, 6444, -29593, -27739, 3104, -14945, -16431, 28805, 22064, -19002, -2549, -11861,
-    -18745, 29320, 20265, -20031, -5632, -15973, -25685, 18013, 2274, 16207, 12616, -26768, -18232,
-    14416, 6385, 16721, 16986, -21629, -8723, 28809, 30286, -10266, 13390, 13324, 23144, 29838,
-    -2354, 23659, 14675, -16747, -5897, -29529, -24971, 7211, -24390, -6724, 30342, 16988, -7496,
-    5157, -8714, 7154, -5895, -5372, -557, -10264, -17451, -17005, 26745, 3110, -24716, -25941,
-    7994, -6727, -3324, -16936, -9040, -30566, 19045, -46, 14154, 7223, -32429, -30051, -4085,
-    -22406, -31336, 4654, -14697, -14888, 28556, 25653, -15917, 2086, -1591, -4351, -13592, -5703,
-    -24140, 28043, 16143, -25940, -17447, -31402, 12097, -26700, -2618, 31373, 21360, -563, 15697,
-    7739, -29605, -26966, 1315, -12901, -16686, 28044, 24108, -18229, -2282, -7761, -14888, -30809,
-    32589, -3066, 21363, 31047, -17716, 4916, 206, 532, -7678, 5860, -6408, -8963, -2621, -16174,
-    -27210, -30634, 3102, 29844, -19840, 5159, -22586, -4677, -32363, 20591, 1234, 16725, 10054,
-    -25752, -20798, 10826, 3289, 9015, 4144, 26419, 25463, -21814, 16654, -10005, -1767, 1234,
-    -5123, -12304, -4677, -21571, 30873, 22052, -16946, -3565, -7761, -15661, -32092, 30533, -5639,
-    15969, 23080, -30312, -16157, 31562, 11580, -31064, -6989, 23403, 7744, -21639, -17975, 16997,
-    12028, 24945, 28048, 463, 24431, 15969, -12131, -498, -21554, -9047, 30600, 12628, -9048,
-    -1011, -18984, -6735, -30309, 19567, 2774, 17751, 11600, -22670, -15660, 18026, 15881, 29572,
-    -29263, 13569, -19774, -15385, -22154, 23917, -7418, 29504, 18006, -26951, 4061, -26970,
-    -31834, 19482, -16177, -5876, -9303, -19004, 28049, 21795, -19262, -6903, -13415, -23888,
-    18798, 7916, 23144, 21625, -7762, 10038, -7160, 15883, 4898, 11601, 29504, -28256, -8188,
-    -23439, 30084, -2791, -25238, -31854, -992, -19841, -24658, 11602, 205, 7982, -1249, 19738,
-    14664, 25222, -12645, 8497, -13073, 8430, -8723, -9218, -5191, -18489, -32605, 27192, -9238,
-    8774, 12542, 17491, 20853, -14187, 2586, -20526, -4935, -29796, 21880, 5345, 22890, 19310,
-    -10076, 4644, -14102, 3803, -14889, -19756, -21640, 19550, -10505, 21795, 6700, 20335, -25751,
-    -10006, 21364, 24108, -24399, -8706, -20610, 31885, 2860, -18041, -19516, 19309, 12799, 27773,
-    31902, 7145, -30824, -32349, 15113, -21316, -14873, -23694, 22889, -9220, 25909, 12609, 30358,
-    -10329, 15694, -2796, 25138, 18007, -30296, -49, 30601, 22647, 208, 18265, 10568, -24208,
-    -18230, 14938, 9459, 19807, 21106, -11873, 4388, -15390, 1749, -18486, -24642, -30633, 5416,
-    32669, -14956, 12613, -9738, 15115, 277, 8252, 20769, 23921, -27986, 8176, -24910, -23874,
-    28737, -238, 21359, -32430, -15916, 9795, 6120, 11070, 9795, -32431, -27481, -2027, -17268,
-    -24140, 16478, 4834, 16467, 13651, -22923, -14117, 20591, 18969, -30566, -19502, 27708, 3616,
-    23674, -26006, -6922, 24703, 30021, -15147, 6714, 4063, 6442, 2345, 21282, 19292, 32414, -1335,
-    26489, 16994, -9303, 2846, -14362, 1235, -17972, -24642, -30119, 5930, -31839, -13414, 15183,
-    -5882, 21796, 10814, 25215, -17012, 2848, -21304, -5961, 32661, 19560, -565, 13130, 5425,
-    31560, 31120, -9996, -31662, 18013, -20534, 10230, -16169, -12824, -16498, 30349, 6966, -15471,
-    -14370, 28555, 26935, -15656, 3884, 1234, -493, -6910, 5603, -6662, -8709, -2621, -16685,
-    -26956, -30891, 2334, 29584, -20868, 3106, -24902, -9046, 25723, 9537, -17271, -13599, 27271,
-    26677, -17198, 1829, -2109, -5890, -15904, -8534, -30048, 19049, 2517, 15956, 10568, -25497,
-    -20539, 11340, 4572, 10302, 6714, 30786, 31890, -11020, -31151, 17755, -21301, 9970, -16941,
-    -14876, -18557, 26493, 31, -25497, -31076, 803, -16503, -21310, 19308, 12024, 25722, 29331,
-    3543, 27264, 22392, -1855, 14672, 4402, -32182, 31891, -8706, -28326, 22639, -13847, 22562,
-    2850, 17507, -31409, -19767, 6200, 459, 794, -6908, 7912, -5117, -5366, 3543, -7688, -12305,
-    -6223, -24393, 26760, 16137, -28249, -20272, 30530, 4648, 27273, -20100, 1308, -26442, -11619,
-    21355, 2086, -28325, -32359, -3054, -21643, -30817, 5171, -11621, -12570, -32351, -30896,
-    -3832, 22648, -32695, -15912, 8514, 6883, 9532, 8000, 31812, -31589, -8448, -25757, 25466,
-    -8706, 30785, 16214, -26697, 3543, -29019, 31900, 16651, -22595, -14104, -23184, 22638, -8451,
-    27447, 13386, -32353, -5707, 21611, 8254, -22156, -19767, 15708, 9712, 19555, 21362, -10849,
-    4393, -14106, 4058, -16168, -19760, -22413, 17243, -12565, 17938, -748, 9795, 22307, 26237,
-    -24387, 14855, -15142, -7682, -10074, -23366, 24701, 14085, -32105, -25415, 20511, -10004,
-    2857, 5604, 3361, 1315, 17426, 13641, 23417, -15728, 2334, -20789, -5705, -32104, 20332, 1234,
-    15956, 9795, -26780, -22595, 8511, -824, 1822, -6653, 8685, -4089, -3055, 6372, -2804, -4083,
-    6629, -3575, -4597, 5344, -5374, -7425, 462, -12828, -19761, -19584, 20326, -6654, 26677,
-    14158, -31841, -4678, 22897, 11079, -18300, -13341, 26500, 26419, -18482, 287, -4680, -10258,
-    -22843, -19586, 17242, -9994, 20508, 4649, 17762, -29865, -17968, 10053, 5601, 9789, 7740,
-    31044, -32617, -9223, -28580, 21868, -14107, 20766, 794, 14420, 28219, -28762, -7683, -23695,
-    28293, -2543, -27036, 30092, -4085, -26779, 28807, -5113, -29092, 25466, -10511, 27450, 11074,
-    31894, -10073, 15701, -1003, 27193, 20070, -24648, 7918, -22850, -21307, -31917, 6192, -31845,
-    -13413, 13903, -5631, 20512, 8506, 23153, -21637, -5115, -32617, -25492, 797, -30305, -17268,
-    11078, -11545, 11774, -6912, -494, 4835, -2800, -3321, 6120, -4342, -3578, 4321, -6398, -7177,
-    -1590, -15907, -22597, -26519, 9280, -22084, -819, -30298, 29574, 11517, -31840, -25168, 20513,
-    -11795, 3618, 4064, 542, -495, 12032, 4397, 11584, 27966, 32410, -10005, -31401, 16990, -19001,
-    9720, -16421, -11291, -16237, 30868, 10041, -12897, -9996, -27738, -26004, 4654, -25940, -9556,
-    22645, 8499, -22407, -21303, 16981, 7919, 17505, 20579, -15212, -2284, -22341, -12385, 23160,
-    5930, -23951, -25671, 10814, -2107, 802, -6150, 7403, -6908, -4351, 1492, -10764, -14117,
-    -12386, 31128, 13897, -7761, -2025, -14631, -3906, -26697, 30088, 16141, -27212, -15916,
-    -30633, 11082, -24141, -564, 32671, 27517, 7403, 26760, 29573, 3548, 24961, 23919, -3906,
-    11598, 3102, 27705, 22392, -20029, 15113, -13076, -2809, -3135, -14104, -22084, -23438, 11854,
-    -16174, 8431, -15903, -12062, -15470, 29844, 9784, -13158, -11534, -29537, -28321, -483, 31887,
-    -21382, 2345, -23627, -8532, 24962, 11840, -15729, -12304, 32658, -32177, -7935, 20579, 25649,
-    -27468, -6664, -21382, 29330, 3103, -20098, -25155, 15183, 3289, 10312, 8501, 32073, 32414,
-    -6149, -26266, 24961, -6150, 31561, 17251, -21314, 8688, -20786, -16688, -24979, 15709, -13605,
-    14344, -7422, 2587, 7661, 2088, 5414, 19997, 17251, -32623, -2877, 21621, 14409, -16756,
-    -10762, -32108, -30120, -5108, 25718, -32176, -14618, 13897, 12285, 18022, 25462, -9047, 8000,
-    -5637, 15113, 1061, 11839, 25650, 29074, -15147, 26422, 2860, 25202, -24979, -8448, 28029,
-    32076, -13846, 13895, 12544, 18279, 26488, -8274, 9799, -2811, 19483, 8257, 23660, -21124,
-    -6135, -31339, -24979, 548, -28256, -15213, 13142, -5641, 19741, 5175, 21601, -26520, -13844,
-    21602, 19998, -32606, -16178, 28737, 3889, 29311, -20351, 35, -23376, -11356, 21624, 7208,
-    -24464, -26436, 11321, -2620, -735, -6670, 5091, -10759, -8983, -7502, -25665, 29054, 15629,
-    -29778, -17464, 30279, 3890, 31109, -18552, 3377, -17980, -2618, -30033, 30080, 12032, -32604,
-    -23377, 21285, -11017, 7208, 8177, 6460, 11577, 30022, 32674, -5900, -27032, 23679, -6158,
-    28996, 13913, -25177, 212, 31646, 29308, 6894, 27277, 31621, 4838, 27534, 29822, 3296, 24193,
-    24939, -4929, 10830, 3351, 25911, 20082, -22093, 9720, -21553, -14383, -24461, 17512, -9244,
-    19488, 1064, 18512, 30796, -25408, 3093, -10840, -16927, -30317, 29767, -9475, 17487, 19487,
-    28304, -20550, 18974, -9991, 5923, 7408, 4916, 9264, 25655, 26504, -16437, 21287, -3566, 14661,
-    22315, 28816, -17465, 22316, -3310, 15946, 23601, 31642, -13353, 28999, 7741, -31601, -13150,
-    12625, -3075, 20005, 8770, 26480, -19831, -1767, -23893, -15205, 18023, 523, 29256, 21364,
-    -17211, 14608, -10763, 1295, 1243, -5623, -6930, -2098, -17188, -21836, -28569, 6971, -23893,
-    -6467, 26761, 17999, -10066, -738, -13099, -3127, -24896, -30318, 21032, -17701, 781, -5955,
-    -13589, -22349, -24973, 9799, -17724, 3041, -23098, -22607, 30541, -482, 27509, -27799, -8450,
-    26482, 28742, -18217, 7465, 214, -227, -3073, 7411, -3568, 783, 8181, 1059, 6180, 18204, 16479,
-    31623, -6469, 16994, 7465, -29857, -30552, 1812, -17265, -23613, 21088, 9206, 22134, 27770,
-    -3902, 15453, 7981, -30117, -30551, 1043, -17268, -24640, 19548, 7404, 18537, 21861, -12643,
-    548, -15920, -2877, -27722, 31112, 15885, -27209, -15149, -30118, 11599, -22089, 1751, -29008,
-    -30827, 17686, -21556, -7440, -17266, 32415, 11579, -9557, -6649, -19776, -14440, 22650, 4640,
-    -26006, -30036, 5669, -11872, -14873, -30825, 32333, -7163, 21090, 26422, -26439, -4097,
-    -18296, -30808, 12352, -5961, -2280, -12321, -2106, -23097, -29283, 25651, -12047, 9269, 9973,
-    10827, 16465, -25494, -17444, 18008, 13314, 23162, 31886, 2263, 25989, 23662, -3135, 12112,
-    4387, 29504, 25476, -15146, 23080, -482, 18008, 30276, -25412, 19, -12388, -20529, 27519,
-    19995, -25927, -11032, -24209, 22390, -6664, 28221, 13652, -28253, -2106, 27017, 20321, -5448,
-    6458, -3581, 15627, 3631, 14923, 31304, -27724, -1011, -15985, -25411, 19550, 7145, 18280,
-    20835, -13416, -1252, -19258, -7505, 30103, 18008, -4165, 4918, -3838, 14340, 1577, 11582,
-    26419, 29076, -14376, 27705, 4404, 28029, -20098, -1250, -25428, -13673, 17255, -499, 29761,
-    20082, -19518, 13314, -15129, -5640, -8274, -22839, 30598, 20254, -23354, -6925, -18039, 31902,
-    10038, -11101, -9733, -24914, -22152, 9800, -16177, 6119, -18728, -16434, -22922, 17510, -8982,
-    20513, 2861, 20059, -30631, -19242, 12093, 5092, 8515, 10037, 30792, 32159, -6155, -27547,
-    23164, -7698, 27196, 10828, -30572, -8014, 18025, 6951, -28575, -30549, 3097, -15212, -21040,
-    25714, 16914, -31578, -18234, 27709, 805, 25199, -27547, -11018, 23401, 24368, -26182, -5384,
-    -19836, 31901, 8495, -13155, -13075, -30055, -31145, -4080, 26486, -31145, -12819, 17492,
-    16658, 26245, -26713, 11518, -23100, -15662, -27032, 14937, -15920, 10748, -13077, -6154,
-    -7756, -21815, 32140, 21800, -19246, -1527, -9298, -18475, -31853, 26683, -12565, 9783, 8949,
-    11337, 15951, -26518, -17962, 16466, 10490, 19561, 25461, -8529, 9282, -3838, 17429, 5941,
-    19035, -28575, -17190, 15181, 10232, 17763, 23405, -12128, 3372, -13091, 2522, -18474, -20287,
-    -26776, 10568, -20288, 2266, -25927, -27741, 23598, -11793, 7470, 7663, 7483, 10811, 30279,
-    -32096, -6152, -26518, 25216, -5382, 31309, 18277, -19775, 9978, -17447, -11294, -17521, 29071,
-    7725, -17265, -17190, 27001, 21286, -24644, -7438, -20862, 29841, 4899, -19321, -21817, 20063,
-    9977, 22645, 28287, -2874, 17763, 10554, -25234, -22330, 13382, 3293, 9025, 7728, 28993, 29071,
-    -12062, 28994, 9282, -31595, -10328, 15708, 1045, 28993, 22133, -18745, 15373, -11022, -240,
-    724, -7167, -10778, -6215, -24643, 30343, 17430, -25158, -12063, -25746, 20332, -9494, 22058,
-    5169, 23402, -25745, -9738, 25973, 27455, -19248, 3872, -3901, -7169, -15660, -11354, 31382,
-    15438, -6986, 1057, -10264, 2524, -15135, -16946, -20606, 20589, -4097, 27712, 16220, -25429,
-    2012, -30812, -32625, 13064, -26701, -17462, 32083, 7481, -29542, -11351, 17248, 2327, 30540,
-    25472, -13094, 23088, 2599, 22372, -29855, -14878, 17233, 13065, 23158, 32653, 986, 26499,
-    23915, -4412, 12108, 4126, 27199, 23930, -17977, 16663, -8454, 4384, 6896, 4140, 7211, 22316,
-    22387, -24658, 8695, -23103, -18233, -30626, 9537, -24659, -4412, 29070, 21088, -4413, 9025,
-    1042, 21287, 14679, 32396, -7241, 17250, 6439, -30372, -31838, -500, -20863, -29268, 11580,
-    -5958, -2539, -12322, -3131, -23613, -30569, 23084, -15390, 3614, 210, -4082, -7952, -304,
-    -16161, -20545, -24976, 12110, -16691, 7150, -17446, -14121, -20092, 23418, -500, -31143,
-    25988, -8725, 28483, 11853, -28515, -5442, 23419, 14662, -15980, -9478, -29028, -27031, 1317,
-    -29029, -16237, 11855, -7442, 15633, -225, 12348, 23343, 27531, -17722, 20774, -5109, 12605,
-    18461, 23161, -26974, 7153, -27726, -23633, 24887, -6652, 15175, 19233, 26758, -22605, 14608,
-    -15392, -3844, -9036, -20275, -32371, 23090, -16421, 3354, -2612, -6654, -12581, -8780, -28756,
-    24685, 6640, 23930, 27255, -3641, 15964, 9008, -29599, -28241, 4126, -12895, -16419, 32397,
-    27198, -13336, 9782, 7922, 10309, 14151, -29601, -22845, 8755, -2360, -1256, -7951, 2524,
-    -12822, -14633, -15980, 27528, 7213, -19065, -19247, 22634, 15117, 30611, -24398, 17688,
-    -13595, -753, -2873, -10511, -18229, -17265, 23157, 1047, -29602, 30096, -4607, -28317, 25727,
-    -7435, 29767, 15447, -24912, 2011, -29786, -32365, 14605, -24390, -14375, -27800, 16731,
-    -15404, 12037, -9742, -2296, -1328, -9999, -15917, -15206, 28038, 8242, -18291, -16424, 25976,
-    20517, -25163, -9491, -23944, 25981, -2809, -31654, 24953, -11291, 24117, 6706, 26488, -21887,
-    -1775, -27997, -19317, 11847, -11550, 10497, -7173, -1012, 2016, -5117, -7181, -2353, -15654,
-    -22087, -27796, 9533, -22088, -2610, -31073, 28028, 7156, 28809, 32140, 5614, 31379, -32368,
-    9212, -29531, -24144, 21806, -8458, 9268, 10755, 14158, 20833, -20600, -5888, -30568, -26511,
-    2337, -27999, -15717, 15445, -3842, 21293, 11076, 29054, -15716, 6708, -12068, 4331, -14367,
-    -13096, -18028, 27782, 6694, -21370, -21306, 19545, 8185, 21100, 25970, -8521, 10564, -1273,
-    19236, 11078, 27254, -17259, 2855, -17209, -4409, -29013, 29309, 10241, 32410, -25690, 16410,
-    -16165, -2816, -9291, -18992, -31343, 24891, -13082, 8494, 5358, 7222, 9265, 26432, 29067,
-    -13352, 25405, 5423, 27768, -22655, -1773, -27488, -19571, 11592, -10784, 10243, -7171, 12,
-    2532, -4087, -4615, 734, -10256, -12582, -13658, -32615, 16203, -6977, 2596, -7186, 4846,
-    -8970, -6929, -6719, -20278, -29802, 24636, -11541, 10035, 7930, 11590, 16460, -28051, -17966,
-    16204, 7929, 17758, 22372, -15716, 26, -18750, -9034, 30867, 18773, -5951, 5682, -3074, 12298,
-    2084, 11832, 23606, 28298, -16182, 21551, -1772, 17229, 24892, -30300, -7958, -29078, 21615,
-    -9758, 20782, 4139, 22881, -29591, -13595, 20055, 15385, 28810, -23636, 13844, -16167, -4618,
-    -12370, -23363, 27508, 12560, -31588, -21323, 20785, -6403, 11832, 13589, 19811, 30850, -6715,
-    18270, 9005, -29846, -26706, 6179, -11857, -11543, -26460, -29333, 3878, -28260, -15712, 15444,
-    -2818, 21041, 12103, 30849, -14169, 10305, -5904, 12561, 282, 11058, 19500, 24183, -23638,
-    8450, -21308, -14643, -28301, 16472, -13359, 10508, -8716, 7, -1059, -6917, -10016, -9283,
-    -25164, 29049, 11535, -30562, -21067, 21302, -5376, 13886, 15905, 24436, -27235, 4597, -27993,
-    -25436, 19247, -11289, 5663, 1770, 2333, 1808, 11536, 8244, 17485, -32412, -20027, 10547,
-    -1830, 3362, -1019, 9993, 3619, 11317, 22586, 28548, -16697, 19246, -2807, 14144, 18732, 27776,
-    -21323, 13593, -12575, -1533, -6968, -13346, -22864, -29070, 8757, -22608, -6711, 31117, 22111,
-    -4913, 11843, 4635, 24128, 23408, -20295, 10508, -14887, -6674, -14421, -26195, 22625, 3826,
-    21351, 22882, -13908, 3619, -12329, -1315, -19254, -22609, 31068, 2849, 32132, -23160, 3107,
-    -21583, -11081, 26752, 14141, -16993, -8972, -27750, -29072, 2594, -28008, -17764, 13389,
-    -5650, 15134, 3109, 17223, 27727, -26961, -510, -20076, -26961, 17224, -2087, 8507, 5145,
-    21557, 20072, -25182, 2796, -29016, -27495, 16675, -17195, -1795, -11595, -19765, -32635,
-    20531, -18224, 777, -9797, -15140, -26722, 31324, -1519, 28020, -31385, -9230, 22881, 21301,
-    -26964, -7703, -27272, 24951, -4106, 27985, 18269, -20812, 4598, -21824, -18756, 31841, 7475,
-    -27495, -13135, 19041, 4631, 30812, 29578, -6421, 30042, 17756, -18758, 5884, -18739, -13875,
-    -25984, 19812, -6937, 19250, 6448, 25188, -27525, -8202, 29044, 27217, -14885, 11312, 3058,
-    8760, 10798, 26188, 31376, -8992, 28759, 14157, -23385, -2853, -32103, 29815, 4343, 28293,
-    31871, 1259, 27265, 27759, -3882, 17757, 13110, -27784, -20794, 15938, 2285, 12103, 13368,
-    32611, -25677, 5659, -12623, -13084, -27237, 32610, -748, 30332, -28557, -4346, 31103, -31384,
-    -6146, 26221, 27470, -17455, 7975, -1830, 280, -3335, 4596, -4860, -1794, 742, -6917, -7705,
-    -7482, -21052, -30064, 21560, -14114, 5661, -1058, -1263, -4106, 2027, -7689, -7447, -7996,
-    -21053, -30834, 20789, -15400, 3349, -4656, -6662, -13613, -12880, -31848, 18513, -5685, 7218,
-    -763, 14105, 7732, 19797, -30357, -16170, 16714, 8194, 19553, 25452, -12881, 6961, -7960, 6652,
-    -6918, -2306, -1829, -9745, -13614, -15964, 30348, 12344, -15194, -8460, -25949, -26759, 7218,
-    -21581, -6713, 31377, 22624, -3630, 12874, 7204, 28238, 29322, -10016, 27211, 11075, -29035,
-    -10055, 20071, 8231, -29074, -27218, 7204, -11599, -10770, -24664, -27019, 7478, -21581, -5688,
-    31637, 23909, -1320, 15704, 12344, -28563, -23104, 11574, -2350, 2084, -2306, 8959, -743, 6176,
-    14613, 13649, 26222, -16485, 2342, -15928, -4406, -27984, 31361, 12557, -29013, -18241, 27207,
-    1571, 27248, -27792, -7939, 28020, 29006, -15650, 11316, 4847, 9023, 11830, 30033, -30813,
-    -2820, -24708, 30868, 4120, -21368, -24388, 17485, 2533, 12878, 13116, -30107, -24131, 8748,
-    -5693, -4341, -12584, -7235, -27214, 28537, 11013, 32410, -24663, 17182, -14366, 11, -4665,
-    -11539, -19264, -21113, 18274, -5644, 22065, 9536, 29051, -17514, 4397, -15412, -1580, -24387,
-    -28262, 22322, -13080, 6692, 3303, 2855, 3608, 16153, 12621, 26224, -17001, 1828, -17468,
-    -5950, -31068, 26223, 5101, 23674, 26480, -5437, 13138, 5406, 28744, 26245, -12842, 23348,
-    2601, 23909, -29081, -13077, 21083, 17951, 31384, -18496, 22578, -3569, 16714, 22835, 32154,
-    -12842, 28747, 8510, -30319, -12374, 15193, 779, 25662, 18791, -23123, 5359, -25414, -22095,
-    27462, -2284, 23138, 30289, -19504, 8490, -1324, -485, -4104, 5102, -6653, -3591, -809, -12050,
-    -14899, -17514, 25473, 5919, -24454, -26185, 12602, -3638, 1059, -4619, 6386, -6394, -1793,
-    1504, -8194, -8475, -7234, -23614, -32633, 18724, -21559, -4620, -16999, -29269, 17483, -2351,
-    7227, 3091, 20008, 15194, -32119, -7235, 18022, 9002, -28567, -27725, 7204, -10321, -11277,
-    -23893, -24970, 8513, -18497, 217, -26440, -28263, 20778, -15390, 3093, -2097, -7165, -11557,
-    -8522, -28239, 26480, 8697, 27017, -32117, 5356, 30615, -31860, 9211, -30809, -23893, 21034,
-    -10764, 7720, 7412, 7227, 12089, 29771, -31581, -4360, -25741, 27530, -762, -28568, 28301,
-    -2562, -29852, 25217, -6675, 28232, 13652, -25437, -2095, 29844, 25964, 218, 18022, 16455,
-    -21114, -12819, 29563, 26689, -17189, 7205, 217, -484, -2562, 6900, -3568, 1037, 7670, 802,
-    6177, 17179, 15451, 30335, -9550, 12625, 780, 23860, 16480, -27491, -556, 29074, 26223, 472,
-    18280, 16457, -20089, -12047, 30850, 29513, -13333, 13375, 10752, 16222, 24169, -14435, 1574,
-    -15411, -3127, -26953, -32630, 16663, -24127, -10014, -23686, 23676, -2305, 31571, 21106,
-    -14899, 16152, -6908, 7204, 10241, 9540, 17741, -28310, -18474, 16457, 8184, 16736, 22625,
-    -15975, -1511, -19781, -11092, 26503, 13116, -15462, -10506, -28518, -28569, 289, -30575,
-    -19831, 6715, -15156, 2015, -21556, -21581, 32599, 2603, -32119, -19316, 5431, -15415, 217,
-    -23868, -25181, 26432, -7420, 17482, 20007, 29074, -17985, 20779, -5622, 13627, 17695, 23162,
-    -26209, 6644, -27725, -22611, 24635, -6137, 16968, 20266, 29329, -17471, 21038, -4339, 15169,
-    20010, 27529, -19527, 16927, -9995, 5147, 4333, 2085, 4633, 15898, 13136, 27249, -15971, 3628,
-    -13873, -1065, -22843, -25438, 26435, -6909, 17996, 20267, 30613, -16186, 23352, -485, 21337,
-    29777, -21817, 6175, -6462, -7682, -16184, -14686, 27271, 10545, -18285, -15135, 29821, 24121,
-    -18734, 2837, -6207, -10510, -19522, -20342, 18532, -4360, 23607, 12107, -32117, -10575, 15449,
-    2579, 27718, 22902, -17211, 15126, -9225, 3351, 3817, 28, 1295, 11013, 5168, 13631, 28489,
-    -30556, -4617, -25738, 28041, -248, -28308, 29840, -1019, -27280, 30097, 267, -25482, -32355,
-    4894, -17516, -19762, 25198, 15381, -31842, -19521, 23863, -2544, 18259, 25405, -28502, -6157,
-    -25224, 27525, -760, -29336, 28810, -3331, -30877, 24698, -8729, 24894, 9535, 32134, -14942,
-    10307, -6675, 12302, -1259, 9003, 16414, 18787, -32375, -4918, 21358, 14400, -20853, -13338,
-    29050, 24637, -18479, 3608, -5691, -8713, -17209, -16742, 24955, 5408, -25738, -26960, 9778,
-    -7492, -4600, -15152, -10062, -32099, 20315, -1839, 11336, 6437, 27973, 27270, -13353, 23862,
-    3369, 24426, -27796, -10510, 24170, 23605, -24646, -4101, -19057, -30043, 13376, -6722, -487,
-    -10269, -811, -18220, -22091, -30366, 5939, -27232, -11348, 19561, 5408, -30367, -32354, -246,
-    -22400, -30041, 10035, -9551, -7167, -19778, -16490, 21618, 2068, -31140, 28814, -5386, -31653,
-    20847, -13611, 17436, -3826, 10805, 17179, 20589, -30573, 217, 27785, 25197, -2354, 15193,
-    10034, -29854, -27470, 5152, -11608, -14106, -29029, -32425, -3569, 26227, -32168, -13336,
-    16462, 13836, 23158, -32112, 1757, 28041, 26228, -557, 18276, 14149, -22146, -15392, 24173,
-    19746, -28757, -12836, -30883, 14677, -19776, 5612, -21304, -19262, -30111, 9023, -24403,
-    -4925, 28813, 20573, -5440, 7483, -1273, 16920, 7997, 21857, -24972, -10765, 26484, 26429,
-    -20018, 2841, -6212, -10766, -20803, -20604, 16734, -7440, 20004, 5169, 21858, -27799, -13336,
-    20831, 18205, 31896, -19005, 23346, -2800, 16976, 24631, -30814, -9753, -30367, 18531, -15151,
-    13325, -8456, 1299, 3044, -2288, -3070, 4843, -4858, -3585, 1503, -8457, -10524, -9291, -26190,
-    26485, 9985, 30350, -28771, 11014, -23622, -16178, -30620, 12873, -21062, 992, -25935, -28258,
-    20268, -13600, 3098, -1322, -4090, -8982, -3892, -18739, -26201, 29776, -2291, 23915, 30804,
-    -16427, 10552, 3561, 8503, 8239, 26177, 28806, -14378, 23608, 3620, 23658, -29078, -11030,
-    21603, 19753, -29535, -13607, 31319, 12357, -25430, -4148, 30348, 22630, -3378, 13387, 6439,
-    29261, 29835, -10010, 29005, 13130, -26716, -4406, 28294, 20573, -7234, 6964, -3330, 12814,
-    3109, 13118, 25407, 32150, -10784, 30291, 13132, -24663, -2606, 31637, 26481, 1763, 21614,
-    20827, -13915, 27, -16183, -6976, -30299, 25966, 5103, 23929, 26737, -5435, 13907, 6177, 29774,
-    28556, -9501, 28490, 11594, -27492, -6463, 23931, 15428, -16487, -8709, -27491, -26510, 3885,
-    -24665, -11090, 21876, 8746, -24969, -24128, 14144, 217, 6456, 4378, 21034, 17507, -29290,
-    -1583, 26503, 22625, -5953, 8257, 9, 18976, 10570, 27251, -17005, 1576, -17469, -5183, -31577,
-    26736, 6125, 23936, 28021, -2614, 16227, 11573, -26516, -24123, 12602, -46, 3121, 780, 15631,
-    6976, 20312, -26518, -15641, 20827, 16916, 28563, -22607, 17431, -14101, 525, -1846, -10246,
-    -15152, -13668, 27791, 11063, -14697, -12559, -30571, -31145, -5106, 25970, -32687, -15387,
-    13892, 10746, 15968, 23144, -14184, 35, -17464, -5189, -31833, 25199, 5862, 21881, 24428,
-    -6732, 8261, -1787, 18969, 7747, 23656, -21638, -7673, -32371, -27549, -4075, 30852, -26264,
-    -5103, 31109, -27035, -5617, 29824, -28834, -8701, 24941, 28735, -21295, 4125, -4420, -9730,
-    -17720, -14700, 23681, 5411, -23439, -27463, 10809, -3394, -2276, -9495, 1490, -17440, -19775,
-    -24210, 12116, -15664, 9458, -15641, -9753, -12644, -31832, 17490, -1337, 6463, 1556, 21279,
-    13145, 30854, -8277, 12632, 785, 26932, 17772, -24402, 6886, -27461, -24145, 27190, -6901,
-    16719, 23078, 30107, -15921, 27191, 1580, 25456, -25495, -9729, 26742, 30018, -18211, 7982,
-    3032, 1579, 786, 15625, 6976, 18776, -26524, -17183, 17749, 13826, 22395, 32141, 2261, 25222,
-    23403, -3651, 10317, 2586, 26418, 19569, -23629, 9456, -23608, -18232, -28580, 9289, -23116,
-    -567, 32163, 27771, 7914, 25995, 30084, 4059, 24453, 24687, -2881, 11861, 5155, 30786, 25996,
-    -12579, 26932, 4408, 27770, -19843, -2274, -25687, -14446, 15203, -2814, 25904, 12890, -30057,
-    -3652, 21372, 14405, -15989, -12039, -31598, -29867, -6385, 25714, -32437, -16923, 12351, 9454,
-    11605, 17234, -22672, -15638, 23146, 21533, -30802, -13349, -30381, 11861, -22345, 3287,
-    -29003, -29541, 20507, -18724, -2298, -7507, -19495, -31082, 28474, -12043, 12096, 13568,
-    16484, 25717, -9820, 6462, -7438, 12540, -4589, 3871, 13053, 7234, 16207, -28325, -21808,
-    11068, 3286, 4664, 3615, 22304, 16229, -31338, -1084, 23169, 17750, -10337, -2788, -17460,
-    -5968, 31908, 21605, 2513, 13918, 12096, -24987, -23091, 12868, 4568, 7236, 7214, 29240, 26254,
-    -14632, 26157, 1325, 23147, -26529, -13582, 20835, 21788, 32678, -15660, 31298, 5693, 32656,
-    -12907, 9549, -7438, 16136, -1503, 10553, 23075, 23683, -22858, 14595, -17953, -7438, -11876,
-    -29004, 20576, 5343, 16229, 17492, -18045, -10243, -32623, -29096, -5874, 26231, -31409,
-    -14613, 14924, 14081, 19825, 29316, -2625, 17256, 10041, -24214, -23608, 12869, 3542, 6976,
-    5673, 26929, 23167, -20285, 16907, -12558, -752, 716, -8961, -13345, -8536, -30806, 21094,
-    4314, 16483, 15697, -19331, -12559, 28291, 29757, -16158, 7989, 6112, 5431, 5933, 25644, 22907,
-    -22595, 14337, -16673, -7946, -10849, -27210, 21867, 8683, 22135, 25208, -4168, 12370, 2592,
-    29242, 23164, -18740, 18449, -8706, 3878, 9453, 4916, 8504, 27700, 27789, -15912, 25902, 1575,
-    21867, -28069, -14617, 16985, 16393, 25218, -29790, 9454, -28496, -24907, 25903, -7165, 12873,
-    19478, 24446, -27477, 10740, -24642, -19767, -30894, 6970, -29534, -9049, 18793, 4134, -28839,
-    32671, -2034, -21129, -31323, 7219, -10079, -11020, -27219, -24214, 5943, -24136, -4168, 28817,
-    18784, -3655, 6459, -3062, 17677, 5945, 18012, -27299, -17957, 14415, 10994, 16739, 21868,
-    -12394, 549, -17455, -2371, -29006, 28549, 14334, -31833, -23109, 25129, -7161, 12358, 19732,
-    23165, -28249, 9452, -27722, -23880, 28214, -4592, 18012, 27700, -28494, -6404, -20873, 29589,
-    3106, -18561, -24125, 16985, 7396, 15711, 17242, -18048, -9476, 31892, -28585, -5364, 25467,
-    -30898, -13846, 14417, 15106, 21363, 30094, 457, 22391, 16473, -12137, -4080, -22592, -12137,
-    22392, 3880, -24474, -29009, 5423, -8541, -11533, -26959, -23447, 6715, -23362, -1602, 31902,
-    23670, 5337, 20337, 19044, -10855, -737, -18222, -3659, -30806, 24441, 9191, 24707, 27268,
-    1995, 20338, 15703, -13940, -7418, -27988, -19851, 8517, -17709, 6364, -20525, -20536, -25761,
-    10059, -21822, 3538, -27464, -30046, 23071, -15900, 796, 197, -7933, -14111, -6999, -30035,
-    22127, 7393, 20595, 21613, -8028, 4405, -9743, 9963, -9216, -5374, 711, -13843, -19252, -18050,
-    19054, -5117, 28982, 14685, -27734, 1997, 30619, 26751, 6880, 24451, 25466, -574, 15457, 9018,
-    -25761, -26178, 7477, -3146, -5360, -14626, -4431, -28747, 26238, 13302, 29850, -28504, 16901,
-    -21038, -10257, -15995, 29849, 7734, -12398, -14099, 32664, -31416, -8188, 19557, 26924,
-    -28235, -7686, -20621, 28049, 1053, -20879, -29006, 9021, -4175, -4590, -15395, -4175, -29005,
-    25726, 12787, 29078, -30301, 14843, -24893, -16680, -25763, 13658, -18480, 10989, -16926,
-    -12312, -13683, 30106, 10048, -9572, -9215, -25162, -18567, 12117, -12570, 15358, -7158, 2080,
-    10988, 3123, 7991, 27179, 25225, -19252, 21783, -7415, 8248, 16643, 15201, 25724, -8801, 6978,
-    -7688, 15101, -2788, 6448, 19470, 15973, 29578, -4175, 15203, 5163, -29105, 31394, -3832,
-    -21909, 29595, 1566, -18055, -26689, 14417, 4304, 8521, 6450, 31546, 27796, -12569, 31547,
-    8778, -31331, -6233, 17517, 5164, -26280, -31571, 1310, -13431, -22576, 22899, 17153, 29852,
-    -25161, 21266, -13840, 541, 3532, -5873, -9226, 1477, -17439, -22847, -23966, 9033, -21563,
-    3791, -27462, -30301, 23838, -15898, 1055, 1478, -6903, -12310, -3148, -24893, 30610, 21782,
-    -22324, -7427, -13941, -30548, 14162, -321, 4406, -2801, 17670, 5434, 16474, -27563, -20524,
-    10564, 6361, 7490, 6966, 30776, 28307, -13338, 31034, 8261, 32665, -8545, 14430, -746, 29749,
-    19313, -22849, 12530, -20009, -13854, -18053, 23939, -490, -26277, 29079, -3574, -24221, 28051,
-    -2546, -24221, 29079, -1518, -22165, 32163, 3623, -13685, -19752, 25469, 21782, -27720, -12568,
-    -24478, 19055, -11798, 22812, 1579, 18271, -30131, -21295, 7735, 2251, 551, -3574, 12788, -477,
-    5936, 21269, 17770, 32664, 709, 23938, 18272, -7516, 1066, -12570, 4307, -17953, -19766,
-    -22164, 13916, -14113, 15359, -8700, 794, 7905, -1247, 538, 15357, 5950, 15187, -28334, -23092,
-    7735, 964, -1247, -6658, 8161, -8443, -6402, 966, -15126, -20280, -19851, 15715, -10001, 21014,
-    1323, 16727, -32186, -25149, 2336, -7258, -14612, -27990, -27047, 809, -32103, -15739, 7749,
-    -13600, 9705, -13840, -9745, -8285, -27975, 23666, 11247, 24968, 30605, 5593, 26253, 26236,
-    2509, 18800, 15699, -15482, -9984, -31076, -25505, -1246, -32361, -18052, 4923, -18484, 1995,
-    -26689, -30049, 24098, -15896, 2592, 2252, -5102, -8460, 1739, -16411, -20282, -21648, 13916,
-    -13087, 15619, -7159, 3105, 10992, 4407, 10044, 29496, 29850, -11545, -32441, 11860, -25681,
-    970, 31135, 27005, 7395, 24710, 27005, 970, 18285, 14155, -18306, -13841, 28034, 28983, -17954,
-    5419, 2511, -1506, -4861, 8679, -5618, -2805, 6623, -5618, -4861, 4567, -9474, -10772, -5456,
-    -25408, 28807, 18189, -27465, -15141, -28071, 13399, -20282, 7653, -21554, -19511, -26785,
-    10315, -21825, 2771, -27979, -30563, 21019, -18214, -2806, -6995, -18471, -31076, 30014, -9477,
-    14672, 19220, 25732, -26449, 13309, -21300, -13856, -21386, 22134, -5118, 30786, 17508, -22852,
-    8427, -22585, -19768, -28838, 8770, -25423, -3138, 28560, 20067, -3139, 8258, -237, 21791,
-    12884, 29575, -9307, 11343, -3065, 22048, 10058, 27261, -14447, 3634, -15403, 2002, -22581,
-    -25169, 31301, -3049, 23662, -31408, -16671, 12612, 9712, 13399, 18266, -20101, -10760, 29575,
-    32585, -12046, 15184, 16908, 23677, -30306, 7142, -31579, -29792, 17680, -20272, -7947, -14959,
-    -31066, 14156, -3395, 2346, -6149, 9713, -5107, -495, 7914, -1252, 1562, 13825, 6972, 15697,
-    -29352, -22070, 8759, 460, 804, -4092, 10483, -2280, 2848, 14338, 8771, 17754, -25241, -15902,
-    18783, 16651, 27274, -27221, 13568, -21558, -13600, -21898, 22133, -5376, 30017, 16736, -24138,
-    5859, -26184, -25680, 26677, -6909, 14413, 20509, 27272, -23110, 16912, -13593, -2292, -3135,
-    -12822, -21567, -21639, 14935, -12059, 15371, -4084, 5932, 14343, 13135, 22123, -17783, -3056,
-    -26194, -16755, 15192, -6663, 20769, 6711, 22635, -23950, -8710, 27776, 31306, -13594, 12357,
-    11259, 16476, 22380, -14185, 800, -18485, -5190, -31325, 23921, 5347, 21618, 21865, -9303,
-    4657, -9491, 7917, -9479, -6407, -3391, -17703, -25939, -31147, 545, 30344, -22152, 32, -26455,
-    -13928, 16738, -1526, 27707, 17766, -24143, 6119, -26439, -24400, 26937, -5879, 16978, 23339,
-    32157, -14120, 30022, 7742, -31597, -11870, 13654, -2042, 23597, 13140, -32369, -7244, 17253,
-    6439, -29604, -31835, 272, -19068, -27466, 14922, 207, 6459, 2586, 21795, 15711, -32110, -3649,
-    20852, 13123, -18556, -14358, 28287, 26934, -18985, 3359, -2366, -7933, -14889, -9562, 32160,
-    18008, -1853, 6975, 532, 21277, 12629, 29316, -9821, 10060, -4352, 19478, 5691, 20834, -25241,
-    -13842, 21863, 21791, -31062, -13861, -31408, 11087, -24656, -54, 31391, 27002, 6628, 24195,
-    26488, -1083, 15715, 10297, -25499, -24892, 10555, -57, 553, -4095, 10994, -3302, 3102, 14591,
-    7493, 17494, -25759, -18465, 16467, 13048, 19315, 27518, -3658, 13405, 4902, -31929, 28054,
-    -8720, -31157, 15204, -20543, 9707, -21291, -16174, -22675, 16232, -10778, 19989, -1245, 14409,
-    27699, 31908, -10264, -29612, 15460, -18232, 11509, -16923, -9494, -12392, -32086, 16978, -828,
-    5695, 787, 21017, 11349, 28286, -11366, 6210, -8981, 11765, -8182, -243, 6111, -5098, -2813,
-    6625, -7154, -4099, 3028, -11781, -12323, -10079, 32424, 18775, -57, 7753, 4126, 26414, 19575,
-    -23117, 10994, -23088, -15664, -24472, 14435, -13352, 15108, -9210, 2583, 7654, -729, 3355,
-    16906, 9551, 22887, -18818, -6897, -29285, -21902, 3384, -21833, -4169, 28314, 20830, -1857,
-    7498, 2326, 24614, 15465, -28772, 1484, 26773, 24942, 970, 14437, 12092, -24217, -23600, 14149,
-    5595, 8269, 10294, -31928, 32427, -3327, -21391, 29343, 4127, -16766, -24114, 20576, 12018,
-    21119, 29057, 196, 17778, 13894, -18309, -15890, 27002, 26667, -23087, -1011, -8543, -20774,
-    31629, 26410, -18462, 3103, 452, -7411, -11804, -3660, -26429, 30602, 19728, -25916, -11033,
-    -21649, 22144, -4351, -32443, 18032, -19001, 14332, -15379, -5637, -5971, -22318, 32657, 25384,
-    -17950, 2589, -61, -8183, -13089, -5972, -29771, 24948, 10733, 24971, 30859, 5850, 25999,
-    27004, 3023, 19317, 17495, -13169, -6640, -24654, -15739, 14178, -6151, 23327, 6211, 25203,
-    -18822, -4840, -27997, -17537, 8782, -12835, 11248, -12807, -5639, -3401, -20260, -27741,
-    32580, -6382, 22118, 30781, -23602, 2844, -5458, -13579, -23627, -21906, 9038, -17203, 7136,
-    -21032, -18231, -24218, 12122, -16176, 10992, -16149, -9237, -10596, -30798, 20062, 4310,
-    13407, 13637, -23447, -20775, 16979, 11505, 17519, 24689, -8028, 5441, -6667, 14075, -4068,
-    5927, 17159, 11866, 24945, -13425, 45, -17205, -1860, -30795, 29056, 13817, 31143, -24401,
-    22042, -13834, 4128, 5850, -1498, 17, 14330, 2872, 12867, 31549, -32595, -5381, -22421, 26259,
-    -498, -24220, 29343, 788, -19595, -30282, 11069, -3148, -3810, -11548, 708, -22315, -26197,
-    -32702, -4839, 23405, -31160, -18975, 10556, 7647, 6983, 9785, -32703, 31398, -6150, -24478,
-    23688, -5380, -31673, 17263, -18745, 14074, -15891, -6152, -6743, -24115, 30343, 21528, -24630,
-    -7437, -17022, 30112, 8755, -11369, -13579, -29538, -27817, -2785, 30344, -22677, -3299,
-    -30566, -18565, 5440, -17460, 3281, -25144, -26198, 29239, -7925, 16979, 24099, 30368, -15404,
-    29754, 3640, 29314, -17792, 557, -21060, -5713, 27543, 18005, -4943, 1587, -6926, 9707, -8950,
-    -2814, 3282, -11263, -11296, -7769, -30795, 23657, 7908, 19835, 24428, -6228, 6215, -3073,
-    17932, 2874, 18001, -29871, -23855, 8750, -60, -3551, -6671, 4824, -13832, -12068, -11110,
-    30373, 16203, -3915, 48, -6672, 8422, -10746, -4874, -830, -18199, -21579, -24988, 6474,
-    -20809, 456, 32688, 30849, 12792, 31146, -23893, 21788, -14345, 4893, 5339, -2009, 525, 13562,
-    1847, 12604, 29496, 29860, -8985, -29871, 14440, -17981, 11250, -18971, -10271, -14707, 28318,
-    11061, -11367, -12546, -26718, -24474, 2104, -24920, -8026, 20095, 9519, -20877, -23853, 18001,
-    9449, 14955, 21599, -13682, -4834, -21321, -10855, 20610, 6950, -22421, -28221, 11834, -577,
-    -1749, -5386, 8676, -9716, -3845, 1995, -14600, -15410, -14710, 22666, 5151, -22164, -29763,
-    10549, -3404, -5861, -12325, -2376, -27706, 32394, 20498, -25394, -7956, -17795, 27035, 6180,
-    -16511, -23081, 22629, 15614, 25493, -27744, 13815, -26679, -16179, -27048, 9559, -20549, 4821,
-    -28478, -26717, 25896, -13316, 9265, 11760, 8530, 16975, -24221, -19741, 18004, 14329, 19838,
-    30597, 965, 19067, 16462, -13942, -10231, -27743, -21909, 3134, -22090, -2891, 27550, 21344,
-    -322, 7762, 4125, 28462, 19327, -21062, 14841, -19481, -7955, -11116, -32331, 18774, 3019,
-    8533, 8237, -32191, 28322, -7184, -28078, 17014, -14124, 18955, -8430, 7465, 15356, 9561,
-    21857, -17798, -9457, -30315, -23452, -1747, -28259, -13686, 10076, -6415, 19726, -205, 16716,
-    32576, -29504, 12, -13172, -26420, 22629, 12785, 22154, 31624, 4818, 23182, 24685, -1094,
-    10076, 5667, 32573, 24725, -11553, 29747, 4679, 31366, -12916, 4680, -11041, 10215, -14596,
-    -7186, -5462, -26418, 30851, 20753, -27447, -9499, -20881, 21641, -2046, -29876, 20099, -12327,
-    23582, -2261, 18771, 32320, -27705, 1810, -9830, -21280, 31366, 26151, -21024, 1812, -2892,
-    -14085, -20547, -18312, 13672, -7955, 21782, 822, 19544, -29105, -22566, 10550, 4305, 1850,
-    2840, 21010, 10845, 28540, -9831, 5449, -7442, 14328, -6630, 4638, 14584, 5707, 17231, -26023,
-    -22307, 13891, 8415, 8791, 13891, -26024, -25648, 10294, 1732, -1490, -3584, 12012, -5088,
-    3099, 15352, 4936, 16463, -26797, -23849, 10810, 4557, 1852, 2329, 21776, 10590, 28286, -9065,
-    5451, -7439, 15608, -5857, 5926, 17664, 9820, 23659, -14462, -4829, -23116, -10350, 18045,
-    3870, -25771, 29610, -242, -18318, -32585, 10553, -3927, -7655, -15662, -5212, 30382, 21090,
-    4297, 11107, 11324, -24745, -27701, 8755, -331, -6112, -10778, 1726, -23332, -25941, -30913,
-    -5599, 24689, -28086, -17422, 15438, 16632, 18045, 30087, 1212, 17274, 13896, -15751, -15880,
-    29060, 31795, -18451, 8244, 8664, 3138, 6702, 28710, 21642, -20284, 19973, -13826, 792, 5837,
-    -6887, -6405, 5324, -14341, -14372, -10353, 27551, 11843, -7527, -9200, -22082, -12667, 17272,
-    -751, -30400, 20870, -14630, 24600, -3546, 15954, 30768, -32074, -6406, -20375, 25495, 20,
-    -21661, 30635, 3619, -12667, -22308, 24951, 21258, -32332, -16684, -30656, 5191, -30820, -7269,
-    14187, 1563, -31171, 22668, -13858, 27170, 52, 22122, -25002, -16140, 19039, 21259, 27293,
-    -22339, 23059, -12286, 5418, 11493, 3906, 10044, 32310, 29349, -9232, -27314, 15985, -16429,
-    17662, -12028, 534, 6867, -5860, -4349, 8152, -9458, -6406, 2242, -17169, -20027, -19346,
-    13158, -11033, 19720, -4319, 10556, 23832, 21638, -24911, 14323, -23338, -13860, -19858, 19068,
-    -5380, 30773, 12643, -26455, 3274, 29605, 28544, 9699, 25493, 30857, 7900, 26007, 29572, 7129,
-    23951, 26745, 2246, 16241, 14152, -18058, -16656, 26232, 26661, -25138, -3323, -11376, -27194,
-    22121, 12268, 21894, 29317, 3016, 19838, 18009, -10349, -5091, -20285, -8036, 24465, 11584,
-    -11892, -13058, -30050, -25513, -2778, 32145, -18574, 821, -22598, -4182, 25751, 16724, -5211,
-    -1748, -11804, 4299, -20510, -21056, -23971, 7504, -21057, 4043, -30019, -30566, 22291, -21025,
-    -3580, -7265, -23595, 29831, 23576, -24624, -5893, -13432, -31820, 15439, 960, 3904, 19, 21263,
-    8787, 25205, -14204, -1750, -20799, -5209, 26778, 16724, -4439, -721, -10005, 6870, -15885,
-    -13860, -12405, 26521, 9271, -12149, -15628, 32659, -30910, -11002, 18524, 25117, 31146,
-    -14373, -31423, 7245, -29023, -4438, 19325, 10042, -18574, -21282, 20580, 17149, 24979, -28508,
-    14322, -26936, -17714, -27055, 8017, -23883, 1730, 30633, 27518, 10211, 24979, 30345, 7384,
-    24979, 27518, 4557, 19325, 19037, -9579, -3548, -17972, -3925, 30634, 21864, 4813, 13672,
-    13640, -20374, -19739, 20323, 18434, 26007, -26195, 17663, -21282, -8719, -12406, 31661, 14155,
-    -1870, -721, -7691, 9439, -11258, -6664, -327, -19996, -25168, -27569, -207, -32621, -15233,
-    4677, -15146, 7127, -21024, -18487, -22171, 11873, -14633, 14581, -13057, -3067, 1217, -14600,
-    -17973, -15488, 19325, -754, -29880, 22152, -12063, 26919, 2106, 24945, -21655, -9716, 30085,
-    -28337, -11258, 21861, 27433, -28992, -5639, -18056, 29091, 6955, -12660, -18455, 30086, 28461,
-    -19484, 4387, 1989, -6120, -8721, 1990, -18971, -21571, -23967, 7758, -20544, 3790, -28994,
-    -29539, 23323, -18201, 532, -1094, -12547, -18486, -14458, 20607, 1304, -26795, 28060, -3836,
-    -24482, 25233, -4350, -27823, 21378, -11290, 26663, 3388, 25461, -20112, -6892, -31594, -21911,
-    -210, -26711, -10346, 16239, 1303, -31164, 23435, -12319, 27691, 3132, 26488, -19341, -5349,
-    -29025, -17799, 6217, -15662, 7131, -21026, -17975, -22681, 12385, -14121, 14585, -12287,
-    -1528, 2506, -11773, -12837, -8545, 31404, 19289, 1478, 8017, 5925, 30262, 23437, -15407,
-    24095, -4063, 16462, 28464, 32431, -8211, -25506, 19324, -9497, 25382, 3390, 25712, -20879,
-    -7918, -31857, -24220, -3292, -30572, -18309, 3905, -17209, 2252, -27707, -28260, 24869,
-    -15886, 5923, 5593, -980, 1298, 15873, 4931, 17489, -27561, -22312, 12093, 5592, 5445, 7467,
-    28722, 23949, -16435, 23069, -5607, 13892, 23840, 25747, -19519, 21528, -9977, 7981, 13560,
-    9556, 19546, -20879, -13318, 27514, 29751, -20001, 5670, 1480, -4581, -7181, 3794, -14862,
-    -15148, -14710, 24203, 5413, -20365, -26427, 14409, 3793, 6727, 6185, 28722, 23432, -17717,
-    21270, -7923, 9012, 16644, 14436, 26745, -8800, 6470, -6410, 15360, -2526, 8754, 21528, 19062,
-    -29026, 5337, 30627, 31884, 12276, -32596, -24400, 23585, -11780, 7470, 10991, 7496, 14152,
-    -28588, -25401, 6957, -2889, -7153, -14632, -6230, -32082, 22634, 6363, 17777, 19550, -12399,
-    -4325, -21314, -9829, 22918, 8499, -18054, -21030, 21607, 16642, 27029, -26710, 16129, -21546,
-    -10262, -16253, 28056, 6958, -14712, -18719, 27005, 24096, -25145, -6149, -15739, -32598,
-    12099, -4689, -3556, -13345, -1091, -25401, -31592, 24353, -17949, 1049, -835, -10752, -16942,
-    -11629, 26000, 9016, -14200, -16149, 29577, 29748, -16921, 6962, 6617, 2869, 3621, 23065,
-    15976, -32360, 192, 22658, 16985, -9318, -3299, -18482, -5206, 30883, 19812, 1990, 10837, 6962,
-    -30907, 30626, -6146, -24481, 23944, -6147, -31419, 17005, -19769, 13557, -17177, -8975,
-    -10087, -30027, 20067, 6361, 15463, 16469, -17284, -11780, 30862, -30134, -10238, 19554, 25636,
-    -31056, -11030, -26021, 17775, -13601, 19984, -4328, 10301, 21783, 21629, -27479, 9961, -27973,
-    -23367, 29751, -4072, 20324, 31807, -23605, 2592, -5203, -12811, -23879, -20880, 10577, -15913,
-    10475, -15638, -10773, -10856, -31829, 17241, 1223, 8264, 3877, 27951, 21628, -21567, 15616,
-    -15896, -5890, -6486, -22321, 31119, 24098, -20009, -1777, -6486, -17953, -30304, 32579, -7416,
-    19298, 27182, -28491, -7174, -20620, 28307, 1822, -20107, -27720, 11589, -576, 1323, -5374,
-    11760, -3560, 2080, 14586, 6721, 15187, -27563, -22321, 9277, 3277, 2609, -490, 18184, 7749,
-    19813, -21909, -12041, 25211, 29235, -20780, 1825, -2635, -10500, -20020, -14200, 21626, 541,
-    -26794, 29593, -4342, -23710, 27794, -3057, -24224, 28565, -2800, -23196, 29850, -487, -19598,
-    -29775, 9023, -3922, -4845, -15907, -3922, -29774, 24700, 12012, 26767, 31639, 9956, 31650,
-    -31070, 17410, -23350, -13080, -19855, 22911, -4085, -30135, 21626, -15394, 22552, -2533,
-    13134, 26921, 30620, -14880, 31805, 7490, 32665, -9316, 13659, -2288, 27436, 15458, -29017,
-    2507, 29336, 25468, 5334, 21112, 20071, -8288, 1838, -12570, 5334, -17181, -17967, -19338,
-    18286, -6917, 26924, 10062, 31376, -8543, 12633, -1521, 26667, 14946, -29278, 1224, 27282,
-    23151, 453, 13404, 8502, -28075, -29773, 2078, -11885, -20007, 27779, 23582, -24120, -6403,
-    -14968, -31316, 13387, -2119, 1068, -6661, 10218, -6899, -2292, 6620, -6128, -5119, 4564,
-    -10755, -11801, -7001, -29002, 23923, 10732, 24455, 29577, 4307, 23684, 22381, -3661, 8265,
-    -1007, 23068, 11606, 29319, -8801, 9808, -4349, 21269, 6210, 22379, -21137, -9724, 29575,
-    -29875, -11010, 19296, 24096, -32599, -13858, -30902, 10321, -25681, 196, 29596, 24692, 4308,
-    18545, 17753, -13683, -6641, -25424, -16510, 12892, -8463, 19729, 556, 15695, 31551, -29000,
-    -2295, -15995, -29000, 15696, 2252, 7238, 4645, 27438, 21373, -21570, 15359, -16921, -6407,
-    -8028, -25145, 27518, 17673, -30800, -17972, 31809, 3382, 30601, -16508, 3383, -17460, 969,
-    -27201, -30567, 22558, -18464, -497, -4171, -15123, -23884, -24217, 6980, -21572, 199, -31828,
-    29572, 12535, 31652, -25684, 20503, -15381, 532, -59, -9983, -14632, -9825, 30624, 16209,
-    -3658, 1841, -6152, 10735, -6383, 17, 8935, -2014, 2331, 15617, 7238, 18265, -24733, -17178,
-    18780, 16902, 25227, -28252, 12276, -26431, -19000, -30386, 5695, -29281, -8541, 17004, 3873,
-    -29359, 29340, -4609, -25760, 24457, -5638, -31927, 17261, -18746, 13306, -16150, -6924, -8539,
-    -26173, 26744, 15106, 31395, -23115, 22560, -10755, 7470, 11251, 8521, 15437, -27043, -21806,
-    12097, 5082, 6979, 7471, 29240, 26511, -14375, 26671, 2096, 24432, -24473, -10241, 26232,
-    30526, -18723, 6958, 3026, 39, -1781, 13049, 1068, 9527, 25385, 24712, -20029, 19218, -10756,
-    3617, 7652, 1324, 4131, 20245, 14431, 29831, -6484, 13147, 1818, 30010, 21628, -18743, 17675,
-    -11013, 1562, 5595, -2789, -2550, 9707, -2789, 1563, 14075, 5693, 14413, -30130, -25662, 4134,
-    -5973, -11784, -23622, -19851, 12118, -13343, 14331, -9213, -493, 5850, -4844, -4605, 6107,
-    -8699, -7947, -1346, -19493, -26194, -30387, -1246, 28548, -22934, -4587, 32660, -22163, 297,
-    -26966, -11369, 16746, 277, 32578, 22400, -15658, 22042, -4072, 12870, 24098, 26768, -19770,
-    22043, -7928, 9015, 16132, 15202, 26234, -9055, 6979, -6921, 14848, -2274, 7729, 20245, 18029,
-    -32107, 713, 24197, 20065, -6484, 3381, -7693, 10479, -7670, -1782, 5339, -6899, -5895, 1742,
-    -14353, -16946, -17019, 21371, 17, -29613, 25740, -8208, 31812, 13404, -24400, 3285, -31315,
-    -32110, 16136, -25919, -13863, -26012, 15716, -14121, 15110, -8957, 2328, 7142, -476, 2586,
-    15880, 8776, 20576, -22414, -11528, 27259, 29501, -18211, 6700, 2515, -221, -2552, 11253, -735,
-    5673, 18963, 15456, 29574, -6481, 13658, 2332, 30270, 23167, -16944, 20248, -6132, 9271, 17164,
-    17255, 29574, -4682, 15457, 5930, -29869, 32162, -2808, -21902, 31391, 4389, -15221, -20267,
-    24693, 18961, -31062, -17456, 31298, 4662, 30860, -15734, 5691, -14888, 5084, -19239, -19000,
-    -24214, 12887, -15917, 10996, -14356, -7950, -8536, -25921, 26489, 14338, 31647, -24141, 21021,
-    -12045, 4131, 5857, 1063, 2075, 16908, 10058, 22121, -19587, -6647, -31079, -23956, 1321,
-    -27225, -12134, 16742, 18, 30785, 21368, -17973, 17165, -9988, 2332, 6370, -479, 791, 14337,
-    6203, 15440, -29868, -23353, 6960, -2113, -4334, -11802, -1856, -22838, -30049, 26929, -12045,
-    9274, 11765, 12114, 18269, -20618, -11274, 27779, 31040, -15387, 9533, 8937, 9800, 12617,
-    -28329, -24382, 6450, -2887, -5363, -14625, -4943, -28493, 25725, 12533, 29333, -30045, 14589,
-    -24381, -16167, -25503, 14941, -16682, 13305, -12302, -5373, -2630, -16928, -25933, -27816,
-    2862, -31074, -13167, 12115, -6917, 19988, 3891, 18269, -28586, -19497, 11588, 7137, 9545,
-    10817, -30129, -28492, 795, -12397, -20782, 25982, 20500, -27979, -13854, -26788, 15969,
-    -16939, 14076, -11788, -4088, -831, -13844, -21050, -19849, 15712, -10257, 20245, 1063, 15443,
-    31296, -27722, -2547, -15479, -26951, 16986, 5081, 13142, 12103, -25246, -22068, 11847, 5080,
-    8002, 6707, 30009, 27791, -14111, 28725, 5689, 28294, -16508, 2606, -19767, -2116, -31318,
-    26237, 10220, 27022, 31377, 8164, 30106, 32405, 12276, -30290, -23879, 26412, -6903, 13644,
-    21786, 26250, -23365, 17675, -14615, -3061, -2886, -14872, -23878, -23960, 8773, -21052, 2512,
-    -27465, -30818, 21788, -17700, -2033, -5198, -15901, -27219, -28585, 1062, 32148, -17791, 5432,
-    -17969, 1999, -24895, -28506, 26415, -10761, 9789, 13564, 14683, 22382, -13936, -480, -20281,
-    -6226, 30104, 18013, -2629, 6204, -2291, 18703, 7232, 20325, -23189, -12044, 24438, 27184,
-    -22839, -1776, -9825, -20526, 29065, 23329, -21812, -4859, -11881, -25410, 21870, 11506, 24706,
-    29837, 4053, 25220, 22898, -2373, 11600, 2852, 29752, 23679, -18480, 20244, -7162, 6707, 14846,
-    12628, 21099, -16509, -4591, -27475, -16766, 12115, -10771, 16389, -3563, 6706, 18188, 15969,
-    28037, -6485, 12372, -234, 27183, 17769, -26449, 6366, -29263, -28762, 22301, -15386, 795, 455,
-    -7676, -13341, -6227, -28493, 24696, 11249, 27020, 32149, 8679, 31903, -31074, 15619, -24125,
-    -14626, -24216, 18024, -12057, 20247, -481, 13901, 27700, -32350, -10515, -28840, 17766,
-    -16684, 14852, -9992, -1006, 2773, -6394, -9231, -2110, -19501, -27221, 32329, -3053, 23666,
-    -31408, -15647, 12616, 10740, 15451, 20326, -15989, -3824, -25678, -15732, 15966, -5632, 24104,
-    10312, 28806, -12648, 7743, -10260, 11254, -7677, -1779, 4315, -6135, -6920, 461, -14874,
-    -19513, -21127, 16481, -9491, 19995, 2089, 17494, 32588, -23869, 3874, -6735, -11276, -23111,
-    -21127, 12883, -13089, 13055, -8449, -495, 4317, -4594, -5122, 3290, -9992, -11547, -8789,
-    -28496, 23406, 7916, 23162, 26233, -3136, 14682, 6701, -30893, -32607, -3065, -22412, 31644,
-    4132, -16245, -20528, 23408, 16395, 31643, -22853, 22050, -8708, 7732, 12540, 12367, 19297,
-    -20357, -8965, 30349, -30637, -7938, 20841, 26418, -25672, -5630, -17787, -30812, 10562, -6480,
-    -3569, -16424, -6223, -30297, 22641, 6370, 21361, 21356, -8794, 4657, -10257, 8426, -9736,
-    -7430, -3396, -18731, -28247, 32328, -3825, 22383, 32328, -18475, 7478, 3029, 2857, -490,
-    16137, 7997, 18014, -25755, -15391, 18015, 16394, 27014, -28503, 12282, -23616, -17709, -27810,
-    12622, -21308, 4830, -23873, -25163, 29760, -2799, 20841, 31302, -20533, 4394, -2624, -5626,
-    -14625, -6736, -28756, 23669, 8684, 24958, 27267, 460, 20332, 14417, -17017, -9995, 31894,
-    -29867, -5369, 23670, 32071, -16935, 8251, 5342, 6453, 4910, 25388, 23158, -23875, 13309,
-    -17706, -11282, -15218, 31896, 9793, -9822, -7169, -24131, -17275, 16990, -7170, 23590, 9280,
-    26240, -16246, 2599, -20022, -3653, -31325, 24183, 6884, 23417, 23926, -4168, 11853, 1310,
-    27443, 20848, -23620, 11509, -20016, -14882, -20873, 21876, -5373, 30528, 17250, -23878, 7398,
-    -24385, -23107, 31814, 802, 26751, -24213, -5623, 29835, -27554, -5880, 26237, -31409, -13077,
-    14930, 15623, 22903, 32406, 3544, 28300, 25724, 2259, 20333, 16472, -14961, -6395, -27476,
-    -20101, 10054, -15912, 7913, -15904, -13856, -16245, 27530, 5420, -18816, -21301, 19299, 12024,
-    23418, 29322, 1230, 22647, 17757, -11107, -1511, -18738, -6224, 32414, 20070, 1229, 13139,
-    8248, -29869, -29781, -490, -15991, -24641, 18529, 8424, 18793, 20842, -11366, 1061, -16425,
-    -829, -25924, 32663, 21274, -20014, -5116, -10595, -24126, 24440, 14849, 31129, -25933, 19476,
-    -14362, -1517, -1599, -11021, -19250, -15991, 22390, -232, -29098, 28301, -7172, -30382, 20077,
-    -16425, 17422, -6909, 4393, 11510, 7998, 13388, -30125, -24642, 4394, -5968, -9479, -22077,
-    -17276, 18278, -5629, 26929, 13395, -31587, -3912, 21877, 11590, -17534, -14104, 27268, 27699,
-    -18474, 2340, -1344, -7165, -15394, -7769, -31323, 19559, 3282, 14681, 11078, -24732, -21814,
-    11850, 5337, 9027, 7224, 31551, 30615, -10510, -30386, 16480, -20791, 10735, -18216, -14366,
-    -17792, 25218, 541, -24732, -32351, 1313, -15738, -22585, 19818, 12789, 24447, 29841, 4308,
-    25989, 22902, -1090, 13397, 4912, -31417, 30616, -8196, -27561, 21364, -13337, 23327, 1575,
-    18017, -30644, -21042, 6710, 1224, -482, -6654, 8420, -6650, -5626, 3280, -10506, -14621,
-    -9827, -32608, 15706, -1347, 5944, -2799, 18700, 7486, 19046, -23449, -12818, 21874, 24611,
-    -27211, -9995, -21906, 25475, -3827, -28588, 24961, -10767, 29239, 10312, 32666, -7513, 16738,
-    2340, -31158, 28303, -9740, -31928, 15453, -23105, 7394, -24126, -23362, -32698, 1061, 27524,
-    -22161, -3308, -31844, -20362, 4660, -21822, -2372, 32417, 23925, 5852, 20852, 20584, -9055,
-    2349, -12571, 4824, -16927, -17968, -20105, 18283, -7432, 25386, 8774, 28805, -13422, 5948,
-    -12574, 7910, -14099, -11289, -11108, -31832, 17496, 200, 8261, 3361, 26157, 20083, -24396,
-    10223, -23608, -18485, -27813, 9803, -22855, 1229, -31061, 30859, 14079, -30033, -20799, 28729,
-    -1506, 22378, -30639, -17441, 12356, 9196, 12372, 16468, -22416, -15128, 22637, 21789, -30035,
-    -13601, -29611, 13399, -21312, 6113, -24124, -23111, 32071, 35, 27261, -24470, -6390, 29831,
-    -28325, -7675, 24691, 30786, -18984, 6702, 1744, -480, -4092, 9454, -3564, 535, 11252, 2862,
-    8759, 25901, 25735, -19255, 20505, -7676, 7474, 14079, 12628, 21352, -17276, -5105, -27736,
-    -18561, 10059, -13602, 10738, -12044, -6406, -4425, -20011, -29536, 30014, -8703, 16211, 21533,
-    28819, -20284, 22305, -6905, 10300, 17165, 18795, 30860, -2111, 19824, 12613, -19074, -15386,
-    25721, 24360, -24125, -5376, -15476, -29522, 14928, -314, 5689, -236, 19733, 10572, 24950,
-    -15734, 36, -20798, -6482, 28821, 17239, -4941, 2608, -7178, 9966, -7158, -2038, 5340, -6644,
-    -5894, 1743, -13841, -16688, -16504, 22654, 1560, -27042, 30364, -1524, -22416, 31906, 4645,
-    -14450, -19495, 26491, 21531, -26949, -10518, -23187, 22396, -5636, 30785, 15714, -23627, 6113,
-    -26949, -25426, 26931, -7931, 14410, 20249, 25479, -24398, 14596, -18727, -8721, -14188,
-    -31834, 14924, -3395, 2349, -5381, 10484, -4333, 1816, 11254, 3635, 10554, 27959, 29078,
-    -12834, 29759, 7490, -32367, -11362, 12117, -3326, 22306, 9290, 27771, -14960, 2866, -15664,
-    718, -24891, -27743, 26162, -11271, 11066, 13311, 14687, 24173, -13161, 1067, -15664, -1082,
-    -26946, -31598, 20507, -21036, -4099, -11875, -25919, 24172, 11769, 25996, -31341, 8171, 32421,
-    -28514, 17167, -21037, -7440, -15472, -32602, 13892, -5450, -1504, -10524, 1233, -18981,
-    -21318, -27294, 7234, -23375, -3136, 29080, 22629, -567, 11862, 7980, -32179, 31137, -4357,
-    -25496, 25483, -3073, -30121, 22142, -10784, 24108, 3124, 24682, -24980, -10498, 27253, 29505,
-    -18723, 7722, 2005, -219, -1530, 11257, -219, 7723, 20509, 18542, -29800, 1748, 27794, 26227,
-    1491, 18028, 16204, -18299, -11785, 31882, -32434, -9987, 19290, 22308, 32418, -14635, 30533,
-    6718, -31855, -12387, 11859, -3843, 20511, 7233, 24684, -21124, -6131, -30315, -23951, 1580,
-    -25176, -11101, 19314, 5408, -28064, -32601, 1811, -17785, -25919, 18517, 5859, 14431, 16975,
-    -20870, -13840, 27256, 26676, -21294, 1557, -6222, -14355, -24657, -25497, 5692, -23630, -4423,
-    27538, 19290, -4938, 4152, -4356, 13567, -1245, 8752, 21277, 19829, -28000, 5600, -32600,
-    -30570, 15881, -24634, -12323, -23697, 19571, -7441, 25135, 7749, 29824, -14958, 4666, -13097,
-    4575, -18722, -16952, -22924, 15460, -10014, 17941, -2274, 13117, 23338, 26510, -18238, 20512,
-    -7672, 10290, 14858, 15458, 27766, -10072, 7749, -4618, 15116, 553, 13629, 26167, 29851,
-    -11558, 30023, 8520, -28778, -8528, 18030, 7717, -27804, -30287, 5405, -12642, -17437, -32374,
-    27965, -14354, 11061, 9203, 10319, 16972, -25750, -18723, 18258, 12286, 20599, 30080, -2107,
-    17773, 12861, -21897, -19236, 21343, 15112, 26510, -26974, 12542, -24377, -14895, -26522,
-    14174, -15153, 11772, -13326, -4359, -5190, -19494, -27489, 31048, -6387, 21856, 27964, -25406,
-    -503, -13414, -23607, 25455, 14343, 30363, -23890, 18713, -14357, 1041, -821, -9216, -13352,
-    -10073, 32676, 19288, -822, 8776, 4639, 26420, 21369, -21062, 13057, -17440, -7698, -12643,
-    -29776, 19802, 2777, 13144, 12606, -27036, -23865, 11065, 206, 1836, -1529, 13057, 2093, 11835,
-    26678, 29078, -13095, 28478, 5948, 31366, -15727, 5949, -12583, 5862, -16411, -13354, -17525,
-    24967, 4637, -23437, -28490, 10549, -5191, -4588, -12839, -4677, -27461, 30338, 15627, -29261,
-    -16694, 32076, 5692, -30573, -12386, 12632, -2560, 22567, 10062, 30079, -12900, 6979, -8216,
-    11259, -7413, 1551, 6889, -2016, 2323, 13057, 5180, 15687, -31919, -26432, 4380, -9047, -14867,
-    -26974, -28836, -475, -32371, -19841, 3124, -19522, -3393, 32166, 25968, 5859, 21372, 24426,
-    -6478, 7238, -2046, 18452, 5696, 21598, -24982, -14094, 23655, 22821, -29515, -9499, -26009,
-    19573, -8986, 23337, 3896, 24938, -23952, -9725, 29564, 32589, -13838, 16201, 15113, 21114,
-    -31859, 2006, 25483, 24939, -2364, 12120, 7206, 32331, 29082, -6673, -30377, 18031, -14641,
-    15885, -9212, 4378, 7917, 1840, 7462, 22052, 19059, -26720, 5090, -32085, -29290, 16656,
-    -22834, -8473, -19067, 27796, 6434, -18811, -22577, 21598, 11772, 23170, 32392, 2777, 24969,
-    25196, -2621, 12120, 6949, 32074, 28568, -7444, -31662, 15975, -17982, 10744, -17693, -9244,
-    -14442, 31395, 14658, -6733, -2786, -11814, -1850, -24374, -28519, 25393, -13581, 9262, 8687,
-    7494, 13631, -31406, -28230, 3095, -11875, -19235, 31366, 25391, -18979, 3097, -2367, -9726,
-    -15408, -11619, 28054, 13120, -10592, -8183, -22090, -16503, 16233, -3330, 26418, 12378,
-    -29545, -3652, 21374, 14917, -15475, -11523, -30058, -27811, -3299, 31366, -23699, -3299,
-    -30058, -19587, 4926, -17466, 1231, -27200, -28774, 23077, -16407, 3610, 974, -6127, -8213,
-    -825, -19748, -23633, -29866, 1327, -31344, -16502, 6725, -12327, 7914, -15378, -10014, -12132,
-    32425, 17743, -1853, 4670, 267, 18707, 7754, 23911, -20101, -7666, -30317, -24213, -470,
-    -27233, -13933, 12895, -3333, 23077, 8269, 29306, -14446, 3130, -13101, 3545, -21286, -19526,
-    -27552, 6728, -22354, -2366, 28831, 24935, 1746, 14696, 14912, -22413, -19486, 21852, 15881,
-    26003, -25437, 13826, -23086, -11045, -21126, 21890, -1022, -31663, 21376, -11817, 22309, -984,
-    19795, 31561, -25400, 4376, -8019, -14863, -24922, -26780, 2614, -25951, -10332, 17778, 5661,
-    -28837, 30885, 8, -21128, -32595, 9518, -9307, -11520, -23122, -20872, 9812, -13100, 10483,
-    -14347, -5904, -6736, -24370, 32390, 21535, -23086, -3591, -13417, -28483, 21596, 6629, 16750,
-    21339, -13932, -4324, -20296, -11105, 22405, 9260, -20101, -22571, 20569, 12024, 20863, 30592,
-    -56, 18551, 16200, -16505, -12290, -31345, -29355, -7150, 26481, -31925, -17174, 13632, 10994,
-    12896, 21085, -17020, -7921, -27746, -21132, 4673, -19009, 200, -30794, 32392, 15878, -28996,
-    -15668, -30639, 7499, -25435, -3911, 24205, 17999, -9052, -3294, -14641, -3655, -30536, 29050,
-    13050, 29860, -24921, 19219, -17687, -1019, -4426, -17430, -24406, -27556, 1589, -28262,
-    -12393, 12641, -2048, 24873, 10585, -32118, -7253, 13670, 4377, 32582, 24464, -10530, 28214,
-    5189, 31618, -14449, 4419, -11560, 7140, -17170, -11560, -14705, 26521, 10286, -14449, -16913,
-    32389, 29756, -15886, 11830, 10480, 9815, 18255, -22931, -17171, 23139, 20503, 31402, -15926,
-    29756, 1590, 29306, -20360, -3550, -25950, -15220, 11871, -5134, 20762, 3133, 22365, -26013,
-    -16143, 21595, 19477, 28832, -19012, 23590, -7663, 14142, 20249, 22406, -24666, 11511, -25140,
-    -15414, -27039, 11098, -17471, 7143, -22313, -16700, -25753, 11098, -15930, 8429, -19486,
-    -12332, -18813, 22406, 2318, -27552, 28317, -766, -24725, 28060, 1805, -22156, -32336, 9259,
-    -9307, -12033, -23380, -21643, 8528, -14900, 7399, -19486, -13872, -19843, 19836, -1537, 31559,
-    18037, -17215, 13827, -15118, -2566, -4934, -19230, -25439, -31919, -3553, 28789, -27550,
-    -10492, 26219, 28477, -22315, 4632, -4678, -11521, -17984, -16500, 19577, 1292, -31407, 23946,
-    -9246, 27705, 6984, -32377, -12388, 9041, -4622, 17169, 817, 16966, 30533, -29767, -510,
-    -17527, -29767, 16967, 206, 5443, 4374, 22822, 15466, -28523, -52, 24976, 23649, -3651, 7758,
-    2832, 24105, 14697, -28009, 204, 25491, 24420, -2110, 9815, 6430, 30015, 23950, -12846, 24619,
-    -723, 22621, -30123, -19742, 14141, 8170, 10071, 16711, -24984, -20513, 18254, 11767, 17781,
-    27763, -5967, 9301, 1549, 25130, 14184, -28007, 458, 25492, 24165, -1599, 9816, 6432, 30783,
-    24465, -12073, 26672, 1849, 26991, -22416, -8431, -32377, -26528, -6375, 31103, -26528, -8431,
-    29047, -30640, -14343, 18768, 18705, 24978, -23638, 15365, -20513, -6933, -13676, 32687, 17226,
-    -1598, 3133, -251, 16907, 4161, 19538, -27812, -20769, 15170, 8682, 11357, 18254, -21645,
-    -15886, 25965, 24359, -27452, -5133, -18560, 29603, 9003, -12650, -15887, -30832, -32439,
-    -9976, 20826, 25130, -31565, -8730, -26270, 18551, -9759, 22562, 818, 21595, -29353, -19743,
-    14400, 8683, 11098, 17741, -22672, -16916, 23653, 20762, 32685, -14384, 32071, 5957, -29548,
-    -9821, 14182, 2321, 30528, 20864, -16184, 18450, -9720, 6690, 10996, 5701, 14657, -31153,
-    -28481, 3607, -10594, -18972, -32116, 28728, -15118, 10805, 10223, 9298, 16716, -24987, -20001,
-    17488, 12278, 18036, 27254, -5456, 9813, 1297, 26155, 15467, -26974, 3539, 30116, 30595, 10221,
-    28831, -29544, 14333, -27196, -15923, -28329, 9299, -21835, 2255, -31565, -32115, 16391,
-    -27454, -13868, -27042, 12896, -16696, 10481, -17945, -10014, -13934, 29858, 13374, -8024,
-    -6636, -17210, -9566, 26775, 14659, -9567, -7149, -19266, -11880, 22150, 7720, -20876, -25396,
-    16459, 6109, 10328, 13632, -26531, -25139, 10806, 968, -467, -2815, 12019, -3037, 5667, 17930,
-    11612, 26227, -12397, 1590, -13867, 3024, -23083, -23119, -31157, -981, 30338, -21134, -3037,
-    -27231, -15223, 10842, -7186, 18446, -981, 14660, 28469, 31144, -8728, -28585, 16238, -14897,
-    15621, -11262, 1809, 5083, -5094, -2561, 6626, -7921, -3590, 2515, -12805, -12585, -11620,
-    29601, 15686, -6224, -2524, -11043, 459, -22314, -24150, -32694, -3039, 27508, -27297, -11520,
-    24424, 26674, -25913, -1790, -13933, -27198, 21855, 8683, 19063, 25196, -7252, 6214, -3333,
-    16651, 1588, 16199, 31557, -29510, -249, -15989, -27968, 19284, 5342, 12896, 15943, -22672,
-    -18459, 21855, 17421, 27801, -22864, 18707, -15377, 525, -827, -11522, -15409, -12906, 26001,
-    10035, -15220, -16405, 30596, 28471, -17434, 7467, 4569, 1071, 2070, 17676, 8781, 22887,
-    -19333, -7667, -30570, -23702, 44, -26973, -12394, 14694, -1016, 28213, 15722, -24661, 5597,
-    -30539, -28002, 21275, -17947, 13, -3399, -14606, -21575, -21646, 11095, -13866, 11765, -13321,
-    -4871, -3912, -20003, -27230, 32583, -5868, 23400, 31812, -21289, 6953, 200, -3813, -7183,
-    3285, -14608, -14893, -15476, 24457, 5411, -21388, -26687, 13636, 1485, 4411, 2071, 21017,
-    12378, 29570, -9053, 9552, -3327, 20760, 6468, 23658, -20875, -8438, 32653, -26786, -5354,
-    29826, -26529, -7924, 27513, -31412, -14864, 15435, 15106, 19831, 31112, -58, 20089, 16206,
-    -14451, -9466, -27742, -22418, 4156, -21832, -2886, 29343, 22887, 1740, 13152, 11322, -26017,
-    -26170, 9524, -1346, -3553, -8724, 3024, -17175, -17976, -20106, 15979, -7697, 23072, 3900,
-    23657, -23189, -11263, 27769, 31296, -17946, 9780, 6880, 5185, 8495, 28725, 25745, -14636,
-    25899, -213, 22116, -28843, -17947, 14921, 12020, 15721, 23916, -10854, 1587, -12837, 3796,
-    -20516, -20290, -26016, 7755, -21576, 970, -32081, 31110, 13820, -32081, -21576, 26414, -6638,
-    16461, 24358, 29599, -14894, 28985, 2871, 28796, -19589, -2269, -24918, -12907, 16236, 269,
-    31040, 19834, -17722, 16392, -12550, 527, 2513, -8181, -8983, -2884, -23087, -29286, 27443,
-    -12808, 11065, 12793, 12893, 22116, -15992, -5097, -24659, -15221, 14436, -4100, 24616, 9296,
-    30852, -11108, 8269, -5644, 16650, -470, 13375, 26930, 29085, -12326, 30529, 6983, -30574,
-    -9821, 13666, 1295, 28986, 18806, -20294, 12538, -19231, -9243, -14704, 30114, 12860, -8537,
-    -7408, -18495, -11878, 23433, 9005, -18818, -21543, 22370, 15107, 26002, -27232, 13051, -25656,
-    -15410, -27041, 11610, -17981, 7655, -21801, -16696, -24727, 12638, -14384, 12025, -13834,
-    -4104, -4423, -20002, -26720, 32329, -5867, 24167, 31815, -20774, 8491, 1488, -1242, -2560,
-    9969, -3812, 3352, 13566, 5698, 16459, -29354, -24115, 9007, -828, -3297, -7185, 3799, -14606,
-    -13867, -14448, 26001, 8493, -16762, -19489, 25970, 20761, -29770, -12324, -28069, 14178,
-    -16951, 11253, -16663, -8470, -11363, -30798, 20315, 3543, 12893, 13376, -25242, -22831, 14148,
-    5598, 8781, 11064, -31411, -31312, -758, -17790, -29513, 14663, -315, 3128, -758, 16905, 4927,
-    18517, -27557, -20260, 14149, 8680, 11609, 16719, -22418, -16919, 22374, 20245, 31654, -17462,
-    28727, 300, 25457, -25244, -11008, 25714, 29241, -21546, 3870, -2886, -10237, -16948, -12395,
-    24973, 8753, -16765, -19232, 25459, 21272, -29770, -12578, -27558, 14435, -16948, 12278,
-    -15635, -7182, -8282, -26429, 27000, 15106, 31396, -22859, 22817, -10497, 8240, 12279, 10064,
-    18263, -22674, -14866, 23661, 23330, -28745, -9750, -24215, 21371, -6924, 28472, 11348, -29541,
-    -4168, 21372, 13379, -16505, -13581, 31370, 32069, -12297, 15437, 17420, 22912, -29539, 7654,
-    -31830, -28511, 19220, -18981, -4352, -9308, -23350, 28288, 18963, -27720, -13347, -27297,
-    15457, -16175, 13053, -12557, -4095, -2882, -16412, -23884, -26526, 5691, -25170, -5709, 24967,
-    14923, -11621, -6644, -22600, -15219, 17772, -1783, 30014, 18286, -21316, 10996, -20265,
-    -13349, -19844, 22398, -1527, -30895, 23169, -11551, 25133, 3637, 25200, -23184, -8185, 30597,
-    -29609, -9213, 23144, 27446, -24891, -1271, -12647, -23863, 25201, 14853, 30364, -24144, 19480,
-    -14099, 1301, 718, -7417, -10779, -4936, -25150, 31370, 19480, -23866, -8466, -19327, 28563,
-    5156, -18557, -22581, 20063, 10998, 21881, 28544, -1596, 17513, 11582, -22671, -20524, 17751,
-    11253, 19569, 26232, -5710, 10832, 532, 25644, 16486, -27996, 2771, 30621, 28802, 8168, 27280,
-    30858, 6883, 28051, 30344, 7140, 27794, 30344, 6883, 27537, 29830, 6112, 26252, 27774, 2771,
-    20855, 19036, -11365, -2275, -18230, -6225, 31136, 20321, 457, 10833, 6700, 32068, 28823,
-    -9235, -31668, 14688, -21315, 7654, -23606, -20287, -29868, 5436, -28512, -9051, 17773, 4642,
-    -28841, 31137, -2040, -22159, 31137, 4643, -15221, -20778, 24947, 18704, -31830, -17716, 30270,
-    2609, 28544, -20103, -1760, -26198, -13678, 15460, -2554, 27186, 14432, -27998, 715, 28053,
-    24688, 1486, 15974, 13380, -21902, -18722, 20577, 16135, 26767, -26969, 14079, -22835, -13091,
-    -21901, 20599, -5382, 28987, 13660, -26714, 717, 29594, 26486, 4315, 20856, 21346, -9564, 1582,
-    -11552, 3801, -17951, -17720, -22156, 15460, -10011, 18709, -1503, 13891, 25648, 29594, -13609,
-    28990, 5436, 31366, -15729, 5437, -13097, 5346, -17951, -15410, -20611, 19315, -3846, 27964,
-    13918, -25949, 465, 29852, 28022, 4834, 22656, 25195, -5190, 9550, 2065, 24365, 15975, -27491,
-    1235, 28825, 27765, 3805, 21115, 22625, -9046, 2869, -8217, 7403, -11524, -6161, -5190, -22061,
-    -29291, 26679, -13067, 11317, 11001, 11863, 20569, -20354, -10496, 32391, -30891, -9211, 23139,
-    26678, -26174, -2047, -15471, -27973, 19542, 4575, 13662, 15687, -23182, -17950, 21599, 16654,
-    28053, -23634, 17169, -16410, -2302, -5962, -18209, -27231, -32690, -4331, 25455, -31662,
-    -15897, 14662, 11771, 16743, 25199, -10589, 4665, -8984, 8687, -10242, -4615, -2107, -16667,
-    -21834, -25751, 8006, -20550, 207, -30288, 32650, 14857, -27719, -15667, -31146, 9033, -24663,
-    -3390, 27538, 21598, -3905, 7493, 1038, 21281, 12119, 30850, -9817, 10578, -1790, 21538, 9293,
-    28536, -14957, 2869, -14128, 1492, -23346, -23894, 30791, -3814, 24937, -31918, -17436, 13887,
-    9202, 12634, 19541, -20611, -11525, 30850, 32075, -12811, 16459, 16398, 22912, -29031, 6632,
-    -32344, -28517, 17170, -21037, -6672, -15469, -31831, 15431, -3905, 1581, -4874, 9203, -5872,
-    781, 7660, -1760, 3095, 14085, 7235, 18515, -27036, -18466, 16974, 11514, 18543, 26997, -6991,
-    9806, -246, 22565, 12119, 31879, -8533, 12891, 1553, 27704, 18802, -21835, 10228, -22062,
-    -14639, -23696, 16746, -9500, 19996, 41, 17742, 30533, -27716, 267, -14444, -24632, 23655,
-    12284, 25484, -30573, 8172, 32680, -27489, 18196, -19493, -4102, -10845, -25147, 26739, 14342,
-    31136, -22863, 20768, -11785, 5923, 6889, 3122, 6951, 22823, 20084, -25689, 7146, -28233,
-    -24147, 25651, -8187, 14404, 18712, 23681, -26203, 9974, -25664, -18750, -32174, 5177, -29802,
-    -12385, 13659, -1532, 24367, 13145, -30574, -5189, 19828, 12089, -21124, -18980, 22627, 16142,
-    29079, -23120, 18199, -14356, 783, -1078, -9730, -14123, -11358, 30620, 15947, -6219, 38,
-    -9241, 3548, -15383, -14895, -17783, 23168, 2325, -27293, 30878, 270, -21383, -30803, 9780,
-    -7763, -7929, -19262, -13931, 22398, 4897, -24726, -29774, 7211, -8793, -11527, -24400, -22157,
-    9034, -16948, 5857, -21036, -19004, -26525, 10062, -20033, 3545, -26433, -26458, 25905, -10243,
-    11837, 14854, 17256, 28285, -6735, 11860, 1300, 26675, 18285, -24401, 7400, -26691, -23116,
-    28989, -3818, 21346, 30788, -22837, 3871, -5451, -11015, -20801, -18301, 16999, -5382, 24877,
-    10060, 31112, -11104, 10318, -4356, 18967, 4921, 20573, -27037, -16154, 18775, 15626, 24966,
-    -28514, 9458, -28491, -22603, 27192, -4847, 18775, 26678, -29263, -6155, -22923, 27278, 785,
-    -24723, 32418, 3870, -16243, -21553, 23660, 15112, 29847, -24657, 17940, -15387, -1783, -4420,
-    -14873, -23628, -25751, 7487, -22344, -2107, 32160, 25973, 5603, 22651, 24174, -5706, 9288,
-    -499, 21794, 12115, 30084, -10332, 10317, -3585, 19482, 6462, 22629, -23695, -10501, 27770,
-    30019, -16927, 9267, 5346, 5433, 6954, 25392, 23166, -20803, 15113, -14615, -3583, -5448,
-    -17956, -27484, -32690, -3564, 25202, -31148, -14616, 15437, 13571, 20593, 29829, -2364, 18795,
-    12096, -21640, -18214, 21092, 15883, 28560, -25683, 15627, -18216, -7179, -12900, -28239,
-    19807, 4319, 15966, 15695, -21125, -13590, 25976, 25136, -22329, -2294, -11873, -22072, 26491,
-    17169, -29526, -17457, 31048, 5941, 32144, -14956, 9283, -10263, 11516, -6908, 18, 5861, -2282,
-    -1267, 9202, -226, 4131, 16655, 12881, 24691, -15214, 1317, -18487, -4420, -31322, 25204, 6888,
-    23677, 25975, -2879, 14426, 6957, -30893, -32606, -2809, -22155, 31902, 4902, -15217, -18985,
-    26234, 20764, -26953, -11289, -24982, 20850, -8977, 24878, 7486, 27774, -17271, 1833, -19773,
-    -4935, 31903, 22633, 2261, 15969, 13895, -22412, -17442, 21092, 16910, 29332, -23884, 18453,
-    -13846, -239, -1080, -9734, -15659, -12388, 29074, 11841, -11361, -8191, -24397, -19328, 13141,
-    -10777, 15369, -4079, 6700, 15626, 13911, 24947, -13673, 2604, -15404, 206, -23868, -27997,
-    26421, -9991, 11840, 14599, 18279, 28288, -6219, 13654, 2845, 29504, 23934, -16688, 19996,
-    -5108, 10298, 17940, 20078, -32108, 721, 25989, 22120, -4677, 9028, -240, 21538, 12883, 30086,
-    -9817, 11599, -2554, 21795, 10571, 28286, -13929, 5432, -12322, 5861, -15386, -13350, -16241,
-    27020, 6954, -18812, -20783, 21861, 13828, 27019, -28769, 11001, -26438, -19517, 32076, 3889,
-    32140, -17012, 6203, -14379, 4320, -18984, -18234, -24978, 13399, -14894, 10746, -13073, -5642,
-    -6730, -21297, -31342, 24882, -15130, 6182, 3293, 805, 528, 13573, 5431, 15434, -32431, -25667,
-    3613, -9559, -14616, -28255, -30376, -1766, 29314, -25493, -4850, 31113, -26778, -4336, 30342,
-    -27035, -5364, 29057, -29348, -8706, 23147, 26936, -23613, -1268, -12386, -21814, 26746, 17427,
-    -29268, -16431, 32077, 7741, -30053, -10072, 17251, 2844, 32590, 27274, -10007, 29507, 11340,
-    -28769, -5189, 23163, 13894, -15984, -10505, -30824, -28834, -2538, 29829, -25750, -4337,
-    31114, -26264, -3566, 31371, -25236, -2281, -31852, -21638, 3631, -22087, -5961, 28818, 18777,
-    -5191, 4661, -4355, 12801, -480, 8496, 20511, 20337, -28513, 4320, 32673, -32368, 12545,
-    -28238, -19518, 29765, 1832, 28027, -23692, -4336, -31598, -23949, 1319, -25945, -12641, 18025,
-    2069, 32334, 25478, -11039, 26424, 6460, 29824, -17267, 3377, -16695, -1333, -27463, -31601,
-    18457, -22324, -6672, -17266, 32418, 12347, -8786, -5875, -17466, -11356, 27279, 13118, -12899,
-    -9472, -25176, -22408, 8262, -16696, 3807, -22579, -21322, -31916, 2608, -31603, -17010, 6978,
-    -12072, 6892, -15125, -10273, -13668, 31650, 15942, -5959, -218, -8217, 3551, -14611, -13100,
-    -15981, 26510, 8489, -18552, -20008, 24681, 16658, 31649, -19524, 23855, -5360, 16200, 22570,
-    29335, -15926, 24884, -478, 22111, -32428, -19497, 11061, 3295, 5176, 5921, 22827, 19568,
-    -25691, 5608, -29263, -26205, 21543, -13587, 5151, 3295, -480, -246, 11005, 1834, 10034, 23598,
-    24707, -20036, 16146, -12560, 526, -304, -8704, -12068, -9042, -30035, 23399, 5350, 19824,
-    22114, -11613, 1321, -13097, 210, -22067, -24662, 30537, -3306, 24426, -32686, -17185, 12605,
-    7406, 11086, 15432, -27033, -20526, 14662, 6377, 12114, 15176, -26006, -19755, 16205, 8946,
-    16226, 21602, -15213, -2792, -21575, -11872, 22909, 7467, -22410, -24123, 15178, 4061, 10059,
-    10295, -32177, -31062, -1784, -19841, -30805, 10810, -6735, -5361, -16176, -8277, 31648, 19291,
-    -1082, 8519, 3357, 25646, 19313, -24657, 8427, -25920, -21573, 31558, 295, 28028, -23698,
-    -5616, 32397, -25240, -2789, -31854, -21128, 2609, -22089, -5965, 27282, 17747, -6737, 555,
-    -9497, 4829, -15123, -13609, -15217, 26255, 7723, -17788, -20520, 23658, 16908, 30366, -21832,
-    22049, -9984, 8495, 12282, 10577, 19289, -21900, -12811, 27000, 27959, -20522, 3357, -3140,
-    -9984, -17204, -13163, 24969, 7726, -18561, -21035, 21605, 14850, 26510, -28511, 12280, -26176,
-    -18231, -30382, 6978, -27484, -6481, 21371, 10810, -19075, -18465, 23661, 19476, -32344,
-    -17203, 30014, 2866, 28800, -19845, -1246, -25171, -12392, 17773, 1301, -32182, 24455, -11807,
-    26673, 4666, 27514, -19331, -2273, -25429, -13677, 15975, -1528, 28472, 16489, -24145, 6370,
-    -28230, -25430, 25646, -10240, 11836, 15366, 17002, 28798, -5966, 12377, 2841, 29243, 21629,
-    -18234, 17165, -11269, 2071, 4828, -3302, -2555, 8169, -4587, -499, 8940, -1760, 3100, 15365,
-    8520, 19805, -23186, -13326, 24689, 25388, -25149, -4352, -15476, -29518, 15952, 715, 6977,
-    3102, 24359, 17771, -27996, 4056, 31906, 31372, 12023, -31831, -24398, 23332, -10501, 7986,
-    11766, 10317, 17238, -23701, -15898, 20837, 19219, 30876, -20541, 24360, -5362, 13898, 22561,
-    27534, -20541, 20763, -8704, 6959, 12281, 10315, 17496, -23700, -15129, 21352, 20248, -32606,
-    -17713, 28987, 2604, 26491, -22671, -5106, 32659, -24213, -480, -29793, -16503, 10315, -11033,
-    13053, -7161, 1047, 7912, -222, 2590, 16393, 10058, 21351, -20102, -7932, 32402, -27041, -3820,
-    29575, -25756, -5362, 29318, -27555, -7418, 25463, 32070, -16928, 9787, 7140, 8002, 9787,
-    32069, -32605, -5891, -24471, 26249, -3578, -28840, 24193, -9747, 28216, 9544, -32621, -9307,
-    14428, 276, 28729, 19825, -21827, 12024, -18983, -11804, -17017, 27535, 5673, -18303, -21810,
-    20323, 12794, 23937, 31631, 4313, 26764, 25977, 1486, 18283, 14669, -18304, -12815, 29062,
-    30527, -14872, 10045, 9709, 10829, 14928, -25244, -19241, 15186, 10736, 16997, 21868, -11881,
-    807, -16684, -1087, -27206, 31633, 19217, -23866, -10259, -19590, 26507, 1307, -22932, -30805,
-    5934, -9826, -13072, -29018, -27045, 293, -32617, -17279, 6205, -16684, 4567, -21552, -22595,
-    -29357, 4149, -30563, -11624, 13659, -3321, 25128, 12117, 32145, -6484, 15716, 4132, -30643,
-    29080, -6663, -28329, 20599, -12575, 22559, 39, 18008, 32582, -24891, 2846, -7255, -14354,
-    -26709, -26273, 2609, -28509, -11110, 15717, -239, 30268, 19829, -20029, 14591, -15638, -5637,
-    -6740, -22577, 31629, 23587, -20265, -1524, -7254, -18723, -30822, 30526, -9986, 15440, 19989,
-    25994, -24653, 15621, -18212, -7691, -11878, -28749, 19809, 5341, 15970, 16211, -19075, -12044,
-    29062, 31298, -14101, 11587, 12022, 14684, 21096, -15221, -3306, -24137, -12908, 19311, 793,
-    -30642, 26507, -9745, 31297, 12372, -27222, -315, 28564, 22894, 713, 14172, 9530, -27044,
-    -26949, 5933, -5971, -9473, -21309, -15737, 19055, -2548, 31552, 19569, -20025, 14590, -14870,
-    -5890, -5970, -21295, 32661, 26156, -15899, 4392, 3539, -1250, -3832, 9964, -3049, 795, 13047,
-    4662, 11589, 31551, -31576, -6145, -22676, 27535, -1262, -24218, 30876, 538, -18822, -27464,
-    12875, 967, 4662, -747, 19215, 9288, 22383, -18565, -5618, -30303, -20621, 5177, -21309, -832,
-    -31831, 27008, 10733, 28051, -32617, 10990, -31317, -26192, 23327, -12300, 4907, 8163, 3635,
-    5678, 24868, 21111, -25677, 10990, -24122, -19252, -28074, 8775, -25164, -1089, 29593, 22639,
-    2252, 15201, 11588, -23192, -21294, 14930, 9447, 14687, 18014, -17025, -8957, -32102, -25249,
-    -1761, 32406, -19081, 3380, -21566, -2376, 31394, 23153, 5077, 18030, 17242, -14199, -7413,
-    -27477, -18825, 8779, -15656, 9189, -16922, -13343, -14455, 27283, 7218, -14970, -18207, 26494,
-    24352, -24890, -6403, -15483, -32086, 12102, -3919, -2273, -12057, 1736, -20521, -24650,
-    -29361, 1325, 31890, -16511, 4924, -16942, 3793, -23604, -25166, 32321, -3301, 23665, -29617,
-    -16152, 14157, 13816, 17773, 25979, -5974, 9550, -2035, 23325, 10835, 28805, -10086, 8009,
-    -7177, 16387, -1501, 9786, 23840, 23171, -23625, 15102, -18978, -8976, -12654, -32085, 15697,
-    -833, 4154, -1780, 17929, 5439, 18523, -26019, -18206, 16211, 13816, 19317, 28033, -2376,
-    14692, 7216, -27563, -31312, 1306, -13686, -23345, 22895, 16126, 28056, -26964, 17412, -20262,
-    -8460, -12657, -31827, 15442, -65, 4412, -1264, 19468, 7239, 21352, -20625, -10494, 29062,
-    -30648, -12551, 16727, 20496, 26513, -24137, 18441, -16151, -3576, -3662, -17693, -27220,
-    -28848, -988, 29835, -20624, -1245, -27734, -12914, 14433, -4347, 26151, 11349, 31890, -6232,
-    14948, 3106, -31162, 26770, -10002, -32703, 12121, -25937, 2250, 31139, 28034, 9703, 27027,
-    31375, 8932, 29597, -32362, 13301, -29771, -21825, 29750, -2786, 21609, -30903, -19749, 9274,
-    5591, 4410, 4391, 24866, 18802, -27478, 7390, -30543, -28763, 22040, -16923, -749, -1862,
-    -12811, -20538, -17539, 17259, -5890, 26924, 10834, 32403, -6744, 15204, 3105, -31417, 26769,
-    -10003, 32321, 11863, -26452, 967, 29596, 25463, 5079, 20087, 20066, -9828, -473, -15401, -319,
-    -26430, -31849, 22812, -19492, -2036, -5973, -18464, -29792, -32701, -7413, 20067, 28209,
-    -27460, -4862, -16767, -31829, 11330, -4689, -3815, -14114, -2119, -26688, 31119, 20241,
-    -24376, -9745, -18566, 27025, 2849, -19852, -27203, 12616, 1479, 3895, -492, 19213, 8521,
-    22124, -19081, -7413, -32104, -23707, -731, -30048, -14969, 10064, -10260, 15615, -5356, 4904,
-    15614, 9808, 20067, -19596, -10495, 30090, -29876, -10752, 19553, 24866, -31827, -12316,
-    -28333, 14177, -19256, 10732, -19234, -13602, -17281, 23943, 1562, -24221, 32167, 2591, -14713,
-    -22832, 22381, 15870, 27541, -27735, 16127, -22318, -11801, -18054, 24971, 1307, -22938,
-    -32341, 4392, -11374, -17692, 30350, 29233, -16408, 6450, 6873, 2868, 3366, 23064, 15975,
-    32664, -67, 21887, 15445, -11119, -6640, -24134, -13689, 16748, -3317, 30516, 16234, -24906,
-    8414, -27457, -25163, 29746, -6383, 17243, 27690, -31313, -9743, -24481, 20602, -9744, 27178,
-    6724, 28292, -14200, 3127, -16428, 3020, -24373, -26708, 30520, -7154, 18011, 26922, -31313,
-    -9746, -25249, 19831, -10518, 24868, 3640, 23663, -22678, -9981, 28032, -31930, -14608, 13898,
-    15101, 18289, 28290, -3147, 14178, 5931, -29362, 31140, -3578, -21909, 29084, 1820, -18312,
-    -27457, 14157, 3276, 6468, 4134, 27177, 20346, -23623, 13299, -21289, -13600, -18569, 22402,
-    -1778, -28592, 24201, -9746, 30520, 9809, -30307, -4433, 19576, 10043, -19597, -20774, 19810,
-    15612, 24202, -31077, 9701, -32596, -28250, 21010, -18205, -2806, -4691, -18462, -28763,
-    -30905, -5098, 23923, -30391, -17178, 12102, 11500, 12892, 18527, -17542, -9981, 32148, -26794,
-    -5612, 27265, -27308, -10753, 21355, 27177, -27459, -6402, -17541, 31138, 7477, -10346, -13324,
-    -30045, -26794, -1759, 30608, -20112, 41, -26191, -9575, 19060, 3365, -26281, 31910, -747,
-    -17543, -29000, 12618, 704, 2612, -3060, 16638, 2613, 13131, -32707, -30541, -4088, -17544,
-    -32597, 9020, -6237, -8438, -21050, -12148, 21118, 2595, -24228, 32683, 1825, -13178, -22573,
-    22900, 18177, 30112, -24132, 23575, -11267, 5168, 11752, 6210, 10822, -30654, -30542, -3056,
-    -15748, -29514, 12879, 1471, 3640, -2285, 19205, 6210, 18275, -23201, -15636, 19304, 21518,
-    30367, -21046, 26916, -4586, 14935, 27944, 32679, -12308, -27825, 15203, -19762, 12782, -17180,
-    -11538, -11633, 32165, 13392, -2639, 298, -9226, 8413, -11268, -9740, -3923, -24118, 30610,
-    23577, -21549, -5113, -9577, -24890, 23929, 16380, 30109, -26187, 21007, -15125, -1514, 447,
-    -10757, -17705, -11632, 26509, 7482, -14460, -16668, 26758, 27175, -21038, -1769, -5722,
-    -16926, -30553, -30394, -4847, 22390, -30908, -17698, 8770, 8413, 8003, 8256, -31937, 32675,
-    -7678, -23199, 25479, -6136, -28853, 21367, -15646, 22806, -2021, 12625, 27689, 31389, -14618,
-    -31935, 10058, -29782, -2894, 23680, 12881, -11890, -8445, -28240, -19600, 8261, -18989, 6358,
-    -22066, -23358, -28594, 4149, -31840, -10861, 13145, -5112, 24863, 10061, 27784, -10861, 6978,
-    -10768, 13041, -7928, -1773, 7130, -4844, -4600, 7387, -7414, -6657, 2505, -14097, -18222,
-    -15999, 21370, -1260, -29106, 25225, -10256, 31034, 10833, -29789, -2891, 22656, 13645, -12915,
-    -9726, -28761, -22167, 4153, -23879, -3406, 27541, 18270, -3150, 4155, -4861, 16125, 44, 10559,
-    27433, 26772, -16941, 26406, -1756, 19040, -31677, -23602, 4392, -2380, -9209, -17454, -9833,
-    27029, 11331, -10091, -10236, -26192, -19343, 8526, -16427, 9185, -18717, -15142, -17029,
-    21890, -750, -27566, 25745, -7176, -30392, 16493, -18999, 14070, -16404, -7434, -7518, -26427,
-    26491, 16384, 31655, -22597, 25123, -8695, 11328, 18698, 19061, 32659, 2250, 23944, 21094,
-    -4433, 5441, -4093, 17413, 2100, 14668, -32703, -29255, -1523, -14713, -27456, 18267, 7132,
-    14179, 16211, -18826, -13835, 27520, 30005, -18976, 5419, 3019, -2528, -5375, 8673, -7668,
-    -4861, 4047, -11524, -13342, -8546, -32598, 18527, 2505, 10322, 6962, -31677, 30111, -7431,
-    -26536, 20859, -11287, 25637, 3640, 23922, -21909, -8953, 29319, -29105, -10752, 20324, 25637,
-    -30285, -10003, -24478, 20345, -9233, 26667, 6724, 28546, -14711, 2870, -16431, 1995, -25401,
-    -27996, 27439, -11267, 11327, 15360, 16232, 26747, -7257, 8780, -3323, 20757, 6724, 22891,
-    -20621, -8696, 31629, -27303, -6640, 27003, -29873, -13580, 17238, 18958, 25741, -25682, 15104,
-    -20778, -10519, -16507, 28310, 6958, -15223, -18465, 26748, 23328, -25405, -7177, -17792,
-    30622, 7730, -12139, -14354, -31848, -31157, -7415, 21609, 29239, -24378, -750, -10083, -20523,
-    29320, 23842, -21809, -3833, -10597, -23865, 25209, 16389, 32418, -22594, 24614, -7161, 11588,
-    19217, 21880, -30304, 6367, 32674, -32360, 14849, -26181, -17197, -29098, 10571, -24137, 715,
-    -32092, 28549, 10738, 30617, -29791, 14851, -23355, -14114, -23699, 19308, -9746, 23077, 4916,
-    22893, -24212, -9734, 26235, 30016, -17445, 6961, 3287, 2088, -236, 15367, 6971, 16983, -28067,
-    -19244, 12615, 7142, 11597, 13129, -27040, -22071, 10560, 2515, 4915, 1565, 20505, 13910,
-    28550, -9051, 11084, -3833, 21276, 9028, 24694, -17789, -1766, -25165, -12906, 18795, 279,
-    -32182, 24963, -12829, 26159, 4660, 25464, -21387, -4849, -31591, -22415, 2605, -24910, -8280,
-    23166, 9786, -18304, -17698, 24179, 20761, -29521, -14115, -29611, 12885, -21826, 5085, -25666,
-    -25681, 27959, -6648, 16211, 23333, 30874, -16429, 27960, 2861, 25976, -23184, -6134, 31373,
-    -26782, -4335, 29574, -26782, -6134, 27775, -30380, -11275, 18781, 21021, 31387, -18228, 26419,
-    -225, 21094, -31407, -18473, 10301, 5344, 7485, 7474, 28474, 27788, -14629, 26419, 3630, 24949,
-    -23697, -7164, 29575, -29865, -8450, 21866, 26676, -24899, -3834, -15473, -27212, 17241, 3545,
-    12881, 10816, -28324, -25413, 5934, -5709, -7936, -19510, -13676, 24190, 4649, -22672, -26183,
-    10561, -1342, 804, -6403, 8682, -6392, -3576, 4313, -7934, -9231, -3140, -21041, -29791, 28729,
-    -9477, 13387, 17935, 23162, -30304, 6884, -31580, -30561, 17165, -21301, -10001, -17787, 29843,
-    6191, -15732, -17446, 26238, 22562, -24386, -7944, -18815, 31127, 6192, -14447, -15905, 28809,
-    26674, -17448, 2596, -827, -5882, -13339, -5196, -26185, 27525, 15365, -30041, -21306, 27959,
-    -743, 20586, -31923, -18477, 8251, 3800, 4911, 1826, 20762, 15448, 29325, -6738, 15192, 1569,
-    31041, 25215, -16165, 23075, -486, 15704, 29243, -27729, -5627, -19331, -32098, 6967, -10851,
-    -11024, -29270, -26014, 3112, -30042, -12650, 15449, -4342, 25387, 13650, 32152, -5454, 19048,
-    6709, -25244, -26185, 6967, -4428, -5367, -16935, -7512, -32352, 18532, 1226, 11853, 5939,
-    -32699, 30871, -8968, -28843, 19820, -15908, 18447, -5367, 6195, 15363, 13908, 22386, -14707,
-    -227, -21819, -7511, 28301, 13905, -8540, -2796, -18221, -6227, -32608, 19816, 2254, 13910,
-    9279, -27302, -26183, 4911, -5972, -9221, -22588, -16509, 18279, -5626, 27953, 14167, -30556,
-    -1089, 25476, 17247, -7258, 1319, -12824, 4051, -17443, -20277, -22420, 14169, -14881, 14589,
-    -8962, -1259, 5080, -4850, -6656, 3795, -11276, -14366, -10597, 32158, 14676, -3402, 2604,
-    -7428, 10477, -5877, -2031, 7393, -3564, -2802, 8935, -2793, -489, 12019, 2605, 8249, 26154,
-    25478, -20279, 20244, -8705, 4909, 11505, 7744, 12619, -29873, -25924, 2854, -7515, -13331,
-    -27986, -25762, 3118, -29529, -10856, 16226, -1516, 30265, 19824, -22077, 13303, -17699,
-    -11026, -13425, 32160, 12105, -5716, -2792, -15138, -2375, -26693, 29838, 18700, -25923,
-    -13853, -24476, 18282, -12569, 20758, -737, 13646, 27954, -32606, -11027, -28843, 16996,
-    -17967, 13820, -12817, -5373, -3400, -17443, -27218, -29871, -224, 29066, -21904, -1509,
-    -29788, -16507, 10571, -12056, 13306, -7676, -491, 6624, -2793, -2290, 9708, -1508, 2080,
-    15362, 8772, 18014, -23960, -14616, 20585, 20759, -32607, -18223, 29241, 2603, 25724, -22674,
-    -5621, 31121, -25501, -3051, 30864, -23188, -995, -30303, -16763, 9800, -12828, 11508, -9990,
-    -4603, -58, -13331, -19509, -18305, 19052, -5374, 28213, 14169, -29019, -315, 27277, 21097,
-    -2372, 9545, 1308, 25898, 18026, -27477, 5595, -31062, -31332, 17932, -22325, -10258, -18048,
-    28305, 4392, -18049, -22582, 18785, 11249, 21109, 26238, -3144, 13914, 4650, -31672, 29334,
-    -8458, -29615, 18283, -17197, 15876, -10246, -491, 4054, -5363, -7174, 1999, -13845, -17711,
-    -17276, 21879, -1263, -30640, 24963, -11287, 27701, 7744, 30090, -13677, 7488, -11289, 10225,
-    -9989, -5120, -1084, -15129, -21568, -22672, 12371, -15401, 10996, -13330, -7434, -6994,
-    -23353, 30089, 20506, -23611, -8205, -18301, 30360, 6959, -14447, -16158, 29576, 27188, -17187,
-    4391, 1230, -2795, -7175, 3801, -11534, -13343, -11362, 32671, 15699, -3396, 3888, -5119,
-    12795, -995, 6190, 19220, 16995, 30605, -3911, 18024, 8503, -24729, -24896, 10046, -315, 806,
-    -5375, 10222, -4334, 23, 10735, 1578, 6448, 23071, 20339, -27991, 7394, -29777, -28248, 22301,
-    -14872, 1309, 1483, -6134, -10771, -2115, -21811, -30046, 28469, -10247, 11847, 16390, 19822,
-    29837, -1087, 20080, 12618, -17793, -13845, 27268, 28468, -18215, 3368, 454, -4594, -11025,
-    -574, -20014, -27473, -32442, -2795, 23414, -29872, -14618, 13906, 14589, 20335, 27784, -2117,
-    17252, 7995, -24734, -25154, 8253, -1091, -1509, -9995, 4307, -14103, -17191, -15739, 24191,
-    1057, -24478, -31836, 1572, -14199, -21042, 22390, 17413, 31643, -24385, 23068, -9222, 5686,
-    12530, 10311, 14681, -24479, -17703, 14939, 13557, 20591, 25733, -2892, 14681, 3374, -30906,
-    29844, -9477, -28849, 19050, -17959, 17156, -8708, 33, 7646, -227, -1252, 14842, 5685, 12112,
-    -31419, -27212, -1766, -12658, -22329, 21879, 16126, 30100, -27980, 18440, -17190, -7676,
-    -8546, -23872, 24193, 16641, -32097, -24381, 25123, -6654, 9544, 18955, 21359, 31389, 3278,
-    27527, 21880, -64, 14421, 5432, -29363, -31326, -4334, -19340, -31069, 5947, -8547, -9995,
-    -27977, -21397, 8767, -21810, 3533, -25672, -31319, 24865, -13594, 1836, 4818, -487, -5360,
-    10729, -1772, -734, 14070, 6196, 10831, -31934, -28243, -4332, -16000, -27472, 12374, 1733,
-    6967, -991, 22551, 14420, 27536, -7005, 13136, -3305, 26406, 15706, -32604, -323, 24959, 15456,
-    -8291, -741, -18212, -2123, -28240, 25993, 14839, -32609, -26950, 22807, -11793, 1579, 6872,
-    801, -1763, 16124, 6456, 13400, -28595, -23100, 4406, -1354, -5109, -15898, -3667, -27725,
-    24709, 14580, 31129, -29262, 19207, -17960, -8444, -9064, -25413, 21369, 13552, 27016, 30878,
-    9954, -32609, -32345, 17922, -22073, -13841, -18829, 25216, -3304, -26539, 28043, -8187,
-    -28595, 21104, -16926, 21008, -3569, 8004, 21265, 21874, -31832, 6873, -32354, 30620, 15097,
-    -27214, -21552, -32191, 4398, 28563, -16000, 4913, -20012, 1477, -26185, 31903, 22038, -18990,
-    -6133, -8803, -22331, 25222, 19211, -28243, -18212, -30390, 9794, -29521, -3662, 24958, 12371,
-    -11887, -7167, -27979, -18826, 11081, -16415, 10987, -13078, -10761, -7774, -26185, 22907,
-    13043, 28300, 32673, 11758, -28755, -25667, 27179, -6139, 12370, 22296, 27271, -24639, 18442,
-    -13337, -3821, -1348, -12309, -22582, -19081, 16733, -11018, 21270, 3112, 15967, -30902,
-    -22075, 3889, -2376, -5883, -16929, -7002, -31326, 18538, 3278, 14421, 9029, -26021, -24387,
-    6203, -1864, -3312, -14101, -1093, -22844, 32674, 26150, -14107, 2863, 5332, 800, -3049, 14327,
-    3628, 9030, 29233, 30613, -14615, 32318, 10053, -31835, -5462, 20334, 6202, -22425, -24128,
-    10058, 2761, 4914, -1251, 20238, 11082, 22650, -15229, -740, -24639, -8804, 23933, 6459,
-    -18314, -20015, 18282, 15353, 25475, 31903, 8928, 32671, 32674, 16895, -24127, -16157, -23454,
-    17765, -14359, 19981, -2539, 8772, 22808, 23675, -27723, 12528, -23100, -19242, -26022, 12367,
-    -22070, 6618, -23357, -25154, -32446, 31, 24961, -24479, -7679, 25218, -31932, -14619, 10570,
-    12272, 14937, 18794, -15485, -4852, -28752, -17284, 11340, -14104, 13557, -8707, -3566, 4048,
-    -7679, -11791, -3405, -23356, 30615, 23324, -19502, -4594, -8031, -20530, 28560, 24095, -20531,
-    -5107, -9573, -22330, 24963, 18698, -29270, -19242, -32702, 6197, 30616, -12913, 10053, -11020,
-    14844, -4082, 2602, 14586, 9283, 15709, -24479, -16675, 15967, 15613, 23675, 30873, 5333,
-    28301, 25219, 4305, 21619, 17509, -10088, -740, -19243, -3663, -31066, 22392, 7902, 22134,
-    21621, -5206, 8000, -5622, 18953, 4916, 15709, -28336, -21042, 7743, 3532, 2860, -2024, 17411,
-    6972, 16223, -25766, -17958, 13397, 12270, 17252, 21107, -10347, 2090, -16417, 2504, -22583,
-    -28239, 31289, -5621, 17508, 28462, -27981, -7935, -19341, 29845, 2089, -16772, -23098, 16996,
-    10984, 19565, 21879, -7007, 6202, -9220, 14068, -4078, 1575, 14838, 7488, 13911, -26797,
-    -21811, 8258, 4043, 3376, -1252, 19464, 9287, 20336, -18573, -7418, 31130, -24484, -2535,
-    30102, -20629, 293, -28496, -10863, 16742, -2282, 31800, 20083, -21558, 15866, -15127, -7422,
-    -5209, -22066, 30101, 25375, -19240, -2281, -4181, -15642, -28238, -26540, 1578, 32414, -14204,
-    8775, -13334, 12782, -9987, -5366, 1988, -12558, -18730, -14203, 23423, 1060, -23713, -31833,
-    1575, -12663, -20268, 23935, 21262, -29264, -16672, -28596, 11343, -25668, 3016, -31577, 28560,
-    14324, -31577, -25668, 25376, -8962, 7744, 16123, 15197, 22650, -10349, 3376, -15388, 5329,
-    -18984, -22070, -23969, 10572, -21557, 6101, -24381, -26440, 31545, -3821, 19564, 32573,
-    -22069, 2089, -2895, -9476, -21041, -13432, 22393, 291, -25512, 31645, -2793, -19344, -30807,
-    6460, -6752, -8962, -24894, -16261, 15711, -9475, 23576, 5431, 20337, -22428, -10761, 23422,
-    30001, -20528, 293, -2640, -10762, -22837, -16004, 18280, -7160, 28715, 13140, 32675, -2126,
-    21879, 10573, -15234, -13331, 27536, 32055, -14360, 8005, 11751, 11341, 13402, -22688, -17701,
-    15202, 15862, 22649, 28566, 4040, 24191, 18286, -4699, 4917, -9728, 13805, -4849, -990, 12777,
-    2862, 5949, 27426, 24450, -23350, 19460, -12560, -3046, 2755, -8706, -15896, -6497, -30808,
-    18286, 5839, 15710, 11604, -19862, -16673, 18801, 20488, 31129, -24119, 25115, -7165, 7750,
-    18690, 18535, 27025, -1871, 16994, 4923, -25259, -28496, 1326, -8555, -15389, 30882, -31428,
-    -8451, 14692, 24856, 31898, -19747, 30511, 3114, 22915, -21147, -6138, 27541, -25773, -6138,
-    22915, -30399, -15134, 9038, 12520, 13908, 15463, -17550, -9737, 27029, -29629, -9995, 14437,
-    23057, 30354, -23600, 25114, -5627, 8012, 20745, 21872, 31142, 5839, 30096, 24460, 7381, 24956,
-    20862, -1358, 12364, -470, 30254, 22644, -23858, 17147, -13851, -8180, -3671, -18991, 31399,
-    30768, -10254, 8784, 17146, 19045, 24461, -3415, 13906, -1240, 31281, 22901, -22829, 18432,
-    -11282, -4581, 2498, -8713, -17945, -8553, 32408, 12125, -2643, 2597, -11521, 9437, -8969,
-    -11263, -1872, -20020, 31914, 30254, -9998, 8271, 16889, 18530, 23434, -4957, 11592, -5351,
-    24856, 12620, 25746, -8555, 10051, -10235, 18687, 1057, 8269, 28196, 29070, -19745, 27940, 800,
-    17520, -28601, -18476, 6984, 7379, 6968, 2872, 28710, 24187, -24114, 18688, -12566, -5609, 441,
-    -12053, -23342, -17035, 18274, -10492, 26142, 8765, 23432, -14979, 1313, -24886, -5213, 28042,
-    11609, -7270, -3312, -21802, -6499, 29585, 11866, -5215, -1255, -17690, -75, -25670, 28571,
-    21771, -22844, -12293, -16522, 29071, 1329, -16266, -22587, 15208, 11747, 19305, 19577, -7529,
-    4143, -14606, 8663, -13848, -16405, -11383, 29843, 7240, -9328, -9993, -30796, -21664, 5171,
-    -27713, -3417, 26246, 11609, -8301, -5108, -24629, -10357, 22135, 558, -23208, -31065, -213,
-    -11643, -20271, 22147, 21511, -30038, -20002, -30660, 6714, 30370, -9072, 12883, -7410, 24853,
-    9028, 22916, -14212, 34, -24888, -5474, 26249, 10065, -9587, -8703, -29000, -18068, 9288,
-    -19235, 9689, -18726, -19492, -18838, 18026, -11012, 26139, 5947, 22141, -18323, -5618, 31650,
-    -20379, 1836, -28233, -7272, 20341, 3379, -22436, -28747, 4408, -4704, -9986, -24890, -15241,
-    15715, -9727, 25623, 6206, 21884, -17811, -5873, 31907, -19867, 2095, -27462, -5732, 22142,
-    6720, -16784, -20264, 18543, 18425, 26768, -30288, 16626, -23862, -17181, -21153, 17002,
-    -13841, 22796, -1246, 11860, 30249, 32164, -12813, -26805, 15973, -20267, 15087, -15125, -9473,
-    -5473, -24891, 25737, 19971, -29518, -18982, -29630, 7234, -31576, -5472, 18543, 3891, -23977,
-    -30031, 2093, -8558, -16410, 30878, -31688, -10500, 13403, 22283, 26251, -26947, 18685, -17697,
-    -9213, -7530, -26178, 21628, 15086, 27279, 32165, 13544, -29262, -25918, 29736, -5618, 13918,
-    27680, 32418, -15638, -29631, 11087, -28489, 1724, 29591, 21370, 4551, 16741, 11347, -18323,
-    -16156, 20857, 23826, -29778, -16152, -27060, 13399, -23606, 8664, -23867, -25148, -30400,
-    1063, 26509, -19349, -2021, -31060, -14466, 10830, -13071, 16375, -6132, 808, 13547, 4920,
-    9032, -32714, 32419, -9730, -24232, 22139, -11273, 29226, 8518, 28819, -9839, 9290, -9219,
-    18176, -734, 8772, 26143, 25480, -22583, 20747, -11016, 806, 7896, -479, -1764, 15863, 4919,
-    11857, -30655, -27978, -2278, -12151, -23609, 20596, 15348, 26764, -32604, 12521, -29263,
-    -25922, 28456, -6647, 12629, 24087, 27791, -22838, 22803, -8705, 4663, 14064, 10057, 14686,
-    -22688, -16672, 16486, 18175, 25991, -31060, 13292, -26438, -22836, -31169, 2861, 27793,
-    -16777, 2091, -23866, -3670, 28820, 15970, -2386, 4149, -7418, 15347, -1762, 4405, 21258,
-    16228, 28306, -2387, 16229, 4662, -25775, -30803, -478, -12411, -22579, 21111, 17658, 29079,
-    -28234, 19715, -17954, -7930, -7014, -24379, 24453, 18944, -31319, -22065, 30767, -479, 20598,
-    -26802, -15129, 13660, 17402, 22137, 29594, 5066, 25735, 20856, -75, 11601, 1581, 32307, 24708,
-    -18466, 25112, -2535, 12632, 28967, 32674, -13840, -28087, 14684, -23093, 10207, -21811,
-    -21294, -24745, 10572, -23608, 5325, -27208, -31318, 25115, -14873, 552, 4040, -4079, -9729,
-    4298, -13846, -19238, -15234, 22649, -2276, -27313, 27532, -9472, -29626, 18023, -21038, 14836,
-    -14617, -9472, -6239, -24126, 25481, 19205, -29010, -19495, -30910, 6971, 32162, -8808, 14939,
-    -3305, 29229, 17509, -27978, 7127, -29266, -31319, 22291, -17188, -4333, -4181, -16674, -30290,
-    -29624, -2539, 23938, -26797, -10764, 18285, 24861, -30040, -14869, -27824, 15193, -22066,
-    10213, -19503, -18725, -21398, 17763, -12815, 21523, 1058, 13656, 31289, -28241, -6133, -17799,
-    -31582, 6975, -7777, -8452, -25664, -17286, 14936, -11530, 19981, 801, 11857, 29233, -32096,
-    -11788, -27564, 18534, -17700, 16899, -8196, -223, 7647, 29, -995, 15100, 6455, 13140, -29876,
-    -24386, 2604, -5462, -10508, -24895, -19083, 13908, -13845, 16128, -5368, 2090, 13043, 7483,
-    11856, -29877, -25671, 1063, -8033, -14620, -31833, -29878, -3826, 22652, -30135, -14878,
-    11088, 13041, 16734, 20340, -11632, 1058, -19754, -1866, -29525, 24965, 12526, 29586, -32604,
-    14068, -26441, -21553, -31164, 4914, 30361, -13431, 8770, -13331, 12270, -9221, -5877, 1733,
-    -12049, -19241, -14715, 23675, 35, -24996, 32670, -1507, -17543, -26955, 11858, 1989, 5942,
-    -1250, 21522, 12367, 24964, -11375, 5429, -14616, 7644, -15132, -16158, -14715, 26503, 3118,
-    -19085, -24127, 13399, 6358, 11597, 9030, -27824, -26954, 1578, -8036, -14618, -32089, -29367,
-    -4081, 22653, -29624, -14876, 11346, 14066, 17507, 21883, -8551, 5172, -12814, 9954, -11020,
-    -10501, -4181, -22842, 29078, 23576, -20787, -6902, -10349, -25156, 20341, 12781, 25217, 28308,
-    5585, 25988, 21883, -70, 13653, 3893, -30140, 31129, -8957, -25514, 22905, -12299, 28201, 7742,
-    26508, -13691, 4402, -18469, 3529, -23355, -29006, 30515, -6907, 14428, 24861, 31129, -18726,
-    29488, 2602, 23165, -22684, -7935, 25992, -30394, -12562, 13400, 17923, 23418, 32161, 7129,
-    31385, 29334, 12269, -31838, -28749, 21779, -14620, -2277, -67, -9994, -19496, -12660, 25730,
-    3635, -19086, -23101, 13659, 7899, 13908, 12117, -22171, -17704, 15716, 15608, 23674, 29337,
-    5071, 26758, 21884, 702, 14936, 5693, -27312, -29269, -1246, -12920, -21816, 20600, 16635,
-    29585, -29516, 17664, -19247, -11783, -13690, 32668, 8778, -6495, -5368, -22063, -9836, 25987,
-    5951, -15748, -17447, 21886, 22289, -28756, -16922, -28083, 13136, -25147, 5585, -26957,
-    -31572, 24347, -14365, -474, 2502, -5113, -12811, -839, -20790, -31829, 30002, -8712, 10835,
-    19208, 23413, 32166, 7129, 32665, 29339, 13554, -29273, -26174, 26919, -5886, 10578, 21522,
-    25725, -28744, 13812, -21307, -17950, -22682, 18529, -14353, 20496, -233, 10063, 26150, 30093,
-    -19493, 26665, 1052, 17772, -30647, -18995, 5694, 3020, 2594, -4587, 14328, 3366, 7749, 27435,
-    28809, -19237, 25637, 25, 15972, 32062, -23877, -1761, -9573, -17709, 28309, 26665, -16682,
-    -218, -835, -7173, -18208, -9316, 31892, 12376, -4948, 1053, -13840, 3534, -16681, -23092,
-    -23708, 12361, -21037, 7390, -20022, -22322, -26534, 10305, -25664, 452, -31587, 24966, 9190,
-    27781, 27536, 5592, 26753, 22910, -63, 16217, 6719, -26535, -26446, 2865, -7261, -11026,
-    -28232, -22938, 7736, -24892, -836, -32613, 22397, 6360, 21872, 18542, -8547, 2855, -15127,
-    4304, -17963, -23094, -24737, 10565, -23352, 3534, -26958, -32604, 22039, -17450, -4847, -6232,
-    -17964, 31905, 30006, -10255, 10061, 16127, 19558, 25995, -3663, 15447, 2094, -31420, 29325,
-    -11785, -31676, 15190, -25921, 5590, -27216, -31061, 23324, -14367, -734, 965, -6400, -14870,
-    -5460, -26960, 23681, 12787, 29838, -32346, 13558, -25418, -21295, -30903, 6708, 32161, -10857,
-    14419, -5619, 24610, 12106, 27791, -9829, 10822, -7933, 18699, 3626, 13655, -32445, -25930,
-    -1765, -11885, -20790, 23936, 18956, -29529, -19498, 32064, 5681, 29075, -15225, 6710, -16930,
-    5336, -18734, -21813, -25247, 11336, -22071, 4566, -24645, -28239, 27697, -7427, 11855, 19473,
-    24698, -29780, 9964, -26446, -24897, 28983, -2545, 18023, 30268, -23620, -2023, -10853, -19251,
-    26762, 22301, -22593, -8962, -17020, -32102, 7744, -9568, -7944, -26437, -19591, 13388, -14873,
-    13306, -7687, -3307, 3797, -5631, -10504, -1600, -18224, -28494, -32183, -1262, 23421, -28842,
-    -11286, 16483, 19732, 30605, -24124, 20761, -8718, 2863, 8681, 6189, 5690, 26414, 26749,
-    -21553, 19476, -7177, 2864, 10223, 7987, 8775, 31297, -30564, -8958, -24987, 26491, -8187,
-    -32697, 19552, -22580, 11508, -16172, -14099, -15991, 30346, 4920, -15735, -15915, 24196,
-    22816, -23369, -10243, -19332, 31116, 2094, -17791, -20542, 17258, 11507, 23920, 25482, -1344,
-    19038, 7749, -23704, -21055, 10577, 4823, 10300, 4923, 30523, 30346, -14867, 30524, 10557,
-    31136, -8798, 16983, -1761, 30267, 23151, -21808, 16133, -10775, -4588, -573, -10261, -20779,
-    -16250, 23407, -2789, -30128, 27519, -12299, 29755, 12356, 32676, -5969, 21352, 5948, -23446,
-    -22853, 9547, 1740, 5932, -2019, 18703, 11329, 20597, -18820, -3834, -32089, -21133, 6704,
-    -23609, -2115, -31589, 22652, 6109, 22896, 19825, -7770, 5935, -10760, 10221, -6659, -5619,
-    2768, -8716, -15128, -9054, -30047, 17255, 2254, 13644, 6718, -30129, -29276, -3305, -17536,
-    -26706, 11859, 454, 6448, -2534, 18959, 10560, 20339, -19592, -5374, 31390, -24475, 795,
-    -32605, -16765, 9791, -15644, 9193, -12826, -12303, -10339, -29017, 17510, 3539, 14674, 9543,
-    -26274, -23106, 7231, -575, 26, -9219, 6108, -9741, -12303, -6999, -25932, 23935, 13304, 30609,
-    -30293, 15361, -21307, -14616, -21133, 23412, -6392, 31810, 19043, -23098, 10736, -18737,
-    -16416, -20618, 22127, -6907, 29755, 16473, -27468, 3541, -30302, 30615, 14593, -26448, -20015,
-    -32438, 6963, 32156, -12392, 13389, -6909, 20505, 7221, 20076, -24214, -10513, 22904, 26416,
-    -22336, -4081, -12392, -22593, 22391, 14079, 30350, -29267, 15108, -20024, -13076, -19330,
-    27265, -226, -24727, -30818, 1831, -14962, -18996, 23163, 18192, -29791, -20014, 29501, 3877,
-    25218, -22671, -3319, 31386, -23699, 1822, -29782, -14190, 15444, -6652, 22562, 9790, 24702,
-    -17274, 1053, -23616, -8793, 26497, 10309, -14705, -11026, 32155, -30382, -4858, 22646, 31813,
-    -17452, 6456, 3285, 3366, -1255, 16136, 8506, 16992, -26013, -15396, 16222, 14851, 24953,
-    31899, 5342, 31121, 28558, 8169, 30607, 30871, 9968, -30817, -28754, 19735, -14884, -3310,
-    -4424, -13599, -26183, -26012, 7476, -26441, -5195, 27780, 14680, -9051, -747, -17703, -4425,
-    -28503, 24703, 10481, 28809, 31385, 8939, -31587, -30553, 17421, -19252, -9736, -15218, -31074,
-    11339, -5710, -747, -14362, -1084, -21821, -30810, 26930, -10000, 8770, 13051, 15701, 20592,
-    -14963, -747, -23870, -10337, 24954, 6457, -19590, -19508, 18023, 13306, 24954, 29845, 4054,
-    27524, 23163, -59, 16474, 8000, -26017, -24647, 6202, -3145, -3829, -15644, -4173, -26702,
-    25991, 14845, -31585, -25410, 23841, -8199, 6717, 14074, 14161, 19310, -16510, -4086, -29521,
-    -18052, 11078, -15644, 10990, -11539, -9219, -5458, -21562, 29846, 23584, -18736, -4078, -7514,
-    -18222, 30875, 27953, -13083, 5690, 8163, 7478, 6461, 29494, 29580, -15642, 29238, 7221, 27534,
-    -15481, 5423, -18728, 1996, -23362, -30036, 27183, -9228, 9030, 15103, 17758, 23936, -8542,
-    8764, -8704, 15360, 26, 6716, 22042, 22128, -30036, 7393, -29273, -30550, 20758, -16167, -4335,
-    -5457, -16167, -30549, -31671, -3060, 21880, -31671, -15911, 8774, 8164, 10818, 9802, -29616,
-    -25934, 551, -9828, -15397, 30621, 30779, -10001, 10833, 16387, 21610, 28052, -319, 21868,
-    11604, -16254, -10515, 28567, -31674, -8717, 14690, 21783, 31118, -23090, 23583, -4863, 8265,
-    18957, 22122, 30624, 2766, 28290, 20601, -1090, 14156, 2611, 32577, 29833, -13581, 31807,
-    12871, -31058, -2632, 26236, 13404, -10086, -2548, -22834, -9572, 27265, 7493, -14713, -13085,
-    27283, 30263, -13600, 5953, 8674, 9017, 6981, 32318, -31847, -10495, -26022, 23409, -13323,
-    26151, 7218, 22914, -19339, -2291, -32085, -18311, 9275, -19236, 6105, -18996, -23091, -26277,
-    10303, -25919, 195, -31589, 24197, 8419, 26751, 25225, 2251, 21611, 13917, -14198, -6146,
-    -30544, -20880, 8247, -22578, 1480, -26963, 30108, 18700, -22338, -13583, -20621, 25722, -4845,
-    -29359, 25722, -13327, 27440, 8503, 26508, -15480, 5163, -19497, 712, -24650, 32418, 22558,
-    -16170, -3048, -4428, -13086, -26949, -25245, 7732, -26693, -4171, 28807, 15456, -6228, 3108,
-    -12045, 6109, -12056, -14872, -12138, 32406, 11343, -6742, -1775, -17442, -4172, -27989, 24450,
-    11762, 29837, 32674, 12276, -26961, -23610, 30010, 25, 21365, -29101, -14111, 13399, 14589,
-    21613, 27277, -1346, 19301, 9030, -21650, -19250, 15456, 12017, 20843, 23680, -5203, 11592,
-    -2792, 24610, 14933, 30618, -4175, 19303, 6203, -23965, -24902, 7489, -1093, -1000, -11273,
-    4048, -14365, -19497, -17797, 21102, -5876, 31291, 18275, -24895, 9446, -22589, -22068, -28847,
-    7481, -30036, -6745, 21360, 5945, -22166, -23616, 10829, 3534, 6968, 1577, 24865, 19047,
-    -30549, 4819, 32411, 28305, 11501, 32411, -30549, 17927, -19762, -10760, -14712, -32612, 9287,
-    -7260, -5369, -21554, -10858, 25729, 5946, -17541, -18990, 19825, 17155, 29840, -27721, 18184,
-    -16422, -7674, -8031, -22590, 25480, 18955, -27731, -18211, -30132, 10563, -28749, -2376,
-    27526, 15970, -5975, 2855, -12045, 6876, -12309, -14358, -10857, -32355, 13399, -2891, 3113,
-    -8704, 10730, -5625, -3821, 6875, -4597, -6648, 5076, -8967, -12816, -5718, -25929, 24964,
-    15356, -32611, -26180, 22810, -10510, 3120, 8931, 4911, 4662, 25893, 23415, -25408, 14328,
-    -18220, -13072, -15227, 30097, 5690, -13429, -14879, 27793, 29234, -15394, 4150, 5332, 2597,
-    -1762, 17155, 8508, 16228, -24480, -15137, 16229, 17412, 27011, -30803, 12529, -24904, -22065,
-    -30904, 5937, 31134, -12400, 11849, -9731, 17928, 1312, 10315, 27437, 30867, -16157, 30265,
-    7223, 28818, -13940, 7738, -14617, 8677, -13080, -12818, -10598, -30556, 15967, 967, 9794,
-    2346, 27695, 22901, -23355, 15102, -15393, -8706, -8799, -24645, 23677, 14588, 31125, -28238,
-    18187, -16936, -7420, -9056, -23361, 24449, 16388, -31329, -23611, 25641, -4601, 12370, 22814,
-    28809, -22583, 21016, -7687, 4404, 11763, 10047, 12885, -27559, -20794, 8003, 2510, 4393,
-    -2278, 17160, 8762, 16997, -24732, -13855, 17769, 18959, 30863, -24894, 20759, -9745, 1579,
-    6880, 2849, 294, 18188, 12872, 21625, -15994, -235, -25664, -10854, 23153, 2864, -24219,
-    -27220, 4407, -7258, -8716, -25919, -19080, 14672, -14098, 15874, -4090, 2094, 13560, 9789,
-    13659, -26533, -18739, 10319, 7391, 11845, 9291, -28590, -25164, 1582, -7517, -11800, -29772,
-    -25507, 4392, -31315, -10858, 17243, -3816, 29492, 19556, -26433, 9189, -23364, -24120, -31674,
-    3622, 27794, -18310, 3109, -24636, -5717, 28553, 13401, -7517, -1002, -17954, -2891, -27730,
-    25480, 14071, 32666, -28234, 20497, -14367, -3561, -1863, -12054, -23607, -19596, 15703,
-    -13328, 18185, -1774, 6976, 21012, 21613, -32346, 5078, 31893, 27536, 9704, 30865, 31134,
-    12274, -28503, -25664, 26924, -5116, 12373, 22812, 29065, -23094, 21271, -7688, 3893, 11761,
-    9789, 11860, -28332, -22337, 4922, -1605, -2804, -14354, -1348, -21822, 32421, 26409, -12571,
-    3638, 7133, 4906, 1839, 22810, 18784, 31394, 708, 26237, 16745, -6489, 4136, -12298, 7904,
-    -10514, -12555, -7259, -25934, 22398, 12530, 28808, 31393, 10731, -29532, -28746, 23068,
-    -11543, 1325, 5848, 1308, -3045, 14329, 5164, 9548, 30777, -31331, -10499, -26020, 22897,
-    -12813, 25639, 6706, 22910, -20365, -3831, 31905, -21907, 3623, -27464, -8286, 23156, 5690,
-    -20880, -21820, 13401, 7647, 14418, 12630, -22423, -16423, 17000, 16642, 27267, -31317, 12016,
-    -25676, -23350, 32320, 2595, 25480, -21651, -2802, 31648, -20880, 4138, -25922, -5974, 26755,
-    11601, -11115, -6655, -26950, -17540, 13906, -12559, 17157, -2543, 5689, 18956, 17760, 27791,
-    -4175, 16476, 3376, -29619, 32153, -6647, -23965, 27784, -5362, -27049, 25985, -9989, 31806,
-    14677, -27723, 2765, -32098, 27533, 11246, 31639, -31321, 15873, -22333, -15130, -22163, 21358,
-    -9220, 27183, 11078, 30101, -9312, 13649, -3824, 24870, 13906, 30871, -5714, 17762, 4143,
-    -28331, -31583, -2539, -18822, -28756, 9798, -3403, -1256, -12819, 1481, -18733, -25412,
-    -28845, 3884, 32670, -13682, 11338, -9994, 16389, -1256, 7483, 21272, 21360, -30554, 5852,
-    -32097, 31641, 14590, -26700, -19760, -31670, 6711, -32354, -10853, 14679, -3570, 25899, 14679,
-    -32098, -2629, 22904, 13135, -14452, -9222, -31069, -25246, 1316, -31070, -14709, 11597, -9997,
-    16390, -1768, 7737, 20759, 20591, -31071, 4311, 30871, 28297, 8423, 28815, 30353, 8423, 30871,
-    32409, 12535, -28497, -22847, 28727, -2026, 19816, 32325, -21045, 4140, -2115, -5881, -15136,
-    -6227, -29268, 22901, 8679, 23675, 25214, -1602, 15452, 6710, -28074, -29524, 543, -13426,
-    -21043, 23417, 17929, -32095, -21816, 26925, -2797, 16478, 28981, -27727, -6652, -19079, 32155,
-    5171, -12655, -15134, 29587, 30008, -13336, 8257, 10732, 11594, 13911, -24221, -17705, 14940,
-    13301, 20846, 25477, -3148, 14679, 2861, -31676, 29071, -11275, -31675, 14936, -25154, 5848,
-    -26956, -29523, 24867, -12051, 4146, 8161, 4912, 4403, 25380, 22388, -26438, 12016, -21817,
-    -18471, -24478, 15192, -17701, 13302, -11794, -7163, -3147, -17705, -29522, -31417, -2799,
-    22650, -29875, -14365, 12371, 14072, 19303, 24450, -5718, 11337, -3307, 24095, 13393, 28818,
-    -7260, 13908, -2023, 27950, 18277, -27724, 6619, -28755, -30551, 22040, -15906, -2537, -2633,
-    -12565, -23868, -20623, 13650, -15388, 14073, -8710, -3308, 3793, -6911, -11533, -2889, -21817,
-    32415, 26153, -14108, 3375, 5078, 1313, -2280, 14844, 5169, 11598, 32577, -28756, -4850,
-    -17796, -30041, 9029, -4947, -3569, -17186, -4690, -29526, 22650, 9445, 24445, 25220, 450,
-    18020, 9800, -21396, -19246, 15969, 13299, 21618, 25992, -1351, 16736, 6460, -25510, -26955,
-    3891, -5979, -9993, -25407, -18315, 13909, -13586, 17153, -4339, 3634, 16381, 12110, 19311,
-    -17030, -5880, -32090, -20885, 4401, -25409, -3923, 27789, 14941, -5466, 805, -13331, 4815,
-    -17186, -21041, -21142, 14683, -14874, 16895, -6905, 1575, 12011, 4661, 8257, 30258, 29590,
-    -14103, 32572, 9544, -31580, -4951, 19825, 6714, -21657, -24123, 11341, 4814, 6975, 3374,
-    27944, 22138, -23869, 15865, -17184, -9734, -9578, -28492, 19051, 8155, 18026, 17766, -12149,
-    -3819, -24383, -10607, 21111, 2089, -24486, -31832, 548, -13179, -22066, 21366, 17661, 29592,
-    -27208, 20489, -15899, -4591, -2385, -16156, -27721, -25772, 2863, -31834, -10866, 13401,
-    -6391, 25115, 9289, 25734, -12408, 3636, -17187, 4555, -22322, -26182, -30654, -991, 25476,
-    -23201, -7416, 26504, -28598, -11529, 16739, 23060, 30619, -20527, 27687, -2021, 16996, 32570,
-    -24895, -1251, -8551, -18727, 29333, 28201, -16672, 2349, 3528, -2794, -8702, 6355, -10762,
-    -13842, -7009, -29266, 19826, 8411, 19822, 18798, -9066, 1062, -17184, 1729, -24125, -31576,
-    27430, -12561, 5434, 10724, 7743, 9032, -30911, -30294, -5360, -17804, -31579, 6463, -7011,
-    -8963, -25919, -16777, 14425, -12042, 20233, -225, 10318, 27943, 30101, -17182, 30514, 5172,
-    26251, -16518, 1318, -24380, -5467, 27019, 12372, -8295, -4849, -22324, -9323, 24964, 6461,
-    -16006, -18470, 21625, 21260, -31321, -19496, 32569, 4403, 27792, -15491, 3376, -21040, 187,
-    -29778, 27020, 15093, -32348, -26180, 24603, -10247, 5176, 12780, 9286, 12886, -25514, -21298,
-    9289, 6097, 6716, 3378, 28199, 22907, -23865, 17148, -15387, -7930, -5212, -21812, 28822,
-    25115, -20014, -4845, -6754, -20014, 28823, 26914, -17959, -1246, -1100, -10506, -21806,
-    -14207, 21363, -3045, -29113, 25218, -13840, 29228, 7228, 26766, -13692, 4659, -18468, 4042,
-    -22841, -28234, 32056, -4594, 18027, 31028, -24641, -3304, -10350, -21814, 23682, 19463,
-    -30296, -20523, 32057, 3629, 26251, -18316, -226, -27977, -10863, 18536, -1763, -31423, 24190,
-    -16413, 24862, 289, 16226, -31936, -23870, 550, -5980, -13590, -29005, -25255, 3116, -31319,
-    -10863, 14939, -5105, 27174, 13654, 31903, -2639, 20594, 9030, -18317, -17957, 20082, 19720,
-    31387, -23609, 25118, -6907, 9031, 19464, 20335, 30619, 2759, 25218, 18797, -4181, 6201, -7161,
-    16636, 805, 8516, 26916, 26762, -20783, 23319, -6135, 8259, 19464, 19308, 29847, 960, 22392,
-    14427, -11377, -5621, -25923, -14204, 16739, -6391, 27688, 12627, 31645, -3924, 18796, 6202,
-    -22943, -25666, 8002, 187, -737, -9475, 7384, -10761, -12302, -5723, -26695, 24193, 15094,
-    30617, -28750, 19207, -17958, -7932, -8550, -24897, 22909, 15608, 30102, -29006, 18436, -18730,
-    -9474, -11119, -28753, 16484, 5072, 13396, 9288, -25512, -24384, 6205, -584, -2795, -12814,
-    1987, -18987, -26435, -28082, 2859, 31133, -14204, 8514, -14615, 11240, -11790, -9475, -4180,
-    -22070, 30361, 25376, -17959, -1764, -2638, -12562, -24380, -19857, 13139, -15643, 14582,
-    -9221, -3820, 4045, -7936, -12816, -3922, -24898, 27791, 19723, -25927, -15129, -24481, 18021,
-    -15130, 19211, -3825, 6716, 19211, 18277, 28818, -2121, 18792, 8001, -22168, -22072, 12371,
-    7130, 11596, 9801, -27309, -25413, 3634, -4694, -8965, -23094, -14974, 19563, -4847, 31801,
-    19049, -23866, 12269, -19502, -16413, -19085, 22133, -6133, -32706, 18792, -22839, 12784,
-    -17960, -14101, -15486, 28044, 3633, -17029, -21301, 18026, 13811, 23932, 28563, 4045, 24703,
-    19568, -4180, 7228, -6133, 18180, 3887, 13142, -31422, -26440, -1507, -10862, -20529, 24965,
-    21521, -26955, -14614, -24739, 18278, -15386, 19467, -3825, 6717, 19467, 18534, 29076, -1351,
-    19820, 9544, -19342, -17703, 19311, 18438, 30099, -26179, 20495, -13079, -2020, 1477, -7683,
-    -15641, -7004, -29785, 19312, 6103, 18275, 14943, -15743, -7940, 32163, -24738, 285, 31648,
-    -17028, 7225, -18983, 4818, -21560, -25922, -31162, 1057, 26506, -21653, -2798, 32160, -19854,
-    4656, -23868, -2892, 30871, 19309, 1220, 12624, 5174, -31163, 31642, -8447, -25766, 23418,
-    -11018, 28720, 9797, 30102, -9317, 12625, -5108, 23837, 10569, 26246, -12401, 5430, -14876,
-    6875, -16416, -17446, -17797, 21878, -3825, -31418, 21878, -17190, 20498, -5108, 7740, 18442,
-    18022, 28814, -2890, 17509, 6969, -24993, -26439, 6199, -3920, -6392, -18217, -8289, 30360,
-    14166, -4435, 806, -11279, 6103, -14101, -15648, -13429, 27534, 6455, -14972, -17442, 25217,
-    24350, -24639, -8194, -16513, 32159, 7741, -9061, -9990, -27211, -20626, 9029, -19502, 6103,
-    -22069, -23871, -29620, 3375, 31641, -14200, 8516, -13079, 11758, -10246, -6139, -65, -15129,
-    -22844, -21653, 12114, -16934, 11501, -14358, -10252, -8545, -27722, 21874, 10473, 23422,
-    26500, 707, 18282, 11594, -19340, -16671, 21875, 21524, -30807, -16933, -31675, 8258, -30812,
-    -6489, 19310, 5426, -24480, -27979, 5427, -5977, -9475, -23357, -16257, 16997, -7166, 26406,
-    10315, 29071, -9575, 10316, -6910, 19981, 3891, 16477, -28593, -21296, 7997, 3532, 2349, -1770,
-    17154, 6204, 15963, -26794, -20011, 11081, 7901, 9802, 10053, -28851, -27978, 802, -10091,
-    -18469, 28816, 27432, -18213, 804, -69, -8446, -16930, -8036, 31390, 14939, -1612, 3892, -6136,
-    15607, -220, 6972, 24602, 22139, -27210, 12780, -23865, -19500, -25770, 10831, -23099, 5328,
-    -27206, -30038, 25632, -13586, 3631, 7641, 2092, 1318, 21005, 13143, 25733, -9065, 7233, -9992,
-    14837, -4846, 1831, 14836, 6977, 13653, -27056, -23093, 6972, 1985, -734, -7420, 9952, -7159,
-    -5878, 5069, -10244, -13845, -6239, -29519, 21108, 9695, 21368, 22393, -3670, 9033, -3308,
-    23830, 10832, 26247, -10352, 5950, -12562, 11494, -11013, -7935, -843, -18723, -27981, -28599,
-    -990, 27532, -20889, -3303, -32607, -17805, 5179, -20786, 2499, -28232, 31643, 21261, -22322,
-    -9221, -13948, 32677, 10569, -4440, -3817, -16417, -2384, -28746, 26246, 15606, 31907, -26183,
-    23574, -12299, 2860, 8667, 1837, 2089, 22031, 14430, 28046, -4955, 13146, -225, 31026, 20856,
-    -21814, 17148, -14611, -5879, -2385, -18209, -29009, -29113, -2532, 25476, -24487, -8957,
-    23677, -32711, -18724, 5431, 5068, 809, -2794, 16376, 3637, 11598, -31941, -30288, -5364,
-    -17292, -32601, 6973, -7013, -9985, -25923, -17293, 12375, -13588, 17403, -6386, 2347, 14832,
-    6979, 13141, -26546, -23605, 6460, 1981, -1760, -8960, 8406, -10499, -11273, -2902, -24120,
-    29334, 24084, -21808, -7160, -10098, -26948, 19055, 11233, 20598, 22396, -3417, 9034, -3819,
-    24340, 10576, 25736, -10099, 5437, -13587, 10976, -12811, -10760, -4701, -25661, 26249, 19458,
-    -29774, -19241, -30400, 5950, 32416, -8555, 13661, -3565, 28711, 14946, -30294, 3268, 28310,
-    23163, 4553, 17516, 13654, -15751, -12297, 28818, -30400, -11527, 14684, 21772, 26766, -25923,
-    19203, -16155, -6133, -3928, -19496, -32604, 31796, -9988, 12373, 20745, 24193, -30033, 12521,
-    -26437, -23351, -31683, 1577, 26250, -19604, -2535, -31319, -15749, 9288, -15386, 12008,
-    -12558, -9475, -4183, -22838, 29590, 24602, -20269, -4848, -7267, -21040, 28049, 24859, -21298,
-    -5875, -9323, -23868, 22910, 17148, 31388, -26435, 22803, -12047, 1066, 7125, -225, -3046,
-    14835, 3374, 8519, 29998, 30102, -15126, -32710, 9285, 32676, -5725, 18281, 3121, -26029,
-    -31578, -1762, -15235, -25667, 14944, 7638, 13912, 11860, -21404, -18214, 15973, 16375, 23678,
-    30108, 6866, 28304, 25225, 6609, 23164, 19828, -3929, 6974, -6901, 18688, 2862, 11860, -32199,
-    -29264, -5873, -16522, -31320, 7749, -4701, -6133, -20779, -8042, 27535, 9548, -9328, -9216,
-    -28489, -18580, 9032, -19238, 8920, -19753, -20523, -21406, 14172, -16669, 16374, -9730, -3047,
-    6094, -6389, -9730, 2497, -16413, -23351, -21404, 11601, -18983, 10979, -17184, -15385, -14464,
-    26507, 2863, -17806, -24123, 14172, 8665, 13657, 12887, -20377, -16670, 18799, 20744, 30618,
-    -23864, 25114, -7676, 7748, 18432, 17510, 26252, -3414, 13913, 809, -32199, 25221, -16668,
-    26913, 1320, 18798, -27058, -17185, 11603, 13034, 15712, 19056, -12153, -2278, -24121, -7784,
-    24451, 6977, -15238, -17441, 22912, 24341, -27208, -12812, -21405, 22394, -8957, 32052, 14170,
-    -29004, 3782, 31389, 25481, 9950, 26506, 26766, 6352, 24193, 20855, -1873, 9802, -1762, 26655,
-    15713, -32603, 1726, 25479, 17770, -3672, 4663, -8445, 15089, -3047, 2607, 18686, 11603, 20854,
-    -13954, -3046, -26435, -10356, 18800, -992, -28603, 25996, -11787, -32457, 11347, -30035, 183,
-    25739, 16997, -3930, 2867, -9733, 12005, -8184, -4850, 5837, -9469, -12047, -2901, -25403,
-    28817, 22029, -24890, -11276, -17806, 26254, 33, -20634, -30801, 5431, -6500, -11269, -26694,
-    -19093, 9549, -18214, 10206, -18208, -16672, -16265, 22399, -2537, -27059, 25740, -9734,
-    -31170, 14432, -24898, 7895, -27203, -27468, 28970, -8699, 12111, 21517, 23683, -28496, 13293,
-    -25148, -20015, -27313, 8263, -26955, -842, 27539, 18792, -1100, 7237, -1769, 23573, 11349,
-    27272, -8810, 7752, -8453, 17405, -2014, 7996, 24087, 21373, -27471, 12008, -26173, -21560,
-    -29883, 3383, 31896, -12407, 8524, -10768, 15607, -6382, 2340, 14064, 5184, 12363, -29884,
-    -28741, -230, -10866, -22316, 25214, 21003, -30284, -16421, -28855, 9295, -26445, 701, 28827,
-    22643, 3785, 15463, 12363, -19860, -18462, 20074, 19462, 28826, -24388, 22033, -12810, 1828,
-    6869, -1759, -2541, 13551, 555, 6711, 25116, 21372, -26443, 12780, -24118, -18733, -25256,
-    11092, -21304, 7384, -24375, -24131, -31166, -217, 27013, -21400, -4843, 32153, -20886, 812,
-    -26959, -8807, 19060, 3368, -25513, 32681, 28, -14977, -25659, 17505, 9952, 16747, 19304,
-    -11380, -3042, -21817, -6754, 26000, 11851, -9325, -8695, -25415, -15750, 13151, -9739, 21517,
-    558, 15190, -31683, -27713, -1001, -10609, -22830, 24957, 20232, -31312, -18220, -31682, 4669,
-    31638, -11379, 9039, -8970, 17664, -2527, 8507, 23575, 21117, -27474, 11239, -27200, -22591,
-    -32451, -472, 25983, -22685, -7668, 28553, -27311, -9724, 21871, 29487, -24888, -2287, -9835,
-    -22832, 25984, 20492, -29515, -15908, -28338, 10835, -24133, 3788, -30800, 31894, 17924,
-    -25918, -14624, -23967, 16745, -13597, 19468, -4330, 8763, 20753, 19571, -31587, 4305, 28309,
-    26239, 5333, 21627, 20585, -7004, 3381, -9743, 9959, -10240, -6146, -321, -16922, -23108,
-    -23965, 8008, -21567, 2507, -29515, -32618, 19213, -23605, -10002, -18052, 27282, 3620, -18824,
-    -25404, 15443, 6105, 11348, 11588, -26535, -25147, 7734, -1093, -3815, -11028, 1478, -19750,
-    -24392, -28077, 2867, -31075, -12143, 11863, -5890, 21783, 5438, 21866, -22422, -11011, 26493,
-    31292, -17951, 7476, 5591, 2867, 2593, 21525, 13918, 29578, -5975, 13148, 1308, 30776, 21629,
-    -18996, 18698, -10498, 2080, 7903, -218, 1310, 17412, 8777, 19814, -20625, -10756, 27525,
-    -32447, -14612, 11592, 13556, 15458, 22129, -11374, 810, -17194, 192, -26947, 32151, 21524,
-    -21551, -6657, -12143, -28490, 18273, 6104, 14687, 14161, -20368, -15897, 22386, 22809, -29776,
-    -13852, -27563, 14686, -19507, 11245, -17697, -13082, -14969, 28050, 6451, -14970, -17954,
-    25727, 23838, -25151, -8198, -17539, 30619, 6195, -12657, -15642, 30097, 30520, -13844, 9281,
-    11758, 12114, 16477, -20625, -13073, 24188, 27435, -22583, -3054, -9317, -21041, 27273, 22552,
-    -24126, -9479, -17540, 30102, 4657, -14457, -18215, 24704, 22809, -26183, -11534, -21652,
-    24190, -5623, -30904, 20849, -17960, 18699, -7422, 3372, 12016, 7228, 11339, -30904, -27725,
-    -1254, -12914, -22328, 22134, 16127, 30101, -27468, 18698, -16675, -6393, -7003, -21301, 28817,
-    23581, -20788, -5878, -10601, -24129, 22136, 14328, 28814, -31064, 14071, -24643, -19242,
-    -27820, 10824, -25411, 1479, -31582, 27018, 11502, 30870, -31579, 15357, -23872, -16930,
-    -24992, 15964, -17188, 14587, -10251, -4080, 1480, -9995, -16930, -11370, 29841, 10056, -9829,
-    -7424, -25668, -17282, 14936, -10506, 19985, 1829, 13909, 31293, -27984, -4852, -17281, -29783,
-    10312, -3661, -1255, -13076, 1480, -19246, -25926, -29617, 2343, 30357, -17281, 5171, -19760,
-    967, -26698, 32155, 20757, -20274, -7423, -12397, -27470, 17764, 5850, 15964, 13909, -20108,
-    -13849, 23419, 25125, -24387, -7678, -16510, -31583, 9028, -6745, -5368, -20528, -10086, 27272,
-    8771, -13428, -12307, 31131, -30647, -7167, 19052, 27950, -25929, -6905, -16769, -31069, 8773,
-    -5976, -4854, -19755, -8289, 29842, 12628, -6491, -1769, -17185, -2379, -27469, 26763, 16125,
-    -30553, -23353, 28205, -3054, 16226, 29747, -27213, -6647, -17285, -31582, 7489, -7263, -7680,
-    -24123, -14973, 18535, -5619, 29746, 16222, -28493, 4560, -31838, 29333, 14326, -29782, -24381,
-    27948, -4339, 14684, 26920, -31582, -13587, -28849, 15450, -22069, 9702, -20017, -18985,
-    -22937, 15964, -15388, 16386, -6653, 1318, 10731, 4399, 6715, 27179, 26244, -20528, 21526,
-    -6653, 6458, 15871, 14679, 22135, -12657, 1573, -19244, -1606, -29010, 26760, 14071, 32671,
-    -26954, 21782, -13077, 290, 3534, -4082, -8963, 3021, -13592, -18986, -16768, 22132, -3052,
-    -30646, 24188, -14618, 25125, 2857, 20077, -27047, -14620, 15709, 16644, 24958, -32094, 8420,
-    -31069, -30809, 18958, -18991, -8193, -12139, -27472, 17765, 5594, 16219, 13653, -20364,
-    -13851, 22906, 24355, -25160, -9220, -19335, 30096, 2346, -17794, -22333, 16739, 9962, 19816,
-    21108, -9057, 4911, -12561, 7906, -11795, -12304, -8799, -28243, 20079, 7392, 20331, 19308,
-    -10342, 1571, -16931, 196, -24130, -32094, 24612, -14622, 1575, 2509, -3057, -8963, 3281,
-    -12567, -17701, -15223, 25727, 2089, -22420, -27216, 7230, -4431, -4342, -17443, -6230, -30813,
-    19823, 4821, 17504, 13655, -18567, -12052, 25992, 29750, -16679, 3891, 3278, 284, -5619, 10731,
-    -2029, -479, 13558, 5939, 10572, 32576, -29528, -6133, -19596, 32667, 3891, -12658, -15907,
-    27536, 27949, -16936, 1323, 963, -4600, -13327, -1607, -21819, 32420, 26921, -12825, 4151,
-    7902, 5423, 3380, 25378, 22128, -27975, 10729, -23876, -23092, -30648, 5166, 30364, -13686,
-    9793, -13328, 12786, -7427, -4332, 4562, -6656, -11529, -2120, -20534, -32089, 28978, -9741,
-    9547, 16127, 19044, 25481, -4691, 13905, -477, 29748, 22386, -22837, 15870, -13852, -7673,
-    -5205, -19763, 30878, 27435, -13853, 3637, 6360, 3367, -219, 19468, 12619, 22397, -14200, 1312,
-    -22323, -4691, 31382, 17256, -323, 9538, -221, 25892, 18276, -30548, 4304, 31897, 27021, 9958,
-    29584, 30362, 10986, -31583, -29777, 20496, -16421, -5361, -5462, -17963, 32676, 31033, -8712,
-    12631, 20239, 26240, -28747, 13814, -21563, -17439, -22937, 18530, -13842, 20498, 26, 11344,
-    27180, 31894, -15642, 31807, 9535, 32417, -8029, 17503, 549, -31674, 27526, -13073, 30008,
-    10050, 31388, -8543, 15705, -1509, 29751, 21102, -23098, 13560, -16678, -11533, -12911, -31584,
-    12626, -3403, 1828, -9735, 7649, -9481, -9992, -4173, -21560, 31643, 25383, -15650, 1318, 1224,
-    -4599, -11790, -1089, -20019, -29523, 31294, -5370, 17509, 27439, -27473, -8449, -20877, 29325,
-    33, -20878, -27730, 8258, -3917, -2800, -15387, -2632, -25159, 29075, 19471, -23875, -13074,
-    -21649, 23928, -6392, -32700, 19559, -21556, 13304, -15137, -10248, -10340, -27473, 19308,
-    7136, 19559, 18280, -12397, -1258, -22070, -8028, 28298, 11855, -9828, -5369, -23612, -13426,
-    21103, -739, -29617, 27785, -9992, -32443, 15706, -24642, 6365, -25672, -27212, 27697, -6911,
-    12881, 21015, 26756, -25670, 15876, -16679, -8708, -10852, -26445, 20334, 8680, 22129, 22904,
-    -5713, 10051, -3568, 21273, 10565, 24188, -15993, 800, -22588, -6998, 28300, 13907, -8284,
-    -2283, -17962, -5200, -31067, 21874, 6108, 20077, 18790, -11369, -740, -19504, -4944, -32608,
-    20589, 3537, 15966, 12108, -21907, -17959, 18020, 15616, 25731, -31839, 9448, -30296, -28498,
-    22042, -14106, 31, 1481, -6139, -12563, -3402, -23615, 30614, 22299, -20018, -5880, -10598,
-    -23873, 22905, 14588, 30098, -29010, 16388, -19762, -11534, -16251, 30611, 6200, -13425,
-    -14365, 29331, 30266, -12824, 8772, 11504, 13391, 16225, -20365, -11025, 25221, 29751, -17194,
-    3377, 1994, -1260, -8702, 5849, -9228, -12814, -6487, -25676, 23938, 14073, 31636, -29262,
-    17929, -17453, -9215, -11113, -26448, 18285, 7648, 19813, 17771, -12142, -747, -22579, -7516,
-    29066, 11860, -8545, -3316, -21551, -8802, 28553, 10061, -10602, -7171, -27718, -18569, 12619,
-    -15640, 13300, -8970, -5616, 1735, -10256, -18466, -12657, 28038, 5436, -15742, -16681, 22913,
-    22552, -26191, -13839, -23965, 21612, -12298, 25124, 6706, 22140, -20880, -5116, 29850, -24992,
-    -1518, 29336, -21908, 1053, -30290, -13427, 15189, -7674, 23325, 9021, 23166, -17539, -1259,
-    -27978, -13427, 17246, -5362, 27694, 15447, -31320, -63, 27013, 18025, -4433, 6197, -7162,
-    15356, 544, 7230, 24094, 23674, -26438, 13557, -20531, -15644, -20110, 22132, -6649, 31548,
-    17249, -25154, 8161, -24643, -24897, 31806, -742, 22649, -27819, -12565, 16482, 19727, 29069,
-    -25410, 19214, -13081, -2793, -319, -9997, -19241, -13683, 25727, 3119, -20880, -24646, 10830,
-    2250, 6195, -736, 21269, 13648, 25992, -10086, 8766, -9990, 14587, -2799, 3118, 16129, 12107,
-    19566, -18053, -5883, -32606, -22679, 2856, -28238, -9572, 20076, 2089, -27306, 32669, -3308,
-    -20110, -31068, 5688, -9060, -11022, -29007, -23709, 5170, -27209, -5719, 24703, 10314, -13944,
-    -11535, 31132, -29364, -6138, 21109, 31546, -20531, 1835, -1866, -7681, -18982, -9833, 29071,
-    9803, -9577, -7680, -26692, -17287, 13652, -12815, 17667, -3054, 5433, 19209, 16992, 27021,
-    -4693, 14423, 550, 32058, 24703, -17955, 23578, -2283, 12115, 26662, 31127, -16927, 30775,
-    6198, 28048, -14715, 5428, -17957, 4047, -21815, -26438, -31933, -741, 24192, -25765, -9223,
-    21623, 28720, -22588, -3049, -9317, -19761, 27278, 23837, -21561, -7160, -12401, -26701, 16999,
-    6874, 16733, 14172, -18056, -11024, 26766, 32317, -13338, 9034, 12527, 14676, 17258, -16772,
-    -6655, 32164, -23197, 1827, -31060, -12403, 14678, -7416, 24092, 9281, 23938, -15487, 801,
-    -23866, -6235, 27530, 12115, -8806, -4852, -22838, -10605, 23933, 4148, -20115, -24127, 11859,
-    5328, 9027, 4920, 31542, 28302, -15127, 30515, 7228, 28563, -12405, 7743, -13587, 11497,
-    -10505, -8189, -1354, -17958, -28492, -29110, -482, 26764, -21914, -3566, 30876, -20886, 1575,
-    -28236, -9321, 19309, 1063, -27569, 30360, -6390, -23971, 26505, -6647, -28083, 22136, -14872,
-    24604, 1062, 16996, -30138, -21812, 4661, 445, -3565, -12045, 1731, -18729, -25923, -27567,
-    3631, -32606, -11890, 12370, -8191, 21264, 4403, 17252, -26796, -18214, 11856, 10983, 14169,
-    16482, -17545, -9733, 29333, -28596, -7934, 20081, 29487, -24383, -4077, -11120, -23612, 21624,
-    15608, 28817, -30291, 16122, -22584, -15642, -20886, 20593, -9218, 28460, 10827, 30617, -7007,
-    14940, -738, 31287, 21879, -20785, 17924, -11276, -2023, 3532, -6907, -11790, -2122, -22327,
-    32672, 26920, -14104, 4146, 6873, 2859, 1062, 20751, 13653, 25734, -9319, 8000, -9734, 15097,
-    -3308, 3374, 16896, 11855, 20336, -16515, -4850, -29780, -17800, 9286, -16674, 9443, -15901,
-    -14618, -13944, 28304, 6200, -14202, -16672, 26247, 26405, -21299, -3565, -8034, -20014, 28818,
-    25634, -19244, -2536, -4950, -15646, -29521, -28337, -483, 27791, -21398, -1768, -32091,
-    -17029, 8256, -17443, 7644, -17959, -18985, -20369, 18022, -10762, 23580, 4658, 20078, -24480,
-    -12562, 20079, 23837, -29525, -14103, -27563, 15965, -19758, 12273, -15390, -11277, -10857,
-    -30039, 16480, 2507, 11082, 5429, 32576, 30100, -11020, -30646, 15965, -22586, 9190, -21301,
-    -20016, -25762, 11853, -21559, 5850, -23614, -25414, 31808, -1512, 22646, -29102, -14106,
-    14423, 15617, 22645, 30357, 2767, 25729, 20591, -3916, 9025, -2797, 21528, 11081, 24959,
-    -14196, 2858, -18733, -575, -27468, 30098, 17930, -25413, -14878, -25246, 17507, -14879, 17418,
-    -5367, 4911, 14590, 11596, 19046, -19849, -8708, 29584, -29615, -7681, 20590, 27954, -24387,
-    -4339, -13681, -25415, 18535, 8421, 19561, 20077, -10598, 1829, -16419, 711, -23358, -30297,
-    26926, -10766, 8255, 12790, 13650, 18535, -18051, -7167, 32413, -24990, -228, 32413, -18051,
-    6712, -18989, 3024, -23615, -28241, 28725, -7167, 13908, 21786, 28299, -23101, 19988, -10253,
-    1830, 6623, 1313, 31, 16389, 9280, 17764, -23447, -12823, 21106, 23328, -27987, -12819, -26016,
-    19816, -14105, 20246, -745, 11596, 25386, 30352, -17703, 26929, 2596, 21875, -26785, -11540,
-    19306, 22046, -30559, -16418, 32584, 9791, -30811, -6995, 21100, 6455, -23701, -23876, 10054,
-    714, 4138, -3054, 15364, 5680, 13394, -32182, -25418, 31, -10852, -17451, 29073, 26157, -16681,
-    1061, -830, -6400, -15645, -7255, -29530, 20336, 5852, 19558, 16995, -13938, -3829, -26182,
-    -14966, 17503, -5879, 26669, 13905, 32414, -4172, 21102, 8770, -20364, -18734, 18023, 14845,
-    25728, 32158, 7906, -32612, 32415, 15359, -24902, -17958, -27560, 12878, -22842, 5337, -24645,
-    -27468, 28468, -6141, 14167, 23071, 30353, -20272, 24871, -2287, 14424, 26927, -30815, -12048,
-    -28328, 18530, -17703, 15107, -8971, -2025, 3285, -5116, -9736, -827, -16938, -25670, -28583,
-    4908, -31325, -12392, 15189, -4854, 24360, 12876, 29841, -8794, 14162, -2028, 26159, 17246,
-    -29271, 2001, 31381, 26242, 6113, 25470, 24443, -1598, 15705, 6967, -28584, -28757, 800,
-    -13422, -19762, 24702, 19475, -28244, -16419, -30383, 11849, -25929, 201, -32613, 25729, 7397,
-    26241, 26243, 1229, 20587, 14421, -16248, -8712, -32610, -27042, -1002, 29842, -22416, 541,
-    -29270, -14449, 14677, -7168, 21789, 7481, 22130, -21645, -6911, 29840, -28327, -5883, 24186,
-    32583, -15907, 9281, 7910, 10051, 10566, -30384, -26958, 544, -11624, -18220, 27787, 24357,
-    -20277, -4081, -9568, -20534, 27274, 21530, -23362, -9992, -18819, 30095, 3116, -17535, -21049,
-    18537, 12534, 24441, 28560, 2511, 24441, 18537, -7513, 4139, -11534, 7651, -10768, -11277,
-    -7255, -25417, 24704, 14333, 32152, -27211, 19731, -14110, -2795, -2115, -11540, -22070,
-    -18820, 18016, -8964, 23587, 7993, 23675, -19333, -2544, -29782, -17791, 11078, -14363, 11251,
-    -9997, -6652, -2114, -15651, -25670, -26786, 6195, -28241, -7511, 22644, 7483, -20619, -20276,
-    16736, 11506, 21102, 24703, -4686, 12622, 31, 27953, 20589, -24899, 10991, -21303, -18217,
-    -24475, 15449, -16676, 13819, -10252, -4339, 455, -11024, -18474, -14708, 25214, 2601, -22676,
-    -27215, 7485, -4430, -4341, -16931, -5972, -30298, 21106, 6364, 20075, 18279, -11627, -999,
-    -20786, -6230, 30870, 16480, -2376, 6199, -4338, 17671, 5428, 15194, -29104, -21815, 6457, 708,
-    -741, -8193, 6877, -8966, -10249, -3660, -21559, 32157, 26153, -14621, 3117, 4307, 29, -4080,
-    11760, 30, 3630, 19470, 15450, 26760, -7516, 11339, -4338, 22811, 10568, 25474, -13684, 3630,
-    -17704, 1737, -24127, -30040, 26924, -11021, 7998, 12788, 12881, 17764, -19081, -9222, 29073,
-    -29875, -8452, 18794, 26152, -27985, -10248, -22678, 25215, -5879, -30645, 21617, -17188,
-    19729, -4855, 6714, 17159, 16733, 25732, -7771, 10566, -5366, 20500, 7739, 20334, -22163,
-    -9224, 25989, 32065, -14622, 9028, 9962, 11850, 13397, -24734, -18477, 13655, 10989, 17504,
-    19823, -12399, 29, -20785, -4946, 32155, 18794, 1479, 12623, 5687, -31161, 32412, -7420,
-    -24479, 25987, -7163, -30647, 20076, -18986, 16900, -9481, -1252, 5078, -3570, -7163, 5078,
-    -9225, -12817, -6487, -26444, 23935, 13302, 30097, -30807, 15101, -22846, -16415, -23706,
-    18275, -13846, 19729, -1258, 10056, 24098, 27269, -22584, 19730, -9484, 1576, 7393, 2339, 1062,
-    18701, 13133, 23164, -13939, 2340, -20014, -2374, -29528, 25219, 11247, 29326, 32158, 11504,
-    -29014, -25925, 25897, -6913, 10314, 18701, 22385, 32416, 4566, 30352, 26248, 6365, 25983,
-    23678, -575, 16218, 6973, -26790, -26702, 3119, -7773, -11539, -28492, -24221, 5938, -27208,
-    -5460, 25728, 11343, -12400, -8197, -29777, -21909, 6710, -24124, -1349, 32668, 22394, 5847,
-    20846, 17768, -10602, -485, -20012, -4177, -31839, 20595, 5332, 18277, 14684, -16000, -8966,
-    31390, -26537, -2798, 27021, -24738, -5368, 26250, -28079, -9224, 18798, 26149, -27729, -11015,
-    -22424, 24957, -6903, -31162, 20331, -20011, 16385, -10511, -3562, 1993, -8199, -15641, -8030,
-    -30301, 17770, 3535, 14675, 8775, -26021, -23876, 5949, -1607, -2544, -13841, -65, -20791,
-    -30546, 30519, -6657, 13917, 23580, 31122, -20779, 26408, -747, 15716, 31034, -24906, -4073,
-    -12914, -23107, 19315, 12529, 25724, 28053, 4562, 26495, 20857, -1864, 12618, 554, 29747,
-    23926, -22063, 18183, -10000, -2273, 4048, -4346, -10498, 1222, -15141, -24119, -23450, 12102,
-    -21293, 6620, -20538, -23863, -28846, 6962, -31574, -9057, 18785, 38, -30903, 28551, -12042,
-    32064, 13902, -29005, 453, 30864, 21882, 2766, 18528, 11859, -19594, -13855, 22397, 24097,
-    -24907, -10500, -20107, 29064, -734, -21906, -28505, 5435, -7515, -7945, -25405, -17795, 16471,
-    -11014, 20757, 3878, 15200, -31158, -21823, 2865, -3403, -6403, -19751, -10599, 29321, 8777,
-    -11628, -8716, -30544, -23450, 5677, -27718, -6231, 25467, 9291, -14713, -11542, 29081, -31932,
-    -8716, 14433, 21782, 30605, -23604, 22811, -6148, 5953, 15871, 16469, 21630, -11372, 4648,
-    -17179, 3535, -19254, -26174, -29618, 4134, 29852, -15740, 8247, -17438, 6620, -16683, -20008,
-    -21136, 18527, -12299, 21528, 3364, 15457, -31415, -21823, 2608, -3660, -6917, -20522, -11884,
-    27265, 5436, -17025, -17454, 20857, 19213, -31076, -22063, 27952, 279, 18286, -31416, -18740,
-    5180, 2251, 1821, -6129, 11503, -492, 811, 16129, 11330, 17259, -21137, -9488, 24456, 30778,
-    -15657, 4411, 4820, 3876, -2015, 17671, 10301, 17517, -21908, -9746, 23172, 29236, -18228, 43,
-    -2120, -7177, -20517, -11629, 28290, 5441, -15485, -15144, 23432, 24608, -22341, -9464, -15485,
-    -29794, 8527, -4692, -1266, -17688, -2379, -25167, 26260, 17668, -26453, -20515, -30648, 9528,
-    -32595, -6747, 21094, 2872, -24995, -27223, 1588, -8805, -12317, 32429, -28594, -1266, 23691,
-    -26281, -7435, 19580, 28975, -21571, -5092, -9833, -19515, 23693, 21008, -25170, -16657,
-    -25252, 19292, -18200, 17412, -4868, 49, 11757, 7726, 6988, 31289, -31339, -12545, -27564,
-    21347, -18457, 18955, -3583, 3132, 15870, 14922, 18552, -15742, -1526, -29508, -14714, 16979,
-    -9976, 23323, 9012, 20350, -19854, -4095, 29602, -23709, 1303, 31400, -16513, 10042, -17946,
-    8417, -14374, -17432, -15741, 27518, 302, -21396, -25939, 6471, -2893, -1523, -16146, -1094,
-    -22340, 30372, 24607, -15402, -2781, -1608, -9234, -22827, -15486, 22378, -5094, -31677, 23920,
-    -19487, 20753, -3580, 5443, 18183, 19036, 25489, -4691, 15953, -469, 31804, 26490, -18717,
-    23838, 276, 12894, 29235, -28252, -10493, -22680, 27518, -6638, -28591, 25462, -14349, 26923,
-    7729, 23687, -18310, 277, -28743, -12656, 18782, -4585, 30007, 20067, -25917, 9961, -21311,
-    -21805, -27561, 10815, -26946, -576, 32404, 21628, 4307, 20325, 14432, -14969, -6147, -31571,
-    -21908, 6447, -25661, -3404, 30606, 17002, -1863, 9019, -3045, 22039, 12874, 24968, -11629,
-    6964, -14355, 8675, -12055, -13070, -9315, -28760, 17771, 5077, 16473, 11860, -21138, -15653,
-    18800, 19212, 31892, -24377, 23325, -6917, 6208, 15357, 15700, 20857, -12914, 1823, -21036,
-    -3148, -30559, 21884, 7646, 23155, 20856, -5205, 9021, -6130, 19211, 6451, 15972, -26793,
-    -17451, 11347, 10472, 15189, 15716, -18056, -8970, 28310, -29621, -7686, 17774, 26663, -27219,
-    -11011, -21910, 26495, -5871, -28592, 24953, -13839, 27179, 7220, 24454, -17797, 282, -27205,
-    -10858, 20843, 295, -28078, 31123, -6901, -24994, 27011, -7929, -30134, 20843, -18981, 17927,
-    -7429, 553, 9445, 3623, 3123, 23066, 19814, -32601, 3534, 30094, 23683, 4562, 21870, 16487,
-    -10859, -1003, -21807, -6490, 30609, 14174, -4178, 3111, -10757, 8930, -8712, -9728, -1865,
-    -18478, -30288, -32191, -3829, 19571, 32317, -20278, 1839, -1609, -6656, -18465, -8291, 31895,
-    13404, -3152, 3112, -9985, 10213, -7168, -6901, 3017, -11024, -17952, -12146, 28298, 6207,
-    -13946, -14879, 26511, 28717, -17193, 1069, 1217, -4600, -13838, -1353, -22076, 31652, 26661,
-    -13853, 2098, 5586, 1054, -4071, 14324, 3368, 7237, 27945, 28297, -19749, 25633, -1002, 14176,
-    30259, -27731, -8183, -18829, 31894, 2355, -13947, -18222, 22402, 21520, -27989, -17434,
-    -28338, 13389, -25659, 4816, -27218, 32424, 22036, -17196, -6126, -6492, -18738, 29341, 27433,
-    -14627, 1586, 4045, -235, -7666, 9185, -4347, -6638, 6101, -6147, -11521, -838, -17969, -30282,
-    -31421, -1778, 20862, -29622, -14115, 10069, 13040, 17754, 19064, -11633, 1821, -21287, -2381,
-    -29533, 22147, 9955, 26237, 24717, 2759, 21611, 12895, -13690, -6660, -32080, -21400, 6191,
-    -26684, -3153, 29579, 14951, -3411, 5165, -9722, 13294, -3059, -1241, 13551, 5680, 8011, 31541,
-    -32614, -12293, -27312, 19301, -18976, 17665, -7686, -1242, 8413, 796, -1757, 16380, 7993,
-    13663, -26540, -19507, 8524, 6613, 8507, 4155, 30257, 27782, -18462, 26660, 1568, 17518,
-    -29110, -18222, 7239, 6613, 7222, 2870, 27687, 23927, -24887, 16636, -14881, -9466, -6752,
-    -22848, 24716, 19463, -27732, -19489, -29881, 9791, -31055, -3924, 23927, 9038, -14976, -12568,
-    26772, 31799, -13340, 6984, 11495, 12104, 12124, -23458, -17709, 12639, 13036, 19300, 20606,
-    -7525, 6451, -12549, 12008, -7171, -6894, 4041, -9228, -16917, -8295, -31587, 13924, 443, 7992,
-    -3296, 22546, 12875, 23946, -10865, 6451, -15634, 8668, -13596, -16148, -12149, 30609, 7240,
-    -9837, -9227, -30539, -21916, 6451, -26685, -2384, 29582, 15978, -1871, 6967, -6125, 18947,
-    5682, 13664, -28085, -21561, 4670, 1470, -1001, -11007, 6353, -11539, -16661, -10095, 31895,
-    10325, -4956, -1772, -18203, -1615, -26958, 25488, 17146, -30042, -24371, 29483, -2029, 15979,
-    32310, -24132, -3553, -9325, -19763, 24718, 23315, -24133, -12548, -18576, 27782, -2525,
-    -22174, -31329, 303, -12666, -18993, 21892, 21259, -28760, -19486, -30141, 9534, -32337, -4698,
-    21871, 5443, -19862, -21049, 12640, 10207, 16217, 14439, -16265, -8456, 28575, -26802, -4858,
-    21636, -30143, -14882, 8016, 12005, 13646, 13156, -19864, -13083, 19839, 25626, -26191, -13315,
-    -20891, 25210, -8432, -30143, 20841, -21797, 17660, -10257, -5348, 3011, -8202, -17941, -7783,
-    -31589, 13414, 441, 7990, -4320, 22030, 11845, 21380, -13951, 1309, -24882, -5213, 29066,
-    11613, -6242, -1260, -19742, -2387, -28759, 22150, 12262, 27782, 27804, 8921, 30095, 26776,
-    10206, 30352, 28318, 12005, -31843, -32078, 20230, -18223, -10489, -10097, -26961, 15983, 7893,
-    17501, 12899, -16266, -9742, 26778, -29630, -8972, 13929, 23827, 31891, -22823, 27683, -1006,
-    13672, 31281, -26193, -8173, -15751, -29534, 6991, -3673, -2548, -19481, -3159, -28505, 20612,
-    11233, 25980, 23953, 3523, 21611, 11874, -12926, -6917, 32178, -21150, 5163, -29247, -4959,
-    25210, 6991, -13955, -13084, 24982, 31278, -15141, 2367, 6862, 3364, -3545, 19455, 9790, 15730,
-    -20381, -10771, 20614, 29478, -21309, -5857, -7531, -19253, 24727, 25109, -21310, -10482,
-    -12157, -28249, 10850, 2492, 7732, -4057, 23310, 13643, 22928, -9330, 7733, -15367, 12002,
-    -9230, -11254, -849, -17968, 32694, -31175, -4347, 15989, 31277, -23880, -6884, -11129, -23623,
-    16504, 12772, 23666, 22158, 179, 16727, 2626, -26293, -29277, -4570, -13957, -24137, 12907,
-    8916, 16213, 10594, -18584, -13600, 18562, 25107, -27222, -16905, -24237, 19039, -19733, 19197,
-    -5891, -1485, 12515, 5675, 3655, 29220, 27520, -23331, 23824, -4863, 4426, 19454, 18525, 23444,
-    -3677, 14157, -4056, 29991, 20325, -29500, 10716, -24394, -27958, -32717, -750, 17789, -28862,
-    -16428, 5711, 9174, 9530, 4169, -31947, 32403, -14335, -27578, 18268, -23845, 14314, -14886,
-    -15107, -10358, -30820, 9823, -1107, 3106, -12537, 10715, -7432, -11508, 1206, -15657, -29241,
-    -25008, 5932, 31925, -7789, 18526, -3799, -30919, 25208, -19991, 24852, -750, 9822, 28707,
-    -32362, -17935, -30917, 11329, 31923, -2904, 23409, 6480, -16012, -15142, 20102, 24595, -26194,
-    -15879, -22693, 21609, -15109, 25625, 5161, 17016, -24234, -12573, 14704, 21256, 30860, -27445,
-    22285, -10005, -2001, 6865, 19, -7142, 11748, -495, -2773, 15603, 7730, 9563, -29373, -24910,
-    -2773, -8813, -16686, 26012, 28196, -16173, -2258, 440, -6408, -20248, -8041, 32657, 10336,
-    -3673, 1818, -15880, 4809, -15916, -25132, -22433, 13126, -23077, 8665, -19257, -24362, -25259,
-    11070, -27704, 1727, -30822, 22926, 10465, 28546, 25496, 6867, 27518, 20870, 1213, 17238, 4936,
-    -25002, -24911, 1853, -4443, -7435, -25903, -14723, 20065, -8684, 29996, 16467, 32693, 2240,
-    30088, 18558, 1726, 15439, 3395, -28087, -29537, -6114, -17036, -27995, 6480, -2645, -1266,
-    -17936, -332, -23368, 27811, 23313, -19257, -10225, -10612, -25682, 14962, 8406, 18523, 12649,
-    -15239, -7435, 28327, -25519, -2038, 23444, -25005, -6151, 19590, 32564, -17717, -199, 1210,
-    -3325, -16905, -1360, -22600, 26786, 23056, -19774, -11764, -12668, -28512, 9311, -76, 4900,
-    -10222, 14059, -754, -1741, 16886, 10555, 12651, -22950, -14889, 12652, 17399, 25461, 27815,
-    7376, 30601, 22932, 7633, 25975, 18563, -1363, 12355, -4054, 27936, 19037, 32183, 5320, 32658,
-    23188, 9946, 28289, 23445, 5834, 24434, 15478, -5989, 4389, -16135, 7890, -13345, -19990,
-    -13955, 26491, -2000, -21665, -28765, 571, -8559, -13088, 29099, -29890, -5636, 14965, 28964,
-    -26197, -12534, -19096, 29316, -5081, -21666, -31337, -2768, -14470, -21828, 13938, 12001,
-    21349, 18050, -6247, 6958, -14590, 12514, -6921, -9963, 3262, -11291, -23584, -14985, 22377,
-    -8164, -31433, 21349, -25384, 15856, -14118, -13818, -8046, -26454, 15481, 9173, 20064, 13682,
-    -11645, -2809, -30009, -12673, 18009, -10220, 27934, 12869, 25503, -7019, 13384, -8936, 24593,
-    10557, 20105, -14729, 21, -29498, -9332, 21096, -3027, -27322, 29577, -12536, -28350, 19040,
-    -23845, 15341, -14114, -13564, -7533, -26707, 16506, 10200, 21096, 16506, -7534, 3107, -18962,
-    4546, -20281, -30270, -30406, -1006, 19589, -26808, -12829, 11109, 18681, 24180, 28071, 7116,
-    29577, 21903, 6345, 22638, 14193, -8305, 23, -22817, -2394, -31331, 17276, 6601, 17757, 9823,
-    -17301, -13598, 19847, 26904, -24650, -12792, -16787, 30092, -1741, -16530, -24136, 9825, 6600,
-    10560, 2115, -31951, 29835, -17161, -32207, 10303, 28842, -5736, 16986, -3541, -31436, 24439,
-    -21532, 23307, -4346, 4426, 20480, 19041, 24986, -1109, 17757, 2113, -25011, -29018, -3284,
-    -11647, -21051, 18048, 17908, 29836, -32582, 18165, -20537, -17162, -17044, 25210, -6625,
-    -26296, 26495, -14592, 32558, 11846, 29869, -3166, 20328, 2627, -21671, -25419, 3656, -598,
-    -3573, -18961, -1369, -26960, 22417, 16878, 32665, -30783, 23047, -14111, -6110, 945, -11285,
-    -25385, -15760, 18271, -12535, 26646, 7991, 19847, -16788, -3317, 30641, -17302, 6964, -24873,
-    3002, -28246, 25757, 18422, -27732, -23845, -30922, 4394, 24728, -15759, 2339, -27445, -4451,
-    26755, 8279, -9592, -8198, -32070, -19358, 7223, -26160, 1974, -31071, 22414, 12254, 27783,
-    26012, 9170, 28297, 23442, 7114, 23671, 16760, -4195, 5425, -12796, 13795, -6397, -6628, 8141,
-    -5883, -11768, 3515, -15393, -25903, -20386, 12107, -22049, 10969, -18220, -21021, -18586,
-    18789, -13568, 25876, 5168, 17529, -22184, -11795, 17787, 26647, -27987, -15110, -22697, 20844,
-    -15368, 25621, 3368, 15729, -26294, -17450, 8277, 11228, 12620, 10333, -22183, -18735, 10848,
-    12769, 16732, 15731, -12418, -3828, -30016, -13189, 15191, -11769, 24077, 5168, 15730, -23983,
-    -15393, 12390, 17908, 23158, 27296, 5829, 25985, 18044, -597, 10052, -4316, 26646, 14935,
-    28066, -1625, 18791, 3651, -21929, -25928, 3909, -599, -4596, -18965, -2141, -29011, 20614,
-    13279, 25988, 25497, 7625, 25217, 19072, 429, 11596, -1746, 31270, 21619, -26162, 16878,
-    -17189, -13826, -9850, -31581, 10590, 430, 3115, -9971, 14565, -3567, -2518, 15336, 4658, 6734,
-    -32724, 31386, -14598, -27583, 15195, -25393, 10968, -22585, -24622, -26297, 6457, -32590,
-    -5223, 19308, 1335, -23728, -30808, -2006, -11649, -22070, 18812, 18163, 28560, -31818, 18163,
-    -22070, -16912, -17817, 22392, -8431, -30410, 18280, -24880, 14566, -18729, -16913, -14732,
-    25476, -2007, -21157, -31579, 50, -10364, -18729, 23438, 25874, -24384, -11771, -14990, 30615,
-    2365, -11136, -16931, 23954, 28443, -21044, -6372, -5996, -20273, 25497, 26644, -21045, -8427,
-    -8052, -24129, 19330, 16877, 28557, 31409, 16106, -25671, -23590, -27841, 6455, 30380, -7281,
-    15194, -5858, 30756, 16993, -31302, 7112, -32095, 27038, 16364, -30039, -27190, 29472, -5624,
-    10333, 25874, 28557, -24620, 24847, -7424, 3908, 17650, 13908, 18043, -12420, -2283, -28218,
-    -9336, 20077, -2775, -27069, 27787, -12798, -29382, 15451, -27191, 9426, -25670, -29504, 31272,
-    -6138, 11874, 26646, 30870, -21280, 30245, 1315, 18555, -25011, -14106, 13159, 19964, 25473,
-    32177, 13025, -27984, -28219, 29988, -5882, 10846, 25619, 29070, -24107, 25363, -6140, 5963,
-    20479, 19047, 26266, 433, 19304, 6477, -19100, -20018, 12903, 13796, 19304, 19585, -5737, 6198,
-    -13055, 14309, -6652, -5859, 8655, -5110, -9971, 6085, -11536, -18966, -9592, 29328, 6221,
-    -8822, -10251, 32693, -21929, 3114, -32330, -8051, 17250, -4317, -31438, 21876, -22822, 20220,
-    -10507, -3803, 6856, -4853, -11513, 4800, -14363, -23078, -16531, 18277, -11770, 27417, 7997,
-    22154, -14475, -227, -27962, -7279, 22390, 1851, -20130, -26184, 5707, 944, -1255, -13826,
-    6085, -15391, -22821, -17302, 17763, -13055, 25618, 4913, 17271, -22442, -12821, 16758, 24847,
-    -31326, -19994, -30665, 7482, 29093, -8306, 13137, -8430, 25362, 9282, 21639, -13960, -227,
-    -27192, -6764, 23675, 3906, -17045, -21044, 14187, 14309, 20591, 21640, -2140, 11340, -4061,
-    28444, 16223, 31662, 3515, 27017, 17527, 174, 9541, -3291, 27160, 15709, 30119, 1203, 23162,
-    11615, -9849, -6650, -29249, -14989, 12883, -14601, 19193, -4079, 2619, 19706, 13655, 20866,
-    -9850, 2091, -19999, 3258, -25666, 30888, 26132, -17186, -3550, 175, -11790, -24110, -15245,
-    17766, -9975, 28446, 10056, 26262, -8563, 9029, -11775, 18165, -2536, 3389, 21763, 16482,
-    26005, -2139, 14941, 562, -28868, 28305, -12803, -29124, 14684, -26425, 9170, -26180, -28995,
-    31016, -6905, 12126, 25876, 29332, -22313, 27419, -3565, 11869, 28704, 32158, -16659, -29892,
-    10570, -31052, -337, 25477, 13410, -6249, -1765, -19744, -1109, -29778, 22919, 13797, 27791,
-    29858, 12769, -31834, -30795, 23307, -16158, -4837, -595, -14102, -26682, -20384, 9800, -22314,
-    7887, -23097, -26940, -29892, 34, 24203, -21154, -5877, 27030, -24238, -6134, 23689, -27836,
-    -12817, 13153, 20481, 25219, -31566, 13799, -26182, -24113, -30405, 2603, 26259, -16784, 805,
-    -27199, -6504, 22908, 5184, -17299, -21040, 15722, 15083, 21880, 25488, 2233, 18796, 9554,
-    -16786, -16157, 20863, 25106, -28237, -14861, -22953, 19052, -15376, 23566, -481, 11610, 31019,
-    -31322, -11778, -23465, 21878, -12807, 28451, 7229, 24715, -14212, 1833, -23089, -1876, 31646,
-    19060, 4806, 14941, 9037, -21923, -21811, 10837, 8917, 10829, 8781, -26036, -26180, 2100,
-    -3935, -10760, -26170, -16785, 13656, -14349, 19453, -4077, 4156, 20224, 15455, 24459, -5477,
-    9802, -6896, 23051, 6975, 19061, -19355, -9474, 25487, -29378, -12816, 11867, 19452, 22394,
-    30371, 7630, 29076, 25231, 9172, 25478, 23175, 3518, 17768, 9811, -17557, -16671, 19578, 23307,
-    -31321, -19744, -30920, 6202, 29343, -9846, 10572, -10750, 20223, 293, 9296, 29989, 30105,
-    -16662, -31948, 7746, 30369, -7276, 13658, -4584, 29219, 15200, -31827, 3519, 27793, 20602,
-    3005, 14172, 6467, -24752, -27720, 2099, -5221, -12557, -28998, -21155, 5948, -26172, 177,
-    30106, 19318, 4289, 14172, 7496, -23468, -25407, 5441, 690, -3305, -13835, 3261, -19754,
-    -27713, -27322, 1321, 28570, -15500, 3635, -22575, 1206, -30804, 25228, 14570, 30363, -31313,
-    19196, -21552, -13066, -14728, 28307, 2869, -14215, -20781, 19575, 19195, 29335, -27971, 21509,
-    -15642, -5354, -851, -15385, -27456, -22696, 6204, -27457, -1108, 27536, 15463, -2137, 3636,
-    -9467, 14825, -4588, -729, 15339, 4665, 9294, -30922, -31573, -7925, -18843, 28823, -986,
-    -17044, -27975, 9552, 2488, 2095, -6383, 16623, 40, 5953, 26903, 22656, -26687, 16880, -20007,
-    -13837, -13189, 28310, 4411, -11905, -17694, 24972, 28188, -22321, -5354, -6765, -22064, 25487,
-    24333, -25406, -12293, -17044, 26509, -1756, -20128, -31574, 2614, -8050, -15126, 30885,
-    -28867, -7673, 17521, 30758, -26692, -7665, -13447, -30547, 9812, 431, 808, -10492, 11482,
-    -8701, -8950, 3515, -14870, -23085, -17045, 15971, -12549, 24077, 2093, 14950, -27838, -22323,
-    3900, 2488, -3048, -12035, 5573, -15642, -21544, -16786, 18026, -10236, 28190, 8774, 25744,
-    -10618, 5691, -15892, 10200, -15127, -15892, -10874, 29335, 7496, -8305, -10244, -29769,
-    -19613, 6719, -23859, 3261, -30033, 27799, 18167, -29005, -21803, -30663, 3635, 27798, -13958,
-    4150, -20263, 4033, -25920, -32342, 27164, -14613, 1841, 7374, -221, -3813, 16112, 2864, 8266,
-    31275, 30106, -14865, -30405, 10831, -30029, 693, 26765, 17003, -1878, 5435, -6899, 18682,
-    1838, 10320, 32303, 32678, -10755, -23723, 21113, -12555, 28193, 5693, 24196, -16012, -2017,
-    -27719, -10101, 17516, -2276, -30661, 22399, -17697, 24082, -3816, 10831, 26395, 27281, -21295,
-    25111, -6130, 9546, 22541, 22397, -30033, 11490, -28233, -26178, 29995, -5874, 14686, 27682,
-    -32603, -14356, -28344, 13401, -24123, 7894, -25664, -26950, 31282, -5104, 16998, 30254,
-    -27464, -6646, -15750, -31576, 8775, -4186, -4847, -18468, -4700, -32603, 18798, 5066, 14429,
-    10060, -22177, -21552, 12117, 9691, 12373, 12374, -21664, -18725, 15202, 15858, 21625, 27538,
-    3008, 21111, 14174, -10871, -6388, -27204, -14212, 14430, -9728, 24082, 4664, 19056, -22436,
-    -13070, 20085, 26395, -28491, -12041, -21407, 22653, -8700, -32458, 14943, -27205, 6864,
-    -29776, -32602, 22028, -19754, -7672, -8556, -25408, 21627, 15345, 27792, -32344, 14574,
-    -26950, -22321, -30401, 3634, 29079, -13953, 5691, -17697, 6865, -20267, -22837, -24489, 8775,
-    -24894, 2497, -31832, 27021, 13805, 31391, -29520, 20231, -18469, -7674, -7783, -24637, 23681,
-    17660, 32161, -25150, 25371, -8960, 6976, 16632, 14428, 21625, -10868, 1322, -18726, 1212,
-    -26949, 30619, 22030, -22067, -9217, -13179, -31576, 11601, -1615, 551, -9989, 8923, -10501,
-    -10503, -2899, -22837, 30875, 26143, -17698, -736, -329, -10245, -19754, -11894, 24708, 3634,
-    -18834, -24380, 12887, 7123, 10830, 8518, -27573, -28235, 38, -9327, -18469, 27795, 28196,
-    -18470, -475, -75, -9475, -19750, -10355, 26506, 5951, -13954, -16928, 24199, 26396, -23611,
-    -7926, -12412, -29008, 13406, 3779, 8515, 1584, 29479, 22393, -24374, 17400, -15644, -9210,
-    -5474, -23354, 25743, 21769, -26439, -15635, -22949, 18537, -15122, 22285, -1253, 10322, 27939,
-    30101, -18206, 30510, 4144, 24199, -18578, -2795, -31828, -16008, 9285, -16923, 10978, -14360,
-    -13582, -9582, -31579, 14175, 1212, 6972, -2017, 23315, 12883, 26253, -8040, 9543, -8443,
-    19460, 2347, 12117, -32712, -29265, -6387, -17292, -32349, 5950, -7784, -10504, -28488, -20377,
-    8001, -22321, 4296, -26695, -32344, 24857, -15902, -1246, 1213, -8193, -17180, -7268, -32608,
-    15460, 1213, 8513, -475, 26143, 17508, -31830, 3784, 29330, 23169, 5069, 20078, 15202, -12151,
-    -5365, -27461, -14721, 14939, -9728, 23316, 5173, 18799, -23459, -13075, 19057, 24087, -30552,
-    -16410, -29112, 11854, -26948, 2757, -32351, 26252, 11752, 29844, 31906, 14065, -27725, -23350,
-    32056, 546, 23167, -24228, -9221, 22397, 30771, -20273, 553, -1870, -9222, -21292, -12664,
-    23675, 811, -22945, -30039, 2097, -9582, -15390, 29854, -32712, -10508, 11351, 19203, 23159,
-    31397, 7381, 31383, 27799, 12007, 32411, -32083, 18433, -20790, -13322, -16262, 28812, 1585,
-    -17034, -22589, 14693, 10465, 18018, 17263, -11895, -2028, -25143, -8811, 24187, 4156, -18578,
-    -21817, 13666, 10720, 16991, 16236, -13439, -4854, -29768, -15752, 12366, -14606, 16631, -5881,
-    -471, 12519, 4143, 5697, 28710, 26502, -21289, 23828, -5367, 7496, 20744, 20590, 30369, 4039,
-    26758, 19832, -331, 11596, 300, 30766, 23161, -22574, 19202, -11022, -3041, 4553, -6139,
-    -12551, -330, -20531, -31826, 31539, -7682, 12637, 23315, 28812, -24629, 22288, -9226, 1587,
-    10722, 5424, 4671, 28455, 26241, -22315, 22031, -6914, 3387, 14834, 11591, 14695, -20890,
-    -12825, 19836, 25371, -26704, -13318, -21917, 23926, -9977, 32054, 15702, -29510, 4298, -31587,
-    26517, 13036, -32358, -31052, 19976, -17196, -9206, -8552, -23878, 21121, 15349, 30350, -31822,
-    16634, -21308, -16659, -20117, 22640, -9463, 31027, 15444, -30795, 2500, 31121, 21891, 5327,
-    21098, 14695, -11893, -3574, -27197, -12921, 19043, -5609, 31284, 19300, -26427, 10724, -22078,
-    -22829, -27312, 9020, -29512, -2897, 26497, 12380, -8809, -3315, -23344, -8809, 26498, 6469,
-    -14464, -14880, 24717, 27942, -19507, -3296, -4698, -14624, -31052, -27571, 283, 26773, -20375,
-    -488, -32338, -14721, 11592, -14349, 15349, -6141, -2013, 9952, 799, -215, 18434, 11079, 18803,
-    -17804, -6397, 30625, -23458, -229, 31139, -16776, 6968, -20263, 4556, -23102, -29001, 31028,
-    -5369, 15204, 27430, -30042, -13067, -25769, 19560, -16409, 20236, -3314, 6722, 20493, 20330,
-    30623, 2503, 26241, 18544, -3666, 7738, -6129, 18694, 5425, 14174, -28852, -21818, 4666, 189,
-    -2286, -12297, 2503, -16679, -24376, -24225, 10050, -24120, 2761, -28244, 30108, 18439, -23619,
-    -15125, -22424, 21357, -10757, 26665, 9278, 26508, -13685, 5938, -16927, 5077, -18735, -22838,
-    -25763, 10050, -24638, 1223, -30300, 27534, 13045, -31842, -27722, 21527, -12825, -479, 2252,
-    -4858, -11531, -1089, -19250, -29264, 32322, -3573, 19824, 31551, -20536, 1835, -3146, -7686,
-    -20267, -12398, 26496, 4663, -18567, -20279, 17000, 12787, 23412, 26509, 451, 20585, 11346,
-    -17540, -12569, 25482, 28978, -17196, 1582, 707, -3832, -13325, -1092, -20537, -31829, 29235,
-    -8459, 10321, 17927, 22638, 30110, 3278, 27778, 20601, -1092, 13644, 2097, 32061, 28293,
-    -15637, 28721, 7219, 25740, -16512, 3108, -23349, -4176, 31636, 17515, -65, 10820, 810, 28205,
-    22385, -24891, 14070, -17451, -13326, -14457, 31123, 6721, -11117, -11026, -32343, -26794,
-    -232, 28310, -20883, 797, -30031, -12659, 15961, -6644, 25892, 12363, 28565, -8033, 13392,
-    -4332, 25635, 14163, 30363, -4435, 18533, 4663, -25510, -28242, 2094, -9063, -14364, 32164,
-    -30651, -5883, 19057, 30259, -23360, -3302, -9577, -20019, 25740, 22806, -23875, -11269,
-    -18314, 29068, 554, -18829, -25160, 11092, 3273, 7480, 298, 25118, 18531, -32342, 3530, 29839,
-    22914, 4558, 20587, 14690, -12919, -5370, -28744, -16774, 12878, -14096, 16123, -5369, 554,
-    12781, 5940, 8521, 32056, -32354, -10498, -25512, 22131, -13326, 25890, 5169, 21369, -21913,
-    -7939, 25739, -30651, -12052, 12633, 17666, 23414, 30880, 5844, 29839, 25483, 6872, 25470,
-    22142, -839, 14163, 3124, -30909, 30611, -10498, -28338, 19560, -18723, 17667, -7941, -475,
-    8415, 1055, -476, 17154, 9793, 17257, -21911, -11539, 22141, 27177, -22848, -5872, -12145,
-    -24647, 18544, 10728, 22642, 23170, -2894, 13391, 297, 30773, 24185, -20778, 20237, -7171,
-    2611, 12526, 8507, 10578, -29366, -25418, 42, -8036, -14624, 31911, -30909, -5629, 18033,
-    29744, -24134, -5611, -12405, -24391, 17520, 10725, 21870, 21375, -4696, 10049, -5868, 21776,
-    9278, 20089, -18574, -5371, 30626, -22686, 1055, -32341, -13691, 12364, -11782, 17922, -1001,
-    6466, 22805, 22386, -30800, 8927, -28758, -30286, 23577, -13339, -473, 3274, -3830, -11011,
-    1990, -15396, -23861, -22682, 12618, -20264, 8930, -17709, -18979, -20368, 19814, -10499,
-    25380, 8506, 24196, -16769, 797, -25407, -8545, 24699, 6719, -17798, -17964, 20084, 18440,
-    31894, -24892, 23067, -8200, 4922, 13043, 11590, 14688, -22938, -14625, 17773, 19468, 31121,
-    -25147, 22039, -8973, 2611, 9959, 6705, 6209, 29234, 29578, -17179, 28464, 5420, 23684, -20367,
-    -2804, 32165, -20110, 5935, -24120, -2120, -32615, 20856, 4562, 19043, 13660, -16513, -9228,
-    29595, -28849, -5630, 20857, 31547, -19252, 1840, -837, -5373, -16665, -5463, -28503, 21115,
-    9443, 24183, 23171, -1352, 15189, 3382, -29880, 32408, -8183, -24226, 26497, -8440, -30394,
-    20072, -20777, 16381, -11026, -5356, 704, -11027, -21033, -15230, 22898, -3043, -28851, 27267,
-    -12039, 31803, 13389, -30544, -580, 27782, 17002, -3922, 6195, -7928, 15353, 285, 5693, 23063,
-    21616, -30802, 7900, -30042, -32087, 20237, -18735, -8699, -10604, -26188, 18544, 9442, 21101,
-    20343, -7007, 6196, -10756, 12526, -5626, -3046, 8414, -2028, -3560, 11498, 543, 2351, 19979,
-    14935, 25224, -8292, 9282, -8701, 17666, 1315, 9546, 27946, 29842, -17183, 29489, 4656, 24965,
-    -19085, -2026, -30291, -15487, 11853, -12559, 16125, -4595, 2605, 15096, 9541, 15712, -23198,
-    -15646, 17512, 18951, 28558, -27207, 18181, -16676, -7931, -7777, -23358, 24966, 18438, -29527,
-    -20524, 32060, 4141, 27021, -17799, 1572, -25152, -7005, 25474, 9544, -13688, -12049, 30619,
-    -30136, -7423, 18797, 28204, -26185, -7417, -16772, -31839, 7490, -7264, -7680, -24379, -14974,
-    18278, -6132, 29231, 15194, -30291, 1989, 29329, 22138, 3017, 17250, 11087, -20114, -16932,
-    19055, 19208, 30613, -25150, 22293, -10252, 2351, 9185, 4141, 3636, 24862, 21103, -29261, 8928,
-    -27728, -28490, 26148, -9482, 6721, 14325, 13906, 18286, -16259, -5369, -31573, -19857, 6711,
-    -22836, 961, -29270, 27537, 15353, -30041, -24378, 27947, -3827, 14430, 27433, -30813, -13070,
-    -27308, 18018, -18725, 15869, -9996, -3818, 2762, -7941, -14869, -6490, -28244, 21112, 9444,
-    23671, 23425, -1865, 14420, 2865, -31421, 29840, -11271, -30392, 16733, -23094, 10215, -20019,
-    -19239, -22938, 16219, -15899, 16385, -6655, 550, 10216, 3626, 4662, 24608, 22130, -27978,
-    10473, -24645, -23352, -31932, 3112, 27791, -18568, 1828, -25410, -7517, 24959, 8772, -15485,
-    -14363, 26763, 28720, -17448, 2092, 1220, -4084, -12044, 193, -18991, -27978, -30904, -487,
-    24965, -24993, -6913, 24195, -32189, -14624, 9033, 10730, 13133, 14173, -21910, -14367, 19314,
-    21267, -31330, -20008, 30263, 3880, 24453, -21138, -3316, 31392, -21395, 3367, -27463, -8031,
-    23157, 5691, -20368, -21562, 13916, 8930, 15961, 15201, -17799, -9483, 28309, -30135, -8456,
-    16745, 24864, -30302, -15638, -29620, 13903, -25662, 4562, -27475, 32678, 21268, -17710, -6643,
-    -8288, -21051, 25997, 21011, -24393, -13582, -22165, 23924, -8442, 31292, 16985, -27204, 5592,
-    -27477, -31830, 21784, -15656, -4073, -4174, -13857, -28231, -26533, 5162, -31316, -10599,
-    17756, -2789, 30522, 21868, -22836, 14588, -14113, -9471, -8029, -23365, 24197, 16387, -30562,
-    -24120, 26154, -3577, 12632, 24355, 31632, -19494, 27183, 2334, 19827, -28330, -13858, 13403,
-    14846, 22894, 27795, 454, 22894, 13403, -13939, -5891, -30030, -20621, 9530, -21036, 3795,
-    -22596, -28746, 29239, -4863, 14431, 24613, -31592, -16924, 31810, 9786, 31906, -9054, 17497,
-    -1248, 31039, 24436, -19496, 19475, -5121, 4664, 14334, 13898, 18542, -18306, -5120, 32420,
-    -23446, 3619, -29262, -10853, 19811, -478, -31413, 28035, -12558, 30012, 11844, -32605, -6226,
-    20840, 5689, -24217, -24393, 7746, -1602, 24, -10503, 4567, -12056, -16414, -13680, 29322,
-    6717, -14452, -13855, 28049, 29239, -14113, 5691, 6879, 6705, 4149, 26154, 24438, -24379,
-    15104, -14885, -9472, -9312, -24394, 22140, 13047, 29577, -32602, 12276, -25936, -23350, 31295,
-    2335, 24195, -23961, -5632, 26508, -29615, -8717, 17514, 23842, -29535, -15383, -30128, 14670,
-    -24893, 4568, -25680, -30547, 23844, -11803, 2351, 5339, 2590, -1762, 15363, 8501, 14429,
-    -28071, -18742, 9033, 5082, 9015, 4407, 28212, 27519, -19495, 22559, -2037, 10832, 23330,
-    29317, -22579, 21018, -6151, 4922, 13307, 13639, 17001, -20361, -7950, 27025, -31926, -9236,
-    13919, 19218, 29057, -27716, 15621, -15920, -10754, -12649, -27228, 15204, 2257, 13636, 5438,
-    -32182, -30569, -7926, -24215, 29570, -5356, -27042, 29313, -8440, -30383, 22888, -17950,
-    18963, -2813, 5695, 16907, 19032, 25484, -6995, 14664, -2787, 25902, 19290, -30544, 2772,
-    -31597, 26511, 8940, 31626, 30366, 10482, -28513, -28231, 22562, -9239, 2868, 7655, 6953, 4153,
-    25131, 25714, -25146, 14338, -14123, -10496, -10849, -24660, 19317, 8683, 24685, 22658, -4168,
-    14920, 42, 29242, 25714, -21290, 18449, -6156, 1328, 9453, 7466, 5954, 27700, 30339, -18462,
-    25902, 4125, 19317, -28069, -12067, 14435, 16393, 27768, -32340, 9454, -25946, -27457, 25903,
-    -4359, 10324, 19735, 27509, -29512, 11768, -20294, -19746, -26525, 16715, -20775, 9456, -13869,
-    -15378, -15987, 31621, 4669, -15731, -13612, 24973, 24876, -17982, -4582, -9049, -15926, 29086,
-    26675, -11815, 3130, 5086, 6176, -469, 19222, 16713, 24460, -10848, 11317, -11007, 13825, 523,
-    3128, 17166, 17999, 23945, -10077, 11318, -9980, 14853, 2323, 6211, 22049, 25710, -28742,
-    10484, -20808, -21289, -28837, 12860, -26687, -567, -30059, 24200, 7657, 29052, 25999, 3031,
-    26225, 18546, -7250, 8236, -9725, 12282, -759, 813, 13824, 11577, 14691, -25498, -13867, 15206,
-    15109, 27510, 31654, 7399, -29288, 32682, 16909, -18495, -12551, -17786, 32649, 3898, -15474,
-    -14126, 24716, 24105, -19010, -6381, -11876, -20552, 21633, 14596, -31347, -28226, 19223,
-    -10788, -3296, -824, -5905, -18459, -11104, -31348, 11354, -6479, 2835, -15119, 1232, -15927,
-    -26170, -28837, 8489, -31568, -9819, 21854, 815, -29352, -30832, -6124, -23441, -31860, -1241,
-    -19586, -23122, 11353, 2002, 11060, 1587, 26417, 25709, -24885, 14339, -12586, -9978, -9049,
-    -21067, 23690, 16138, -27493, -23085, 28218, 3348, 20091, -28837, -10531, 14438, 17167, 30075,
-    -30024, 13056, -18243, -16917, -22410, 24934, -9207, 28477, 17995, -30539, 207, -31607, 22661,
-    3805, 25191, 17521, -10074, 5917, -15377, 3291, -13616, -21545, -22666, 19795, -13836, 18199,
-    2833, 10322, 25395, -31349, -16664, 29508, 11314, 30367, -11870, 16712, -5614, 23083, 15684,
-    28567, -9300, 17227, -2274, 26938, 22624, -25919, 8691, -19268, -20522, -28060, 14914, -22836,
-    3809, -21067, -26948, 28996, 8, 19569, 31052, -16955, 4407, -818, 1294, -9215, 4065, -7445,
-    -13070, -8785, -24150, 22911, 10747, 31363, 32420, 10233, -25178, -24635, 27453, 523, 18541,
-    30794, -18496, 2608, -3903, -3590, -17438, -9043, -28776, 17772, 1237, 16714, 8006, -28576,
-    -22865, 3895, -6475, -4875, -21805, -14185, 27251, 2611, -22924, -22608, 9294, -309, 6435,
-    -4585, 14855, 7720, 12120, -32691, -23121, -987, -11103, -14640, 29083, 27448, -11300, 5183,
-    7144, 10032, 6211, 29503, -32117, -13579, -32691, 16971, -26430, 3547, -25178, -32341, 20767,
-    -13614, -3813, -4677, -10530, -26172, -23952, 13372, -21290, 4833, -18497, -24374, -30376,
-    8746, -32085, -10844, 20312, -988, 31819, 28536, -15381, 25395, 7719, 23169, -22408, -1790,
-    31393, -23693, 5150, -28233, -10843, 23655, 3122, -26264, -25947, 3380, -9817, -9242, -29259,
-    -25751, 7721, -27975, -7504, 26997, 9548, -15986, -9498, 29852, -32177, -5130, 17774, 25649,
-    -24663, -9725, -21383, 31878, -216, -20869, -23635, 10322, -53, 7464, -3300, 17424, 11319,
-    18288, -22669, -7186, 24971, 31045, -12070, 8010, 9456, 14916, 13407, -23698, -12841, 17777,
-    18451, -31603, -24372, 22821, -3591, 7755, 17424, 23394, 29343, 462, 28020, 17007, -7249, 7718,
-    -11007, 10227, -2820, -4324, 6372, 8, -5096, 8428, 1037, -1756, 12797, 8491, 10323, 32329,
-    -25434, -4326, -16245, -23121, 14950, 5600, 18000, 12380, -21386, -11556, 21119, 23333, -23379,
-    -11521, -21385, 30335, -2526, -24212, -29033, 816, -14447, -15926, 23433, 21277, -22866,
-    -13319, -22670, 27507, -6894, -31408, 25194, -17689, 20765, 1036, 10581, 24877, -32118, -18461,
-    27962, 7461, 24458, -20612, 1551, -29771, -15215, 18000, -7926, 23079, 12603, 25227, -14701,
-    7721, -17180, 3547, -16438, -23091, -26779, 12861, -23863, 1749, -24919, 32421, 19997, -15668,
-    -5872, -9045, -17467, 28824, 23852, -15155, -1759, -4419, -8473, -23347, -19325, 20569, -9212,
-    23852, 12345, 25997, -14699, 8748, -15896, 5348, -13098, -17695, -18553, 26738, -1761, -28319,
-    -32630, -5359, -25749, 31878, -3817, -25235, -31602, -1247, -20609, -24406, 10576, -1335, 6436,
-    -4845, 14086, 6436, 10832, 29763, -27746, -7929, -23180, 31622, -1504, -22923, -27232, 5436,
-    -9046, -6415, -25661, -19326, 17744, -11527, 18712, 4380, 13402, 30277, -24662, -4331, -16498,
-    -23634, 15459, 4576, 17230, 11861, -23695, -14639, 17002, 15113, 29565, -31058, 11258, -22350,
-    -21292, -31147, 10547, -30545, -7503, 24683, 7235, -20868, -16438, 18030, 14342, 29822, -31572,
-    11001, -23121, -22320, 32590, 7720, 30365, -14956, 12604, -12042, 12802, -2046, 1066, 11516,
-    9777, 11603, -31661, -22863, 1067, -9046, -10784, -30030, -28064, 4637, 32164, -15985, 13119,
-    -12556, 13058, -2559, 809, 11001, 8750, 10061, 31561, -26974, -5359, -19583, -28002, 8006,
-    -6991, -2301, -19237, -8533, -31085, 15973, -1852, 10551, -1247, 22564, 17747, 30621, -3908,
-    22888, 9290, -19843, -14378, 21370, 20507, -27229, -16667, -30636, 14663, -25663, 2261, -26972,
-    31135, 17168, -20548, -13070, -20868, 28283, -2276, -26779, -32370, -3304, -22924, -29543,
-    3379, -13159, -13095, 29337, 29247, -10012, 9035, 12284, 18259, 20343, -13674, 3354, -20265,
-    -3651, -27486, 24454, 10484, 31368, 31907, 11255, -25944, -24634, 28218, 14, 18542, 31816,
-    -18748, 3123, -2110, -2813, -14868, -4166, -22859, 28566, 19222, -21318, -12041, -20099, 29826,
-    -219, -22669, -26458, 6464, -6479, -3585, -20264, -10334, 31368, 10834, -9564, -2556, -22320,
-    -11106, 28285, 6979, -16247, -13093, 25741, 26673, -16692, -730, -3397, -7697, -21804, -15476,
-    24686, -1501, -28326, 32139, -6898, -26270, 28798, -8183, -30896, 22887, -18464, 18193, -3586,
-    3897, 14081, 14918, 18289, -18559, -3330, 32682, -22414, 7208, -25916, -4938, 31367, 15719,
-    -4425, 7724, -7412, 14337, 3355, 7237, 24617, 28284, -23090, 18964, -7441, 813, 7398, 4896,
-    1584, 20505, 18774, 28569, -4168, 20831, 5953, -24472, -22089, 8010, 457, 4897, -5612, 13821,
-    4384, 7495, 26414, 30084, -19748, 24616, 1043, 15204, 30527, -23630, -3814, -13164, -20803,
-    20859, 14336, 31625, -30285, 15365, -18235, -13580, -18045, 30596, 1841, -19074, -20548, 14949,
-    8682, 20316, 18033, -12907, 1556, -22061, -6225, -32111, 16490, -1086, 11324, -473, 25386,
-    20833, -29772, 5597, -28255, 32423, 18448, -18490, -10497, -14962, -29284, 10835, -4169, 2586,
-    -11783, 5084, -10779, -15895, -12649, -32624, 10063, -8281, -2554, -21035, -9309, 30857, 11348,
-    -8796, -2039, -21035, -8539, 31372, 12633, -6741, 1047, -15639, 199, -20285, -30031, 29755,
-    -4866, 14689, 24358, -30824, -16666, 32326, 11325, -31830, -6225, 22891, 6721, -21389, -19258,
-    14689, 10222, 20321, 20343, -10082, 5416, -14611, 5596, -13860, -18209, -17534, 24948, -2532,
-    -28585, 29574, -8957, -30384, 21350, -18724, 16906, -6408, 553, 8681, 4644, 3380, 22559, 21349,
-    -31573, 4312, -31851, 28052, 10737, -31337, -30545, 17934, -16946, -9213, -11879, -25427,
-    18030, 7139, 20834, 17773, -12394, 789, -21550, -6226, -32621, 16744, -1087, 10557, -476,
-    24871, 19295, -31060, 3026, 32402, 25738, 7395, 28033, 25738, 3026, 23664, 17000, -10082, 1563,
-    -17954, -1601, -25165, 29335, 18960, -22596, -13071, -21132, 25978, -4590, -29613, 25978,
-    -12815, 27443, 9273, 27791, -14192, 7989, -14873, 7397, -13086, -14359, -13420, 32147, 10057,
-    -9052, -4861, -22583, -13164, 23924, 2090, -24987, -28762, 2862, -11110, -14113, 31133, 31810,
-    -8203, 14172, 20759, 29576, -24636, 19475, -10261, -477, 3798, -1780, -7673, 5083, -7435,
-    -12042, -5197, -22084, 28565, 20761, -20800, -9729, -16504, -30823, 8519, -8024, -4351, -22065,
-    -12136, 26490, 4664, -19847, -20028, 15716, 10479, 21350, 21884, -7512, 9272, -8186, 15876,
-    2590, 8776, 26156, 29832, -19238, 25129, 791, 16485, 31811, -22340, -220, -8025, -13345,
-    -31060, -29870, -495, 25481, -26015, -5379, 24197, -32183, -12576, 10577, 12792, 18779, 21371,
-    -10596, 5930, -14611, 6110, -13346, -17181, -15992, 27518, 1581, -21647, -24911, 8778, -1088,
-    2590, -8699, 9192, -4863, -5872, 4566, -6406, -12040, -3401, -20541, 31394, 25898, -13089,
-    2354, 4566, 2075, -3815, 13561, 4646, 8007, 27953, 30860, -16923, 28982, 6959, 25996, -17536,
-    3105, -24121, -5971, 29834, 14173, -6229, 2079, -13585, 3795, -15655, -21295, -21905, 16471,
-    -14614, 16647, -3833, 3634, 14847, 12616, 18283, -19592, -7174, 29335, -28330, -4861, 22910,
-    -32442, -15142, 8262, 8421, 11073, 9804, -29359, -25165, 1067, -8543, -13086, -31829, -29360,
-    -1264, 24712, -26533, -7176, 21372, 29751, -19513, -473, -4431, -10004, -25145, -19594, 15697,
-    -14352, 16645, -2808, 3382, 15874, 14411, 19830, -15995, -1266, -27716, -13682, 19038, -5100,
-    29238, 19038, -27460, 6879, -25681, -29002, 25898, -7949, 7494, 14846, 17495, 21886, -10855,
-    5931, -15124, 6108, -14116, -18208, -17279, 24949, -2531, -28073, 29832, -8442, -29101, 22893,
-    -16153, 21530, 277, 12117, 27184, -31335, -13841, -30641, 15954, -24122, 6368, -22854, -25921,
-    31041, 20, 21881, -29355, -12574, 14172, 15878, 25205, 31648, 5598, 32401, 28564, 9710, -32107,
-    -31832, 15622, -20800, -14613, -21643, 24690, -6389, 32071, 21092, -21553, 13310, -12833,
-    -8959, -8022, -21571, 26508, 18707, -24656, -15384, -26525, 19292, -16413, 16139, -4354, 2350,
-    11512, 9782, 11859, -30380, -22601, 2865, -5966, -7181, -23092, -16503, 21861, -4588, 31043,
-    22375, -21808, 14082, -11551, -7415, -5451, -16691, -32087, 30273, -5384, 14689, 22820, -31342,
-    -18722, 28732, 6695, 25482, -20099, 1813, -27976, -12903, 20832, -1762, 32330, 26743, -15898,
-    23850, 4127, 18797, -29607, -14635, 11859, 10485, 18519, 19569, -14188, 1301, -22067, -7506,
-    31628, 14942, -5451, 4901, -9475, 8942, -5123, -5362, 3031, -6666, -12815, -6221, -23371,
-    26764, 16653, -26199, -18726, -31920, 10810, -30035, -6220, 24946, 9801, -17529, -12063, 26764,
-    27961, -14891, 3635, 2260, 1815, -5361, 9970, 274, 1064, 14853, 11582, 17255, -23184, -10264,
-    22653, 25904, -21059, -4846, -12390, -21316, 22140, 14339, 32654, -28233, 17681, -14122, -6387,
-    -7249, -17206, 31136, 27190, -10525, 6465, 9456, 12606, 11862, -27553, -19006, 8522, 3287,
-    8494, 1326, 23590, 21601, -30800, 4572, -29543, 30110, 14082, -24404, -20777, -31921, 9778,
-    -32343, -9305, 20573, 1068, -30380, -32627, -7927, -27039, 27255, -10240, 30530, 16975, -28231,
-    2260, -29286, 28310, 12540, -28001, -25661, 25134, -3587, 11092, 20765, 29052, -26174, 15883,
-    -12841, -7669, -7505, -17724, 29597, 24878, -13356, 557, 462, -1277, -11780, -52, -14127,
-    -25144, -26266, 11831, -25145, -309, -28004, 26513, 11770, -29803, -28743, 19995, -11043,
-    -2014, -52, -4361, -15378, -6734, -24407, 23430, 12284, -32117, -30798, 15626, -17212, -12551,
-    -17013, -31604, 5954, -12645, -8731, -32596, -28322, 2578, 28827, -21126, 5406, -26430, -8019,
-    28537, 9808, -13931, -6673, -31569, -24982, 6435, -29257, -9562, 23912, 3640, -24469, -23634,
-    6468, -3396, 12, -14094, -312, -17721, -28743, -32694, 784, 23171, -27811, -7955, 19060, 24875,
-    -24661, -10752, -21643, 30081, -2528, -24213, -29801, 557, -15219, -17722, 21375, 17678,
-    -29288, -22830, 27188, 1553, 17776, -32437, -17466, 4413, 973, 2581, -7667, 8940, -1788, -4069,
-    8169, 1040, -1757, 13309, 8237, 10836, -32438, -24917, -2785, -13677, -19777, 21117, 15365,
-    -32114, -27714, 19478, -11041, -2784, -55, -5644, -16919, -8793, -28517, 17006, 2515, 16716,
-    8011, -26784, -21578, 5699, -1599, 1040, -11779, 3542, -11297, -18975, -16247, 27254, -214,
-    -24471, -27745, 2100, -11365, -12325, 30371, 32326, -5644, 14952, 23588, -29546, -17688, 32327,
-    12089, -32595, -6481, 23655, 5699, -21902, -19008, 12896, 8424, 18515, 15209, -17277, -4873,
-    31401, -24473, 4123, -32080, -13422, 16974, -8179, 23330, 12091, 23946, -14964, 5667, -20517,
-    -315, -24402, 29599, 19732, -19520, -11264, -16249, -30828, 6984, -9054, -5385, -26169, -16764,
-    19288, -9207, 24871, 12349, 25745, -12652, 9523, -14349, 9965, -7954, -9465, -2629, -15664,
-    -29768, -30642, 1556, 25230, -23960, -2556, 27800, -25502, -1528, 27286, -24988, -1528, 27800,
-    -24474, -500, 29342, -21904, 3613, -29256, -10853, 21347, -472, -29871, 31113, -9724, -29357,
-    22375, -17692, 19218, -2555, 5953, 17933, 20061, 27284, -3656, 19548, 5182, -26016, -24914,
-    3641, -6228, -6667, -24115, -15737, 21604, -5354, 31295, 21861, -23345, 13562, -13863, -11266,
-    -10339, -25685, 18547, 7908, 22375, 19318, -8798, 6185, -13323, 7908, -9750, -12552, -7512,
-    -24399, 22915, 13562, 32142, -30542, 16390, -18232, -12552, -16249, 32655, 5696, -12395,
-    -10779, 31397, -30128, -2812, 21631, -31927, -14121, 8268, 9193, 13636, 11609, -25246, -17462,
-    11353, 9192, 16720, 14437, -19079, -8467, 26260, -32443, -9753, 11355, 16902, 24942, 29859,
-    4566, 31110, 23691, 4566, 24942, 17523, -7771, 6182, -13319, 8164, -8725, -12291, -5971,
-    -21832, 26003, 19216, -23632, -16146, -24988, 21087, -15376, 20246, 1555, 10581, 26671, -31599,
-    -16148, 32069, 12606, -31826, -4940, 25200, 9295, -16506, -10781, 27029, 30783, -11039, 8269,
-    12021, 16975, 17521, -16250, -2300, -30025, -17535, 14406, -14349, 14592, -3328, 44, 11507,
-    7981, 8268, 31039, -29799, -10236, -25245, 26485, -10236, 31039, 17233, -28484, 3540, -28514,
-    29342, 15363, -24146, -20003, -29869, 12349, -28485, -1856, 31625, 18804, -572, 14407, 2870,
-    32067, 31112, -13322, 32325, 15178, -28743, 971, -31597, 24200, 7139, 27514, 23943, 457, 20575,
-    10322, -20104, -13607, 20860, 21788, -26458, -15635, -27813, 18518, -20005, 12794, -10781,
-    -8953, -5454, -17977, 31140, 27443, -10268, 5955, 10223, 12863, 11866, -26272, -17721, 10068,
-    7138, 13891, 9554, -27301, -21062, 5443, -574, 1299, -11006, 5594, -8982, -15118, -9055,
-    -27743, 17008, 4566, 18004, 10840, -21392, -14122, 18037, 19215, -31599, -24369, 24613, -3072,
-    9556, 21529, 28025, -27967, 14848, -15924, -13061, -14450, -30316, 8785, -6741, -1017, -19743,
-    -5970, -28773, 18808, 5081, 20829, 13925, -15737, -4872, 32687, -22676, 6951, -27710, -5714,
-    28797, 11098, -10341,
-];
+/// pxtone's pseudo random levels, built on first use
+pub(super) fn random_table() -> &'static [i16] {
+    static TABLE: OnceLock<Vec<i16>> = OnceLock::new();
+    TABLE.get_or_init(|| {
+        let mut buf: [i32; 2] = [0x4444, 0x8888];
+        (0..SMP_NUM_RAND)
+            .map(|_| {
+                let w1 = i32::from(buf[0] as i16) + buf[1];
+                let w2 = (w1 as u16).swap_bytes() as i16;
+                buf[1] = i32::from(buf[0] as i16);
+                buf[0] = i32::from(w2);
+                w2
+            })
+            .collect()
+    })
+}
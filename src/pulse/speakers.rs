@@ -0,0 +1,69 @@
+//! Stereo downmix of WAV files with more channels than pxtone plays
+
+use std::f64::consts::FRAC_1_SQRT_2;
+
+use super::OutputFormat;
+
+/// `dwChannelMask` bits in the order WAV channels follow them
+const FRONT_LEFT: u32 = 0x1;
+const FRONT_RIGHT: u32 = 0x2;
+const LOW_FREQUENCY: u32 = 0x8;
+const LEFT_SPEAKERS: u32 = 0x10 | 0x40 | 0x200 | 0x1000 | 0x8000;
+const RIGHT_SPEAKERS: u32 = 0x20 | 0x80 | 0x400 | 0x4000 | 0x2_0000;
+
+/// Speakers of `ch` channels when a file gives none, as Windows assumes
+pub(super) fn default_channel_mask(ch: u16) -> u32 {
+    match ch {
+        1 => 0x4,
+        2 => 0x3,
+        3 => 0x7,
+        4 => 0x33,
+        5 => 0x37,
+        6 => 0x3f,
+        7 => 0x13f,
+        8 => 0x63f,
+        _ => 0,
+    }
+}
+
+/// Left and right levels of a speaker; centers go to both sides, the low frequency channel to
+/// neither
+fn stereo_levels(speaker: u32) -> [f64; 2] {
+    match speaker {
+        FRONT_LEFT => [1.0, 0.0],
+        FRONT_RIGHT => [0.0, 1.0],
+        LOW_FREQUENCY => [0.0, 0.0],
+        _ if speaker & LEFT_SPEAKERS != 0 => [FRAC_1_SQRT_2, 0.0],
+        _ if speaker & RIGHT_SPEAKERS != 0 => [0.0, FRAC_1_SQRT_2],
+        _ => [FRAC_1_SQRT_2, FRAC_1_SQRT_2],
+    }
+}
+
+/// Interleaved `smp` of `ch` channels at the speakers of `channel_mask`, mixed down to stereo;
+/// each side is scaled by the sum of its levels so that it cannot clip
+pub(super) fn downmix(smp: &[u8], ch: u16, channel_mask: u32, format: OutputFormat) -> Vec<u8> {
+    let mut speakers = (0..32)
+        .map(|bit| 1 << bit)
+        .filter(|bit| channel_mask & bit != 0);
+    // channels past the mask are taken as centers
+    let levels = (0..ch)
+        .map(|_| stereo_levels(speakers.next().unwrap_or(0)))
+        .collect::<Vec<_>>();
+    let totals = [0, 1].map(|side| levels.iter().map(|l| l[side]).sum::<f64>().max(1.0));
+
+    let block_size = usize::from(ch) * format.bytes_per_sample();
+    let mut mixed = Vec::with_capacity(smp.len() / usize::from(ch) * 2);
+    for mut frame in smp.chunks_exact(block_size) {
+        let mut out = [0.0; 2];
+        for level in &levels {
+            let smp: f32 = format.read_sample(&mut frame).unwrap();
+            for (out, level) in out.iter_mut().zip(level) {
+                *out += f64::from(smp) * level;
+            }
+        }
+        for (out, total) in out.iter().zip(&totals) {
+            format.write_level(&mut mixed, out / total);
+        }
+    }
+    mixed
+}
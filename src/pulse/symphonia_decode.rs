@@ -0,0 +1,65 @@
+//! Compressed woices decoded by symphonia
+
+use std::io::{self, Cursor};
+
+use byteorder::{LittleEndian, WriteBytesExt as _};
+use symphonia::core::audio::SampleBuffer;
+use symphonia::core::codecs::DecoderOptions;
+use symphonia::core::errors::Error as SymphoniaError;
+use symphonia::core::formats::FormatOptions;
+use symphonia::core::io::MediaSourceStream;
+use symphonia::core::probe::Hint;
+
+use super::{OggVorbis, Pcm};
+use crate::error::{ErrorKind, Result};
+
+impl OggVorbis {
+    pub(super) fn decode_stream(&self) -> Result<Pcm> {
+        let error = ErrorKind::Symphonia;
+        let bytes = Box::new(Cursor::new(self.bytes.clone()));
+        let source = MediaSourceStream::new(bytes, Default::default());
+        let mut hint = Hint::new();
+        hint.with_extension("ogg");
+        let (format_options, metadata_options) = (FormatOptions::default(), Default::default());
+        let probed = symphonia::default::get_probe()
+            .format(&hint, source, &format_options, &metadata_options)
+            .map_err(error)?;
+        let mut format = probed.format;
+        let track = format
+            .default_track()
+            .ok_or(ErrorKind::InvalidFormat("Ogg Vorbis track"))?;
+        let track_id = track.id;
+        let params = &track.codec_params;
+        let ch = params.channels.map_or(0, |channels| channels.count()) as u16;
+        let sps = params.sample_rate.unwrap_or(0);
+        let mut decoder = symphonia::default::get_codecs()
+            .make(params, &DecoderOptions::default())
+            .map_err(error)?;
+
+        let mut smp = Vec::new();
+        let mut buffer = None;
+        loop {
+            let packet = match format.next_packet() {
+                Ok(packet) => packet,
+                // the end of the stream
+                Err(SymphoniaError::IoError(err)) if err.kind() == io::ErrorKind::UnexpectedEof => {
+                    break
+                }
+                Err(err) => return Err(error(err).into()),
+            };
+            if packet.track_id() != track_id {
+                continue;
+            }
+            let decoded = decoder.decode(&packet).map_err(error)?;
+            let buffer = buffer.get_or_insert_with(|| {
+                SampleBuffer::<i16>::new(decoded.capacity() as u64, *decoded.spec())
+            });
+            buffer.copy_interleaved_ref(decoded);
+            for &sample in buffer.samples() {
+                smp.write_i16::<LittleEndian>(sample)?;
+            }
+        }
+        self.fit_stored_length(&mut smp, ch);
+        Pcm::from_raw(ch, sps, 16, smp)
+    }
+}
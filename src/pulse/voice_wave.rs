@@ -0,0 +1,206 @@
+//! Editing of voice waves
+
+#[cfg(feature = "fft")]
+use rustfft::{num_complex::Complex, FftPlanner};
+
+use super::{Point, VoiceWave, VOICE_SMP_BODY};
+
+/// Coordinate x positions are stored in a byte
+const LIMIT_COORDINATE_RESO: i32 = 256;
+
+impl VoiceWave {
+    fn overtone_points(&self) -> &[Point] {
+        match self {
+            VoiceWave::Overtone { points } => points,
+            VoiceWave::Coodinate { .. } => panic!("not an overtone wave"),
+        }
+    }
+
+    fn overtone_points_mut(&mut self) -> &mut Vec<Point> {
+        match self {
+            VoiceWave::Overtone { points } => points,
+            VoiceWave::Coodinate { .. } => panic!("not an overtone wave"),
+        }
+    }
+
+    fn coordinate_parts(&mut self) -> (&mut Vec<Point>, &mut i32) {
+        match self {
+            VoiceWave::Coodinate { points, reso } => (points, reso),
+            VoiceWave::Overtone { .. } => panic!("not a coordinate wave"),
+        }
+    }
+
+    /// Steps in a cycle of a coordinate wave
+    pub fn reso(&self) -> i32 {
+        match self {
+            VoiceWave::Coodinate { reso, .. } => *reso,
+            VoiceWave::Overtone { .. } => panic!("not a coordinate wave"),
+        }
+    }
+
+    /// Add a point to a coordinate wave in x order, replacing one at the same x
+    pub fn insert_point(&mut self, x: i32, y: i32) {
+        let (points, reso) = self.coordinate_parts();
+        assert!((0..*reso).contains(&x));
+        let y = y.clamp(i8::MIN.into(), i8::MAX.into());
+        let index = points.partition_point(|point| point.x < x);
+        match points.get_mut(index) {
+            Some(point) if point.x == x => point.y = y,
+            _ => points.insert(index, Point { x, y }),
+        }
+    }
+
+    /// Move a point of a coordinate wave, keeping it between its neighbours
+    pub fn move_point(&mut self, index: usize, x: i32, y: i32) {
+        let (points, reso) = self.coordinate_parts();
+        let min = if index > 0 {
+            points[index - 1].x + 1
+        } else {
+            0
+        };
+        let max = points.get(index + 1).map_or(*reso, |point| point.x) - 1;
+        points[index] = Point {
+            x: x.max(min).min(max),
+            y: y.clamp(i8::MIN.into(), i8::MAX.into()),
+        };
+    }
+
+    pub fn remove_point(&mut self, index: usize) -> Point {
+        self.coordinate_parts().0.remove(index)
+    }
+
+    /// Change the steps of a coordinate wave, dropping points beyond them
+    pub fn set_reso(&mut self, new_reso: i32) {
+        assert!(new_reso > 0 && new_reso <= LIMIT_COORDINATE_RESO);
+        let (points, reso) = self.coordinate_parts();
+        points.retain(|point| point.x < new_reso);
+        *reso = new_reso;
+    }
+
+    /// Redraw a coordinate wave over `new_reso` steps, keeping its shape;
+    /// points falling on the same step are merged into the last
+    pub fn resample(&mut self, new_reso: i32) {
+        assert!(new_reso > 0 && new_reso <= LIMIT_COORDINATE_RESO);
+        let (points, reso) = self.coordinate_parts();
+        let old_reso = *reso;
+        let mut resampled: Vec<Point> = Vec::with_capacity(points.len());
+        for point in points.iter() {
+            let x = (i64::from(point.x) * i64::from(new_reso) / i64::from(old_reso)) as i32;
+            match resampled.last_mut() {
+                Some(last) if last.x == x => last.y = point.y,
+                _ => resampled.push(Point { x, y: point.y }),
+            }
+        }
+        *points = resampled;
+        *reso = new_reso;
+    }
+
+    /// Drop points of a coordinate wave that stay within `tolerance` of the line through
+    /// the points kept (Douglas-Peucker)
+    pub fn simplify(&mut self, tolerance: f32) {
+        assert!(tolerance >= 0.0);
+        let (points, _) = self.coordinate_parts();
+        if points.len() <= 2 {
+            return;
+        }
+        let mut keep = vec![false; points.len()];
+        keep[0] = true;
+        keep[points.len() - 1] = true;
+        let mut spans = vec![(0, points.len() - 1)];
+        while let Some((first, last)) = spans.pop() {
+            let (a, b) = (&points[first], &points[last]);
+            let farthest = (first + 1..last)
+                .map(|i| (i, distance(&points[i], a, b)))
+                .fold(
+                    (first, 0.0),
+                    |far, cur| if cur.1 > far.1 { cur } else { far },
+                );
+            if farthest.1 > f64::from(tolerance) {
+                keep[farthest.0] = true;
+                spans.push((first, farthest.0));
+                spans.push((farthest.0, last));
+            }
+        }
+        let mut kept = keep.into_iter();
+        points.retain(|_| kept.next().unwrap());
+    }
+
+    /// Amplitude of a harmonic of an overtone wave, or 0; it sounds divided by `harmonic`
+    pub fn harmonic(&self, harmonic: i32) -> i32 {
+        self.overtone_points()
+            .iter()
+            .filter(|point| point.x == harmonic)
+            .map(|point| point.y)
+            .sum()
+    }
+
+    /// Set a harmonic of an overtone wave, removing it at amplitude 0
+    pub fn set_harmonic(&mut self, harmonic: i32, amplitude: i32) {
+        assert!(harmonic > 0);
+        let points = self.overtone_points_mut();
+        points.retain(|point| point.x != harmonic);
+        if amplitude != 0 {
+            let index = points.partition_point(|point| point.x < harmonic);
+            points.insert(
+                index,
+                Point {
+                    x: harmonic,
+                    y: amplitude,
+                },
+            );
+        }
+    }
+
+    /// Scale the amplitudes of an overtone wave so that its cycle peaks at `peak` of full scale
+    pub fn normalize(&mut self, peak: f32) {
+        assert!(peak >= 0.0);
+        let current = self
+            .render_cycle(VOICE_SMP_BODY as usize)
+            .iter()
+            .fold(0.0, |max: f32, s| max.max(s.abs()));
+        if current == 0.0 {
+            return;
+        }
+        let scale = f64::from(peak / current);
+        for point in self.overtone_points_mut() {
+            point.y = (f64::from(point.y) * scale).round() as i32;
+        }
+    }
+
+    /// Overtone wave of up to `harmonic_num` harmonics closest to a sampled cycle.
+    /// Overtones are sines, so each harmonic keeps its level but not its phase.
+    #[cfg(feature = "fft")]
+    pub fn from_cycle(cycle: &[f32], harmonic_num: usize) -> Self {
+        assert!(!cycle.is_empty());
+        let len = cycle.len();
+        let mut bins = cycle
+            .iter()
+            .map(|&s| Complex::new(f64::from(s), 0.0))
+            .collect::<Vec<_>>();
+        FftPlanner::new().plan_fft_forward(len).process(&mut bins);
+
+        let points = (1..=harmonic_num.min(len / 2))
+            .filter_map(|k| {
+                let sine = -bins[k].im;
+                let level = (2.0 * bins[k].norm() / len as f64).copysign(sine);
+                let y = (level * k as f64 * 128.0).round() as i32;
+                if y == 0 {
+                    return None;
+                }
+                Some(Point { x: k as i32, y })
+            })
+            .collect();
+        VoiceWave::Overtone { points }
+    }
+}
+
+/// Distance of `point` from the line through `a` and `b`
+fn distance(point: &Point, a: &Point, b: &Point) -> f64 {
+    let (px, py) = (f64::from(point.x - a.x), f64::from(point.y - a.y));
+    let (dx, dy) = (f64::from(b.x - a.x), f64::from(b.y - a.y));
+    let len = dx.hypot(dy);
+    if len == 0.0 {
+        return px.hypot(py);
+    }
+    (px * dy - py * dx).abs() / len
+}
@@ -0,0 +1,111 @@
+//! Python extension module, built from the cdylib with the `python` feature
+
+use std::io::Cursor;
+
+use pyo3::exceptions::PyValueError;
+use pyo3::prelude::*;
+use pyo3::types::{PyBytes, PyDict};
+
+use crate::error::{Error, ErrorKind};
+use crate::options::{ParseOptions, RenderOptions};
+use crate::project::Project;
+use crate::pulse::{Noise, OutputFormat};
+
+impl From<Error> for PyErr {
+    fn from(err: Error) -> Self {
+        PyValueError::new_err(err.to_string())
+    }
+}
+
+/// Options of `channels` and `bits` per sample, 32 bits being float
+fn render_options(rate: u32, channels: u16, bits: u16) -> Result<RenderOptions, Error> {
+    let format = match bits {
+        8 => OutputFormat::U8,
+        16 => OutputFormat::I16,
+        24 => OutputFormat::I24,
+        32 => OutputFormat::F32,
+        _ => return Err(ErrorKind::UnsupportedBitDepth(bits).into()),
+    };
+    Ok(RenderOptions::new(channels, rate, format))
+}
+
+/// WAV file of the `.ptnoise` in `bytes`
+#[pyfunction]
+#[pyo3(signature = (bytes, rate = 44100, channels = 2, bits = 16))]
+fn render_noise<'py>(
+    py: Python<'py>,
+    bytes: &[u8],
+    rate: u32,
+    channels: u16,
+    bits: u16,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let options = render_options(rate, channels, bits)?;
+    let noise = Noise::parse(Cursor::new(bytes), &ParseOptions::default())?;
+    let wav = py.allow_threads(|| noise.build(&options))?.into_bytes();
+    Ok(PyBytes::new(py, &wav))
+}
+
+/// WAV file of the `.ptcop` or `.pttune` in `bytes`, played once through
+#[pyfunction]
+#[pyo3(signature = (bytes, rate = 44100, channels = 2, bits = 16))]
+fn render_project<'py>(
+    py: Python<'py>,
+    bytes: &[u8],
+    rate: u32,
+    channels: u16,
+    bits: u16,
+) -> PyResult<Bound<'py, PyBytes>> {
+    let options = render_options(rate, channels, bits)?;
+    let mut project = Project::parse(Cursor::new(bytes), &ParseOptions::default())?;
+    let wav = py.allow_threads(|| project.render(&options))?.into_bytes();
+    Ok(PyBytes::new(py, &wav))
+}
+
+/// Names, master settings and length of a project
+#[pyfunction]
+fn project_info<'py>(py: Python<'py>, bytes: &[u8]) -> PyResult<Bound<'py, PyDict>> {
+    let project = Project::parse(Cursor::new(bytes), &ParseOptions::default())?;
+    let master = project.master();
+    let info = PyDict::new(py);
+    info.set_item("name", project.name())?;
+    info.set_item("comment", project.comment())?;
+    info.set_item("beat_num", master.beat_num())?;
+    info.set_item("beat_tempo", master.beat_tempo())?;
+    info.set_item("beat_clock", master.beat_clock())?;
+    info.set_item("meas_num", master.meas_num())?;
+    info.set_item("repeat_meas", master.repeat_meas())?;
+    info.set_item("last_meas", master.last_meas())?;
+    info.set_item("duration", project.duration().as_secs_f64())?;
+    let units = project
+        .units()
+        .iter()
+        .map(|unit| unit.name())
+        .collect::<Vec<_>>();
+    info.set_item("units", units)?;
+    let woices = (0..project.woices().len())
+        .map(|w| project.woice_name(w))
+        .collect::<Vec<_>>();
+    info.set_item("woices", woices)?;
+    Ok(info)
+}
+
+/// Events of a project as `(clock, unit, kind, value)`
+#[pyfunction]
+fn project_events(bytes: &[u8]) -> PyResult<Vec<(i32, u8, String, i32)>> {
+    let project = Project::parse(Cursor::new(bytes), &ParseOptions::default())?;
+    Ok(project
+        .evelist()
+        .events()
+        .iter()
+        .map(|e| (e.clock, e.unit_no, format!("{:?}", e.kind), e.value))
+        .collect())
+}
+
+#[pymodule]
+fn pxtone(m: &Bound<'_, PyModule>) -> PyResult<()> {
+    m.add_function(wrap_pyfunction!(render_noise, m)?)?;
+    m.add_function(wrap_pyfunction!(render_project, m)?)?;
+    m.add_function(wrap_pyfunction!(project_info, m)?)?;
+    m.add_function(wrap_pyfunction!(project_events, m)?)?;
+    Ok(())
+}
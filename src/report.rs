@@ -0,0 +1,206 @@
+//! Problems found while reading files, for linting assets
+
+use std::fmt;
+
+use crate::error::{ErrorKind, Result};
+use crate::options::ParseOptions;
+
+/// Problem worked around while reading
+#[derive(Clone, Debug, PartialEq)]
+pub struct Warning {
+    /// Where the value was read, such as `woice 2 / unit 0 / main`
+    pub location: String,
+    pub field: &'static str,
+    pub kind: WarningKind,
+}
+
+#[derive(Clone, Debug, PartialEq)]
+pub enum WarningKind {
+    /// Value outside `min..=max`, read as the nearest limit
+    Clamped { value: f64, min: f64, max: f64 },
+    /// Value outside `min..=max`, kept as it is
+    OutOfRange { value: f64, min: f64, max: f64 },
+    /// Flag bits the format does not define, which are dropped
+    UnknownFlags(u32),
+    /// File revision newer than the newest one known, read as that one
+    NewerVersion { found: u32, max: u32 },
+    /// Float that is infinite or NaN, read as a neutral value
+    NonFinite,
+    /// Audio of this many channels, mixed down to stereo
+    Downmixed(u16),
+}
+
+impl fmt::Display for Warning {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        if !self.location.is_empty() {
+            write!(f, "{}: ", self.location)?;
+        }
+        match self.kind {
+            WarningKind::Clamped { value, min, max }
+            | WarningKind::OutOfRange { value, min, max } => {
+                write!(f, "{} {} is out of {}..={}", self.field, value, min, max)
+            }
+            WarningKind::UnknownFlags(flags) => {
+                write!(f, "{} has unknown bits {:#x}", self.field, flags)
+            }
+            WarningKind::NewerVersion { found, max } => {
+                write!(f, "{} {} is newer than {}", self.field, found, max)
+            }
+            WarningKind::NonFinite => write!(f, "{} is not finite", self.field),
+            WarningKind::Downmixed(ch) => {
+                write!(f, "{} {} are mixed down to stereo", self.field, ch)
+            }
+        }
+    }
+}
+
+/// What reading does about a problem
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+enum Mode {
+    /// Record it and read on
+    #[default]
+    Collect,
+    /// Clamp as pxtone does, failing only on unknown flags
+    Lenient,
+    /// Fail
+    Strict,
+}
+
+/// Warnings collected by `validate`
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Report {
+    warnings: Vec<Warning>,
+    location: Vec<String>,
+    mode: Mode,
+    allow_newer: bool,
+}
+
+impl Report {
+    /// Report for reading with `options`
+    pub(crate) fn with_options(options: &ParseOptions) -> Self {
+        Self {
+            mode: if options.strict {
+                Mode::Strict
+            } else {
+                Mode::Lenient
+            },
+            allow_newer: options.allow_newer,
+            ..Self::default()
+        }
+    }
+
+    pub fn warnings(&self) -> &[Warning] {
+        &self.warnings
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.warnings.is_empty()
+    }
+
+    /// Run `read` a level deeper, at `location` added to its warnings and errors
+    pub(crate) fn within<R, F>(&mut self, location: String, read: F) -> Result<R>
+    where
+        F: FnOnce(&mut Self) -> Result<R>,
+    {
+        #[cfg(feature = "tracing")]
+        let _span = tracing::debug_span!("within", location = location.as_str()).entered();
+        self.location.push(location);
+        let result = read(self);
+        let location = self.location.pop().unwrap();
+        result.map_err(|err| err.within(&location))
+    }
+
+    fn push(&mut self, field: &'static str, kind: WarningKind, fatal: bool) -> Result<()> {
+        if fatal {
+            // the error gathers the location as it leaves each level
+            let warning = Warning {
+                location: String::new(),
+                field,
+                kind,
+            };
+            return Err(ErrorKind::InvalidData(warning).into());
+        }
+        self.warnings.push(Warning {
+            location: self.location.join(" / "),
+            field,
+            kind,
+        });
+        Ok(())
+    }
+
+    pub(crate) fn clamp<T>(&mut self, field: &'static str, value: T, min: T, max: T) -> Result<T>
+    where
+        T: PartialOrd + Copy + Into<f64>,
+    {
+        let clamped = if value < min {
+            min
+        } else if value > max {
+            max
+        } else {
+            return Ok(value);
+        };
+        let kind = WarningKind::Clamped {
+            value: value.into(),
+            min: min.into(),
+            max: max.into(),
+        };
+        self.push(field, kind, self.mode == Mode::Strict)?;
+        Ok(clamped)
+    }
+
+    pub(crate) fn check_range<T>(
+        &mut self,
+        field: &'static str,
+        value: T,
+        min: T,
+        max: T,
+    ) -> Result<()>
+    where
+        T: PartialOrd + Copy + Into<f64>,
+    {
+        if value < min || value > max {
+            let kind = WarningKind::OutOfRange {
+                value: value.into(),
+                min: min.into(),
+                max: max.into(),
+            };
+            self.push(field, kind, self.mode == Mode::Strict)?;
+        }
+        Ok(())
+    }
+
+    /// `value`, or `fallback` when it is infinite or NaN, which would spread through mixing
+    pub(crate) fn finite(&mut self, field: &'static str, value: f32, fallback: f32) -> Result<f32> {
+        if value.is_finite() {
+            return Ok(value);
+        }
+        self.push(field, WarningKind::NonFinite, self.mode == Mode::Strict)?;
+        Ok(fallback)
+    }
+
+    /// Note audio of `ch` channels mixed down to stereo, which pxtone rejects
+    pub(crate) fn downmixed(&mut self, field: &'static str, ch: u16) -> Result<()> {
+        self.push(field, WarningKind::Downmixed(ch), self.mode == Mode::Strict)
+    }
+
+    /// Fail with `UnsupportedVersion` on a revision newer than `max`, unless newer ones are
+    /// allowed or only collected
+    pub(crate) fn version(&mut self, field: &'static str, found: u32, max: u32) -> Result<()> {
+        if found <= max {
+            return Ok(());
+        }
+        if !self.allow_newer && self.mode != Mode::Collect {
+            return Err(ErrorKind::UnsupportedVersion { found, max }.into());
+        }
+        self.push(field, WarningKind::NewerVersion { found, max }, false)
+    }
+
+    /// `flags` without the `uncovered` bits, which pxtone rejects
+    pub(crate) fn flags(&mut self, field: &'static str, flags: u32, uncovered: u32) -> Result<u32> {
+        if flags & uncovered != 0 {
+            let kind = WarningKind::UnknownFlags(flags & uncovered);
+            self.push(field, kind, self.mode != Mode::Collect)?;
+        }
+        Ok(flags & !uncovered)
+    }
+}
@@ -0,0 +1,220 @@
+//! Positions in clocks, measures, seconds and samples, following tempo changes
+
+use crate::error::{ensure, Result};
+use crate::evelist::{EveList, EventKind};
+use crate::master::Master;
+
+/// Conversions between clocks and time for a song whose tempo may change
+///
+/// With a single tempo the sample positions match `Master::clock_to_sample` exactly.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ClockMap {
+    beat_num: i32,
+    beat_clock: i32,
+    /// (clock, beats per minute) in clock order, starting at 0
+    tempos: Vec<(i32, f32)>,
+}
+
+impl ClockMap {
+    /// Map keeping the master tempo throughout; beats of fewer than one clock and measures
+    /// of fewer than one beat count as one
+    pub fn new(master: &Master) -> Self {
+        Self {
+            beat_num: master.beat_num().max(1),
+            beat_clock: master.beat_clock().max(1),
+            tempos: vec![(0, master.beat_tempo())],
+        }
+    }
+
+    /// Master tempo, changed by any `BeatTempo` events in the list; events before clock 0 or
+    /// with a tempo that is not a positive number are skipped
+    pub fn with_events(master: &Master, evelist: &EveList) -> Self {
+        let mut map = Self::new(master);
+        for event in evelist.events() {
+            if event.kind == EventKind::BeatTempo {
+                let _ = map.set_tempo(event.clock, event.value_f32());
+            }
+        }
+        map
+    }
+
+    /// Change the tempo from `clock` on
+    pub fn set_tempo(&mut self, clock: i32, tempo: f32) -> Result<()> {
+        ensure(clock >= 0, "tempo clock")?;
+        ensure(tempo.is_finite() && tempo > 0.0, "tempo")?;
+        let index = self.tempos.partition_point(|&(c, _)| c < clock);
+        match self.tempos.get_mut(index) {
+            Some(change) if change.0 == clock => change.1 = tempo,
+            _ => self.tempos.insert(index, (clock, tempo)),
+        }
+        Ok(())
+    }
+
+    /// (clock, beats per minute) of each tempo, the first at clock 0
+    pub fn tempos(&self) -> &[(i32, f32)] {
+        &self.tempos
+    }
+
+    pub fn tempo_at(&self, clock: i32) -> f32 {
+        let index = self.tempos.partition_point(|&(c, _)| c <= clock);
+        self.tempos[index.max(1) - 1].1
+    }
+
+    pub fn beat_num(&self) -> i32 {
+        self.beat_num
+    }
+
+    pub fn beat_clock(&self) -> i32 {
+        self.beat_clock
+    }
+
+    pub fn meas_clock(&self, meas: i32) -> i32 {
+        self.this_clock(meas, 0, 0)
+    }
+
+    pub fn this_clock(&self, meas: i32, beat: i32, clock: i32) -> i32 {
+        self.beat_num * self.beat_clock * meas + self.beat_clock * beat + clock
+    }
+
+    /// Measure, beat in the measure and clock in the beat, counted from 0
+    pub fn position(&self, clock: i32) -> (i32, i32, i32) {
+        let meas_clock = self.meas_clock(1);
+        (
+            clock.div_euclid(meas_clock),
+            clock.rem_euclid(meas_clock) / self.beat_clock,
+            clock.rem_euclid(self.beat_clock),
+        )
+    }
+
+    /// Samples per clock at `sps` and the tempo of `clock`
+    pub fn clock_rate_at(&self, clock: i32, sps: u32) -> f32 {
+        self.clock_rate(self.tempo_at(clock), sps)
+    }
+
+    /// Samples per clock at `sps` and `tempo`, rounded as pxtone does
+    fn clock_rate(&self, tempo: f32, sps: u32) -> f32 {
+        (60.0 * f64::from(sps) / (f64::from(tempo) * f64::from(self.beat_clock))) as f32
+    }
+
+    /// Tempo spans as (start clock, end clock, tempo); the last one is open
+    fn spans(&self) -> impl Iterator<Item = (i32, i32, f32)> + '_ {
+        let ends = self.tempos[1..]
+            .iter()
+            .map(|&(c, _)| c)
+            .chain(Some(i32::MAX));
+        self.tempos
+            .iter()
+            .zip(ends)
+            .map(|(&(start, tempo), end)| (start, end, tempo))
+    }
+
+    /// Sample position of `clock`, which may be fractional
+    pub(crate) fn samples_at(&self, clock: f64, sps: u32) -> f64 {
+        let mut samples = 0.0;
+        for (start, end, tempo) in self.spans() {
+            let rate = f64::from(self.clock_rate(tempo, sps));
+            let end = f64::from(end).min(clock);
+            if end <= f64::from(start) {
+                break;
+            }
+            samples += (end - f64::from(start)) * rate;
+        }
+        samples
+    }
+
+    pub fn clock_to_sample(&self, clock: i32, sps: u32) -> i32 {
+        self.samples_at(f64::from(clock), sps) as i32
+    }
+
+    /// Clock at `sample`, saturating at `i32::MAX` past the last clock
+    pub fn sample_to_clock(&self, sample: i32, sps: u32) -> i32 {
+        self.clocks_at(f64::from(sample), sps) as i32
+    }
+
+    /// Fractional clock at `sample`, which may be fractional as well
+    pub(crate) fn clocks_at(&self, sample: f64, sps: u32) -> f64 {
+        let mut start_sample = 0.0;
+        for (start, end, tempo) in self.spans() {
+            let rate = self.clock_rate(tempo, sps);
+            let span = f64::from(end - start) * f64::from(rate);
+            if sample < start_sample + span {
+                let clocks = (sample - start_sample) as f32 / rate;
+                return f64::from(start) + f64::from(clocks);
+            }
+            start_sample += span;
+        }
+        f64::from(i32::MAX)
+    }
+
+    pub fn clock_to_secs(&self, clock: f64) -> f64 {
+        let mut secs = 0.0;
+        for (start, end, tempo) in self.spans() {
+            let end = f64::from(end).min(clock);
+            if end <= f64::from(start) {
+                break;
+            }
+            secs +=
+                (end - f64::from(start)) * 60.0 / (f64::from(tempo) * f64::from(self.beat_clock));
+        }
+        secs
+    }
+
+    /// Clock at `secs`, saturating at `i32::MAX` past the last clock
+    pub fn secs_to_clock(&self, secs: f64) -> f64 {
+        let mut start_secs = 0.0;
+        for (start, end, tempo) in self.spans() {
+            let clocks_per_sec = f64::from(tempo) * f64::from(self.beat_clock) / 60.0;
+            let span = f64::from(end - start) / clocks_per_sec;
+            if secs < start_secs + span {
+                return f64::from(start) + (secs - start_secs) * clocks_per_sec;
+            }
+            start_secs += span;
+        }
+        f64::from(i32::MAX)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn matches_master_at_one_tempo() {
+        let master = Master::new(4, 128.0, 480);
+        let map = ClockMap::new(&master);
+        for &sps in &[11025, 22050, 44100, 48000] {
+            for clock in (0..100_000).step_by(997) {
+                assert_eq!(
+                    map.clock_to_sample(clock, sps),
+                    master.clock_to_sample(clock, sps)
+                );
+            }
+            for sample in (0..10_000_000).step_by(99_991) {
+                assert_eq!(
+                    map.sample_to_clock(sample, sps),
+                    master.sample_to_clock(sample, sps)
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn follows_tempo_changes() {
+        let mut map = ClockMap::new(&Master::new(4, 120.0, 480));
+        map.set_tempo(1920, 240.0).unwrap();
+        // 22050 samples per beat, then 11025
+        assert_eq!(map.clock_to_sample(1920, 44100), 88200);
+        assert_eq!(map.clock_to_sample(2400, 44100), 99225);
+        assert_eq!(map.sample_to_clock(99225, 44100), 2400);
+        assert_eq!(map.clock_to_secs(2400.0), 2.25);
+        assert_eq!(map.secs_to_clock(2.25), 2400.0);
+    }
+
+    #[test]
+    fn saturates_past_the_last_clock() {
+        // a tenth of a sample per clock
+        let map = ClockMap::new(&Master::new(4, 10000.0, 480));
+        assert_eq!(map.sample_to_clock(i32::MAX, 8000), i32::MAX);
+        assert_eq!(map.secs_to_clock(1e9), f64::from(i32::MAX));
+    }
+}
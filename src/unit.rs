@@ -0,0 +1,158 @@
+use std::io::{Read, Write};
+
+use crate::descriptor::check_count;
+use crate::error::{ensure, Result};
+
+use byteorder::{LittleEndian, ReadBytesExt as _, WriteBytesExt as _};
+
+use crate::delay::MAX_GROUP_NUM;
+use crate::evelist::EventKind;
+
+/// Track playing one woice at a time
+#[derive(Clone, Debug, PartialEq)]
+pub struct Unit {
+    name: String,
+    played: bool,
+
+    woice: usize,
+    group: i32,
+    key: i32,
+    velocity: i32,
+    volume: i32,
+    pan_volume: i32,
+    pan_time: i32,
+    tuning: f32,
+}
+
+impl Default for Unit {
+    fn default() -> Self {
+        Self {
+            name: String::new(),
+            played: true,
+            woice: EventKind::DEFAULT_VOICENO as usize,
+            group: EventKind::DEFAULT_GROUPNO,
+            key: EventKind::DEFAULT_KEY,
+            velocity: EventKind::DEFAULT_VELOCITY,
+            volume: EventKind::DEFAULT_VOLUME,
+            pan_volume: EventKind::DEFAULT_PAN_VOLUME,
+            pan_time: EventKind::DEFAULT_PAN_TIME,
+            tuning: EventKind::DEFAULT_TUNING,
+        }
+    }
+}
+
+impl Unit {
+    pub const MAX_NUM: usize = 50;
+    pub const MAX_NAME_SIZE: usize = 16;
+
+    const NUM_SIZE: u32 = 4;
+
+    pub fn new(name: &str) -> Self {
+        let mut unit = Self::default();
+        unit.set_name(name);
+        unit
+    }
+
+    /// Number of units (`num UNIT`)
+    pub(crate) fn read_num<T: Read>(bytes: &mut T) -> Result<usize> {
+        let size = bytes.read_u32::<LittleEndian>()?;
+        ensure(size == Self::NUM_SIZE, "unit count size")?;
+
+        let num = bytes.read_i16::<LittleEndian>()?;
+        let rrr = bytes.read_i16::<LittleEndian>()?;
+        ensure(rrr == 0, "unit count reserved data")?;
+        ensure(num >= 0, "unit count")?;
+        check_count(num as u32, Self::MAX_NUM as u32)
+    }
+
+    pub(crate) fn write_num<W: Write>(bytes: &mut W, num: usize) -> Result<()> {
+        bytes.write_u32::<LittleEndian>(Self::NUM_SIZE)?;
+        bytes.write_i16::<LittleEndian>(num as i16)?;
+        bytes.write_i16::<LittleEndian>(0)?;
+        Ok(())
+    }
+
+    pub fn name(&self) -> &str {
+        &self.name
+    }
+
+    /// Set the name, truncated to `MAX_NAME_SIZE` characters
+    pub fn set_name(&mut self, name: &str) {
+        self.name = name.chars().take(Self::MAX_NAME_SIZE).collect();
+    }
+
+    pub fn played(&self) -> bool {
+        self.played
+    }
+
+    pub fn set_played(&mut self, played: bool) {
+        self.played = played;
+    }
+
+    /// Index of the woice played at the start of the song
+    pub fn woice(&self) -> usize {
+        self.woice
+    }
+
+    pub fn set_woice(&mut self, woice: usize) {
+        self.woice = woice;
+    }
+
+    pub fn group(&self) -> i32 {
+        self.group
+    }
+
+    pub fn set_group(&mut self, group: i32) {
+        self.group = group.clamp(0, MAX_GROUP_NUM - 1);
+    }
+
+    pub fn key(&self) -> i32 {
+        self.key
+    }
+
+    pub fn set_key(&mut self, key: i32) {
+        self.key = key;
+    }
+
+    pub fn velocity(&self) -> i32 {
+        self.velocity
+    }
+
+    pub fn set_velocity(&mut self, velocity: i32) {
+        self.velocity = velocity;
+    }
+
+    pub fn volume(&self) -> i32 {
+        self.volume
+    }
+
+    pub fn set_volume(&mut self, volume: i32) {
+        self.volume = volume;
+    }
+
+    /// Pan from 0 (left) to 128 (right)
+    pub fn pan_volume(&self) -> i32 {
+        self.pan_volume
+    }
+
+    pub fn set_pan_volume(&mut self, pan_volume: i32) {
+        self.pan_volume = pan_volume.clamp(0, 128);
+    }
+
+    /// Stereo delay pan from 0 (left) to 128 (right)
+    pub fn pan_time(&self) -> i32 {
+        self.pan_time
+    }
+
+    pub fn set_pan_time(&mut self, pan_time: i32) {
+        self.pan_time = pan_time.clamp(0, 128);
+    }
+
+    pub fn tuning(&self) -> f32 {
+        self.tuning
+    }
+
+    pub fn set_tuning(&mut self, tuning: f32) {
+        self.tuning = tuning;
+    }
+}
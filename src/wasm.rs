@@ -0,0 +1,78 @@
+//! JavaScript bindings for browsers and Node, built with the `wasm-bindgen` feature
+
+use std::io::Cursor;
+
+use wasm_bindgen::prelude::*;
+
+use crate::options::{ParseOptions, RenderOptions};
+use crate::project::{Project, Renderer};
+use crate::pulse::{Noise, OutputFormat, Sample as _};
+
+/// Samples of the `.ptnoise` in `bytes` as interleaved stereo floats, at 44100 Hz unless
+/// `sample_rate` is given
+#[wasm_bindgen(js_name = decodeNoise)]
+pub fn decode_noise(bytes: &[u8], sample_rate: Option<u32>) -> Result<Vec<f32>, JsError> {
+    let options = RenderOptions::new(2, sample_rate.unwrap_or(44100), OutputFormat::F32);
+    let noise = Noise::parse(Cursor::new(bytes), &ParseOptions::default())?;
+    let pcm = noise.build(&options)?;
+    Ok(pcm.frames::<f32>().flatten().collect())
+}
+
+/// Song rendered block by block, as an `AudioWorkletProcessor` asks for its outputs
+#[wasm_bindgen]
+pub struct Moo {
+    renderer: Renderer<'static>,
+}
+
+#[wasm_bindgen]
+impl Moo {
+    /// Player of the `.ptcop` or `.pttune` in `bytes` at the worklet's `sampleRate`
+    #[wasm_bindgen(constructor)]
+    pub fn new(bytes: &[u8], sample_rate: u32) -> Result<Moo, JsError> {
+        let options = RenderOptions::new(2, sample_rate, OutputFormat::F32);
+        let project = Project::parse(Cursor::new(bytes), &ParseOptions::default())?;
+        Ok(Self {
+            renderer: project.into_renderer(&options)?,
+        })
+    }
+
+    /// Fill the channel buffers of an output, zeroing what is left past the end; false once
+    /// the song has ended, for `process` to return
+    pub fn process(&mut self, left: &mut [f32], right: &mut [f32]) -> bool {
+        let mut playing = true;
+        for (l, r) in left.iter_mut().zip(right.iter_mut()) {
+            let [left, right] = match self.renderer.next() {
+                Some(frame) => frame.map(f32::from_i16),
+                None => {
+                    playing = false;
+                    [0.0; 2]
+                }
+            };
+            *l = left;
+            *r = right;
+        }
+        playing
+    }
+
+    /// Play the song `loops` times in total
+    #[wasm_bindgen(js_name = setLoops)]
+    pub fn set_loops(&mut self, loops: u32) {
+        self.renderer.set_loops(loops);
+    }
+
+    pub fn seek(&mut self, smp_count: i32) {
+        self.renderer.seek(smp_count);
+    }
+
+    /// Position of the next frame in samples
+    #[wasm_bindgen(getter = smpCount)]
+    pub fn smp_count(&self) -> i32 {
+        self.renderer.smp_count()
+    }
+
+    /// Position where the song stops, in samples
+    #[wasm_bindgen(getter = smpEnd)]
+    pub fn smp_end(&self) -> i32 {
+        self.renderer.smp_end()
+    }
+}
@@ -0,0 +1,823 @@
+use std::{
+    io::{Read, Seek, Write},
+    mem,
+    ops::Range,
+    sync::Arc,
+    time::Duration,
+};
+
+mod cache;
+
+use crate::descriptor::{read_payload, read_struct, write_sized};
+use crate::error::{ensure, Result};
+
+#[cfg(not(any(feature = "ogg", feature = "symphonia")))]
+use crate::error::ErrorKind;
+
+use byteorder::{LittleEndian, WriteBytesExt as _};
+
+use crate::delay::MAX_CHANNEL;
+use crate::envelope::{Envelope, Interpolation};
+use crate::evelist::EventKind;
+use crate::options::{
+    Clipping, Dither, Downmix, PanLaw, RenderOptions, SampleInterpolation, VelocityCurve,
+};
+use crate::pitch::{Frequency, KEY_PER_SEMITONE};
+use crate::pulse::{Noise, OggVorbis, OutputFormat, Pcm, Voice, VoiceUnit};
+use crate::report::Report;
+
+pub use cache::WoiceCache;
+
+/// Instrument assigned to units
+#[derive(Clone, Debug, PartialEq)]
+pub enum Woice {
+    Voice(Voice),
+    Noise(Noise, VoiceUnit),
+    Pcm(Pcm, VoiceUnit),
+    OggVorbis(OggVorbis, VoiceUnit),
+}
+
+impl Woice {
+    const BASIC_SPS: u32 = 44100;
+
+    pub fn from_voice(voice: Voice) -> Self {
+        Woice::Voice(voice)
+    }
+
+    pub fn from_noise(noise: Noise) -> Self {
+        Woice::Noise(noise, VoiceUnit::default())
+    }
+
+    pub fn from_pcm(pcm: Pcm) -> Self {
+        let mut unit = VoiceUnit::default();
+        // loop very short samples
+        if pcm.sec() < 0.005 {
+            unit.set_flags(unit.flags() | VoiceUnit::FLAG_WAVELOOP);
+        }
+        Woice::Pcm(pcm, unit)
+    }
+
+    pub fn from_ogg_vorbis(ogg_vorbis: OggVorbis) -> Self {
+        Woice::OggVorbis(ogg_vorbis, VoiceUnit::default())
+    }
+
+    /// Sampled woice embedded in a project file (`matePCM`)
+    pub(crate) fn read_mate_pcm<T: Read + Seek>(
+        bytes: &mut T,
+        report: &mut Report,
+        place: Option<&mut Range<u64>>,
+    ) -> Result<Self> {
+        let (_size, _x3x_unit_no, basic_key, flags, ch, bps, sps, tuning, data_size): (
+            u32,
+            u16,
+            u16,
+            u32,
+            u16,
+            u16,
+            u32,
+            f32,
+            u32,
+        ) = read_struct(bytes)?;
+
+        let smp = read_payload(bytes, data_size, place)?;
+        let pcm = Pcm::from_raw(ch, sps, bps, smp)?;
+        Ok(Woice::Pcm(
+            pcm,
+            Self::mate_unit(basic_key, flags, tuning, report)?,
+        ))
+    }
+
+    /// Synthesized woice embedded in a project file (`matePTV`)
+    pub(crate) fn read_mate_ptv<T: Read + Seek>(
+        bytes: &mut T,
+        report: &mut Report,
+    ) -> Result<Self> {
+        let (_size, _x3x_unit_no, rrr, x3x_tuning, _ptv_size): (u32, u16, u16, f32, i32) =
+            read_struct(bytes)?;
+        ensure(rrr == 0, "matePTV reserved data")?;
+
+        let x3x_tuning = report.finite("tuning", x3x_tuning, 1.0)?;
+        let mut voice = Voice::read(bytes, report)?;
+        if x3x_tuning != 1.0 {
+            voice.set_x3x_tuning(x3x_tuning);
+        }
+        Ok(Woice::Voice(voice))
+    }
+
+    /// Noise woice embedded in a project file (`matePTN`)
+    pub(crate) fn read_mate_ptn<T: Read + Seek>(
+        bytes: &mut T,
+        report: &mut Report,
+    ) -> Result<Self> {
+        let (_size, _x3x_unit_no, basic_key, flags, tuning, rrr): (u32, u16, u16, u32, f32, i32) =
+            read_struct(bytes)?;
+        ensure(rrr == 0 || rrr == 1, "matePTN reserved data")?;
+
+        let noise = Noise::read(bytes, report)?;
+        Ok(Woice::Noise(
+            noise,
+            Self::mate_unit(basic_key, flags, tuning, report)?,
+        ))
+    }
+
+    /// Ogg Vorbis woice embedded in a project file (`mateOGGV`)
+    pub(crate) fn read_mate_oggv<T: Read + Seek>(
+        bytes: &mut T,
+        report: &mut Report,
+        place: Option<&mut Range<u64>>,
+    ) -> Result<Self> {
+        let (_size, _xxx, basic_key, flags, tuning): (u32, u16, u16, u32, f32) =
+            read_struct(bytes)?;
+
+        let ogg_vorbis = OggVorbis::read(bytes, place)?;
+        Ok(Woice::OggVorbis(
+            ogg_vorbis,
+            Self::mate_unit(basic_key, flags, tuning, report)?,
+        ))
+    }
+
+    /// Body of the block embedding the woice in a project file, without its tag
+    pub(crate) fn write_mate<W: Write + Seek>(&self, bytes: &mut W) -> Result<()> {
+        match self {
+            Woice::Voice(voice) => write_sized(bytes, |bytes| {
+                bytes.write_u16::<LittleEndian>(0)?;
+                bytes.write_u16::<LittleEndian>(0)?;
+                bytes.write_f32::<LittleEndian>(voice.x3x_tuning())?;
+                write_sized(bytes, |bytes| voice.write(bytes))
+            }),
+            Woice::Noise(noise, unit) => write_sized(bytes, |bytes| {
+                bytes.write_u16::<LittleEndian>(0)?;
+                Self::write_mate_unit(bytes, unit)?;
+                bytes.write_i32::<LittleEndian>(1)?;
+                noise.write(bytes)
+            }),
+            Woice::Pcm(pcm, unit) => write_sized(bytes, |bytes| {
+                let (bps, smp) = pcm.to_raw();
+                bytes.write_u16::<LittleEndian>(0)?;
+                bytes.write_u16::<LittleEndian>(unit.basic_key() as u16)?;
+                bytes.write_u32::<LittleEndian>(unit.flags())?;
+                bytes.write_u16::<LittleEndian>(pcm.ch())?;
+                bytes.write_u16::<LittleEndian>(bps)?;
+                bytes.write_u32::<LittleEndian>(pcm.sps())?;
+                bytes.write_f32::<LittleEndian>(unit.tuning())?;
+                bytes.write_u32::<LittleEndian>(smp.len() as u32)?;
+                bytes.write_all(&smp)?;
+                Ok(())
+            }),
+            Woice::OggVorbis(ogg_vorbis, unit) => write_sized(bytes, |bytes| {
+                bytes.write_u16::<LittleEndian>(0)?;
+                Self::write_mate_unit(bytes, unit)?;
+                ogg_vorbis.write(bytes)
+            }),
+        }
+    }
+
+    /// Basic key, flags and tuning of a noise or Ogg Vorbis woice
+    fn write_mate_unit<W: Write>(bytes: &mut W, unit: &VoiceUnit) -> Result<()> {
+        bytes.write_u16::<LittleEndian>(unit.basic_key() as u16)?;
+        bytes.write_u32::<LittleEndian>(unit.flags())?;
+        bytes.write_f32::<LittleEndian>(unit.tuning())?;
+        Ok(())
+    }
+
+    fn mate_unit(
+        basic_key: u16,
+        flags: u32,
+        tuning: f32,
+        report: &mut Report,
+    ) -> Result<VoiceUnit> {
+        let flags = report.flags("flags", flags, VoiceUnit::FLAG_UNCOVERED)?;
+        let tuning = report.finite("tuning", tuning, 1.0)?;
+        let mut unit = VoiceUnit::default();
+        unit.set_basic_key(i32::from(basic_key));
+        unit.set_tuning(tuning);
+        unit.set_flags(flags);
+        Ok(unit)
+    }
+
+    /// Key the woice played at in x3x projects, where key events are relative to it
+    pub(crate) fn x3x_basic_key(&self) -> i32 {
+        match self {
+            Woice::Voice(voice) => voice.x3x_basic_key(),
+            Woice::Noise(_, unit) | Woice::Pcm(_, unit) | Woice::OggVorbis(_, unit) => {
+                unit.basic_key()
+            }
+        }
+    }
+
+    /// Unit tuning of x3x projects, or 0
+    pub(crate) fn x3x_tuning(&self) -> f32 {
+        match self {
+            Woice::Voice(voice) => voice.x3x_tuning(),
+            _ => 0.0,
+        }
+    }
+
+    /// Length of one shot of the sound; `None` for voices, which hold while keyed on
+    pub fn duration(&self) -> Option<Duration> {
+        match self {
+            Woice::Voice(_) => None,
+            Woice::Noise(noise, _) => Some(noise.duration()),
+            Woice::Pcm(pcm, _) => Some(Duration::from_secs_f64(
+                f64::from(pcm.smp_num()) / f64::from(pcm.sps()),
+            )),
+            Woice::OggVorbis(ogg_vorbis, _) => Some(Duration::from_secs_f64(
+                f64::from(ogg_vorbis.smp_num()) / f64::from(ogg_vorbis.sps()),
+            )),
+        }
+    }
+
+    pub fn voice_units(&self) -> &[VoiceUnit] {
+        match self {
+            Woice::Voice(voice) => voice.units(),
+            Woice::Noise(_, unit) | Woice::Pcm(_, unit) | Woice::OggVorbis(_, unit) => {
+                std::slice::from_ref(unit)
+            }
+        }
+    }
+
+    /// Raise the woice by `semitones` wherever it plays, by lowering the basic key of each
+    /// voice
+    pub fn transpose(&mut self, semitones: i32) {
+        for unit in self.voice_units_mut() {
+            let key = semitones.saturating_mul(KEY_PER_SEMITONE);
+            unit.set_basic_key(unit.basic_key().saturating_sub(key));
+        }
+    }
+
+    /// Key, volume and loop settings of each voice
+    pub fn voice_units_mut(&mut self) -> &mut [VoiceUnit] {
+        match self {
+            Woice::Voice(voice) => voice.units_mut(),
+            Woice::Noise(_, unit) | Woice::Pcm(_, unit) | Woice::OggVorbis(_, unit) => {
+                std::slice::from_mut(unit)
+            }
+        }
+    }
+
+    /// Render the samples and envelopes to play at the sample rate of `options`
+    pub fn ready(&self, options: &RenderOptions) -> Result<WoiceInstance> {
+        options.validate()?;
+        let sps = options.sample_rate;
+        let smps = match self {
+            Woice::Voice(voice) => voice.units().iter().map(VoiceUnit::wave_frames).collect(),
+            Woice::Noise(noise, _) => {
+                // built as pxtone does, keeping the quality and seed; gain, clipping and dither
+                // are applied to the whole song
+                let pcm = noise.build(&RenderOptions {
+                    channels: 2,
+                    sample_rate: Self::BASIC_SPS,
+                    format: OutputFormat::I16,
+                    gain: 1.0,
+                    normalize: false,
+                    loudness_target: None,
+                    clipping: Clipping::Hard,
+                    dither: Dither::None,
+                    max_duration: None,
+                    ..*options
+                })?;
+                vec![pcm.to_stereo_44k()]
+            }
+            Woice::Pcm(pcm, unit) => vec![Self::sampling_frames(pcm, unit)],
+            #[cfg(any(feature = "ogg", feature = "symphonia"))]
+            Woice::OggVorbis(ogg_vorbis, unit) => {
+                vec![Self::sampling_frames(ogg_vorbis.decode()?, unit)]
+            }
+            #[cfg(not(any(feature = "ogg", feature = "symphonia")))]
+            Woice::OggVorbis(..) => return Err(ErrorKind::OggVorbisNotSupported.into()),
+        };
+
+        let smp_head = match self {
+            Woice::Pcm(pcm, _) => pcm.loop_start_44k() as usize,
+            _ => 0,
+        };
+
+        let voices = self
+            .voice_units()
+            .iter()
+            .zip(smps)
+            .map(|(unit, smp)| {
+                let (env, env_release) = unit.envelope_table(sps);
+                let env_interpolation = unit
+                    .envelope()
+                    .map_or(Interpolation::Linear, Envelope::interpolation);
+                VoiceInstance {
+                    // a loop starting at the end plays the whole sample again
+                    smp_head: if smp_head < smp.len() { smp_head } else { 0 },
+                    smp,
+                    env,
+                    env_release,
+                    env_interpolation,
+                    basic_key: unit.basic_key(),
+                    tuning: unit.tuning(),
+                    flags: unit.flags(),
+                }
+            })
+            .collect();
+
+        Ok(WoiceInstance {
+            voices,
+            velocity_curve: options.velocity_curve,
+            sample_interpolation: options.sample_interpolation,
+            pan_law: options.pan_law,
+            downmix: options.downmix,
+        })
+    }
+
+    /// Stereo 44.1kHz frames scaled by the unit volume
+    fn sampling_frames(pcm: &Pcm, unit: &VoiceUnit) -> Vec<[i16; 2]> {
+        let mut smp = pcm.to_stereo_44k();
+        if unit.volume() != 128 {
+            for frame in smp.iter_mut() {
+                for s in frame.iter_mut() {
+                    let work = i32::from(*s) * unit.volume() / 128;
+                    *s = work.clamp(i16::MIN.into(), i16::MAX.into()) as i16;
+                }
+            }
+        }
+        smp
+    }
+
+    /// Sampler playing this woice at `key`
+    pub fn tone(&self, key: i32, options: &RenderOptions) -> Result<ToneSampler> {
+        let instance = Arc::new(self.ready(options)?);
+        let sps = options.sample_rate;
+        let mut sampler = ToneSampler::new(instance, sps, EventKind::DEFAULT_BEATTEMPO as f32);
+        sampler.set_key(key);
+        Ok(sampler)
+    }
+}
+
+/// Woice rendered for playing
+pub struct WoiceInstance {
+    voices: Vec<VoiceInstance>,
+    velocity_curve: VelocityCurve,
+    sample_interpolation: SampleInterpolation,
+    pan_law: PanLaw,
+    downmix: Downmix,
+}
+
+impl WoiceInstance {
+    /// Bytes taken by the samples and envelopes
+    pub fn byte_size(&self) -> usize {
+        self.voices
+            .iter()
+            .map(|vi| mem::size_of_val(&vi.smp[..]) + vi.env.len())
+            .sum()
+    }
+
+    /// 44.1kHz samples of each voice unit
+    #[cfg(test)]
+    pub(crate) fn voice_frames(&self) -> impl Iterator<Item = &[[i16; 2]]> {
+        self.voices.iter().map(|vi| &vi.smp[..])
+    }
+}
+
+struct VoiceInstance {
+    smp: Vec<[i16; 2]>, // 44.1kHz
+    smp_head: usize,
+    env: Vec<u8>,
+    env_release: i32,
+    env_interpolation: Interpolation,
+    basic_key: i32,
+    tuning: f32,
+    flags: u32,
+}
+
+impl VoiceInstance {
+    /// Index of the sample played after `index`, holding the last one when not looping
+    fn next_index(&self, index: usize) -> usize {
+        if index + 1 < self.smp.len() {
+            index + 1
+        } else if self.flags & VoiceUnit::FLAG_WAVELOOP != 0 {
+            self.smp_head
+        } else {
+            index
+        }
+    }
+
+    /// Frame at `pos` between samples in 16-bit scale
+    fn frame(&self, pos: f64, interpolation: SampleInterpolation) -> [i32; 2] {
+        let i1 = pos as usize;
+        let t = pos.fract();
+        let sample = |i: usize, ch: usize| f64::from(self.smp[i][ch]);
+        match interpolation {
+            SampleInterpolation::Nearest => self.smp[i1].map(i32::from),
+            SampleInterpolation::Linear => {
+                let i2 = self.next_index(i1);
+                [0, 1].map(|ch| {
+                    let (s1, s2) = (sample(i1, ch), sample(i2, ch));
+                    (s1 + (s2 - s1) * t).round() as i32
+                })
+            }
+            SampleInterpolation::Cubic => {
+                let i0 = i1.saturating_sub(1);
+                let i2 = self.next_index(i1);
+                let i3 = self.next_index(i2);
+                [0, 1].map(|ch| {
+                    let (s0, s1) = (sample(i0, ch), sample(i1, ch));
+                    let (s2, s3) = (sample(i2, ch), sample(i3, ch));
+                    let a = -0.5 * s0 + 1.5 * s1 - 1.5 * s2 + 0.5 * s3;
+                    let b = s0 - 2.5 * s1 + 2.0 * s2 - 0.5 * s3;
+                    let c = -0.5 * s0 + 0.5 * s2;
+                    (((a * t + b) * t + c) * t + s1).round() as i32
+                })
+            }
+        }
+    }
+}
+
+#[derive(Clone, Default)]
+struct VoiceTone {
+    smp_pos: f64,
+    offset_freq: f32,
+    env_volume: i32,
+    env_start: i32,
+    env_pos: i32,
+    life_count: i32,
+    on_count: i32,
+}
+
+/// Pitched playback state of a woice
+#[derive(Clone)]
+pub struct ToneSampler {
+    instance: Arc<WoiceInstance>,
+    smp_stride: f32,
+    smooth_smp: i32,
+
+    key_now: i32,
+    key_start: i32,
+    key_margin: i32,
+    portament_sample_num: i32,
+    portament_sample_pos: i32,
+
+    pan_vols: [i32; MAX_CHANNEL],
+    velocity: i32,
+    volume: i32,
+    tuning: f32,
+
+    tones: Vec<VoiceTone>,
+}
+
+impl ToneSampler {
+    pub(crate) fn new(instance: Arc<WoiceInstance>, sps: u32, beat_tempo: f32) -> Self {
+        let mut sampler = Self {
+            instance,
+            smp_stride: Woice::BASIC_SPS as f32 / sps as f32,
+            smooth_smp: (sps / 250) as i32,
+            key_now: EventKind::DEFAULT_KEY,
+            key_start: EventKind::DEFAULT_KEY,
+            key_margin: 0,
+            portament_sample_num: 0,
+            portament_sample_pos: 0,
+            pan_vols: [64; MAX_CHANNEL],
+            velocity: EventKind::DEFAULT_VELOCITY,
+            volume: EventKind::DEFAULT_VOLUME,
+            tuning: EventKind::DEFAULT_TUNING,
+            tones: Vec::new(),
+        };
+        sampler.reset_voice(beat_tempo);
+        sampler
+    }
+
+    fn reset_voice(&mut self, beat_tempo: f32) {
+        self.tones = self
+            .instance
+            .voices
+            .iter()
+            .map(|vi| {
+                let offset_freq = if vi.flags & VoiceUnit::FLAG_BEATFIT != 0 {
+                    (vi.smp.len() as f32 * beat_tempo) / (44100.0 * 60.0 * vi.tuning)
+                } else {
+                    let key = EventKind::DEFAULT_BASICKEY.saturating_sub(vi.basic_key);
+                    Frequency::get(key) * vi.tuning
+                };
+                VoiceTone {
+                    offset_freq,
+                    ..VoiceTone::default()
+                }
+            })
+            .collect();
+    }
+
+    pub(crate) fn instance(&self) -> &Arc<WoiceInstance> {
+        &self.instance
+    }
+
+    /// Switch to another woice, as a VoiceNo event does
+    pub(crate) fn set_instance(&mut self, instance: Arc<WoiceInstance>, beat_tempo: f32) {
+        self.instance = instance;
+        self.key_now = EventKind::DEFAULT_KEY;
+        self.key_start = EventKind::DEFAULT_KEY;
+        self.key_margin = 0;
+        self.reset_voice(beat_tempo);
+    }
+
+    /// Start a note held for `on_count` samples, followed by the envelope release
+    pub fn key_on(&mut self, on_count: i32) {
+        self.key_on_with(on_count, |env_release| on_count + env_release);
+    }
+
+    /// `key_on` with the life of each enveloped voice given by its release length
+    pub(crate) fn key_on_with<F: Fn(i32) -> i32>(&mut self, on_count: i32, life_count: F) {
+        if on_count <= 0 {
+            self.zero_lives();
+            return;
+        }
+
+        self.key_now = self.key_start + self.key_margin;
+        self.key_start = self.key_now;
+        self.key_margin = 0;
+
+        for (vt, vi) in self.tones.iter_mut().zip(&self.instance.voices) {
+            if vi.smp.is_empty() {
+                continue;
+            }
+            vt.life_count = if vi.env_release != 0 {
+                life_count(vi.env_release)
+            } else {
+                on_count
+            };
+            if vt.life_count <= 0 {
+                continue;
+            }
+            vt.on_count = on_count;
+            vt.smp_pos = 0.0;
+            vt.env_pos = 0;
+            vt.env_start = if vi.env.is_empty() { 128 } else { 0 };
+            vt.env_volume = vt.env_start;
+        }
+    }
+
+    pub fn zero_lives(&mut self) {
+        for vt in self.tones.iter_mut() {
+            vt.life_count = 0;
+        }
+    }
+
+    /// Whether any voice is still sounding
+    pub fn is_alive(&self) -> bool {
+        self.tones.iter().any(|vt| vt.life_count > 0)
+    }
+
+    /// Whether a note is held, before its release
+    pub fn is_on(&self) -> bool {
+        self.tones
+            .iter()
+            .any(|vt| vt.life_count > 0 && vt.on_count > 0)
+    }
+
+    /// Key sounding now, between the old and new keys during portamento
+    pub fn key(&self) -> i32 {
+        self.key_now
+    }
+
+    /// Key reached at the end of a portamento
+    pub fn target_key(&self) -> i32 {
+        self.key_start + self.key_margin
+    }
+
+    /// How far a portamento has gone from 0.0 to 1.0; 1.0 when not gliding
+    pub fn glide(&self) -> f32 {
+        if self.portament_sample_num != 0 && self.key_margin != 0 {
+            self.portament_sample_pos as f32 / self.portament_sample_num as f32
+        } else {
+            1.0
+        }
+    }
+
+    pub fn velocity(&self) -> i32 {
+        self.velocity
+    }
+
+    /// Frequency factor of `Tuning` events
+    pub fn tuning(&self) -> f32 {
+        self.tuning
+    }
+
+    pub fn set_key(&mut self, key: i32) {
+        self.key_start = self.key_now;
+        self.key_margin = key.saturating_sub(self.key_start);
+        self.portament_sample_pos = 0;
+    }
+
+    /// Samples taken to glide to a new key
+    pub fn set_portament(&mut self, sample_num: i32) {
+        self.portament_sample_num = sample_num;
+    }
+
+    pub fn set_pan_volume(&mut self, ch: u16, pan: i32) {
+        self.pan_vols = [64; MAX_CHANNEL];
+        if ch == 2 && self.instance.pan_law != PanLaw::Original {
+            let levels = self.instance.pan_law.levels(f64::from(pan - 64) / 64.0);
+            self.pan_vols = levels.map(|level| (level * 64.0).round() as i32);
+        } else if ch == 2 {
+            if pan >= 64 {
+                self.pan_vols[0] = 128 - pan;
+            } else {
+                self.pan_vols[1] = pan;
+            }
+        }
+    }
+
+    pub fn set_velocity(&mut self, velocity: i32) {
+        self.velocity = velocity;
+    }
+
+    pub fn set_volume(&mut self, volume: i32) {
+        self.volume = volume;
+    }
+
+    pub fn set_tuning(&mut self, tuning: f32) {
+        self.tuning = tuning;
+    }
+
+    /// Advance the envelopes; called before `sample`
+    pub fn envelope(&mut self) {
+        for (vt, vi) in self.tones.iter_mut().zip(&self.instance.voices) {
+            if vt.life_count <= 0 || vi.env.is_empty() {
+                continue;
+            }
+            if vt.on_count > 0 {
+                if let Some(&volume) = vi.env.get(vt.env_pos as usize) {
+                    vt.env_volume = i32::from(volume);
+                    vt.env_pos += 1;
+                }
+            } else {
+                // release
+                let len = vi.env_release.max(1);
+                vt.env_volume = match vi.env_interpolation {
+                    Interpolation::Linear => vt.env_start - vt.env_start * vt.env_pos / len,
+                    interpolation => {
+                        let pos = vt.env_pos.min(len);
+                        interpolation.level(f64::from(vt.env_start), 0.0, pos, len) as i32
+                    }
+                };
+                vt.env_pos += 1;
+            }
+        }
+    }
+
+    /// Current sample of each channel in 16-bit scale
+    pub fn sample(&self, ch_num: u16) -> [i32; MAX_CHANNEL] {
+        let mut smps = [0; MAX_CHANNEL];
+        for (ch, smp) in smps.iter_mut().enumerate() {
+            for (vt, vi) in self.tones.iter().zip(&self.instance.voices) {
+                if vt.life_count <= 0 {
+                    continue;
+                }
+                let frame = vi.frame(vt.smp_pos, self.instance.sample_interpolation);
+                let mut work = frame[ch];
+                if ch_num == 1 {
+                    work = match self.instance.downmix {
+                        Downmix::Original | Downmix::Average => (work + frame[1 - ch]) / 2,
+                        downmix => downmix.apply(f64::from(work), f64::from(frame[1 - ch])) as i32,
+                    };
+                }
+
+                work = self
+                    .instance
+                    .velocity_curve
+                    .apply(work, self.velocity, self.volume);
+                work = work * self.pan_vols[ch] / 64;
+
+                if !vi.env.is_empty() {
+                    work = work * vt.env_volume / 128;
+                }
+
+                // smooth tail
+                if vi.flags & VoiceUnit::FLAG_SMOOTH != 0 && vt.life_count < self.smooth_smp {
+                    work = work * vt.life_count / self.smooth_smp;
+                }
+                *smp += work;
+            }
+        }
+        smps
+    }
+
+    /// Move to the next sample, following portamento
+    pub fn increment(&mut self) {
+        let freq = Frequency::get2(self.increment_key()) * self.smp_stride;
+
+        for (vt, vi) in self.tones.iter_mut().zip(&self.instance.voices) {
+            if vt.life_count > 0 {
+                vt.life_count -= 1;
+            }
+            if vt.life_count <= 0 {
+                continue;
+            }
+            vt.on_count -= 1;
+
+            vt.smp_pos += f64::from(vt.offset_freq * self.tuning * freq);
+
+            let smp_end = vi.smp.len() as f64;
+            if vt.smp_pos >= smp_end {
+                if vi.flags & VoiceUnit::FLAG_WAVELOOP != 0 {
+                    let smp_head = vi.smp_head as f64;
+                    vt.smp_pos -= smp_end - smp_head;
+                    if vt.smp_pos >= smp_end {
+                        vt.smp_pos = smp_head;
+                    }
+                } else {
+                    vt.life_count = 0;
+                }
+            }
+
+            // OFF
+            if vt.on_count == 0 && !vi.env.is_empty() {
+                vt.env_start = vt.env_volume;
+                vt.env_pos = 0;
+            }
+        }
+    }
+
+    fn increment_key(&mut self) -> i32 {
+        if self.portament_sample_num != 0 && self.key_margin != 0 {
+            if self.portament_sample_pos < self.portament_sample_num {
+                self.portament_sample_pos += 1;
+                self.key_now = (f64::from(self.key_start)
+                    + f64::from(self.key_margin) * f64::from(self.portament_sample_pos)
+                        / f64::from(self.portament_sample_num))
+                    as i32;
+            } else {
+                self.key_now = self.key_start + self.key_margin;
+                self.key_start = self.key_now;
+                self.key_margin = 0;
+            }
+        } else {
+            self.key_now = self.key_start + self.key_margin;
+        }
+        self.key_now
+    }
+}
+
+impl Iterator for ToneSampler {
+    type Item = [i16; MAX_CHANNEL];
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if !self.is_alive() {
+            return None;
+        }
+        self.envelope();
+        let smps = self.sample(2);
+        self.increment();
+        Some(smps.map(|smp| smp.clamp(i16::MIN.into(), i16::MAX.into()) as i16))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use std::io::Cursor;
+
+    #[test]
+    fn loops_from_the_end_of_a_sample() {
+        let samples = (0..1000).map(|i| f64::from(i * 32 - 16000));
+        let mut pcm = Pcm::from_samples(1, 44100, OutputFormat::I16, samples).unwrap();
+        pcm.set_loop_start(1000);
+        let mut woice = Woice::from_pcm(pcm);
+        woice.voice_units_mut()[0].set_flags(VoiceUnit::FLAG_WAVELOOP);
+
+        let mut tone = woice
+            .tone(EventKind::DEFAULT_KEY, &RenderOptions::default())
+            .unwrap();
+        tone.key_on(4000);
+        let frames = tone.take(3000).collect::<Vec<_>>();
+        assert_eq!(frames.len(), 3000);
+        assert_eq!(frames[1000], frames[0]);
+    }
+
+    #[test]
+    fn plays_keys_out_of_range() {
+        let samples = (0..1000).map(|i| f64::from(i * 32 - 16000));
+        let pcm = Pcm::from_samples(1, 44100, OutputFormat::I16, samples).unwrap();
+        for &(key, next) in &[(i32::MIN, i32::MAX), (i32::MAX, i32::MIN)] {
+            let mut woice = Woice::from_pcm(pcm.clone());
+            woice.voice_units_mut()[0].set_basic_key(key);
+            woice.transpose(key);
+            let mut tone = woice.tone(key, &RenderOptions::default()).unwrap();
+            tone.key_on(100);
+            tone.set_portament(50);
+            tone.set_key(next);
+            tone.take(100).for_each(drop);
+        }
+    }
+
+    #[test]
+    fn noises_ignore_song_level_settings() {
+        let bytes = include_bytes!("../resources/drum_bass1.ptnoise");
+        let woice = Woice::from_noise(Noise::new(Cursor::new(&bytes[..])).unwrap());
+        let frames = |options: RenderOptions| woice.ready(&options).unwrap().voices[0].smp.clone();
+        let plain = frames(RenderOptions::default());
+        assert_eq!(frames(RenderOptions::default().with_normalize(true)), plain);
+        assert_eq!(
+            frames(RenderOptions::default().with_loudness_target(Some(-14.0))),
+            plain
+        );
+        assert_eq!(
+            frames(RenderOptions::default().with_dither(Dither::Tpdf)),
+            plain
+        );
+    }
+}
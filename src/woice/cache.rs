@@ -0,0 +1,208 @@
+use std::collections::HashMap;
+use std::io::Cursor;
+use std::sync::Arc;
+
+use super::{Woice, WoiceInstance};
+
+use crate::envelope::Envelope;
+use crate::error::Result;
+use crate::options::{Downmix, PanLaw, RenderOptions, SampleInterpolation, Upmix, VelocityCurve};
+use crate::pulse::Quality;
+
+/// Woice content and the render settings readying depends on, compared in full so that
+/// woices whose hashes collide are kept apart
+#[derive(Clone, PartialEq, Eq, Hash)]
+struct Key {
+    mate: Vec<u8>,
+    /// Volume and envelope of each voice unit, which the project block leaves out
+    units: Vec<(i32, Option<Envelope>)>,
+    loop_start: Option<u32>,
+
+    sample_rate: u32,
+    quality: Quality,
+    random_seed: Option<u32>,
+    noise_table_size: u32,
+    /// `VelocityCurve` variant and decibel range bits
+    velocity_curve: (u8, u32),
+    sample_interpolation: SampleInterpolation,
+    pan_law: PanLaw,
+    downmix: Downmix,
+    upmix: Upmix,
+}
+
+struct Entry {
+    instance: Arc<WoiceInstance>,
+    size: usize,
+    used: u64,
+}
+
+/// Readied woices kept for reuse, keyed by their content and the render settings readying
+/// depends on; the least recently used are dropped beyond a size budget
+pub struct WoiceCache {
+    entries: HashMap<Key, Entry>,
+    budget: usize,
+    size: usize,
+    tick: u64,
+}
+
+impl WoiceCache {
+    /// Cache holding up to `budget` bytes of samples and envelopes, with the woice blocks
+    /// they were readied from
+    pub fn new(budget: usize) -> Self {
+        Self {
+            entries: HashMap::new(),
+            budget,
+            size: 0,
+            tick: 0,
+        }
+    }
+
+    /// `woice.ready(options)`, shared with earlier calls for equal woices and settings
+    pub fn ready(&mut self, woice: &Woice, options: &RenderOptions) -> Result<Arc<WoiceInstance>> {
+        let key = Self::key(woice, options)?;
+        self.tick += 1;
+        if let Some(entry) = self.entries.get_mut(&key) {
+            entry.used = self.tick;
+            return Ok(entry.instance.clone());
+        }
+
+        let instance = Arc::new(woice.ready(options)?);
+        let size = instance.byte_size() + key.mate.len();
+        if size > self.budget {
+            return Ok(instance);
+        }
+        while self.size + size > self.budget {
+            self.evict();
+        }
+        self.size += size;
+        self.entries.insert(
+            key,
+            Entry {
+                instance: instance.clone(),
+                size,
+                used: self.tick,
+            },
+        );
+        Ok(instance)
+    }
+
+    fn evict(&mut self) {
+        let oldest = self
+            .entries
+            .iter()
+            .min_by_key(|(_, entry)| entry.used)
+            .map(|(key, _)| key.clone());
+        if let Some(entry) = oldest.and_then(|key| self.entries.remove(&key)) {
+            self.size -= entry.size;
+        }
+    }
+
+    fn key(woice: &Woice, options: &RenderOptions) -> Result<Key> {
+        let mut mate = Cursor::new(Vec::new());
+        woice.write_mate(&mut mate)?;
+        let units = woice
+            .voice_units()
+            .iter()
+            .map(|unit| (unit.volume(), unit.envelope().cloned()))
+            .collect();
+        let loop_start = match woice {
+            Woice::Pcm(pcm, _) => Some(pcm.loop_start()),
+            _ => None,
+        };
+        let velocity_curve = match options.velocity_curve {
+            VelocityCurve::Original => (0, 0),
+            VelocityCurve::Linear => (1, 0),
+            VelocityCurve::Decibel(range) => (2, range.to_bits()),
+        };
+        Ok(Key {
+            mate: mate.into_inner(),
+            units,
+            loop_start,
+            sample_rate: options.sample_rate,
+            quality: options.quality,
+            random_seed: options.random_seed,
+            noise_table_size: options.noise_table_size,
+            velocity_curve,
+            sample_interpolation: options.sample_interpolation,
+            pan_law: options.pan_law,
+            downmix: options.downmix,
+            upmix: options.upmix,
+        })
+    }
+
+    /// Woices held
+    pub fn len(&self) -> usize {
+        self.entries.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.entries.is_empty()
+    }
+
+    /// Bytes held, up to the budget
+    pub fn size(&self) -> usize {
+        self.size
+    }
+
+    pub fn budget(&self) -> usize {
+        self.budget
+    }
+
+    /// Drop the least recently used woices down to `budget`
+    pub fn set_budget(&mut self, budget: usize) {
+        self.budget = budget;
+        while self.size > self.budget {
+            self.evict();
+        }
+    }
+
+    pub fn clear(&mut self) {
+        self.entries.clear();
+        self.size = 0;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    use crate::pulse::{OutputFormat, Voice};
+
+    fn voice() -> Woice {
+        let bytes = include_bytes!("../../resources/reference/waves.ptvoice");
+        Woice::from_voice(Voice::new(Cursor::new(&bytes[..])).unwrap())
+    }
+
+    #[test]
+    fn shares_equal_woices() {
+        let options = RenderOptions::default();
+        let mut cache = WoiceCache::new(usize::MAX);
+        let first = cache.ready(&voice(), &options).unwrap();
+        let second = cache.ready(&voice(), &options).unwrap();
+        assert!(Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.len(), 1);
+        assert!(cache.size() > first.byte_size());
+
+        let options = RenderOptions::new(2, 22050, OutputFormat::I16);
+        let other = cache.ready(&voice(), &options).unwrap();
+        assert!(!Arc::ptr_eq(&first, &other));
+        assert_eq!(cache.len(), 2);
+    }
+
+    #[test]
+    fn keeps_envelopes_apart() {
+        let options = RenderOptions::default();
+        let mut cache = WoiceCache::new(usize::MAX);
+        let with_release = |release| {
+            let mut woice = voice();
+            for unit in woice.voice_units_mut() {
+                unit.set_envelope(Some(Envelope::new(1000, vec![(10, 128)], release)));
+            }
+            woice
+        };
+        let first = cache.ready(&with_release(100), &options).unwrap();
+        let second = cache.ready(&with_release(200), &options).unwrap();
+        assert!(!Arc::ptr_eq(&first, &second));
+        assert_eq!(cache.len(), 2);
+    }
+}